@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "reservations";
+DROP TABLE IF EXISTS "stock_movements";
+DROP TABLE IF EXISTS "stock_balances";
+DROP TABLE IF EXISTS "products";
+DROP TABLE IF EXISTS "locations";
+DROP TABLE IF EXISTS "warehouses";
+
+DROP TYPE reservation_status;
+DROP TYPE document_type;
+DROP TYPE movement_kind;
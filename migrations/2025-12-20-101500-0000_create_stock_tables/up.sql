@@ -0,0 +1,78 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'warehouse';
+ALTER TYPE resource_type ADD VALUE 'location';
+ALTER TYPE resource_type ADD VALUE 'product';
+ALTER TYPE resource_type ADD VALUE 'stock';
+ALTER TYPE resource_type ADD VALUE 'reservation';
+
+CREATE TYPE movement_kind AS ENUM ('receipt', 'issue', 'adjustment', 'transfer');
+
+CREATE TYPE document_type AS ENUM ('sales_order');
+
+CREATE TYPE reservation_status AS ENUM ('active', 'released', 'consumed', 'expired');
+
+CREATE TABLE "warehouses"
+(
+    "id"   UUID               NOT NULL PRIMARY KEY,
+    "code" VARCHAR(32) UNIQUE NOT NULL,
+    "name" VARCHAR(256)       NOT NULL
+);
+
+CREATE TABLE "locations"
+(
+    "id"           UUID        NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID        NOT NULL REFERENCES warehouses (id) ON DELETE CASCADE,
+    "code"         VARCHAR(64) NOT NULL,
+    UNIQUE ("warehouse_id", "code")
+);
+
+CREATE TABLE "products"
+(
+    "id"          UUID               NOT NULL PRIMARY KEY,
+    "sku"         VARCHAR(64) UNIQUE NOT NULL,
+    "name"        VARCHAR(256)       NOT NULL,
+    "description" TEXT
+);
+
+CREATE TABLE "stock_balances"
+(
+    "id"          UUID           NOT NULL PRIMARY KEY,
+    "product_id"  UUID           NOT NULL REFERENCES products (id),
+    "location_id" UUID           NOT NULL REFERENCES locations (id),
+    "on_hand"     NUMERIC(18, 6) NOT NULL DEFAULT 0 CHECK ("on_hand" >= 0),
+    UNIQUE ("product_id", "location_id")
+);
+
+CREATE TABLE "stock_movements"
+(
+    "id"               UUID           NOT NULL PRIMARY KEY,
+    "kind"             movement_kind  NOT NULL,
+    "product_id"       UUID           NOT NULL REFERENCES products (id),
+    "from_location_id" UUID REFERENCES locations (id),
+    "to_location_id"   UUID REFERENCES locations (id),
+    "quantity"         NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "document_type"    document_type,
+    "document_id"      UUID,
+    "created_by"       UUID REFERENCES users (id),
+    "created_at"       TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK ("from_location_id" IS NOT NULL OR "to_location_id" IS NOT NULL)
+);
+
+CREATE INDEX "stock_movements_product_id_idx" ON "stock_movements" ("product_id", "created_at");
+
+CREATE TABLE "reservations"
+(
+    "id"            UUID               NOT NULL PRIMARY KEY,
+    "product_id"    UUID               NOT NULL REFERENCES products (id),
+    "location_id"   UUID               NOT NULL REFERENCES locations (id),
+    "quantity"      NUMERIC(18, 6)     NOT NULL CHECK ("quantity" >= 0),
+    "status"        reservation_status NOT NULL DEFAULT 'active',
+    "document_type" document_type      NOT NULL,
+    "document_id"   UUID               NOT NULL,
+    "expires_at"    TIMESTAMPTZ,
+    "created_by"    UUID REFERENCES users (id),
+    "created_at"    TIMESTAMPTZ        NOT NULL DEFAULT now()
+);
+
+CREATE INDEX "reservations_active_idx" ON "reservations" ("product_id", "location_id") WHERE "status" = 'active';
+CREATE INDEX "reservations_document_idx" ON "reservations" ("document_type", "document_id");
@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "count_tasks";
+DROP TABLE IF EXISTS "count_sessions";
+
+ALTER TABLE "products"
+    DROP COLUMN "abc_class";
+
+ALTER TABLE "locations"
+    DROP COLUMN "zone";
+
+DROP TYPE count_task_status;
+DROP TYPE count_session_status;
+DROP TYPE count_scope;
+DROP TYPE abc_class;
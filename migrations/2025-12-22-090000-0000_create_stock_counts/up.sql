@@ -0,0 +1,53 @@
+-- Your SQL goes here
+ALTER TYPE resource_action ADD VALUE 'approve';
+ALTER TYPE resource_type ADD VALUE 'stock_count';
+ALTER TYPE document_type ADD VALUE 'stock_count';
+
+CREATE TYPE abc_class AS ENUM ('a', 'b', 'c');
+
+CREATE TYPE count_scope AS ENUM ('zone', 'abc_class', 'full');
+
+CREATE TYPE count_session_status AS ENUM ('open', 'completed', 'cancelled');
+
+CREATE TYPE count_task_status AS ENUM ('pending', 'recount', 'counted', 'approved');
+
+ALTER TABLE "locations"
+    ADD COLUMN "zone" VARCHAR(32);
+
+ALTER TABLE "products"
+    ADD COLUMN "abc_class" abc_class;
+
+CREATE TABLE "count_sessions"
+(
+    "id"                UUID                 NOT NULL PRIMARY KEY,
+    "warehouse_id"      UUID                 NOT NULL REFERENCES warehouses (id),
+    "scope"             count_scope          NOT NULL,
+    "zone"              VARCHAR(32),
+    "abc_class"         abc_class,
+    "blind"             BOOLEAN              NOT NULL DEFAULT FALSE,
+    "tolerance_percent" NUMERIC(5, 2)        NOT NULL DEFAULT 0 CHECK ("tolerance_percent" >= 0),
+    "status"            count_session_status NOT NULL DEFAULT 'open',
+    "created_by"        UUID REFERENCES users (id),
+    "created_at"        TIMESTAMPTZ          NOT NULL DEFAULT now(),
+    "completed_at"      TIMESTAMPTZ,
+    CHECK ("scope" <> 'zone' OR "zone" IS NOT NULL),
+    CHECK ("scope" <> 'abc_class' OR "abc_class" IS NOT NULL)
+);
+
+CREATE TABLE "count_tasks"
+(
+    "id"                UUID              NOT NULL PRIMARY KEY,
+    "session_id"        UUID              NOT NULL REFERENCES count_sessions (id) ON DELETE CASCADE,
+    "product_id"        UUID              NOT NULL REFERENCES products (id),
+    "location_id"       UUID              NOT NULL REFERENCES locations (id),
+    "expected_quantity" NUMERIC(18, 6)    NOT NULL,
+    "counted_quantity"  NUMERIC(18, 6) CHECK ("counted_quantity" >= 0),
+    "variance"          NUMERIC(18, 6),
+    "attempts"          SMALLINT          NOT NULL DEFAULT 0,
+    "status"            count_task_status NOT NULL DEFAULT 'pending',
+    "counted_by"        UUID REFERENCES users (id),
+    "counted_at"        TIMESTAMPTZ,
+    "approved_by"       UUID REFERENCES users (id),
+    "movement_id"       UUID REFERENCES stock_movements (id),
+    UNIQUE ("session_id", "product_id", "location_id")
+);
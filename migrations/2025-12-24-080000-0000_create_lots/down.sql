@@ -0,0 +1,21 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE "count_tasks"
+    DROP CONSTRAINT "count_tasks_session_id_product_id_location_id_lot_id_key",
+    DROP COLUMN "lot_id",
+    ADD CONSTRAINT "count_tasks_session_id_product_id_location_id_key"
+        UNIQUE ("session_id", "product_id", "location_id");
+
+ALTER TABLE "stock_movements"
+    DROP COLUMN "lot_id";
+
+ALTER TABLE "stock_balances"
+    DROP CONSTRAINT "stock_balances_product_id_location_id_lot_id_key",
+    DROP COLUMN "lot_id",
+    ADD CONSTRAINT "stock_balances_product_id_location_id_key"
+        UNIQUE ("product_id", "location_id");
+
+DROP TABLE IF EXISTS "lots";
+
+ALTER TABLE "products"
+    DROP COLUMN "expiry_tracked",
+    DROP COLUMN "lot_tracked";
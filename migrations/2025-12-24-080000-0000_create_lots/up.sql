@@ -0,0 +1,35 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'lot';
+
+ALTER TABLE "products"
+    ADD COLUMN "lot_tracked"    BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN "expiry_tracked" BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE "lots"
+(
+    "id"          UUID        NOT NULL PRIMARY KEY,
+    "product_id"  UUID        NOT NULL REFERENCES products (id),
+    "lot_number"  VARCHAR(64),
+    "expiry_date" DATE,
+    "blocked"     BOOLEAN     NOT NULL DEFAULT FALSE,
+    "created_at"  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CHECK ("lot_number" IS NOT NULL OR "expiry_date" IS NOT NULL),
+    UNIQUE NULLS NOT DISTINCT ("product_id", "lot_number", "expiry_date")
+);
+
+CREATE INDEX "lots_expiry_date_idx" ON "lots" ("expiry_date") WHERE "expiry_date" IS NOT NULL;
+
+ALTER TABLE "stock_balances"
+    ADD COLUMN "lot_id" UUID REFERENCES lots (id),
+    DROP CONSTRAINT "stock_balances_product_id_location_id_key",
+    ADD CONSTRAINT "stock_balances_product_id_location_id_lot_id_key"
+        UNIQUE NULLS NOT DISTINCT ("product_id", "location_id", "lot_id");
+
+ALTER TABLE "stock_movements"
+    ADD COLUMN "lot_id" UUID REFERENCES lots (id);
+
+ALTER TABLE "count_tasks"
+    ADD COLUMN "lot_id" UUID REFERENCES lots (id),
+    DROP CONSTRAINT "count_tasks_session_id_product_id_location_id_key",
+    ADD CONSTRAINT "count_tasks_session_id_product_id_location_id_lot_id_key"
+        UNIQUE NULLS NOT DISTINCT ("session_id", "product_id", "location_id", "lot_id");
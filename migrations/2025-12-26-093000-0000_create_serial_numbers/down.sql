@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "stock_movement_serials";
+DROP TABLE IF EXISTS "serial_numbers";
+
+ALTER TABLE "products"
+    DROP COLUMN "serial_tracked";
+
+DROP TYPE serial_status;
@@ -0,0 +1,32 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'serial_number';
+
+CREATE TYPE serial_status AS ENUM ('in_stock', 'issued', 'written_off');
+
+ALTER TABLE "products"
+    ADD COLUMN "serial_tracked" BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE "serial_numbers"
+(
+    "id"            UUID          NOT NULL PRIMARY KEY,
+    "product_id"    UUID          NOT NULL REFERENCES products (id),
+    "serial_number" VARCHAR(128)  NOT NULL,
+    "status"        serial_status NOT NULL DEFAULT 'in_stock',
+    "location_id"   UUID REFERENCES locations (id),
+    "lot_id"        UUID REFERENCES lots (id),
+    "created_at"    TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    "updated_at"    TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    UNIQUE ("product_id", "serial_number"),
+    CHECK ("status" <> 'in_stock' OR "location_id" IS NOT NULL)
+);
+
+CREATE INDEX "serial_numbers_location_id_idx" ON "serial_numbers" ("location_id", "product_id");
+
+CREATE TABLE "stock_movement_serials"
+(
+    "movement_id"      UUID NOT NULL REFERENCES stock_movements (id),
+    "serial_number_id" UUID NOT NULL REFERENCES serial_numbers (id),
+    PRIMARY KEY ("movement_id", "serial_number_id")
+);
+
+CREATE INDEX "stock_movement_serials_serial_number_id_idx" ON "stock_movement_serials" ("serial_number_id");
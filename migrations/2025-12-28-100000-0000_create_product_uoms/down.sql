@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "product_uoms";
+
+ALTER TABLE "products"
+    DROP COLUMN "fractional_quantities",
+    DROP COLUMN "base_uom";
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+ALTER TABLE "products"
+    ADD COLUMN "base_uom"              VARCHAR(16) NOT NULL DEFAULT 'ea',
+    ADD COLUMN "fractional_quantities" BOOLEAN     NOT NULL DEFAULT FALSE;
+
+CREATE TABLE "product_uoms"
+(
+    "id"         UUID           NOT NULL PRIMARY KEY,
+    "product_id" UUID           NOT NULL REFERENCES products (id) ON DELETE CASCADE,
+    "code"       VARCHAR(16)    NOT NULL,
+    "factor"     NUMERIC(18, 6) NOT NULL CHECK ("factor" > 0),
+    "barcode"    VARCHAR(64) UNIQUE,
+    UNIQUE ("product_id", "code")
+);
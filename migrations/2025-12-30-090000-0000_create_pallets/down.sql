@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "pallets";
+DROP SEQUENCE IF EXISTS "pallet_serial_reference";
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'pallet';
+
+-- Serial references of SSCCs allocated to pallets, unique within the company prefix.
+CREATE SEQUENCE "pallet_serial_reference";
+
+CREATE TABLE "pallets"
+(
+    "id"          UUID        NOT NULL PRIMARY KEY,
+    "sscc"        CHAR(18)    NOT NULL UNIQUE,
+    "location_id" UUID REFERENCES locations (id),
+    "created_at"  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "transfer_order_lines";
+DROP TABLE IF EXISTS "transfer_orders";
+
+ALTER TABLE "locations"
+    DROP COLUMN "in_transit";
+
+DROP TYPE transfer_order_status;
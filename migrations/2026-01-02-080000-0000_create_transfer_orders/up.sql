@@ -0,0 +1,37 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'transfer_order';
+ALTER TYPE document_type ADD VALUE 'transfer_order';
+
+CREATE TYPE transfer_order_status AS ENUM ('open', 'shipped', 'partially_received', 'received', 'closed');
+
+-- Virtual locations holding shipped stock until it is received at the destination.
+ALTER TABLE "locations"
+    ADD COLUMN "in_transit" BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE "transfer_orders"
+(
+    "id"                       UUID                  NOT NULL PRIMARY KEY,
+    "source_warehouse_id"      UUID                  NOT NULL REFERENCES warehouses (id),
+    "destination_warehouse_id" UUID                  NOT NULL REFERENCES warehouses (id),
+    "transit_location_id"      UUID REFERENCES locations (id),
+    "status"                   transfer_order_status NOT NULL DEFAULT 'open',
+    "created_by"               UUID REFERENCES users (id),
+    "created_at"               TIMESTAMPTZ           NOT NULL DEFAULT now(),
+    "shipped_at"               TIMESTAMPTZ,
+    "closed_at"                TIMESTAMPTZ,
+    CHECK ("source_warehouse_id" <> "destination_warehouse_id")
+);
+
+CREATE TABLE "transfer_order_lines"
+(
+    "id"                   UUID           NOT NULL PRIMARY KEY,
+    "transfer_order_id"    UUID           NOT NULL REFERENCES transfer_orders (id) ON DELETE CASCADE,
+    "product_id"           UUID           NOT NULL REFERENCES products (id),
+    "from_location_id"     UUID           NOT NULL REFERENCES locations (id),
+    "quantity"             NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "received_quantity"    NUMERIC(18, 6) NOT NULL DEFAULT 0,
+    "written_off_quantity" NUMERIC(18, 6) NOT NULL DEFAULT 0,
+    CHECK ("received_quantity" + "written_off_quantity" <= "quantity")
+);
+
+CREATE INDEX "transfer_order_lines_transfer_order_id_idx" ON "transfer_order_lines" ("transfer_order_id");
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "replenishment_suggestions";
+DROP TABLE IF EXISTS "purchase_orders";
+DROP TABLE IF EXISTS "replenishment_rules";
+
+DROP TYPE suggestion_status;
+DROP TYPE replenishment_kind;
@@ -0,0 +1,58 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'replenishment';
+ALTER TYPE document_type ADD VALUE 'purchase_order';
+
+CREATE TYPE replenishment_kind AS ENUM ('purchase', 'pick_face');
+CREATE TYPE suggestion_status AS ENUM ('pending', 'accepted', 'rejected', 'superseded');
+
+-- A rule without a location keeps the warehouse stocked through purchasing, a rule with a
+-- location keeps that pick face stocked from the rest of the warehouse.
+CREATE TABLE "replenishment_rules"
+(
+    "id"               UUID           NOT NULL PRIMARY KEY,
+    "product_id"       UUID           NOT NULL REFERENCES products (id),
+    "warehouse_id"     UUID           NOT NULL REFERENCES warehouses (id),
+    "location_id"      UUID REFERENCES locations (id),
+    "reorder_point"    NUMERIC(18, 6) NOT NULL CHECK ("reorder_point" >= 0),
+    "max_quantity"     NUMERIC(18, 6) CHECK ("max_quantity" > "reorder_point"),
+    "reorder_quantity" NUMERIC(18, 6) CHECK ("reorder_quantity" > 0),
+    "updated_at"       TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK ("max_quantity" IS NOT NULL OR "reorder_quantity" IS NOT NULL),
+    UNIQUE NULLS NOT DISTINCT ("product_id", "warehouse_id", "location_id")
+);
+
+CREATE TABLE "purchase_orders"
+(
+    "id"           UUID           NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID           NOT NULL REFERENCES warehouses (id),
+    "product_id"   UUID           NOT NULL REFERENCES products (id),
+    "quantity"     NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "created_by"   UUID REFERENCES users (id),
+    "created_at"   TIMESTAMPTZ    NOT NULL DEFAULT now()
+);
+
+CREATE INDEX "purchase_orders_product_id_warehouse_id_idx" ON "purchase_orders" ("product_id", "warehouse_id");
+
+CREATE TABLE "replenishment_suggestions"
+(
+    "id"               UUID               NOT NULL PRIMARY KEY,
+    "rule_id"          UUID               NOT NULL REFERENCES replenishment_rules (id) ON DELETE CASCADE,
+    "kind"             replenishment_kind NOT NULL,
+    "product_id"       UUID               NOT NULL REFERENCES products (id),
+    "warehouse_id"     UUID               NOT NULL REFERENCES warehouses (id),
+    "location_id"      UUID REFERENCES locations (id),
+    "from_location_id" UUID REFERENCES locations (id),
+    "quantity"         NUMERIC(18, 6)     NOT NULL CHECK ("quantity" > 0),
+    "available"        NUMERIC(18, 6)     NOT NULL,
+    "inbound"          NUMERIC(18, 6)     NOT NULL,
+    "status"           suggestion_status  NOT NULL DEFAULT 'pending',
+    "created_by"       UUID REFERENCES users (id),
+    "created_at"       TIMESTAMPTZ        NOT NULL DEFAULT now(),
+    "reviewed_by"      UUID REFERENCES users (id),
+    "reviewed_at"      TIMESTAMPTZ,
+    "document_type"    document_type,
+    "document_id"      UUID
+);
+
+CREATE INDEX "replenishment_suggestions_warehouse_id_status_idx" ON "replenishment_suggestions" ("warehouse_id", "status");
+CREATE UNIQUE INDEX "replenishment_suggestions_pending_rule_id_idx" ON "replenishment_suggestions" ("rule_id") WHERE "status" = 'pending';
@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "cost_layers";
+
+ALTER TABLE "stock_movements"
+    DROP COLUMN "total_cost",
+    DROP COLUMN "unit_cost";
+
+ALTER TABLE "products"
+    DROP COLUMN "costing_method";
+
+DROP TABLE IF EXISTS "valuation_settings";
+
+DROP TYPE costing_method;
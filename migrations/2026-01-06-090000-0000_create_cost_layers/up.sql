@@ -0,0 +1,43 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'valuation';
+
+CREATE TYPE costing_method AS ENUM ('fifo', 'weighted_average');
+
+-- Company-wide settings, a single row.
+CREATE TABLE "valuation_settings"
+(
+    "id"             BOOLEAN        NOT NULL PRIMARY KEY DEFAULT TRUE CHECK ("id"),
+    "costing_method" costing_method NOT NULL DEFAULT 'fifo'
+);
+
+INSERT INTO "valuation_settings" DEFAULT VALUES;
+
+-- Overrides the company costing method when set.
+ALTER TABLE "products"
+    ADD COLUMN "costing_method" costing_method;
+
+-- Inbound movements carry the cost of what they brought in, outbound movements the cost
+-- of goods issued. Movements between locations are not valued.
+ALTER TABLE "stock_movements"
+    ADD COLUMN "unit_cost"  NUMERIC(20, 6),
+    ADD COLUMN "total_cost" NUMERIC(20, 6);
+
+-- Stock still valued, oldest first. Products valued at weighted average keep a single layer.
+CREATE TABLE "cost_layers"
+(
+    "id"                 UUID           NOT NULL PRIMARY KEY,
+    "product_id"         UUID           NOT NULL REFERENCES products (id),
+    "movement_id"        UUID REFERENCES stock_movements (id),
+    "created_at"         TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    "remaining_quantity" NUMERIC(18, 6) NOT NULL CHECK ("remaining_quantity" >= 0),
+    "remaining_value"    NUMERIC(20, 6) NOT NULL CHECK ("remaining_value" >= 0)
+);
+
+CREATE INDEX "cost_layers_product_id_created_at_idx" ON "cost_layers" ("product_id", "created_at") WHERE "remaining_quantity" > 0;
+
+-- Stock received before costs were captured is valued at zero.
+INSERT INTO "cost_layers" ("id", "product_id", "remaining_quantity", "remaining_value")
+SELECT gen_random_uuid(), "product_id", SUM("on_hand"), 0
+FROM "stock_balances"
+GROUP BY "product_id"
+HAVING SUM("on_hand") > 0;
@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "return_inspections";
+DROP TABLE IF EXISTS "return_authorization_lines";
+DROP TABLE IF EXISTS "return_authorizations";
+
+ALTER TABLE "locations"
+    DROP COLUMN "quarantine";
+
+DROP TYPE return_disposition;
+DROP TYPE return_reason;
+DROP TYPE return_status;
@@ -0,0 +1,54 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'return_authorization';
+ALTER TYPE document_type ADD VALUE 'return_authorization';
+
+CREATE TYPE return_status AS ENUM ('open', 'completed', 'closed');
+CREATE TYPE return_reason AS ENUM ('damaged', 'defective', 'wrong_item', 'not_as_described', 'no_longer_needed', 'other');
+CREATE TYPE return_disposition AS ENUM ('restock', 'refurbish', 'scrap', 'return_to_vendor');
+
+-- Stock in quarantine locations is on hand but cannot be promised or reserved.
+ALTER TABLE "locations"
+    ADD COLUMN "quarantine" BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE "return_authorizations"
+(
+    "id"             UUID          NOT NULL PRIMARY KEY,
+    "sales_order_id" UUID          NOT NULL,
+    "warehouse_id"   UUID          NOT NULL REFERENCES warehouses (id),
+    "status"         return_status NOT NULL DEFAULT 'open',
+    "created_by"     UUID REFERENCES users (id),
+    "created_at"     TIMESTAMPTZ   NOT NULL DEFAULT now(),
+    "closed_at"      TIMESTAMPTZ
+);
+
+CREATE INDEX "return_authorizations_sales_order_id_idx" ON "return_authorizations" ("sales_order_id");
+
+CREATE TABLE "return_authorization_lines"
+(
+    "id"                      UUID           NOT NULL PRIMARY KEY,
+    "return_authorization_id" UUID           NOT NULL REFERENCES return_authorizations (id) ON DELETE CASCADE,
+    "product_id"              UUID           NOT NULL REFERENCES products (id),
+    "reason"                  return_reason  NOT NULL,
+    "note"                    VARCHAR(512),
+    "quantity"                NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "received_quantity"       NUMERIC(18, 6) NOT NULL DEFAULT 0,
+    "inspected_quantity"      NUMERIC(18, 6) NOT NULL DEFAULT 0,
+    CHECK ("received_quantity" <= "quantity"),
+    CHECK ("inspected_quantity" <= "received_quantity")
+);
+
+CREATE INDEX "return_authorization_lines_return_authorization_id_idx" ON "return_authorization_lines" ("return_authorization_id");
+
+CREATE TABLE "return_inspections"
+(
+    "id"             UUID               NOT NULL PRIMARY KEY,
+    "line_id"        UUID               NOT NULL REFERENCES return_authorization_lines (id) ON DELETE CASCADE,
+    "quantity"       NUMERIC(18, 6)     NOT NULL CHECK ("quantity" > 0),
+    "disposition"    return_disposition NOT NULL,
+    "to_location_id" UUID REFERENCES locations (id),
+    "note"           VARCHAR(512),
+    "inspected_by"   UUID REFERENCES users (id),
+    "inspected_at"   TIMESTAMPTZ        NOT NULL DEFAULT now()
+);
+
+CREATE INDEX "return_inspections_line_id_idx" ON "return_inspections" ("line_id");
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "assembly_order_lines";
+DROP TABLE IF EXISTS "assembly_orders";
+DROP TABLE IF EXISTS "bom_components";
+
+DROP TYPE assembly_order_status;
+DROP TYPE assembly_kind;
@@ -0,0 +1,43 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'kit';
+ALTER TYPE resource_type ADD VALUE 'assembly_order';
+ALTER TYPE document_type ADD VALUE 'assembly_order';
+
+CREATE TYPE assembly_kind AS ENUM ('assembly', 'disassembly');
+CREATE TYPE assembly_order_status AS ENUM ('open', 'completed', 'cancelled');
+
+-- Bill of materials: base units of each component that go into one unit of the kit.
+CREATE TABLE "bom_components"
+(
+    "kit_product_id"       UUID           NOT NULL REFERENCES products (id) ON DELETE CASCADE,
+    "component_product_id" UUID           NOT NULL REFERENCES products (id),
+    "quantity"             NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    PRIMARY KEY ("kit_product_id", "component_product_id"),
+    CHECK ("kit_product_id" <> "component_product_id")
+);
+
+CREATE TABLE "assembly_orders"
+(
+    "id"               UUID                  NOT NULL PRIMARY KEY,
+    "kind"             assembly_kind         NOT NULL,
+    "kit_product_id"   UUID                  NOT NULL REFERENCES products (id),
+    "warehouse_id"     UUID                  NOT NULL REFERENCES warehouses (id),
+    "from_location_id" UUID                  NOT NULL REFERENCES locations (id),
+    "to_location_id"   UUID                  NOT NULL REFERENCES locations (id),
+    "quantity"         NUMERIC(18, 6)        NOT NULL CHECK ("quantity" > 0),
+    "status"           assembly_order_status NOT NULL DEFAULT 'open',
+    "created_by"       UUID REFERENCES users (id),
+    "created_at"       TIMESTAMPTZ           NOT NULL DEFAULT now(),
+    "completed_at"     TIMESTAMPTZ
+);
+
+-- Components of the order, copied from the bill of materials when the order is created.
+CREATE TABLE "assembly_order_lines"
+(
+    "id"                UUID           NOT NULL PRIMARY KEY,
+    "assembly_order_id" UUID           NOT NULL REFERENCES assembly_orders (id) ON DELETE CASCADE,
+    "product_id"        UUID           NOT NULL REFERENCES products (id),
+    "quantity"          NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0)
+);
+
+CREATE INDEX "assembly_order_lines_assembly_order_id_idx" ON "assembly_order_lines" ("assembly_order_id");
@@ -0,0 +1,17 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "putaway_tasks";
+DROP TABLE IF EXISTS "putaway_rules";
+
+ALTER TABLE "locations"
+    DROP COLUMN "position_y",
+    DROP COLUMN "position_x",
+    DROP COLUMN "max_weight",
+    DROP COLUMN "max_volume";
+
+ALTER TABLE "products"
+    DROP COLUMN "unit_weight",
+    DROP COLUMN "unit_volume",
+    DROP COLUMN "category";
+
+DROP TYPE putaway_task_status;
+DROP TYPE putaway_strategy;
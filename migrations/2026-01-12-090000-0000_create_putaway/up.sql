@@ -0,0 +1,53 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'putaway';
+
+CREATE TYPE putaway_strategy AS ENUM ('fixed_location', 'consolidate', 'empty_location', 'category_zone', 'capacity');
+CREATE TYPE putaway_task_status AS ENUM ('open', 'completed', 'cancelled');
+
+-- Volume in cubic metres and weight in kilograms of one base unit.
+ALTER TABLE "products"
+    ADD COLUMN "category"    VARCHAR(64),
+    ADD COLUMN "unit_volume" NUMERIC(18, 6) CHECK ("unit_volume" >= 0),
+    ADD COLUMN "unit_weight" NUMERIC(18, 6) CHECK ("unit_weight" >= 0);
+
+-- Capacity of the location and its position on the warehouse floor plan, in metres.
+ALTER TABLE "locations"
+    ADD COLUMN "max_volume" NUMERIC(18, 6) CHECK ("max_volume" >= 0),
+    ADD COLUMN "max_weight" NUMERIC(18, 6) CHECK ("max_weight" >= 0),
+    ADD COLUMN "position_x" NUMERIC(10, 2),
+    ADD COLUMN "position_y" NUMERIC(10, 2);
+
+CREATE TABLE "putaway_rules"
+(
+    "id"           UUID             NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID             NOT NULL REFERENCES warehouses (id) ON DELETE CASCADE,
+    "sequence"     INTEGER          NOT NULL,
+    "strategy"     putaway_strategy NOT NULL,
+    "product_id"   UUID REFERENCES products (id) ON DELETE CASCADE,
+    "category"     VARCHAR(64),
+    "location_id"  UUID REFERENCES locations (id) ON DELETE CASCADE,
+    "zone"         VARCHAR(32),
+    UNIQUE ("warehouse_id", "sequence"),
+    CHECK ("strategy" <> 'fixed_location' OR "location_id" IS NOT NULL),
+    CHECK ("strategy" <> 'category_zone' OR ("category" IS NOT NULL AND "zone" IS NOT NULL))
+);
+
+CREATE TABLE "putaway_tasks"
+(
+    "id"                    UUID                NOT NULL PRIMARY KEY,
+    "warehouse_id"          UUID                NOT NULL REFERENCES warehouses (id),
+    "product_id"            UUID                NOT NULL REFERENCES products (id),
+    "from_location_id"      UUID                NOT NULL REFERENCES locations (id),
+    "quantity"              NUMERIC(18, 6)      NOT NULL CHECK ("quantity" > 0),
+    "suggested_location_id" UUID REFERENCES locations (id),
+    "strategy"              putaway_strategy,
+    "to_location_id"        UUID REFERENCES locations (id),
+    "status"                putaway_task_status NOT NULL DEFAULT 'open',
+    "movement_id"           UUID REFERENCES stock_movements (id),
+    "created_by"            UUID REFERENCES users (id),
+    "created_at"            TIMESTAMPTZ         NOT NULL DEFAULT now(),
+    "completed_by"          UUID REFERENCES users (id),
+    "completed_at"          TIMESTAMPTZ
+);
+
+CREATE INDEX "putaway_tasks_warehouse_id_status_idx" ON "putaway_tasks" ("warehouse_id", "status");
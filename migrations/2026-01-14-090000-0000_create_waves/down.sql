@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "pick_tasks";
+DROP TABLE IF EXISTS "pick_orders";
+DROP TABLE IF EXISTS "waves";
+
+ALTER TABLE "locations"
+    DROP COLUMN "pick_sequence";
+
+DROP TYPE pick_task_status;
+DROP TYPE wave_status;
+DROP TYPE pick_order_status;
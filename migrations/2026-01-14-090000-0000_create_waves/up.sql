@@ -0,0 +1,60 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'pick_order';
+ALTER TYPE resource_type ADD VALUE 'wave';
+
+CREATE TYPE pick_order_status AS ENUM ('released', 'waved', 'picked', 'cancelled');
+CREATE TYPE wave_status AS ENUM ('open', 'completed', 'cancelled');
+CREATE TYPE pick_task_status AS ENUM ('open', 'picked', 'cancelled');
+
+-- Configured walking order of pick locations, ahead of the floor plan positions.
+ALTER TABLE "locations"
+    ADD COLUMN "pick_sequence" INTEGER;
+
+CREATE TABLE "waves"
+(
+    "id"           UUID        NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID        NOT NULL REFERENCES warehouses (id),
+    "status"       wave_status NOT NULL DEFAULT 'open',
+    "totes"        INTEGER CHECK ("totes" > 0),
+    "created_by"   UUID REFERENCES users (id),
+    "created_at"   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    "completed_at" TIMESTAMPTZ
+);
+
+-- Sales order released to the warehouse for picking. Its lines are the active
+-- reservations of the order.
+CREATE TABLE "pick_orders"
+(
+    "id"           UUID              NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID              NOT NULL REFERENCES warehouses (id),
+    "document_id"  UUID              NOT NULL,
+    "priority"     INTEGER           NOT NULL DEFAULT 0,
+    "carrier"      VARCHAR(64),
+    "cutoff_at"    TIMESTAMPTZ,
+    "status"       pick_order_status NOT NULL DEFAULT 'released',
+    "wave_id"      UUID REFERENCES waves (id),
+    "tote"         INTEGER,
+    "created_by"   UUID REFERENCES users (id),
+    "created_at"   TIMESTAMPTZ       NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX "pick_orders_document_id_idx" ON "pick_orders" ("document_id") WHERE "status" <> 'cancelled';
+CREATE INDEX "pick_orders_warehouse_id_status_idx" ON "pick_orders" ("warehouse_id", "status");
+
+CREATE TABLE "pick_tasks"
+(
+    "id"              UUID             NOT NULL PRIMARY KEY,
+    "wave_id"         UUID             NOT NULL REFERENCES waves (id) ON DELETE CASCADE,
+    "pick_order_id"   UUID             NOT NULL REFERENCES pick_orders (id),
+    "reservation_id"  UUID             NOT NULL REFERENCES reservations (id),
+    "product_id"      UUID             NOT NULL REFERENCES products (id),
+    "location_id"     UUID             NOT NULL REFERENCES locations (id),
+    "quantity"        NUMERIC(18, 6)   NOT NULL CHECK ("quantity" > 0),
+    "picked_quantity" NUMERIC(18, 6) CHECK ("picked_quantity" >= 0),
+    "sequence"        INTEGER          NOT NULL,
+    "tote"            INTEGER,
+    "status"          pick_task_status NOT NULL DEFAULT 'open',
+    "picked_by"       UUID REFERENCES users (id),
+    "picked_at"       TIMESTAMPTZ,
+    UNIQUE ("wave_id", "sequence")
+);
@@ -0,0 +1,23 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE "stock_movements"
+    DROP COLUMN "reason",
+    DROP COLUMN "to_status",
+    DROP COLUMN "status";
+
+-- Held stock goes back to the single balance of its product, location and lot.
+INSERT INTO "stock_balances" ("id", "product_id", "location_id", "lot_id", "on_hand", "status")
+SELECT gen_random_uuid(), "product_id", "location_id", "lot_id", "on_hand", 'available'
+FROM "stock_balances"
+WHERE "status" <> 'available'
+ON CONFLICT ON CONSTRAINT "stock_balances_product_id_location_id_lot_id_status_key"
+    DO UPDATE SET "on_hand" = "stock_balances"."on_hand" + EXCLUDED."on_hand";
+
+DELETE FROM "stock_balances" WHERE "status" <> 'available';
+
+ALTER TABLE "stock_balances"
+    DROP CONSTRAINT "stock_balances_product_id_location_id_lot_id_status_key",
+    DROP COLUMN "status",
+    ADD CONSTRAINT "stock_balances_product_id_location_id_lot_id_key"
+        UNIQUE NULLS NOT DISTINCT ("product_id", "location_id", "lot_id");
+
+DROP TYPE stock_status;
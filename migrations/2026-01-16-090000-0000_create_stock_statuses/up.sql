@@ -0,0 +1,20 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'stock_hold';
+ALTER TYPE movement_kind ADD VALUE 'status_change';
+
+CREATE TYPE stock_status AS ENUM ('available', 'quarantine', 'damaged', 'on_hold');
+
+-- Only available stock can be reserved or allocated.
+ALTER TABLE "stock_balances"
+    ADD COLUMN "status" stock_status NOT NULL DEFAULT 'available',
+    DROP CONSTRAINT "stock_balances_product_id_location_id_lot_id_key",
+    ADD CONSTRAINT "stock_balances_product_id_location_id_lot_id_status_key"
+        UNIQUE NULLS NOT DISTINCT ("product_id", "location_id", "lot_id", "status");
+
+-- `status` is the status of the stock moved, `to_status` the one status changes put it in.
+-- `kind` is compared as text, a new enum value cannot be used in the transaction adding it.
+ALTER TABLE "stock_movements"
+    ADD COLUMN "status"    stock_status NOT NULL DEFAULT 'available',
+    ADD COLUMN "to_status" stock_status,
+    ADD COLUMN "reason"    VARCHAR(256),
+    ADD CHECK ("kind"::text <> 'status_change' OR ("to_status" IS NOT NULL AND "to_status" <> "status"));
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "stock_snapshot_lines";
+DROP TABLE IF EXISTS "stock_snapshots";
+
+DROP INDEX IF EXISTS "stock_movements_created_at_idx";
+
+DROP TYPE snapshot_kind;
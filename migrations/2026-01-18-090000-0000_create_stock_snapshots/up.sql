@@ -0,0 +1,32 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'stock_snapshot';
+
+CREATE TYPE snapshot_kind AS ENUM ('daily', 'month_end', 'manual');
+
+-- As-of queries read the movements posted after the latest snapshot.
+CREATE INDEX "stock_movements_created_at_idx" ON "stock_movements" ("created_at");
+
+-- Stock on hand at the end of `as_of`, taken by the daily job at midnight UTC or on demand.
+CREATE TABLE "stock_snapshots"
+(
+    "id"         UUID          NOT NULL PRIMARY KEY,
+    "kind"       snapshot_kind NOT NULL,
+    "as_of"      TIMESTAMPTZ   NOT NULL,
+    "created_by" UUID REFERENCES users (id),
+    "created_at" TIMESTAMPTZ   NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX "stock_snapshots_as_of_idx" ON "stock_snapshots" ("as_of") WHERE "kind" <> 'manual';
+CREATE INDEX "stock_snapshots_kind_as_of_idx" ON "stock_snapshots" ("kind", "as_of");
+
+CREATE TABLE "stock_snapshot_lines"
+(
+    "id"          UUID           NOT NULL PRIMARY KEY,
+    "snapshot_id" UUID           NOT NULL REFERENCES stock_snapshots (id) ON DELETE CASCADE,
+    "product_id"  UUID           NOT NULL REFERENCES products (id),
+    "location_id" UUID           NOT NULL REFERENCES locations (id),
+    "lot_id"      UUID REFERENCES lots (id),
+    "status"      stock_status   NOT NULL,
+    "quantity"    NUMERIC(18, 6) NOT NULL,
+    UNIQUE NULLS NOT DISTINCT ("snapshot_id", "product_id", "location_id", "lot_id", "status")
+);
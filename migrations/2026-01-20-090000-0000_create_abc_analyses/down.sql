@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "slotting_recommendations";
+DROP TABLE IF EXISTS "abc_analysis_lines";
+DROP TABLE IF EXISTS "abc_analyses";
+
+DROP TYPE slotting_status;
+DROP TYPE abc_criterion;
@@ -0,0 +1,60 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'abc_analysis';
+
+CREATE TYPE abc_criterion AS ENUM ('pick_frequency', 'value');
+CREATE TYPE slotting_status AS ENUM ('pending', 'accepted', 'superseded');
+
+-- Classification of the products of a warehouse by their issues over a window. Shares are
+-- fractions of the total score: products making up the first `a_share` are class A, up
+-- to `b_share` class B, the rest class C.
+CREATE TABLE "abc_analyses"
+(
+    "id"           UUID           NOT NULL PRIMARY KEY,
+    "warehouse_id" UUID           NOT NULL REFERENCES warehouses (id),
+    "criterion"    abc_criterion  NOT NULL,
+    "window_days"  INTEGER        NOT NULL CHECK ("window_days" > 0),
+    "period_start" TIMESTAMPTZ    NOT NULL,
+    "period_end"   TIMESTAMPTZ    NOT NULL,
+    "a_share"      NUMERIC(7, 6)  NOT NULL,
+    "b_share"      NUMERIC(7, 6)  NOT NULL,
+    "applied_at"   TIMESTAMPTZ,
+    "created_by"   UUID REFERENCES users (id),
+    "created_at"   TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK ("a_share" > 0 AND "a_share" <= "b_share" AND "b_share" <= 1)
+);
+
+CREATE INDEX "abc_analyses_warehouse_id_idx" ON "abc_analyses" ("warehouse_id", "created_at");
+
+CREATE TABLE "abc_analysis_lines"
+(
+    "id"               UUID           NOT NULL PRIMARY KEY,
+    "analysis_id"      UUID           NOT NULL REFERENCES abc_analyses (id) ON DELETE CASCADE,
+    "product_id"       UUID           NOT NULL REFERENCES products (id),
+    "rank"             INTEGER        NOT NULL,
+    "score"            NUMERIC(18, 6) NOT NULL,
+    "share"            NUMERIC(7, 6)  NOT NULL,
+    "cumulative_share" NUMERIC(7, 6)  NOT NULL,
+    "abc_class"        abc_class      NOT NULL,
+    UNIQUE ("analysis_id", "product_id"),
+    UNIQUE ("analysis_id", "rank")
+);
+
+-- Moves a fast mover into a more accessible location, swapping the product stored there
+-- into the location it leaves.
+CREATE TABLE "slotting_recommendations"
+(
+    "id"                   UUID            NOT NULL PRIMARY KEY,
+    "analysis_id"          UUID            NOT NULL REFERENCES abc_analyses (id) ON DELETE CASCADE,
+    "warehouse_id"         UUID            NOT NULL REFERENCES warehouses (id),
+    "product_id"           UUID            NOT NULL REFERENCES products (id),
+    "rank"                 INTEGER         NOT NULL,
+    "from_location_id"     UUID            NOT NULL REFERENCES locations (id),
+    "to_location_id"       UUID            NOT NULL REFERENCES locations (id),
+    "displaced_product_id" UUID REFERENCES products (id),
+    "status"               slotting_status NOT NULL DEFAULT 'pending',
+    "reviewed_by"          UUID REFERENCES users (id),
+    "reviewed_at"          TIMESTAMPTZ,
+    CHECK ("from_location_id" <> "to_location_id")
+);
+
+CREATE INDEX "slotting_recommendations_warehouse_id_status_idx" ON "slotting_recommendations" ("warehouse_id", "status");
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "cross_docks";
+DROP TABLE IF EXISTS "outbound_demands";
+
+DROP TYPE demand_status;
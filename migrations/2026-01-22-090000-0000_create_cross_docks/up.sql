@@ -0,0 +1,48 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'cross_dock';
+
+CREATE TYPE demand_status AS ENUM ('open', 'fulfilled', 'cancelled');
+
+-- Quantity an outbound document still needs from a warehouse and could not be allocated
+-- from stock. Receipts of the product can be cross-docked against it.
+CREATE TABLE "outbound_demands"
+(
+    "id"                 UUID           NOT NULL PRIMARY KEY,
+    "warehouse_id"       UUID           NOT NULL REFERENCES warehouses (id),
+    "document_type"      document_type  NOT NULL,
+    "document_id"        UUID           NOT NULL,
+    "product_id"         UUID           NOT NULL REFERENCES products (id),
+    "quantity"           NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "allocated_quantity" NUMERIC(18, 6) NOT NULL DEFAULT 0 CHECK ("allocated_quantity" >= 0),
+    "due_at"             TIMESTAMPTZ,
+    "status"             demand_status  NOT NULL DEFAULT 'open',
+    "created_by"         UUID REFERENCES users (id),
+    "created_at"         TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK ("allocated_quantity" <= "quantity")
+);
+
+CREATE INDEX "outbound_demands_warehouse_id_product_id_idx" ON "outbound_demands" ("warehouse_id", "product_id") WHERE "status" = 'open';
+CREATE INDEX "outbound_demands_document_idx" ON "outbound_demands" ("document_type", "document_id");
+
+-- Received stock routed from the receiving location straight to a dock staging location,
+-- where it is reserved for the outbound document of the demand. The transfers posted
+-- carry the outbound document.
+CREATE TABLE "cross_docks"
+(
+    "id"                    UUID           NOT NULL PRIMARY KEY,
+    "demand_id"             UUID           NOT NULL REFERENCES outbound_demands (id),
+    "warehouse_id"          UUID           NOT NULL REFERENCES warehouses (id),
+    "product_id"            UUID           NOT NULL REFERENCES products (id),
+    "from_location_id"      UUID           NOT NULL REFERENCES locations (id),
+    "staging_location_id"   UUID           NOT NULL REFERENCES locations (id),
+    "quantity"              NUMERIC(18, 6) NOT NULL CHECK ("quantity" > 0),
+    "inbound_document_type" document_type,
+    "inbound_document_id"   UUID,
+    "reservation_id"        UUID           NOT NULL REFERENCES reservations (id),
+    "created_by"            UUID REFERENCES users (id),
+    "created_at"            TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK (("inbound_document_type" IS NULL) = ("inbound_document_id" IS NULL))
+);
+
+CREATE INDEX "cross_docks_demand_id_idx" ON "cross_docks" ("demand_id");
+CREATE INDEX "cross_docks_inbound_document_idx" ON "cross_docks" ("inbound_document_type", "inbound_document_id");
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "parcels";
+DROP TABLE IF EXISTS "shipments";
+
+DROP TYPE shipment_status;
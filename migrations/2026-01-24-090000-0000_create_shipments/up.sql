@@ -0,0 +1,49 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'shipment';
+
+CREATE TYPE shipment_status AS ENUM ('open', 'shipped', 'cancelled');
+
+-- Packed goods handed to a carrier. Parcels are added while the shipment is open; shipping
+-- books the carrier service and stores the tracking number and label of every parcel.
+CREATE TABLE "shipments"
+(
+    "id"                  UUID            NOT NULL PRIMARY KEY,
+    "warehouse_id"        UUID            NOT NULL REFERENCES warehouses (id),
+    "pick_order_id"       UUID REFERENCES pick_orders (id),
+    "document_id"         UUID,
+    "ship_to_name"        VARCHAR(128)    NOT NULL,
+    "ship_to_address"     VARCHAR(512)    NOT NULL,
+    "ship_to_postal_code" VARCHAR(16)     NOT NULL,
+    "ship_to_country"     CHAR(2)         NOT NULL,
+    "carrier"             VARCHAR(64),
+    "service"             VARCHAR(64),
+    "shipping_cost"       NUMERIC(18, 6),
+    "currency"            CHAR(3),
+    "status"              shipment_status NOT NULL DEFAULT 'open',
+    "shipped_by"          UUID REFERENCES users (id),
+    "shipped_at"          TIMESTAMPTZ,
+    "created_by"          UUID REFERENCES users (id),
+    "created_at"          TIMESTAMPTZ     NOT NULL DEFAULT now(),
+    CHECK (("shipping_cost" IS NULL) = ("currency" IS NULL))
+);
+
+CREATE INDEX "shipments_warehouse_id_status_idx" ON "shipments" ("warehouse_id", "status");
+CREATE INDEX "shipments_document_id_idx" ON "shipments" ("document_id");
+
+CREATE TABLE "parcels"
+(
+    "id"                 UUID           NOT NULL PRIMARY KEY,
+    "shipment_id"        UUID           NOT NULL REFERENCES shipments (id) ON DELETE CASCADE,
+    "length_cm"          NUMERIC(10, 2) NOT NULL CHECK ("length_cm" > 0),
+    "width_cm"           NUMERIC(10, 2) NOT NULL CHECK ("width_cm" > 0),
+    "height_cm"          NUMERIC(10, 2) NOT NULL CHECK ("height_cm" > 0),
+    "weight_kg"          NUMERIC(10, 3) NOT NULL CHECK ("weight_kg" > 0),
+    "tracking_number"    VARCHAR(64),
+    "label"              BYTEA,
+    "label_content_type" VARCHAR(64),
+    "created_at"         TIMESTAMPTZ    NOT NULL DEFAULT now(),
+    CHECK (("label" IS NULL) = ("label_content_type" IS NULL))
+);
+
+CREATE INDEX "parcels_shipment_id_idx" ON "parcels" ("shipment_id");
+CREATE INDEX "parcels_tracking_number_idx" ON "parcels" ("tracking_number");
@@ -0,0 +1,63 @@
+-- This file should undo anything in `up.sql`
+DELETE
+FROM "valuation_settings"
+WHERE "organization_id" <> '00000000-0000-0000-0000-000000000001';
+
+ALTER TABLE "valuation_settings"
+    DROP CONSTRAINT "valuation_settings_pkey",
+    ADD PRIMARY KEY ("id");
+
+DROP INDEX "stock_snapshots_as_of_idx";
+CREATE UNIQUE INDEX "stock_snapshots_as_of_idx" ON "stock_snapshots" ("as_of") WHERE "kind" <> 'manual';
+
+ALTER TABLE "roles"
+    DROP CONSTRAINT "roles_organization_id_name_key",
+    ADD CONSTRAINT "roles_name_key" UNIQUE ("name");
+
+ALTER TABLE "product_uoms"
+    DROP CONSTRAINT "product_uoms_organization_id_barcode_key",
+    ADD CONSTRAINT "product_uoms_barcode_key" UNIQUE ("barcode");
+
+ALTER TABLE "products"
+    DROP CONSTRAINT "products_organization_id_sku_key",
+    ADD CONSTRAINT "products_sku_key" UNIQUE ("sku");
+
+ALTER TABLE "warehouses"
+    DROP CONSTRAINT "warehouses_organization_id_code_key",
+    ADD CONSTRAINT "warehouses_code_key" UNIQUE ("code");
+
+DO
+$$
+    DECLARE
+        "table" TEXT;
+    BEGIN
+        FOREACH "table" IN ARRAY ARRAY [
+            'abc_analyses', 'abc_analysis_lines', 'assembly_order_lines', 'assembly_orders',
+            'bom_components', 'cost_layers', 'count_sessions', 'count_tasks', 'cross_docks',
+            'locations', 'lots', 'outbound_demands', 'pallets', 'parcels', 'pick_orders',
+            'pick_tasks', 'product_uoms', 'products', 'purchase_orders', 'putaway_rules',
+            'putaway_tasks', 'replenishment_rules', 'replenishment_suggestions', 'reservations',
+            'return_authorization_lines', 'return_authorizations', 'return_inspections',
+            'role_rules', 'roles', 'rules', 'serial_numbers', 'shipments',
+            'slotting_recommendations', 'stock_balances', 'stock_movement_serials',
+            'stock_movements', 'stock_snapshot_lines', 'stock_snapshots',
+            'transfer_order_lines', 'transfer_orders', 'user_roles', 'valuation_settings',
+            'warehouses', 'waves'
+            ]
+            LOOP
+                EXECUTE format('DROP POLICY "tenant_isolation" ON %I', "table");
+                EXECUTE format('ALTER TABLE %I DISABLE ROW LEVEL SECURITY', "table");
+                EXECUTE format('ALTER TABLE %I DROP COLUMN "organization_id"', "table");
+            END LOOP;
+    END
+$$;
+
+ALTER DEFAULT PRIVILEGES IN SCHEMA public REVOKE USAGE, SELECT ON SEQUENCES FROM "warehouse_tenant";
+ALTER DEFAULT PRIVILEGES IN SCHEMA public REVOKE SELECT, INSERT, UPDATE, DELETE ON TABLES FROM "warehouse_tenant";
+REVOKE USAGE, SELECT ON ALL SEQUENCES IN SCHEMA public FROM "warehouse_tenant";
+REVOKE SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public FROM "warehouse_tenant";
+
+DROP FUNCTION current_organization_id();
+
+DROP TABLE IF EXISTS "user_organizations";
+DROP TABLE IF EXISTS "organizations";
@@ -0,0 +1,122 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'organization';
+
+-- Client companies sharing the deployment. Users belong to one or more of them and act as
+-- one at a time, the active organization of their access token.
+CREATE TABLE "organizations"
+(
+    "id"         UUID         NOT NULL PRIMARY KEY,
+    "name"       VARCHAR(128) NOT NULL,
+    "created_at" TIMESTAMPTZ  NOT NULL DEFAULT now()
+);
+
+CREATE TABLE "user_organizations"
+(
+    "user_id"         UUID        NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    "organization_id" UUID        NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    "added_by"        UUID REFERENCES users (id),
+    "created_at"      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY ("user_id", "organization_id")
+);
+
+CREATE INDEX "user_organizations_organization_id_idx" ON "user_organizations" ("organization_id");
+
+-- Data from before organizations existed belongs to a default one, which every existing
+-- user is a member of.
+INSERT INTO "organizations" ("id", "name")
+VALUES ('00000000-0000-0000-0000-000000000001', 'Default');
+
+INSERT INTO "user_organizations" ("user_id", "organization_id")
+SELECT "id", '00000000-0000-0000-0000-000000000001'
+FROM "users";
+
+-- The organization the connection acts as, set by the application when a connection is
+-- checked out for a request.
+CREATE FUNCTION current_organization_id() RETURNS UUID
+    LANGUAGE sql
+    STABLE
+AS
+$$
+SELECT NULLIF(current_setting('app.organization_id', TRUE), '')::UUID
+$$;
+
+-- Connections acting as an organization switch to this role. Unlike the role owning the
+-- tables it is subject to row-level security, so it only sees and writes the rows of the
+-- current organization. Roles are shared by every database of the cluster.
+DO
+$$
+    BEGIN
+        CREATE ROLE "warehouse_tenant" NOLOGIN;
+    EXCEPTION
+        WHEN duplicate_object OR unique_violation THEN NULL;
+    END
+$$;
+
+DO
+$$
+    BEGIN
+        IF NOT pg_has_role(current_user, 'warehouse_tenant', 'MEMBER') THEN
+            EXECUTE format('GRANT "warehouse_tenant" TO %I', current_user);
+        END IF;
+    END
+$$;
+
+GRANT SELECT, INSERT, UPDATE, DELETE ON ALL TABLES IN SCHEMA public TO "warehouse_tenant";
+GRANT USAGE, SELECT ON ALL SEQUENCES IN SCHEMA public TO "warehouse_tenant";
+ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT SELECT, INSERT, UPDATE, DELETE ON TABLES TO "warehouse_tenant";
+ALTER DEFAULT PRIVILEGES IN SCHEMA public GRANT USAGE, SELECT ON SEQUENCES TO "warehouse_tenant";
+
+-- Every table but users is owned by an organization, defaulting to the current one.
+DO
+$$
+    DECLARE
+        "table" TEXT;
+    BEGIN
+        FOREACH "table" IN ARRAY ARRAY [
+            'abc_analyses', 'abc_analysis_lines', 'assembly_order_lines', 'assembly_orders',
+            'bom_components', 'cost_layers', 'count_sessions', 'count_tasks', 'cross_docks',
+            'locations', 'lots', 'outbound_demands', 'pallets', 'parcels', 'pick_orders',
+            'pick_tasks', 'product_uoms', 'products', 'purchase_orders', 'putaway_rules',
+            'putaway_tasks', 'replenishment_rules', 'replenishment_suggestions', 'reservations',
+            'return_authorization_lines', 'return_authorizations', 'return_inspections',
+            'role_rules', 'roles', 'rules', 'serial_numbers', 'shipments',
+            'slotting_recommendations', 'stock_balances', 'stock_movement_serials',
+            'stock_movements', 'stock_snapshot_lines', 'stock_snapshots',
+            'transfer_order_lines', 'transfer_orders', 'user_roles', 'valuation_settings',
+            'warehouses', 'waves'
+            ]
+            LOOP
+                EXECUTE format('ALTER TABLE %I ADD COLUMN "organization_id" UUID REFERENCES organizations (id) ON DELETE CASCADE', "table");
+                EXECUTE format('UPDATE %I SET "organization_id" = %L', "table", '00000000-0000-0000-0000-000000000001');
+                EXECUTE format('ALTER TABLE %I ALTER COLUMN "organization_id" SET NOT NULL, ALTER COLUMN "organization_id" SET DEFAULT current_organization_id()', "table");
+                EXECUTE format('CREATE INDEX %I ON %I ("organization_id")', "table" || '_organization_id_idx', "table");
+                EXECUTE format('ALTER TABLE %I ENABLE ROW LEVEL SECURITY', "table");
+                EXECUTE format('CREATE POLICY "tenant_isolation" ON %I USING ("organization_id" = current_organization_id())', "table");
+            END LOOP;
+    END
+$$;
+
+-- Codes and names only have to be unique within an organization.
+ALTER TABLE "warehouses"
+    DROP CONSTRAINT "warehouses_code_key",
+    ADD CONSTRAINT "warehouses_organization_id_code_key" UNIQUE ("organization_id", "code");
+
+ALTER TABLE "products"
+    DROP CONSTRAINT "products_sku_key",
+    ADD CONSTRAINT "products_organization_id_sku_key" UNIQUE ("organization_id", "sku");
+
+ALTER TABLE "product_uoms"
+    DROP CONSTRAINT "product_uoms_barcode_key",
+    ADD CONSTRAINT "product_uoms_organization_id_barcode_key" UNIQUE ("organization_id", "barcode");
+
+ALTER TABLE "roles"
+    DROP CONSTRAINT "roles_name_key",
+    ADD CONSTRAINT "roles_organization_id_name_key" UNIQUE ("organization_id", "name");
+
+DROP INDEX "stock_snapshots_as_of_idx";
+CREATE UNIQUE INDEX "stock_snapshots_as_of_idx" ON "stock_snapshots" ("organization_id", "as_of") WHERE "kind" <> 'manual';
+
+-- Every organization has its own costing method.
+ALTER TABLE "valuation_settings"
+    DROP CONSTRAINT "valuation_settings_pkey",
+    ADD PRIMARY KEY ("organization_id");
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS "partner_contacts";
+DROP TABLE IF EXISTS "partner_addresses";
+DROP TABLE IF EXISTS "business_partners";
+
+DROP TYPE address_kind;
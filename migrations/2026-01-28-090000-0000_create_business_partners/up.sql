@@ -0,0 +1,72 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'business_partner';
+ALTER TYPE resource_type ADD VALUE 'partner_contact';
+
+CREATE TYPE address_kind AS ENUM ('billing', 'shipping');
+
+-- Counterparties of purchase and sales documents. A partner plays one or more roles.
+-- `normalized_name` and `tax_id` back the duplicate checks made when partners are created.
+CREATE TABLE "business_partners"
+(
+    "id"              UUID         NOT NULL PRIMARY KEY,
+    "code"            VARCHAR(32)  NOT NULL,
+    "name"            VARCHAR(256) NOT NULL,
+    "normalized_name" VARCHAR(256) NOT NULL,
+    "tax_id"          VARCHAR(32),
+    "is_supplier"     BOOLEAN      NOT NULL DEFAULT FALSE,
+    "is_customer"     BOOLEAN      NOT NULL DEFAULT FALSE,
+    "is_carrier"      BOOLEAN      NOT NULL DEFAULT FALSE,
+    "email"           VARCHAR(256),
+    "phone"           VARCHAR(32),
+    "created_by"      UUID REFERENCES users (id),
+    "created_at"      TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    "organization_id" UUID         NOT NULL DEFAULT current_organization_id() REFERENCES organizations (id) ON DELETE CASCADE,
+    CONSTRAINT "business_partners_organization_id_code_key" UNIQUE ("organization_id", "code"),
+    CHECK ("is_supplier" OR "is_customer" OR "is_carrier")
+);
+
+CREATE INDEX "business_partners_organization_id_tax_id_idx" ON "business_partners" ("organization_id", "tax_id");
+CREATE INDEX "business_partners_organization_id_normalized_name_idx" ON "business_partners" ("organization_id", "normalized_name");
+
+ALTER TABLE "business_partners" ENABLE ROW LEVEL SECURITY;
+CREATE POLICY "tenant_isolation" ON "business_partners" USING ("organization_id" = current_organization_id());
+
+-- At most one default address of each kind per partner.
+CREATE TABLE "partner_addresses"
+(
+    "id"              UUID         NOT NULL PRIMARY KEY,
+    "partner_id"      UUID         NOT NULL REFERENCES business_partners (id) ON DELETE CASCADE,
+    "kind"            address_kind NOT NULL,
+    "street"          VARCHAR(512) NOT NULL,
+    "city"            VARCHAR(128) NOT NULL,
+    "postal_code"     VARCHAR(16)  NOT NULL,
+    "country"         CHAR(2)      NOT NULL,
+    "is_default"      BOOLEAN      NOT NULL DEFAULT FALSE,
+    "created_at"      TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    "organization_id" UUID         NOT NULL DEFAULT current_organization_id() REFERENCES organizations (id) ON DELETE CASCADE
+);
+
+CREATE INDEX "partner_addresses_partner_id_idx" ON "partner_addresses" ("partner_id");
+CREATE INDEX "partner_addresses_organization_id_idx" ON "partner_addresses" ("organization_id");
+CREATE UNIQUE INDEX "partner_addresses_default_idx" ON "partner_addresses" ("partner_id", "kind") WHERE "is_default";
+
+ALTER TABLE "partner_addresses" ENABLE ROW LEVEL SECURITY;
+CREATE POLICY "tenant_isolation" ON "partner_addresses" USING ("organization_id" = current_organization_id());
+
+CREATE TABLE "partner_contacts"
+(
+    "id"              UUID         NOT NULL PRIMARY KEY,
+    "partner_id"      UUID         NOT NULL REFERENCES business_partners (id) ON DELETE CASCADE,
+    "name"            VARCHAR(128) NOT NULL,
+    "email"           VARCHAR(256),
+    "phone"           VARCHAR(32),
+    "position"        VARCHAR(128),
+    "created_at"      TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    "organization_id" UUID         NOT NULL DEFAULT current_organization_id() REFERENCES organizations (id) ON DELETE CASCADE
+);
+
+CREATE INDEX "partner_contacts_partner_id_idx" ON "partner_contacts" ("partner_id");
+CREATE INDEX "partner_contacts_organization_id_idx" ON "partner_contacts" ("organization_id");
+
+ALTER TABLE "partner_contacts" ENABLE ROW LEVEL SECURITY;
+CREATE POLICY "tenant_isolation" ON "partner_contacts" USING ("organization_id" = current_organization_id());
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE "locations"
+    DROP COLUMN "attributes";
+ALTER TABLE "products"
+    DROP COLUMN "attributes";
+
+DROP TABLE IF EXISTS "attribute_definitions";
+
+DROP TYPE attribute_kind;
+DROP TYPE attribute_entity;
@@ -0,0 +1,37 @@
+-- Your SQL goes here
+ALTER TYPE resource_type ADD VALUE 'attribute_definition';
+
+CREATE TYPE attribute_entity AS ENUM ('product', 'location');
+CREATE TYPE attribute_kind AS ENUM ('string', 'number', 'enum', 'date', 'boolean');
+
+-- Extra fields an organization defines for its products and locations. Values live in the
+-- `attributes` JSONB column of the entity, keyed by `key`, and are validated against these
+-- definitions whenever they are written.
+CREATE TABLE "attribute_definitions"
+(
+    "id"              UUID             NOT NULL PRIMARY KEY,
+    "entity"          attribute_entity NOT NULL,
+    "key"             VARCHAR(64)      NOT NULL,
+    "label"           VARCHAR(128)     NOT NULL,
+    "kind"            attribute_kind   NOT NULL,
+    "required"        BOOLEAN          NOT NULL DEFAULT FALSE,
+    "options"         TEXT[]           NOT NULL DEFAULT '{}',
+    "position"        INTEGER          NOT NULL DEFAULT 0,
+    "created_at"      TIMESTAMPTZ      NOT NULL DEFAULT now(),
+    "organization_id" UUID             NOT NULL DEFAULT current_organization_id() REFERENCES organizations (id) ON DELETE CASCADE,
+    CONSTRAINT "attribute_definitions_organization_id_entity_key_key" UNIQUE ("organization_id", "entity", "key"),
+    CHECK (("kind" = 'enum') = (cardinality("options") > 0)),
+    CHECK (array_position("options", NULL) IS NULL)
+);
+
+ALTER TABLE "attribute_definitions" ENABLE ROW LEVEL SECURITY;
+CREATE POLICY "tenant_isolation" ON "attribute_definitions" USING ("organization_id" = current_organization_id());
+
+ALTER TABLE "products"
+    ADD COLUMN "attributes" JSONB NOT NULL DEFAULT '{}';
+ALTER TABLE "locations"
+    ADD COLUMN "attributes" JSONB NOT NULL DEFAULT '{}';
+
+-- Backs the containment filters of the list endpoints.
+CREATE INDEX "products_attributes_idx" ON "products" USING GIN ("attributes" jsonb_path_ops);
+CREATE INDEX "locations_attributes_idx" ON "locations" USING GIN ("attributes" jsonb_path_ops);
@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TYPE attachment_owner AS ENUM ('product', 'location', 'stock_movement', 'shipment', 'return_authorization', 'business_partner');
+
+-- Files attached to records, e.g. product photos, delivery notes or damage photos. The
+-- content is kept in the configured storage under `storage_key`; the owner is referenced
+-- by type and id, so attachments of a removed record stay until removed themselves.
+CREATE TABLE "attachments"
+(
+    "id"              UUID             NOT NULL PRIMARY KEY,
+    "owner_type"      attachment_owner NOT NULL,
+    "owner_id"        UUID             NOT NULL,
+    "file_name"       VARCHAR(255)     NOT NULL,
+    "content_type"    VARCHAR(127)     NOT NULL,
+    "size"            BIGINT           NOT NULL CHECK ("size" >= 0),
+    "storage_key"     VARCHAR(255)     NOT NULL UNIQUE,
+    "created_by"      UUID REFERENCES users (id),
+    "created_at"      TIMESTAMPTZ      NOT NULL DEFAULT now(),
+    "organization_id" UUID             NOT NULL DEFAULT current_organization_id() REFERENCES organizations (id) ON DELETE CASCADE
+);
+
+CREATE INDEX "attachments_owner_type_owner_id_idx" ON "attachments" ("owner_type", "owner_id");
+CREATE INDEX "attachments_organization_id_idx" ON "attachments" ("organization_id");
+
+ALTER TABLE "attachments" ENABLE ROW LEVEL SECURITY;
+CREATE POLICY "tenant_isolation" ON "attachments" USING ("organization_id" = current_organization_id());
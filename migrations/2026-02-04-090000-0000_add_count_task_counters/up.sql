@@ -0,0 +1,7 @@
+-- Everyone who counted a task, so that none of them can approve its variance.
+ALTER TABLE "count_tasks"
+    ADD COLUMN "counters" UUID[] NOT NULL DEFAULT '{}';
+
+UPDATE "count_tasks"
+SET "counters" = ARRAY["counted_by"]
+WHERE "counted_by" IS NOT NULL;
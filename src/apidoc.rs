@@ -1,11 +1,61 @@
 use utoipa::OpenApi;
 
 pub const AUTH_TAG: &str = "Auth";
+pub const ORGANIZATION_TAG: &str = "Organization";
+pub const WAREHOUSE_TAG: &str = "Warehouse";
+pub const PRODUCT_TAG: &str = "Product";
+pub const ATTRIBUTE_TAG: &str = "Custom attributes";
+pub const STOCK_TAG: &str = "Stock";
+pub const STOCK_HOLD_TAG: &str = "Stock holds";
+pub const SNAPSHOT_TAG: &str = "Stock history";
+pub const PUTAWAY_TAG: &str = "Putaway";
+pub const WAVE_TAG: &str = "Waves";
+pub const RESERVATION_TAG: &str = "Reservation";
+pub const RETURN_TAG: &str = "Return";
+pub const STOCK_COUNT_TAG: &str = "Stock count";
+pub const TRANSFER_ORDER_TAG: &str = "Transfer order";
+pub const REPLENISHMENT_TAG: &str = "Replenishment";
+pub const ABC_TAG: &str = "ABC analysis";
+pub const CROSS_DOCK_TAG: &str = "Cross-docking";
+pub const SHIPMENT_TAG: &str = "Shipment";
+pub const BUSINESS_PARTNER_TAG: &str = "Business partner";
+pub const VALUATION_TAG: &str = "Valuation";
+pub const KIT_TAG: &str = "Kit";
+pub const LOT_TAG: &str = "Lot";
+pub const SERIAL_NUMBER_TAG: &str = "Serial number";
+pub const LABEL_TAG: &str = "Label";
+pub const SCAN_TAG: &str = "Scanning";
+pub const ATTACHMENT_TAG: &str = "Attachments";
 
 #[derive(OpenApi)]
 #[openapi(
     tags(
         (name = AUTH_TAG, description = "Authorization API endpoints"),
+        (name = ORGANIZATION_TAG, description = "Organizations, their members and switching between them"),
+        (name = WAREHOUSE_TAG, description = "Warehouses and storage locations"),
+        (name = PRODUCT_TAG, description = "Product catalogue"),
+        (name = ATTRIBUTE_TAG, description = "Organization-defined attributes of products and locations"),
+        (name = STOCK_TAG, description = "Stock movements and levels"),
+        (name = STOCK_HOLD_TAG, description = "Quarantine, damaged and on-hold stock kept out of allocation"),
+        (name = SNAPSHOT_TAG, description = "Stock as of past dates and daily and month-end snapshots"),
+        (name = PUTAWAY_TAG, description = "Putaway rules and tasks moving received stock into storage"),
+        (name = WAVE_TAG, description = "Order release, wave and cluster picking along the pick path"),
+        (name = RESERVATION_TAG, description = "Stock reservations for demand documents"),
+        (name = RETURN_TAG, description = "Customer returns, inspection and disposition"),
+        (name = STOCK_COUNT_TAG, description = "Cycle counts and stocktakes"),
+        (name = TRANSFER_ORDER_TAG, description = "Stock transfers between warehouses"),
+        (name = REPLENISHMENT_TAG, description = "Reorder points and replenishment suggestions"),
+        (name = ABC_TAG, description = "ABC classification by pick frequency or value and slotting recommendations"),
+        (name = CROSS_DOCK_TAG, description = "Outbound demand and routing received stock straight to dock staging"),
+        (name = SHIPMENT_TAG, description = "Shipments, parcels, carrier rate-shopping and labels"),
+        (name = BUSINESS_PARTNER_TAG, description = "Suppliers, customers and carriers with their addresses and contacts"),
+        (name = VALUATION_TAG, description = "Inventory valuation and cost of goods issued"),
+        (name = KIT_TAG, description = "Bills of materials, kit assembly and disassembly"),
+        (name = LOT_TAG, description = "Lots, expiry dates and blocking"),
+        (name = SERIAL_NUMBER_TAG, description = "Serial numbers of individual units"),
+        (name = LABEL_TAG, description = "Pallets and printable barcode labels"),
+        (name = SCAN_TAG, description = "Parsing of scanned barcodes"),
+        (name = ATTACHMENT_TAG, description = "Photos and documents attached to products, locations, movements, shipments, returns and partners"),
     )
 )]
 pub struct ApiDoc;
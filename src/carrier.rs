@@ -0,0 +1,20 @@
+//! Carriers shipments can be shipped with. The manual carrier is always available; the
+//! mock carrier quotes fixed rates and prints labels locally, for tests and demos.
+
+use crate::config::ShippingConfig;
+use crate::contract::carrier::Carrier;
+
+mod manual;
+mod mock;
+
+pub use manual::ManualCarrier;
+pub use mock::MockCarrier;
+
+pub fn carriers(config: &ShippingConfig) -> Vec<Box<dyn Carrier>> {
+    let mut carriers: Vec<Box<dyn Carrier>> = vec![Box::new(ManualCarrier)];
+    if config.mockcarrier {
+        carriers.push(Box::new(MockCarrier));
+    }
+
+    carriers
+}
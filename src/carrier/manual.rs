@@ -0,0 +1,49 @@
+use crate::contract::carrier::Carrier;
+use crate::domain::{CarrierLabel, Parcel, Rate, ShipData, Shipment, ShipmentError};
+use anyhow::Result;
+
+/// Shipments booked with a carrier outside the system, e.g. on its website or by phone.
+/// It quotes no rates and prints no labels; tracking numbers, if any, are entered.
+pub struct ManualCarrier;
+
+#[async_trait::async_trait]
+impl Carrier for ManualCarrier {
+    fn code(&self) -> &str {
+        "manual"
+    }
+
+    async fn rates(&self, _shipment: &Shipment, _parcels: &[Parcel]) -> Result<Vec<Rate>> {
+        Ok(Vec::new())
+    }
+
+    async fn ship(
+        &self,
+        _shipment: &Shipment,
+        parcels: &[Parcel],
+        request: &ShipData,
+    ) -> Result<Vec<CarrierLabel>> {
+        if request.tracking_numbers.is_empty() {
+            return Ok(parcels
+                .iter()
+                .map(|_| CarrierLabel {
+                    tracking_number: None,
+                    label: None,
+                    content_type: None,
+                })
+                .collect());
+        }
+        if request.tracking_numbers.len() != parcels.len() {
+            return Err(ShipmentError::TrackingNumbersMismatch.into());
+        }
+
+        Ok(request
+            .tracking_numbers
+            .iter()
+            .map(|tracking_number| CarrierLabel {
+                tracking_number: Some(tracking_number.clone()),
+                label: None,
+                content_type: None,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::contract::carrier::Carrier;
+use crate::domain::{CarrierLabel, Parcel, Rate, ShipData, Shipment, ShipmentError};
+use crate::label::parcel_label;
+use anyhow::Result;
+use rust_decimal::Decimal;
+
+const CODE: &str = "mock";
+const CURRENCY: &str = "USD";
+
+/// Service, base price per parcel, price per billable kilogram and transit days.
+fn services() -> [(&'static str, Decimal, Decimal, i32); 2] {
+    [
+        ("ground", Decimal::new(500, 2), Decimal::ONE, 5),
+        ("express", Decimal::new(1200, 2), Decimal::new(250, 2), 1),
+    ]
+}
+
+/// Carrier quoting fixed rates by billable weight and printing its labels locally. Its
+/// tracking numbers are derived from the parcel ids, so shipping is deterministic.
+pub struct MockCarrier;
+
+#[async_trait::async_trait]
+impl Carrier for MockCarrier {
+    fn code(&self) -> &str {
+        CODE
+    }
+
+    async fn rates(&self, _shipment: &Shipment, parcels: &[Parcel]) -> Result<Vec<Rate>> {
+        let weight: Decimal = parcels.iter().map(Parcel::billable_weight).sum();
+
+        Ok(services()
+            .into_iter()
+            .map(|(service, base, per_kg, transit_days)| Rate {
+                carrier: CODE.to_string(),
+                service: service.to_string(),
+                amount: (base * Decimal::from(parcels.len()) + per_kg * weight).round_dp(2),
+                currency: CURRENCY.to_string(),
+                transit_days: Some(transit_days),
+            })
+            .collect())
+    }
+
+    async fn ship(
+        &self,
+        shipment: &Shipment,
+        parcels: &[Parcel],
+        request: &ShipData,
+    ) -> Result<Vec<CarrierLabel>> {
+        if !services()
+            .iter()
+            .any(|(service, ..)| *service == request.service)
+        {
+            return Err(ShipmentError::UnknownService(request.service.clone()).into());
+        }
+
+        parcels
+            .iter()
+            .map(|parcel| {
+                let tracking_number = format!(
+                    "MOCK{}",
+                    parcel.id.simple().to_string()[..16].to_uppercase()
+                );
+                let label = parcel_label(shipment, CODE, &request.service, &tracking_number)?
+                    .render(request.label_format)?;
+
+                Ok(CarrierLabel {
+                    tracking_number: Some(tracking_number),
+                    label: Some(label),
+                    content_type: Some(request.label_format.content_type().to_string()),
+                })
+            })
+            .collect()
+    }
+}
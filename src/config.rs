@@ -1,3 +1,4 @@
+use crate::domain::build_sscc;
 use anyhow::{Context, Error, anyhow, bail};
 use config::Environment;
 use dotenvy::dotenv;
@@ -10,6 +11,17 @@ use url::Url;
 pub struct Config {
     pub database: DatabaseConfig,
     pub server: ServerConfig,
+    pub gs1: Gs1Config,
+    #[serde(default)]
+    pub reservation: ReservationConfig,
+    #[serde(default)]
+    pub replenishment: ReplenishmentConfig,
+    #[serde(default)]
+    pub snapshot: SnapshotConfig,
+    #[serde(default)]
+    pub shipping: ShippingConfig,
+    #[serde(default)]
+    pub storage: StorageConfig,
 }
 
 #[derive(serde::Deserialize, Clone)]
@@ -17,6 +29,83 @@ pub struct ServerConfig {
     pub jwtsecret: SecretString,
 }
 
+/// Identifies the company in the GS1 keys it allocates, such as pallet SSCCs. The company
+/// prefix is the one GS1 licensed to the company and has no default, so that keys of another
+/// company are never allocated.
+#[derive(serde::Deserialize, Clone)]
+pub struct Gs1Config {
+    pub companyprefix: String,
+    #[serde(default)]
+    pub extensiondigit: u8,
+}
+
+/// Every `intervalsecs` seconds active reservations past their `expires_at` are expired,
+/// releasing the stock they hold. Overdue reservations are never expired when it is 0.
+#[derive(serde::Deserialize, Clone)]
+pub struct ReservationConfig {
+    pub intervalsecs: u64,
+}
+
+impl Default for ReservationConfig {
+    fn default() -> Self {
+        Self { intervalsecs: 60 }
+    }
+}
+
+/// Replenishment suggestions are computed every `intervalsecs` seconds, or only on demand
+/// when it is 0.
+#[derive(serde::Deserialize, Clone, Default)]
+pub struct ReplenishmentConfig {
+    pub intervalsecs: u64,
+}
+
+/// Every `intervalsecs` seconds the daily and month-end stock snapshot due at the last
+/// midnight UTC is taken if it is missing. Snapshots are only taken on demand when it is 0.
+#[derive(serde::Deserialize, Clone, Default)]
+pub struct SnapshotConfig {
+    pub intervalsecs: u64,
+}
+
+/// The manual carrier is always available. `mockcarrier` adds a carrier quoting fixed
+/// rates and printing labels locally, meant for tests and demos.
+#[derive(serde::Deserialize, Clone, Default)]
+pub struct ShippingConfig {
+    pub mockcarrier: bool,
+}
+
+/// Attachment contents are kept under `path` on the local filesystem, or in `bucket` of an
+/// S3-compatible service when `backend` is `s3`. `endpoint` points to a service other than
+/// AWS, such as a local MinIO; credentials and region are read from the standard `AWS_*`
+/// environment variables. Uploads larger than `maxbytes` are refused.
+#[derive(serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    pub path: String,
+    pub bucket: String,
+    pub endpoint: Option<String>,
+    pub maxbytes: usize,
+}
+
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Local,
+    S3,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::Local,
+            path: "attachments".to_string(),
+            bucket: "warehouse".to_string(),
+            endpoint: None,
+            maxbytes: 10 * 1024 * 1024,
+        }
+    }
+}
+
 #[derive(serde::Deserialize, Clone, Default)]
 pub struct DatabaseConfig {
     pub username: String,
@@ -97,7 +186,11 @@ pub fn get_configuration() -> Result<Config, anyhow::Error> {
         .add_source(Environment::with_prefix("warehouse").separator("_"))
         .build()?;
 
-    settings
+    let config = settings
         .try_deserialize::<Config>()
-        .context("deserialize configuration")
+        .context("deserialize configuration")?;
+    build_sscc(config.gs1.extensiondigit, &config.gs1.companyprefix, 0)
+        .context("validate GS1 company prefix")?;
+
+    Ok(config)
 }
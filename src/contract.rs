@@ -1,5 +1,9 @@
 pub mod http;
 
+#[cfg(feature = "ssr")]
+pub mod carrier;
 pub mod error;
 #[cfg(feature = "ssr")]
 pub mod repository;
+#[cfg(feature = "ssr")]
+pub mod storage;
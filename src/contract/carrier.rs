@@ -0,0 +1,29 @@
+use crate::domain;
+use anyhow::Result;
+
+/// A shipping carrier, quoting rates for the parcels of a shipment and booking one of its
+/// services for them. The shipping workflow only talks to carriers through this trait, so
+/// an integration with a real carrier is a new implementation registered in
+/// `crate::carrier::carriers`.
+#[async_trait::async_trait]
+pub trait Carrier: Send + Sync {
+    /// Code shipments refer to the carrier by.
+    fn code(&self) -> &str;
+
+    /// Rates of the services offered for the parcels. Carriers unable to quote return none
+    /// and accept any service.
+    async fn rates(
+        &self,
+        shipment: &domain::Shipment,
+        parcels: &[domain::Parcel],
+    ) -> Result<Vec<domain::Rate>>;
+
+    /// Books the service for the parcels, returning the tracking number and label of every
+    /// parcel in the order given.
+    async fn ship(
+        &self,
+        shipment: &domain::Shipment,
+        parcels: &[domain::Parcel],
+        request: &domain::ShipData,
+    ) -> Result<Vec<domain::CarrierLabel>>;
+}
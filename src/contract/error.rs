@@ -1,4 +1,9 @@
-use crate::domain::{AuthError, RepositoryError};
+use crate::domain::{
+    AbcError, AttachmentError, AttributeError, AuthError, CrossDockError, Gs1Error, KitError,
+    LabelError, ListError, LotError, PartnerError, PutawayError, ReplenishmentError,
+    RepositoryError, ReturnError, SerialError, ShipmentError, StockCountError, StockError,
+    StockHoldError, TransferOrderError, UomError, WaveError,
+};
 use anyhow::Chain;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use validator::{ValidationError, ValidationErrors};
@@ -12,35 +17,216 @@ pub enum ErrorCode {
     AuthenticationFailed = 3,
     ObjectNotFound = 4,
     ObjectAlreadyExists = 5,
+    PermissionDenied = 6,
+    InsufficientStock = 7,
+    InvalidState = 8,
 }
 
 impl From<Chain<'_>> for ErrorCode {
-    fn from(chain: Chain) -> Self {
-        for cause in chain {
-            if cause.downcast_ref::<ValidationError>().is_some()
-                || cause.downcast_ref::<ValidationErrors>().is_some()
-            {
-                return ErrorCode::ValidationFailed;
+    fn from(mut chain: Chain) -> Self {
+        chain
+            .find_map(classify)
+            .unwrap_or(ErrorCode::UnexpectedError)
+    }
+}
+
+/// Message of the domain error deciding the code of the chain, meant for the client.
+/// Unexpected, authentication and repository errors give none, so that their causes, such
+/// as the constraints a row violates, stay on the server.
+pub fn error_detail(mut chain: Chain) -> Option<String> {
+    chain
+        .find_map(|cause| classify(cause).map(|code| (code, cause)))
+        .filter(|(code, cause)| {
+            !matches!(
+                code,
+                ErrorCode::UnexpectedError | ErrorCode::AuthenticationFailed
+            ) && cause.downcast_ref::<RepositoryError>().is_none()
+        })
+        .map(|(_, cause)| cause.to_string())
+}
+
+/// Code of a single error of a chain, `None` when the errors it wraps decide.
+fn classify(cause: &(dyn std::error::Error + 'static)) -> Option<ErrorCode> {
+    if cause.downcast_ref::<ValidationError>().is_some()
+        || cause.downcast_ref::<ValidationErrors>().is_some()
+    {
+        return Some(ErrorCode::ValidationFailed);
+    }
+
+    if let Some(auth_error) = cause.downcast_ref::<AuthError>() {
+        match auth_error {
+            AuthError::InvalidCredentials(_) => {
+                return Some(ErrorCode::AuthenticationFailed);
+            }
+            AuthError::PermissionDenied => return Some(ErrorCode::PermissionDenied),
+            AuthError::UnexpectedError(_) => return None,
+        }
+    }
+
+    if let Some(repo_error) = cause.downcast_ref::<RepositoryError>() {
+        match repo_error {
+            RepositoryError::NotFound => return Some(ErrorCode::ObjectNotFound),
+            RepositoryError::Exists(_) => return Some(ErrorCode::ObjectAlreadyExists),
+            RepositoryError::UnexpectedError(_) => return None,
+        }
+    }
+
+    if let Some(stock_error) = cause.downcast_ref::<StockError>() {
+        match stock_error {
+            StockError::InsufficientStock { .. } => return Some(ErrorCode::InsufficientStock),
+            StockError::ReservationNotActive => return Some(ErrorCode::InvalidState),
+        }
+    }
+
+    if let Some(lot_error) = cause.downcast_ref::<LotError>() {
+        match lot_error {
+            LotError::LotNumberRequired | LotError::ExpiryDateRequired | LotError::NotTracked => {
+                return Some(ErrorCode::ValidationFailed);
+            }
+            LotError::Blocked => return Some(ErrorCode::InvalidState),
+        }
+    }
+
+    if let Some(serial_error) = cause.downcast_ref::<SerialError>() {
+        match serial_error {
+            SerialError::NotTracked | SerialError::CountMismatch => {
+                return Some(ErrorCode::ValidationFailed);
+            }
+            SerialError::NotInStock(_) | SerialError::AlreadyInStock(_) => {
+                return Some(ErrorCode::InvalidState);
+            }
+        }
+    }
+
+    if let Some(gs1_error) = cause.downcast_ref::<Gs1Error>() {
+        match gs1_error {
+            Gs1Error::NotAscii
+            | Gs1Error::UnknownApplicationIdentifier(_)
+            | Gs1Error::InvalidValue(_)
+            | Gs1Error::CheckDigitMismatch(_)
+            | Gs1Error::WrongProduct
+            | Gs1Error::ScanMismatch(_) => return Some(ErrorCode::ValidationFailed),
+            Gs1Error::InvalidCompanyPrefix | Gs1Error::SerialReferenceExhausted => {
+                return None;
+            }
+        }
+    }
+
+    if cause.downcast_ref::<LabelError>().is_some() {
+        return Some(ErrorCode::ValidationFailed);
+    }
+
+    if cause.downcast_ref::<UomError>().is_some() {
+        return Some(ErrorCode::ValidationFailed);
+    }
+
+    if let Some(transfer_error) = cause.downcast_ref::<TransferOrderError>() {
+        match transfer_error {
+            TransferOrderError::NotOpen
+            | TransferOrderError::NotInTransit
+            | TransferOrderError::Completed
+            | TransferOrderError::Changed => {
+                return Some(ErrorCode::InvalidState);
+            }
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(replenishment_error) = cause.downcast_ref::<ReplenishmentError>() {
+        match replenishment_error {
+            ReplenishmentError::NotPending => return Some(ErrorCode::InvalidState),
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(return_error) = cause.downcast_ref::<ReturnError>() {
+        match return_error {
+            ReturnError::NotOpen | ReturnError::AwaitingInspection | ReturnError::Changed => {
+                return Some(ErrorCode::InvalidState);
+            }
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(putaway_error) = cause.downcast_ref::<PutawayError>() {
+        match putaway_error {
+            PutawayError::NotOpen | PutawayError::NothingToPutAway => {
+                return Some(ErrorCode::InvalidState);
             }
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(hold_error) = cause.downcast_ref::<StockHoldError>() {
+        match hold_error {
+            StockHoldError::NothingToHold => return Some(ErrorCode::InvalidState),
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
 
-            if let Some(auth_error) = cause.downcast_ref::<AuthError>() {
-                match auth_error {
-                    AuthError::InvalidCredentials(_) => {
-                        return ErrorCode::AuthenticationFailed;
-                    }
-                    AuthError::UnexpectedError(_) => continue,
-                }
+    if let Some(abc_error) = cause.downcast_ref::<AbcError>() {
+        match abc_error {
+            AbcError::SharesOutOfOrder => return Some(ErrorCode::ValidationFailed),
+            _ => return Some(ErrorCode::InvalidState),
+        }
+    }
+
+    if let Some(cross_dock_error) = cause.downcast_ref::<CrossDockError>() {
+        match cross_dock_error {
+            CrossDockError::DemandNotOpen | CrossDockError::NothingToCrossDock => {
+                return Some(ErrorCode::InvalidState);
             }
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(shipment_error) = cause.downcast_ref::<ShipmentError>() {
+        match shipment_error {
+            ShipmentError::NotOpen
+            | ShipmentError::NoParcels
+            | ShipmentError::PickOrderNotPicked => return Some(ErrorCode::InvalidState),
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if let Some(wave_error) = cause.downcast_ref::<WaveError>() {
+        match wave_error {
+            WaveError::ExceedsTask => return Some(ErrorCode::ValidationFailed),
+            _ => return Some(ErrorCode::InvalidState),
+        }
+    }
+
+    if let Some(kit_error) = cause.downcast_ref::<KitError>() {
+        match kit_error {
+            KitError::NotOpen => return Some(ErrorCode::InvalidState),
+            _ => return Some(ErrorCode::ValidationFailed),
+        }
+    }
+
+    if cause.downcast_ref::<AttributeError>().is_some() {
+        return Some(ErrorCode::ValidationFailed);
+    }
+
+    if cause.downcast_ref::<AttachmentError>().is_some() {
+        return Some(ErrorCode::ValidationFailed);
+    }
 
-            if let Some(repo_error) = cause.downcast_ref::<RepositoryError>() {
-                match repo_error {
-                    RepositoryError::NotFound => return ErrorCode::ObjectNotFound,
-                    RepositoryError::Exists(_) => return ErrorCode::ObjectAlreadyExists,
-                    RepositoryError::UnexpectedError(_) => continue,
-                }
+    if cause.downcast_ref::<ListError>().is_some() {
+        return Some(ErrorCode::ValidationFailed);
+    }
+
+    if let Some(partner_error) = cause.downcast_ref::<PartnerError>() {
+        match partner_error {
+            PartnerError::DuplicateTaxId(_) | PartnerError::SimilarName(_) => {
+                return Some(ErrorCode::ObjectAlreadyExists);
             }
+            PartnerError::NoRole => return Some(ErrorCode::ValidationFailed),
         }
+    }
 
-        ErrorCode::UnexpectedError
+    if cause.downcast_ref::<StockCountError>().is_some() {
+        return Some(ErrorCode::InvalidState);
     }
+
+    None
 }
@@ -1,2 +1,3 @@
 pub const AUTHORIZATION_HEADER: &str = "Authorization";
 pub const AUTHORIZATION_SCHEME: &str = "Bearer";
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
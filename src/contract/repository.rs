@@ -1,13 +1,59 @@
 use anyhow::Result;
 use uuid::Uuid;
 
+mod abc;
+mod attachment;
+mod attribute;
+mod business_partner;
+mod cross_dock;
+mod kit;
+mod lot;
+mod organization;
+mod pallet;
+mod product;
+mod putaway;
+mod replenishment;
+mod reservation;
+mod returns;
 mod role;
 mod rule;
+mod serial;
+mod shipment;
+mod snapshot;
+mod stock;
+mod stock_count;
+mod transfer;
 mod user;
+mod valuation;
+mod warehouse;
+mod wave;
 
+pub use abc::*;
+pub use attachment::*;
+pub use attribute::*;
+pub use business_partner::*;
+pub use cross_dock::*;
+pub use kit::*;
+pub use lot::*;
+pub use organization::*;
+pub use pallet::*;
+pub use product::*;
+pub use putaway::*;
+pub use replenishment::*;
+pub use reservation::*;
+pub use returns::*;
 pub use role::*;
 pub use rule::*;
+pub use serial::*;
+pub use shipment::*;
+pub use snapshot::*;
+pub use stock::*;
+pub use stock_count::*;
+pub use transfer::*;
 pub use user::*;
+pub use valuation::*;
+pub use warehouse::*;
+pub use wave::*;
 
 #[async_trait::async_trait]
 pub trait Repository<T>: Send + Sync {
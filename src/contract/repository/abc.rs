@@ -0,0 +1,52 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait AbcRepository: Repository<domain::AbcAnalysis> {
+    /// Scores of the products issued from locations of the warehouse after `from` and up
+    /// to `to`. Products not issued in that period are left out.
+    async fn product_scores(
+        &self,
+        warehouse_id: Uuid,
+        criterion: domain::AbcCriterion,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<domain::ProductScore>>;
+
+    /// Saves the analysis with its lines and recommendations. Pending recommendations of
+    /// earlier analyses of the warehouse are superseded.
+    async fn save(
+        &self,
+        analysis: domain::AbcAnalysis,
+        lines: Vec<domain::AbcAnalysisLine>,
+        recommendations: Vec<domain::SlottingRecommendation>,
+    ) -> Result<domain::AbcAnalysis>;
+
+    /// Newest first.
+    async fn list(&self, query: domain::AbcAnalysisQuery) -> Result<Vec<domain::AbcAnalysis>>;
+
+    /// By rank.
+    async fn list_lines(&self, analysis_id: Uuid) -> Result<Vec<domain::AbcAnalysisLine>>;
+
+    /// Sets the ABC class of the products to the one of their line and marks the
+    /// analysis applied.
+    async fn apply(&self, analysis: domain::AbcAnalysis) -> Result<domain::AbcAnalysis>;
+
+    async fn get_recommendation(&self, id: Uuid) -> Result<domain::SlottingRecommendation>;
+
+    /// Highest ranked products first.
+    async fn list_recommendations(
+        &self,
+        query: domain::SlottingRecommendationQuery,
+    ) -> Result<Vec<domain::SlottingRecommendation>>;
+
+    /// Saves the reviewed recommendation and posts the transfers it was accepted as.
+    async fn accept_recommendation(
+        &self,
+        recommendation: domain::SlottingRecommendation,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<domain::SlottingRecommendation>;
+}
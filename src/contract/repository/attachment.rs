@@ -0,0 +1,24 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait AttachmentRepository: Repository<domain::Attachment> {
+    /// Attachments of the record, oldest first.
+    async fn list(
+        &self,
+        owner_type: domain::AttachmentOwner,
+        owner_id: Uuid,
+    ) -> Result<Vec<domain::Attachment>>;
+
+    /// Whether the record exists in the organization.
+    async fn owner_exists(
+        &self,
+        owner_type: domain::AttachmentOwner,
+        owner_id: Uuid,
+    ) -> Result<bool>;
+
+    /// Removes the metadata of the attachment, returning it so its content can be removed.
+    async fn remove(&self, id: Uuid) -> Result<domain::Attachment>;
+}
@@ -0,0 +1,16 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait AttributeRepository: Repository<domain::AttributeDefinition> {
+    /// Definitions of the entity type in form order.
+    async fn list(
+        &self,
+        entity: domain::AttributeEntity,
+    ) -> Result<Vec<domain::AttributeDefinition>>;
+
+    /// Removes the definition along with the values entities have for it.
+    async fn remove(&self, id: Uuid) -> Result<()>;
+}
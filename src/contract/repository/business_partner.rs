@@ -0,0 +1,43 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait BusinessPartnerRepository: Repository<domain::BusinessPartner> {
+    /// Creates the partner together with its addresses and contacts.
+    async fn create_with_details(
+        &self,
+        partner: domain::BusinessPartner,
+        addresses: Vec<domain::PartnerAddress>,
+        contacts: Vec<domain::PartnerContact>,
+    ) -> Result<domain::BusinessPartner>;
+
+    /// Partners with the given normalized tax id or normalized name.
+    async fn find_duplicates(
+        &self,
+        tax_id: Option<&str>,
+        normalized_name: &str,
+    ) -> Result<Vec<domain::BusinessPartner>>;
+
+    /// Ordered by code.
+    async fn search(
+        &self,
+        query: domain::BusinessPartnerQuery,
+    ) -> Result<Vec<domain::BusinessPartner>>;
+
+    /// Default addresses first.
+    async fn list_addresses(&self, partner_id: Uuid) -> Result<Vec<domain::PartnerAddress>>;
+
+    /// A default address replaces the previous default address of its kind.
+    async fn add_address(&self, address: domain::PartnerAddress) -> Result<domain::PartnerAddress>;
+
+    async fn remove_address(&self, partner_id: Uuid, address_id: Uuid) -> Result<()>;
+
+    /// Ordered by name.
+    async fn list_contacts(&self, partner_id: Uuid) -> Result<Vec<domain::PartnerContact>>;
+
+    async fn add_contact(&self, contact: domain::PartnerContact) -> Result<domain::PartnerContact>;
+
+    async fn remove_contact(&self, partner_id: Uuid, contact_id: Uuid) -> Result<()>;
+}
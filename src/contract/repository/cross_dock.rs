@@ -0,0 +1,31 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// `create` registers an outbound demand.
+#[async_trait::async_trait]
+pub trait CrossDockRepository: Repository<domain::OutboundDemand> {
+    /// Most urgent first.
+    async fn list_demands(
+        &self,
+        query: domain::OutboundDemandQuery,
+    ) -> Result<Vec<domain::OutboundDemand>>;
+
+    /// Fails with `NotFound` if the demand is no longer open.
+    async fn cancel_demand(&self, id: Uuid) -> Result<domain::OutboundDemand>;
+
+    /// Transfers the stock to the staging location, reserves it there for the document of
+    /// the demand and allocates it to the demand. Open putaway tasks of the product from
+    /// the receiving location are reduced by the quantity, or cancelled. Fails with
+    /// `CrossDockError::ExceedsDemand` if the demand no longer has the quantity open.
+    async fn cross_dock(
+        &self,
+        cross_dock: domain::CrossDock,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<domain::CrossDock>;
+
+    /// Newest first.
+    async fn list(&self, query: domain::CrossDockQuery) -> Result<Vec<domain::CrossDock>>;
+}
@@ -0,0 +1,40 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// Bills of materials and the assembly orders built from them. Stock movements of an
+/// order are posted together with its update, in one transaction.
+#[async_trait::async_trait]
+pub trait KitRepository: Repository<domain::AssemblyOrder> {
+    /// Replaces the bill of materials of the kit.
+    async fn replace_components(
+        &self,
+        kit_product_id: Uuid,
+        components: Vec<domain::BomComponent>,
+    ) -> Result<Vec<domain::BomComponent>>;
+
+    /// Empty if the product is not a kit.
+    async fn list_components(&self, kit_product_id: Uuid) -> Result<Vec<domain::BomComponent>>;
+
+    async fn create_with_lines(
+        &self,
+        order: domain::AssemblyOrder,
+        lines: Vec<domain::AssemblyOrderLine>,
+    ) -> Result<domain::AssemblyOrder>;
+
+    async fn list_lines(&self, order_id: Uuid) -> Result<Vec<domain::AssemblyOrderLine>>;
+
+    /// Saves the order and posts its movements, outbound ones first. Inbound movements
+    /// without a unit cost share the cost of the stock taken out, in proportion to their
+    /// quantity at the current cost of their products. Fails with `NotOpen` if the order is
+    /// no longer open.
+    async fn update_with_movements(
+        &self,
+        order: domain::AssemblyOrder,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<(domain::AssemblyOrder, Vec<domain::StockMovement>)>;
+
+    /// Movements posted for the order, oldest first.
+    async fn list_movements(&self, order_id: Uuid) -> Result<Vec<domain::StockMovement>>;
+}
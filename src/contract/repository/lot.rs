@@ -0,0 +1,30 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait LotRepository: Repository<domain::Lot> {
+    /// Returns the lot of the product with the same lot number and expiry date,
+    /// creating `val` if there is none yet.
+    async fn get_or_create(&self, val: domain::Lot) -> Result<domain::Lot>;
+
+    /// Looks a lot of the product up by whichever of lot number and expiry date is given.
+    async fn find(
+        &self,
+        product_id: Uuid,
+        lot_number: Option<String>,
+        expiry_date: Option<NaiveDate>,
+    ) -> Result<domain::Lot>;
+
+    async fn set_blocked(&self, id: Uuid, blocked: bool) -> Result<domain::Lot>;
+
+    /// Blocks every lot that expired before `today` and returns how many were blocked.
+    async fn block_expired(&self, today: NaiveDate) -> Result<usize>;
+
+    async fn list_expiring(
+        &self,
+        query: domain::ExpiringStockQuery,
+    ) -> Result<Vec<domain::ExpiringStock>>;
+}
@@ -0,0 +1,42 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait OrganizationRepository: Repository<domain::Organization> {
+    /// Creates the organization with its first member, granted the rules through the role,
+    /// both created in the new organization.
+    async fn create_with_owner(
+        &self,
+        organization: domain::Organization,
+        owner: domain::OrganizationMember,
+        role: domain::Role,
+        rules: Vec<domain::Rule>,
+    ) -> Result<domain::Organization>;
+
+    async fn list(&self) -> Result<Vec<domain::Organization>>;
+
+    /// Organizations the user is a member of, oldest membership first.
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<domain::Organization>>;
+
+    async fn get_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<domain::OrganizationMember>;
+
+    /// Members of the organization with their email.
+    async fn list_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<(domain::OrganizationMember, String)>>;
+
+    async fn add_member(
+        &self,
+        member: domain::OrganizationMember,
+    ) -> Result<domain::OrganizationMember>;
+
+    /// Removes the membership and the roles the user was assigned in the organization.
+    async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> Result<()>;
+}
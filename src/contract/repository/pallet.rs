@@ -0,0 +1,9 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait PalletRepository: Repository<domain::Pallet> {
+    /// Allocates the next SSCC serial reference, never handing out the same one twice.
+    async fn next_serial_reference(&self) -> Result<i64>;
+}
@@ -0,0 +1,25 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait ProductRepository: Repository<domain::Product> {
+    /// Ordered by SKU.
+    async fn list(&self, query: domain::ProductQuery) -> Result<Vec<domain::Product>>;
+
+    async fn page(&self, query: domain::ProductListQuery) -> Result<domain::Page<domain::Product>>;
+
+    /// Replaces the custom attribute values of the product.
+    async fn set_attributes(
+        &self,
+        id: Uuid,
+        attributes: serde_json::Value,
+    ) -> Result<domain::Product>;
+
+    async fn add_uom(&self, val: domain::ProductUom) -> Result<domain::ProductUom>;
+
+    async fn list_uoms(&self, product_id: Uuid) -> Result<Vec<domain::ProductUom>>;
+
+    async fn find_uom_by_barcode(&self, barcode: &str) -> Result<domain::ProductUom>;
+}
@@ -0,0 +1,42 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait PutawayRepository: Repository<domain::PutawayTask> {
+    /// Replaces the putaway rules of the warehouse.
+    async fn replace_rules(
+        &self,
+        warehouse_id: Uuid,
+        rules: Vec<domain::PutawayRule>,
+    ) -> Result<Vec<domain::PutawayRule>>;
+
+    /// In sequence order.
+    async fn list_rules(&self, warehouse_id: Uuid) -> Result<Vec<domain::PutawayRule>>;
+
+    /// Regular locations of the warehouse, neither in transit nor in quarantine, with what
+    /// they hold and what open putaway tasks are about to bring.
+    async fn list_occupancy(&self, warehouse_id: Uuid) -> Result<Vec<domain::LocationOccupancy>>;
+
+    async fn create_tasks(
+        &self,
+        tasks: Vec<domain::PutawayTask>,
+    ) -> Result<Vec<domain::PutawayTask>>;
+
+    /// Oldest first.
+    async fn list_tasks(&self, query: domain::PutawayTaskQuery)
+    -> Result<Vec<domain::PutawayTask>>;
+
+    /// Posts the transfer of a confirmed task and saves the task with it. Fails with
+    /// `NotOpen` if the task is no longer open.
+    async fn complete(
+        &self,
+        task: domain::PutawayTask,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<(domain::PutawayTask, Vec<domain::StockMovement>)>;
+
+    /// Fails with `NotOpen` if the task is no longer open.
+    async fn update(&self, task: domain::PutawayTask) -> Result<domain::PutawayTask>;
+}
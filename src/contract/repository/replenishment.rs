@@ -0,0 +1,52 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// `create` saves the rule, replacing the rule of the same product, warehouse and location.
+#[async_trait::async_trait]
+pub trait ReplenishmentRepository: Repository<domain::ReplenishmentRule> {
+    async fn list_rules(
+        &self,
+        query: domain::ReplenishmentRuleQuery,
+    ) -> Result<Vec<domain::ReplenishmentRule>>;
+
+    /// Quantity on open purchase orders and transfer orders bound for the warehouse.
+    async fn inbound_quantity(&self, product_id: Uuid, warehouse_id: Uuid) -> Result<Decimal>;
+
+    /// Saves the suggestions of a run. Pending suggestions of the evaluated rules are
+    /// superseded, whether the run suggested anything for them or not.
+    async fn replace_suggestions(
+        &self,
+        rule_ids: Vec<Uuid>,
+        suggestions: Vec<domain::ReplenishmentSuggestion>,
+    ) -> Result<Vec<domain::ReplenishmentSuggestion>>;
+
+    async fn get_suggestion(&self, id: Uuid) -> Result<domain::ReplenishmentSuggestion>;
+
+    /// Newest first.
+    async fn list_suggestions(
+        &self,
+        query: domain::ReplenishmentSuggestionQuery,
+    ) -> Result<Vec<domain::ReplenishmentSuggestion>>;
+
+    /// Saves the reviewed suggestion together with the purchase order it was accepted as.
+    async fn accept_as_purchase_order(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+        order: domain::PurchaseOrder,
+    ) -> Result<domain::ReplenishmentSuggestion>;
+
+    /// Saves the reviewed suggestion and posts the pick-face replenishment it was accepted as.
+    async fn accept_as_movement(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+        movement: domain::StockMovement,
+    ) -> Result<domain::ReplenishmentSuggestion>;
+
+    async fn update_suggestion(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+    ) -> Result<domain::ReplenishmentSuggestion>;
+}
@@ -0,0 +1,34 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// `create` reserves stock atomically and fails with `StockError::InsufficientStock`
+/// if the location does not have enough available quantity.
+#[async_trait::async_trait]
+pub trait ReservationRepository: Repository<domain::Reservation> {
+    async fn release(&self, id: Uuid) -> Result<domain::Reservation>;
+
+    /// Issues `quantity` of the reserved stock out of its location, or everything still
+    /// reserved when `None`, first expired lots first, and decreases the reservation
+    /// accordingly.
+    async fn consume(
+        &self,
+        id: Uuid,
+        quantity: Option<Decimal>,
+        serial_numbers: Vec<String>,
+        created_by: Option<Uuid>,
+    ) -> Result<Vec<domain::StockMovement>>;
+
+    /// Reservations of the document in every status, oldest first.
+    async fn list_by_document(
+        &self,
+        document_type: domain::DocumentType,
+        document_id: Uuid,
+    ) -> Result<Vec<domain::Reservation>>;
+
+    /// Marks every active reservation that expired before `now` as expired.
+    async fn expire_overdue(&self, now: DateTime<Utc>) -> Result<usize>;
+}
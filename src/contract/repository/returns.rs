@@ -0,0 +1,55 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Stock movements of a return authorization are posted together with its update, in one
+/// transaction.
+#[async_trait::async_trait]
+pub trait ReturnAuthorizationRepository: Repository<domain::ReturnAuthorization> {
+    /// Fails with `ReturnError::ExceedsShipped` if the lines, together with what other
+    /// authorizations of the sales order cover, exceed what was shipped on it.
+    async fn create_with_lines(
+        &self,
+        authorization: domain::ReturnAuthorization,
+        lines: Vec<domain::ReturnAuthorizationLine>,
+    ) -> Result<domain::ReturnAuthorization>;
+
+    async fn list_lines(&self, id: Uuid) -> Result<Vec<domain::ReturnAuthorizationLine>>;
+
+    /// Oldest first.
+    async fn list_inspections(&self, id: Uuid) -> Result<Vec<domain::ReturnInspection>>;
+
+    /// Quantity issued on the sales order, `None` if the product was never shipped on it.
+    async fn shipped_quantity(
+        &self,
+        sales_order_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Option<Decimal>>;
+
+    /// Quantity of the product already covered by return authorizations of the sales order.
+    /// Closed authorizations only count what was received.
+    async fn authorized_quantity(&self, sales_order_id: Uuid, product_id: Uuid) -> Result<Decimal>;
+
+    /// Saves the authorization, its lines and new inspections and posts the movements.
+    /// `read_lines` are the lines as the changes were computed from, the update fails with
+    /// `ReturnError::Changed` if another request changed them since, and with
+    /// `ReturnError::NotOpen` unless the authorization is still open.
+    async fn update_with_movements(
+        &self,
+        authorization: domain::ReturnAuthorization,
+        lines: Vec<domain::ReturnAuthorizationLine>,
+        read_lines: Vec<domain::ReturnAuthorizationLine>,
+        inspections: Vec<domain::ReturnInspection>,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<(domain::ReturnAuthorization, Vec<domain::StockMovement>)>;
+
+    /// Movements posted for the authorization, oldest first.
+    async fn list_movements(&self, id: Uuid) -> Result<Vec<domain::StockMovement>>;
+
+    async fn summarize(
+        &self,
+        query: domain::ReturnSummaryQuery,
+    ) -> Result<Vec<domain::ReturnSummary>>;
+}
@@ -1,5 +1,10 @@
 use crate::contract::repository::Repository;
 use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
 
 #[async_trait::async_trait]
-pub trait RuleRepository: Repository<domain::Rule> {}
+pub trait RuleRepository: Repository<domain::Rule> {
+    /// Returns every rule granted to the user through its roles.
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<domain::Rule>>;
+}
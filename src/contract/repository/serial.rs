@@ -0,0 +1,13 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// Serial numbers are registered and moved by stock movements; see `StockRepository`.
+#[async_trait::async_trait]
+pub trait SerialNumberRepository: Repository<domain::SerialNumber> {
+    async fn find(&self, product_id: Uuid, serial_number: &str) -> Result<domain::SerialNumber>;
+
+    /// Movements the unit took part in, oldest first.
+    async fn list_movements(&self, id: Uuid) -> Result<Vec<domain::StockMovement>>;
+}
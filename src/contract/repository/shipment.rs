@@ -0,0 +1,33 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait ShipmentRepository: Repository<domain::Shipment> {
+    /// Newest first.
+    async fn list(&self, query: domain::ShipmentQuery) -> Result<Vec<domain::Shipment>>;
+
+    /// Parcels of the shipment in the order they were added.
+    async fn list_parcels(&self, shipment_id: Uuid) -> Result<Vec<domain::Parcel>>;
+
+    async fn get_parcel(&self, id: Uuid) -> Result<domain::Parcel>;
+
+    /// Fails with `ShipmentError::NotOpen` if the shipment is no longer open.
+    async fn add_parcel(&self, parcel: domain::Parcel) -> Result<domain::Parcel>;
+
+    /// Fails with `ShipmentError::NotOpen` if the shipment is no longer open.
+    async fn remove_parcel(&self, shipment_id: Uuid, parcel_id: Uuid) -> Result<()>;
+
+    /// Saves the carrier, service and cost of the shipment along with the tracking number
+    /// and label of its parcels. Fails with `ShipmentError::NotOpen` if the shipment was
+    /// shipped or cancelled meanwhile.
+    async fn ship(
+        &self,
+        shipment: domain::Shipment,
+        parcels: Vec<domain::Parcel>,
+    ) -> Result<domain::Shipment>;
+
+    /// Fails with `ShipmentError::NotOpen` if the shipment is no longer open.
+    async fn cancel(&self, id: Uuid) -> Result<domain::Shipment>;
+}
@@ -0,0 +1,32 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait SnapshotRepository: Repository<domain::StockSnapshot> {
+    /// Computes the positions at `snapshot.as_of` and saves them with the snapshot.
+    async fn take(&self, snapshot: domain::StockSnapshot) -> Result<domain::StockSnapshot>;
+
+    /// The daily or month-end snapshot as of the moment, if it was taken.
+    async fn find_scheduled(&self, as_of: DateTime<Utc>) -> Result<Option<domain::StockSnapshot>>;
+
+    /// Most recent first.
+    async fn list(&self, query: domain::StockSnapshotQuery) -> Result<Vec<domain::StockSnapshot>>;
+
+    async fn list_lines(
+        &self,
+        snapshot_id: Uuid,
+        query: domain::StockPositionQuery,
+    ) -> Result<Vec<domain::StockPosition>>;
+
+    /// Stock on hand at the moment, by product, location, lot and status. Starts from the
+    /// latest snapshot taken as of the moment or earlier and replays the movements posted
+    /// since.
+    async fn positions_as_of(
+        &self,
+        as_of: DateTime<Utc>,
+        query: domain::StockPositionQuery,
+    ) -> Result<Vec<domain::StockPosition>>;
+}
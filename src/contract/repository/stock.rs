@@ -0,0 +1,46 @@
+use crate::domain;
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait StockRepository: Send + Sync {
+    /// Records the movement and updates the affected balances in a single transaction.
+    /// Fails with `StockError::InsufficientStock` if the source location does not have
+    /// enough unreserved stock. Without a lot, outbound stock is allocated first-expired-first-out
+    /// and one movement is recorded per lot. Given serial numbers move along with the stock.
+    async fn post_movement(
+        &self,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<Vec<domain::StockMovement>>;
+
+    /// Posts the movements in a single transaction, all or none of them. Reserved stock
+    /// can only be moved when `respect_reservations` is off.
+    async fn post_movements(
+        &self,
+        movements: Vec<domain::StockMovement>,
+        respect_reservations: bool,
+    ) -> Result<Vec<domain::StockMovement>>;
+
+    /// Posts the movements in a single transaction regardless of reservations, then releases
+    /// the newest active reservations at every location the stock left until the remaining
+    /// ones are backed by allocatable stock again. Returns the posted movements and the
+    /// released reservations.
+    async fn post_movements_releasing_reservations(
+        &self,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<(Vec<domain::StockMovement>, Vec<domain::Reservation>)>;
+
+    async fn get_levels(&self, query: domain::StockLevelQuery) -> Result<Vec<domain::StockLevel>>;
+
+    /// Balances with stock on hand, by product, location and lot.
+    async fn list_balances(
+        &self,
+        query: domain::StockBalanceQuery,
+    ) -> Result<Vec<domain::StockBalance>>;
+
+    /// Movements in the order they were posted.
+    async fn list_movements(
+        &self,
+        query: domain::StockMovementQuery,
+    ) -> Result<Vec<domain::StockMovement>>;
+}
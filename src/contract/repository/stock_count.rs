@@ -0,0 +1,30 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// `create` plans the session and generates a count task for every stock balance
+/// of the warehouse that matches the session scope.
+#[async_trait::async_trait]
+pub trait StockCountRepository: Repository<domain::CountSession> {
+    async fn list_tasks(&self, session_id: Uuid) -> Result<Vec<domain::CountTask>>;
+
+    async fn get_task(&self, id: Uuid) -> Result<domain::CountTask>;
+
+    /// Adds a task to its session, failing with `SessionNotOpen` unless the session is
+    /// still open.
+    async fn create_task(&self, task: domain::CountTask) -> Result<domain::CountTask>;
+
+    /// Saves the task and, when given, posts the adjustment in the same transaction. Fails
+    /// with `TaskChanged` unless the task still has one of the `expected` statuses, and
+    /// with `StockMoved` when an adjustment is given but the stock counted is no longer the
+    /// `expected_quantity` of the task.
+    async fn update_task(
+        &self,
+        task: domain::CountTask,
+        expected: &[domain::CountTaskStatus],
+        adjustment: Option<domain::StockMovement>,
+    ) -> Result<domain::CountTask>;
+
+    async fn update_session(&self, session: domain::CountSession) -> Result<domain::CountSession>;
+}
@@ -0,0 +1,60 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// Stock movements of a transfer order are posted together with the order update, in one
+/// transaction. Movements out of the transit location without serial numbers are allocated
+/// over whatever the transit location holds, blocked lots included.
+#[async_trait::async_trait]
+pub trait TransferOrderRepository: Repository<domain::TransferOrder> {
+    async fn create_with_lines(
+        &self,
+        order: domain::TransferOrder,
+        lines: Vec<domain::TransferOrderLine>,
+    ) -> Result<domain::TransferOrder>;
+
+    async fn list_lines(&self, order_id: Uuid) -> Result<Vec<domain::TransferOrderLine>>;
+
+    /// Creates the transit location and moves the lines into it. Fails with
+    /// `TransferOrderError::Changed` unless the order is still open.
+    async fn ship(
+        &self,
+        order: domain::TransferOrder,
+        transit_location: domain::Location,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<domain::TransferOrder>;
+
+    /// Saves the order and its lines and posts the movements out of the transit location.
+    /// `read_status` and `read_lines` are the order as the changes were computed from, the
+    /// update fails with `TransferOrderError::Changed` if another request changed it since.
+    async fn update_with_movements(
+        &self,
+        order: domain::TransferOrder,
+        lines: Vec<domain::TransferOrderLine>,
+        read_status: domain::TransferOrderStatus,
+        read_lines: Vec<domain::TransferOrderLine>,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<(domain::TransferOrder, Vec<domain::StockMovement>)>;
+
+    /// Movements posted for the order, oldest first.
+    async fn list_movements(&self, order_id: Uuid) -> Result<Vec<domain::StockMovement>>;
+
+    /// Serial numbers of the product still at the transit location, in serial number order.
+    async fn list_in_transit_serials(
+        &self,
+        transit_location_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Vec<String>>;
+
+    async fn list_in_transit(
+        &self,
+        query: domain::InTransitQuery,
+    ) -> Result<Vec<domain::InTransitStock>>;
+
+    /// Lines of closed orders that were written off, most recently closed first.
+    async fn list_discrepancies(
+        &self,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<domain::TransferDiscrepancy>>;
+}
@@ -0,0 +1,27 @@
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// Receipts and issues are valued when posted; see `StockRepository`.
+#[async_trait::async_trait]
+pub trait ValuationRepository: Send + Sync {
+    async fn get_settings(&self) -> Result<domain::ValuationSettings>;
+
+    async fn update_settings(
+        &self,
+        settings: domain::ValuationSettings,
+    ) -> Result<domain::ValuationSettings>;
+
+    /// `None` makes the product follow the company costing method.
+    async fn set_costing_method(
+        &self,
+        product_id: Uuid,
+        costing_method: Option<domain::CostingMethod>,
+    ) -> Result<domain::Product>;
+
+    /// Products that had stock movements by `as_of`, ordered by product.
+    async fn list_valuations(
+        &self,
+        query: domain::ValuationQuery,
+    ) -> Result<Vec<domain::ProductValuation>>;
+}
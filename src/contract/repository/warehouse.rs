@@ -0,0 +1,28 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait WarehouseRepository: Repository<domain::Warehouse> {}
+
+#[async_trait::async_trait]
+pub trait LocationRepository: Repository<domain::Location> {
+    async fn find_by_code(&self, warehouse_id: Uuid, code: &str) -> Result<domain::Location>;
+
+    /// Ordered by warehouse and code, leaving out in-transit locations.
+    async fn list(&self, query: domain::LocationQuery) -> Result<Vec<domain::Location>>;
+
+    /// Leaves out in-transit locations.
+    async fn page(
+        &self,
+        query: domain::LocationListQuery,
+    ) -> Result<domain::Page<domain::Location>>;
+
+    /// Replaces the custom attribute values of the location.
+    async fn set_attributes(
+        &self,
+        id: Uuid,
+        attributes: serde_json::Value,
+    ) -> Result<domain::Location>;
+}
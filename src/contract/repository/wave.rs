@@ -0,0 +1,52 @@
+use crate::contract::repository::Repository;
+use crate::domain;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Pick orders and the waves they are picked in. Picking consumes the reservation of the
+/// task, in the same transaction as the task update.
+#[async_trait::async_trait]
+pub trait WaveRepository: Repository<domain::Wave> {
+    async fn create_order(&self, order: domain::PickOrder) -> Result<domain::PickOrder>;
+
+    async fn get_order(&self, id: Uuid) -> Result<domain::PickOrder>;
+
+    /// Oldest first.
+    async fn list_orders(&self, query: domain::PickOrderQuery) -> Result<Vec<domain::PickOrder>>;
+
+    async fn update_order(&self, order: domain::PickOrder) -> Result<domain::PickOrder>;
+
+    /// Creates the wave and assigns the orders to it, failing with
+    /// `WaveError::OrderNotReleased` if any of them was waved or cancelled meanwhile.
+    async fn create_with_tasks(
+        &self,
+        wave: domain::Wave,
+        orders: Vec<domain::PickOrder>,
+        tasks: Vec<domain::PickTask>,
+    ) -> Result<domain::Wave>;
+
+    /// Ordered by tote, then release.
+    async fn list_wave_orders(&self, wave_id: Uuid) -> Result<Vec<domain::PickOrder>>;
+
+    /// Ordered along the pick path.
+    async fn list_tasks(&self, wave_id: Uuid) -> Result<Vec<domain::PickTask>>;
+
+    async fn get_task(&self, id: Uuid) -> Result<domain::PickTask>;
+
+    /// Consumes `quantity` of the task's reservation, out of `lot_id` when given, and marks
+    /// the task picked, then its order and wave once nothing is left open in them.
+    async fn pick(
+        &self,
+        task_id: Uuid,
+        quantity: Decimal,
+        lot_id: Option<Uuid>,
+        serial_numbers: Vec<String>,
+        picked_by: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<(domain::PickTask, Vec<domain::StockMovement>)>;
+
+    /// Cancels the open tasks and releases the orders not picked in full for another wave.
+    async fn cancel(&self, wave_id: Uuid) -> Result<domain::Wave>;
+}
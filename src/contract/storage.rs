@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+/// Where attachment contents are kept, addressed by key. Metadata stays in the database, so
+/// a backend only stores and returns bytes; backends are created in
+/// `crate::storage::storage` from the configuration.
+#[async_trait::async_trait]
+pub trait Storage: Send + Sync {
+    /// Stores the content under the key, replacing any content already there.
+    async fn put(&self, key: &str, content: Vec<u8>, content_type: &str) -> Result<()>;
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>>;
+
+    /// Removes the content under the key. Removing a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<()>;
+}
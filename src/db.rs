@@ -1,8 +1,10 @@
 use crate::config::DatabaseConfig;
-use deadpool::managed::Object;
-use diesel_async::AsyncPgConnection;
-use diesel_async::pooled_connection::AsyncDieselConnectionManager;
+use deadpool::managed::{Hook, HookError, Object};
+use diesel_async::pooled_connection::{AsyncDieselConnectionManager, PoolError};
+use diesel_async::{AsyncPgConnection, SimpleAsyncConnection};
 use secrecy::ExposeSecret;
+use std::future::Future;
+use uuid::Uuid;
 
 pub type ConnectionManager = AsyncDieselConnectionManager<AsyncPgConnection>;
 
@@ -10,12 +12,57 @@ pub type Connection = Object<ConnectionManager>;
 
 pub type Pool = deadpool::managed::Pool<ConnectionManager, Connection>;
 
+/// Database role connections acting as an organization switch to. It is subject to the
+/// row-level security policies of the tables owned by organizations.
+const TENANT_ROLE: &str = "warehouse_tenant";
+
+tokio::task_local! {
+    static ORGANIZATION_ID: Uuid;
+}
+
 pub async fn connect(conf: &DatabaseConfig) -> Pool {
     let conn_manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new(
         conf.connection_string().expose_secret(),
     );
 
     Pool::builder(conn_manager)
+        .post_create(Hook::async_fn(|conn, _| {
+            Box::pin(act_as_organization(conn, current_organization_id()))
+        }))
+        .post_recycle(Hook::async_fn(|conn, _| {
+            Box::pin(act_as_organization(conn, current_organization_id()))
+        }))
         .build()
         .expect("Failed to build connection pool")
 }
+
+/// Runs the future as the organization: the connections it checks out only see and write
+/// rows of that organization.
+pub async fn with_organization<F: Future>(organization_id: Uuid, f: F) -> F::Output {
+    ORGANIZATION_ID.scope(organization_id, f).await
+}
+
+/// The organization the current task acts as, if any.
+pub fn current_organization_id() -> Option<Uuid> {
+    ORGANIZATION_ID
+        .try_with(|organization_id| *organization_id)
+        .ok()
+}
+
+/// Connections checked out outside of an organization keep the role they logged in with,
+/// which owns the tables and so is not restricted to any organization.
+async fn act_as_organization(
+    conn: &mut AsyncPgConnection,
+    organization_id: Option<Uuid>,
+) -> Result<(), HookError<PoolError>> {
+    let query = match organization_id {
+        Some(organization_id) => format!(
+            "SET ROLE {TENANT_ROLE}; SELECT set_config('app.organization_id', '{organization_id}', false)"
+        ),
+        None => "RESET ROLE; SELECT set_config('app.organization_id', '', false)".to_string(),
+    };
+
+    conn.batch_execute(&query)
+        .await
+        .map_err(|err| HookError::Backend(PoolError::QueryError(err)))
+}
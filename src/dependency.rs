@@ -1,18 +1,63 @@
+use crate::carrier;
 use crate::config::Config;
+use crate::contract::carrier::Carrier;
 use crate::contract::repository::{
-    RoleRepository, RoleRuleRepository, RuleRepository, UserRepository, UserRoleRepository,
+    AbcRepository, AttachmentRepository, AttributeRepository, BusinessPartnerRepository,
+    CrossDockRepository, KitRepository, LocationRepository, LotRepository, OrganizationRepository,
+    PalletRepository, ProductRepository, PutawayRepository, ReplenishmentRepository,
+    ReservationRepository, ReturnAuthorizationRepository, RoleRepository, RoleRuleRepository,
+    RuleRepository, SerialNumberRepository, ShipmentRepository, SnapshotRepository,
+    StockCountRepository, StockRepository, TransferOrderRepository, UserRepository,
+    UserRoleRepository, ValuationRepository, WarehouseRepository, WaveRepository,
 };
+use crate::contract::storage::Storage;
 use crate::db;
 use crate::repository::postgresql::{
-    PostgresRoleRepository, PostgresRoleRuleRepository, PostgresRuleRepository,
-    PostgresUserRepository, PostgresUserRoleRepository,
+    PostgresAbcRepository, PostgresAttachmentRepository, PostgresAttributeRepository,
+    PostgresBusinessPartnerRepository, PostgresCrossDockRepository, PostgresKitRepository,
+    PostgresLocationRepository, PostgresLotRepository, PostgresOrganizationRepository,
+    PostgresPalletRepository, PostgresProductRepository, PostgresPutawayRepository,
+    PostgresReplenishmentRepository, PostgresReservationRepository,
+    PostgresReturnAuthorizationRepository, PostgresRoleRepository, PostgresRoleRuleRepository,
+    PostgresRuleRepository, PostgresSerialNumberRepository, PostgresShipmentRepository,
+    PostgresSnapshotRepository, PostgresStockCountRepository, PostgresStockRepository,
+    PostgresTransferOrderRepository, PostgresUserRepository, PostgresUserRoleRepository,
+    PostgresValuationRepository, PostgresWarehouseRepository, PostgresWaveRepository,
 };
+use crate::service::abc::AbcService;
+use crate::service::attachment::AttachmentService;
+use crate::service::attribute::AttributeService;
 use crate::service::auth::AuthService;
+use crate::service::authorization::AuthorizationService;
+use crate::service::business_partner::BusinessPartnerService;
+use crate::service::cross_dock::CrossDockService;
+use crate::service::kit::KitService;
+use crate::service::label::LabelService;
+use crate::service::lot::LotService;
+use crate::service::organization::OrganizationService;
+use crate::service::pallet::PalletService;
+use crate::service::product::ProductService;
+use crate::service::putaway::PutawayService;
+use crate::service::replenishment::ReplenishmentService;
+use crate::service::reservation::ReservationService;
+use crate::service::returns::ReturnAuthorizationService;
+use crate::service::scan::ScanService;
+use crate::service::serial::SerialNumberService;
+use crate::service::shipment::ShipmentService;
+use crate::service::snapshot::SnapshotService;
+use crate::service::stock::StockService;
+use crate::service::stock_count::StockCountService;
+use crate::service::stock_hold::StockHoldService;
+use crate::service::transfer::TransferOrderService;
+use crate::service::valuation::ValuationService;
+use crate::service::warehouse::WarehouseService;
+use crate::service::wave::WaveService;
 use despatma::dependency_container;
+use std::sync::Arc;
 
 #[dependency_container(pub)]
 impl AppContainer {
-    fn new(config: Config) {}
+    fn new(config: Config, storage: Arc<dyn Storage>) {}
 
     #[Singleton]
     async fn db_pool(&self, config: &Config) -> db::Pool {
@@ -23,6 +68,10 @@ impl AppContainer {
         Box::new(PostgresUserRepository::new(db_pool.clone()))
     }
 
+    async fn organization_repository(&self, db_pool: &db::Pool) -> Box<dyn OrganizationRepository> {
+        Box::new(PostgresOrganizationRepository::new(db_pool.clone()))
+    }
+
     async fn user_role_repository(&self, db_pool: &db::Pool) -> Box<dyn UserRoleRepository> {
         Box::new(PostgresUserRoleRepository::new(db_pool.clone()))
     }
@@ -39,12 +88,479 @@ impl AppContainer {
         Box::new(PostgresRuleRepository::new(db_pool.clone()))
     }
 
+    async fn warehouse_repository(&self, db_pool: &db::Pool) -> Box<dyn WarehouseRepository> {
+        Box::new(PostgresWarehouseRepository::new(db_pool.clone()))
+    }
+
+    async fn location_repository(&self, db_pool: &db::Pool) -> Box<dyn LocationRepository> {
+        Box::new(PostgresLocationRepository::new(db_pool.clone()))
+    }
+
+    async fn product_repository(&self, db_pool: &db::Pool) -> Box<dyn ProductRepository> {
+        Box::new(PostgresProductRepository::new(db_pool.clone()))
+    }
+
+    async fn lot_repository(&self, db_pool: &db::Pool) -> Box<dyn LotRepository> {
+        Box::new(PostgresLotRepository::new(db_pool.clone()))
+    }
+
+    async fn serial_number_repository(
+        &self,
+        db_pool: &db::Pool,
+    ) -> Box<dyn SerialNumberRepository> {
+        Box::new(PostgresSerialNumberRepository::new(db_pool.clone()))
+    }
+
+    async fn pallet_repository(&self, db_pool: &db::Pool) -> Box<dyn PalletRepository> {
+        Box::new(PostgresPalletRepository::new(db_pool.clone()))
+    }
+
+    async fn stock_repository(&self, db_pool: &db::Pool) -> Box<dyn StockRepository> {
+        Box::new(PostgresStockRepository::new(db_pool.clone()))
+    }
+
+    async fn stock_count_repository(&self, db_pool: &db::Pool) -> Box<dyn StockCountRepository> {
+        Box::new(PostgresStockCountRepository::new(db_pool.clone()))
+    }
+
+    async fn transfer_order_repository(
+        &self,
+        db_pool: &db::Pool,
+    ) -> Box<dyn TransferOrderRepository> {
+        Box::new(PostgresTransferOrderRepository::new(db_pool.clone()))
+    }
+
+    async fn replenishment_repository(
+        &self,
+        db_pool: &db::Pool,
+    ) -> Box<dyn ReplenishmentRepository> {
+        Box::new(PostgresReplenishmentRepository::new(db_pool.clone()))
+    }
+
+    async fn reservation_repository(&self, db_pool: &db::Pool) -> Box<dyn ReservationRepository> {
+        Box::new(PostgresReservationRepository::new(db_pool.clone()))
+    }
+
+    async fn return_authorization_repository(
+        &self,
+        db_pool: &db::Pool,
+    ) -> Box<dyn ReturnAuthorizationRepository> {
+        Box::new(PostgresReturnAuthorizationRepository::new(db_pool.clone()))
+    }
+
+    async fn putaway_repository(&self, db_pool: &db::Pool) -> Box<dyn PutawayRepository> {
+        Box::new(PostgresPutawayRepository::new(db_pool.clone()))
+    }
+
+    async fn wave_repository(&self, db_pool: &db::Pool) -> Box<dyn WaveRepository> {
+        Box::new(PostgresWaveRepository::new(db_pool.clone()))
+    }
+
+    async fn snapshot_repository(&self, db_pool: &db::Pool) -> Box<dyn SnapshotRepository> {
+        Box::new(PostgresSnapshotRepository::new(db_pool.clone()))
+    }
+
+    async fn abc_repository(&self, db_pool: &db::Pool) -> Box<dyn AbcRepository> {
+        Box::new(PostgresAbcRepository::new(db_pool.clone()))
+    }
+
+    async fn cross_dock_repository(&self, db_pool: &db::Pool) -> Box<dyn CrossDockRepository> {
+        Box::new(PostgresCrossDockRepository::new(db_pool.clone()))
+    }
+
+    async fn shipment_repository(&self, db_pool: &db::Pool) -> Box<dyn ShipmentRepository> {
+        Box::new(PostgresShipmentRepository::new(db_pool.clone()))
+    }
+
+    async fn attribute_repository(&self, db_pool: &db::Pool) -> Box<dyn AttributeRepository> {
+        Box::new(PostgresAttributeRepository::new(db_pool.clone()))
+    }
+
+    async fn business_partner_repository(
+        &self,
+        db_pool: &db::Pool,
+    ) -> Box<dyn BusinessPartnerRepository> {
+        Box::new(PostgresBusinessPartnerRepository::new(db_pool.clone()))
+    }
+
+    async fn carriers(&self, config: &Config) -> Vec<Box<dyn Carrier>> {
+        carrier::carriers(&config.shipping)
+    }
+
+    async fn attachment_repository(&self, db_pool: &db::Pool) -> Box<dyn AttachmentRepository> {
+        Box::new(PostgresAttachmentRepository::new(db_pool.clone()))
+    }
+
+    async fn kit_repository(&self, db_pool: &db::Pool) -> Box<dyn KitRepository> {
+        Box::new(PostgresKitRepository::new(db_pool.clone()))
+    }
+
+    async fn valuation_repository(&self, db_pool: &db::Pool) -> Box<dyn ValuationRepository> {
+        Box::new(PostgresValuationRepository::new(db_pool.clone()))
+    }
+
     #[Singleton]
     async fn auth_service(
         &self,
         config: &Config,
         user_repository: Box<dyn UserRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
     ) -> AuthService {
-        AuthService::new(config.server.jwtsecret.clone(), user_repository)
+        AuthService::new(
+            config.server.jwtsecret.clone(),
+            user_repository,
+            organization_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn authorization_service(
+        &self,
+        rule_repository: Box<dyn RuleRepository>,
+    ) -> AuthorizationService {
+        AuthorizationService::new(rule_repository)
+    }
+
+    #[Singleton]
+    async fn organization_service(
+        &self,
+        organization_repository: Box<dyn OrganizationRepository>,
+        user_repository: Box<dyn UserRepository>,
+        rule_repository: Box<dyn RuleRepository>,
+    ) -> OrganizationService {
+        OrganizationService::new(organization_repository, user_repository, rule_repository)
+    }
+
+    #[Singleton]
+    async fn warehouse_service(
+        &self,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        attribute_repository: Box<dyn AttributeRepository>,
+    ) -> WarehouseService {
+        WarehouseService::new(
+            warehouse_repository,
+            location_repository,
+            attribute_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn product_service(
+        &self,
+        product_repository: Box<dyn ProductRepository>,
+        attribute_repository: Box<dyn AttributeRepository>,
+    ) -> ProductService {
+        ProductService::new(product_repository, attribute_repository)
+    }
+
+    #[Singleton]
+    async fn attribute_service(
+        &self,
+        attribute_repository: Box<dyn AttributeRepository>,
+    ) -> AttributeService {
+        AttributeService::new(attribute_repository)
+    }
+
+    #[Singleton]
+    async fn stock_service(
+        &self,
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> StockService {
+        StockService::new(stock_repository, product_repository, lot_repository)
+    }
+
+    #[Singleton]
+    async fn lot_service(&self, lot_repository: Box<dyn LotRepository>) -> LotService {
+        LotService::new(lot_repository)
+    }
+
+    #[Singleton]
+    async fn serial_number_service(
+        &self,
+        serial_number_repository: Box<dyn SerialNumberRepository>,
+    ) -> SerialNumberService {
+        SerialNumberService::new(serial_number_repository)
+    }
+
+    #[Singleton]
+    async fn reservation_service(
+        &self,
+        config: &Config,
+        reservation_repository: Box<dyn ReservationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> ReservationService {
+        ReservationService::new(
+            config.reservation.clone(),
+            reservation_repository,
+            product_repository,
+            organization_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn stock_count_service(
+        &self,
+        stock_count_repository: Box<dyn StockCountRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> StockCountService {
+        StockCountService::new(
+            stock_count_repository,
+            stock_repository,
+            product_repository,
+            location_repository,
+            lot_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn transfer_order_service(
+        &self,
+        transfer_order_repository: Box<dyn TransferOrderRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> TransferOrderService {
+        TransferOrderService::new(
+            transfer_order_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn replenishment_service(
+        &self,
+        config: &Config,
+        replenishment_repository: Box<dyn ReplenishmentRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> ReplenishmentService {
+        ReplenishmentService::new(
+            config.replenishment.clone(),
+            replenishment_repository,
+            stock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            organization_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn return_authorization_service(
+        &self,
+        return_authorization_repository: Box<dyn ReturnAuthorizationRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> ReturnAuthorizationService {
+        ReturnAuthorizationService::new(
+            return_authorization_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            lot_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn snapshot_service(
+        &self,
+        config: &Config,
+        snapshot_repository: Box<dyn SnapshotRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> SnapshotService {
+        SnapshotService::new(
+            config.snapshot.clone(),
+            snapshot_repository,
+            organization_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn abc_service(
+        &self,
+        abc_repository: Box<dyn AbcRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        putaway_repository: Box<dyn PutawayRepository>,
+        stock_repository: Box<dyn StockRepository>,
+    ) -> AbcService {
+        AbcService::new(
+            abc_repository,
+            warehouse_repository,
+            putaway_repository,
+            stock_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn cross_dock_service(
+        &self,
+        cross_dock_repository: Box<dyn CrossDockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        stock_repository: Box<dyn StockRepository>,
+    ) -> CrossDockService {
+        CrossDockService::new(
+            cross_dock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            stock_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn shipment_service(
+        &self,
+        shipment_repository: Box<dyn ShipmentRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        wave_repository: Box<dyn WaveRepository>,
+        carriers: Vec<Box<dyn Carrier>>,
+    ) -> ShipmentService {
+        ShipmentService::new(
+            shipment_repository,
+            warehouse_repository,
+            wave_repository,
+            carriers,
+        )
+    }
+
+    #[Singleton]
+    async fn business_partner_service(
+        &self,
+        business_partner_repository: Box<dyn BusinessPartnerRepository>,
+    ) -> BusinessPartnerService {
+        BusinessPartnerService::new(business_partner_repository)
+    }
+
+    #[Singleton]
+    async fn attachment_service(
+        &self,
+        config: &Config,
+        attachment_repository: Box<dyn AttachmentRepository>,
+        storage: &Arc<dyn Storage>,
+    ) -> AttachmentService {
+        AttachmentService::new(
+            config.storage.clone(),
+            attachment_repository,
+            storage.clone(),
+        )
+    }
+
+    #[Singleton]
+    async fn stock_hold_service(
+        &self,
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> StockHoldService {
+        StockHoldService::new(stock_repository, product_repository, lot_repository)
+    }
+
+    #[Singleton]
+    async fn putaway_service(
+        &self,
+        putaway_repository: Box<dyn PutawayRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> PutawayService {
+        PutawayService::new(
+            putaway_repository,
+            stock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn wave_service(
+        &self,
+        wave_repository: Box<dyn WaveRepository>,
+        reservation_repository: Box<dyn ReservationRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> WaveService {
+        WaveService::new(
+            wave_repository,
+            reservation_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            lot_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn kit_service(
+        &self,
+        kit_repository: Box<dyn KitRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> KitService {
+        KitService::new(
+            kit_repository,
+            stock_repository,
+            location_repository,
+            product_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn valuation_service(
+        &self,
+        valuation_repository: Box<dyn ValuationRepository>,
+    ) -> ValuationService {
+        ValuationService::new(valuation_repository)
+    }
+
+    #[Singleton]
+    async fn pallet_service(
+        &self,
+        config: &Config,
+        pallet_repository: Box<dyn PalletRepository>,
+        location_repository: Box<dyn LocationRepository>,
+    ) -> PalletService {
+        PalletService::new(config.gs1.clone(), pallet_repository, location_repository)
+    }
+
+    #[Singleton]
+    async fn label_service(
+        &self,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        pallet_repository: Box<dyn PalletRepository>,
+    ) -> LabelService {
+        LabelService::new(
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            pallet_repository,
+        )
+    }
+
+    #[Singleton]
+    async fn scan_service(&self, product_repository: Box<dyn ProductRepository>) -> ScanService {
+        ScanService::new(product_repository)
     }
 }
@@ -1,11 +1,65 @@
+mod abc;
+mod attachment;
+mod attribute;
 mod auth;
+mod business_partner;
+mod cross_dock;
 mod error;
+mod gs1;
+mod kit;
+mod label;
+mod list;
+mod lot;
+mod organization;
+mod pallet;
+mod product;
+mod putaway;
+mod replenishment;
+mod reservation;
+mod returns;
 mod role;
 mod rule;
+mod serial;
+mod shipment;
+mod snapshot;
+mod stock;
+mod stock_count;
+mod stock_hold;
+mod transfer;
 mod user;
+mod valuation;
+mod warehouse;
+mod wave;
 
+pub use abc::*;
+pub use attachment::*;
+pub use attribute::*;
 pub use auth::*;
+pub use business_partner::*;
+pub use cross_dock::*;
 pub use error::*;
+pub use gs1::*;
+pub use kit::*;
+pub use label::*;
+pub use list::*;
+pub use lot::*;
+pub use organization::*;
+pub use pallet::*;
+pub use product::*;
+pub use putaway::*;
+pub use replenishment::*;
+pub use reservation::*;
+pub use returns::*;
 pub use role::*;
 pub use rule::*;
+pub use serial::*;
+pub use shipment::*;
+pub use snapshot::*;
+pub use stock::*;
+pub use stock_count::*;
+pub use stock_hold::*;
+pub use transfer::*;
 pub use user::*;
+pub use valuation::*;
+pub use warehouse::*;
+pub use wave::*;
@@ -0,0 +1,252 @@
+use crate::domain::{AbcClass, LocationOccupancy, pick_path};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// What products are ranked by: `PickFrequency` counts the issues of a product,
+/// `Value` sums their cost.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::AbcCriterion")
+)]
+pub enum AbcCriterion {
+    PickFrequency,
+    Value,
+}
+
+/// A recommendation stays `Pending` until it is accepted or a later analysis of the
+/// warehouse replaces it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::SlottingStatus"
+    )
+)]
+pub enum SlottingStatus {
+    Pending,
+    Accepted,
+    Superseded,
+}
+
+/// Classification of the products stored in or issued from a warehouse over the
+/// `window_days` before `period_end`. Applying it sets the ABC class of the products,
+/// which cycle-count sessions can be scoped by.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::abc_analyses))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct AbcAnalysis {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub criterion: AbcCriterion,
+    pub window_days: i32,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub a_share: Decimal,
+    pub b_share: Decimal,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct AbcAnalysisData {
+    pub warehouse_id: Uuid,
+    pub criterion: AbcCriterion,
+    pub window_days: i32,
+    /// Share of the total score covered by class A products.
+    pub a_share: Decimal,
+    /// Share of the total score covered by class A and B products together.
+    pub b_share: Decimal,
+}
+
+#[derive(Clone, Default)]
+pub struct AbcAnalysisQuery {
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// Rank of a product in an analysis, 1 for the highest score. Shares are fractions of
+/// the total score of the warehouse.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::abc_analysis_lines))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct AbcAnalysisLine {
+    pub id: Uuid,
+    pub analysis_id: Uuid,
+    pub product_id: Uuid,
+    pub rank: i32,
+    pub score: Decimal,
+    pub share: Decimal,
+    pub cumulative_share: Decimal,
+    pub abc_class: AbcClass,
+}
+
+#[derive(Clone)]
+pub struct ProductScore {
+    pub product_id: Uuid,
+    pub score: Decimal,
+}
+
+/// Ranks the products by score, ties by product id, and classifies them along the
+/// cumulative share of the total. A product is class A while the products ranked above
+/// it cover less than `a_share`, so the top product always is, and class B while they
+/// cover less than `b_share`. Products without a score are class C.
+pub fn classify(analysis: &AbcAnalysis, mut scores: Vec<ProductScore>) -> Vec<AbcAnalysisLine> {
+    scores.sort_by(|a, b| b.score.cmp(&a.score).then(a.product_id.cmp(&b.product_id)));
+    let total: Decimal = scores.iter().map(|score| score.score).sum();
+
+    let mut covered = Decimal::ZERO;
+    scores
+        .into_iter()
+        .enumerate()
+        .map(|(index, score)| {
+            let abc_class = if score.score.is_zero() || covered >= analysis.b_share {
+                AbcClass::C
+            } else if covered >= analysis.a_share {
+                AbcClass::B
+            } else {
+                AbcClass::A
+            };
+            let share = if total.is_zero() {
+                Decimal::ZERO
+            } else {
+                score.score / total
+            };
+            covered += share;
+
+            AbcAnalysisLine {
+                id: Uuid::new_v4(),
+                analysis_id: analysis.id,
+                product_id: score.product_id,
+                rank: index as i32 + 1,
+                score: score.score,
+                share: share.round_dp(6),
+                cumulative_share: covered.min(Decimal::ONE).round_dp(6),
+                abc_class,
+            }
+        })
+        .collect()
+}
+
+/// Proposed move of a fast mover into a more accessible location. Accepting it transfers
+/// the available stock of the product there, and the stock of the displaced product, if
+/// any, back into the location the product leaves.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::slotting_recommendations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct SlottingRecommendation {
+    pub id: Uuid,
+    pub analysis_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    /// Rank of the product in the analysis.
+    pub rank: i32,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub displaced_product_id: Option<Uuid>,
+    pub status: SlottingStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct SlottingRecommendationQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub analysis_id: Option<Uuid>,
+    pub status: Option<SlottingStatus>,
+}
+
+/// Moves class A products, highest ranked first, into the storage locations that come
+/// first on the pick path, one product per location. Only locations holding a single
+/// product or nothing take part. A product sitting in several of them counts as stored
+/// in the one that comes first, products stored elsewhere keep their place. Each move
+/// swaps the products of the two locations, and later moves start from the layout the
+/// earlier ones leave behind.
+pub fn slotting(
+    analysis: &AbcAnalysis,
+    lines: &[AbcAnalysisLine],
+    storage: &[LocationOccupancy],
+) -> Vec<SlottingRecommendation> {
+    let slots = pick_path(
+        storage
+            .iter()
+            .filter(|occupancy| occupancy.product_ids.len() <= 1)
+            .map(|occupancy| occupancy.location.clone())
+            .collect(),
+    );
+
+    let stored: HashMap<Uuid, Uuid> = storage
+        .iter()
+        .filter_map(|occupancy| match occupancy.product_ids.as_slice() {
+            [product_id] => Some((occupancy.location.id, *product_id)),
+            _ => None,
+        })
+        .collect();
+    let mut occupant: HashMap<Uuid, Option<Uuid>> = slots
+        .iter()
+        .map(|slot| (slot.id, stored.get(&slot.id).copied()))
+        .collect();
+    let mut position: HashMap<Uuid, Uuid> = HashMap::new();
+    for slot in slots.iter().rev() {
+        if let Some(product_id) = occupant[&slot.id] {
+            position.insert(product_id, slot.id);
+        }
+    }
+
+    let mut fast_movers = lines
+        .iter()
+        .filter(|line| line.abc_class == AbcClass::A && position.contains_key(&line.product_id))
+        .collect::<Vec<_>>();
+    fast_movers.sort_by_key(|line| line.rank);
+
+    let mut recommendations = Vec::new();
+    for (line, slot) in fast_movers.into_iter().zip(&slots) {
+        let from = position[&line.product_id];
+        if from == slot.id {
+            continue;
+        }
+
+        let displaced = occupant[&slot.id];
+        recommendations.push(SlottingRecommendation {
+            id: Uuid::new_v4(),
+            analysis_id: analysis.id,
+            warehouse_id: analysis.warehouse_id,
+            product_id: line.product_id,
+            rank: line.rank,
+            from_location_id: from,
+            to_location_id: slot.id,
+            displaced_product_id: displaced,
+            status: SlottingStatus::Pending,
+            reviewed_by: None,
+            reviewed_at: None,
+        });
+
+        occupant.insert(slot.id, Some(line.product_id));
+        occupant.insert(from, displaced);
+        position.insert(line.product_id, slot.id);
+        if let Some(displaced) = displaced {
+            position.insert(displaced, from);
+        }
+    }
+
+    recommendations
+}
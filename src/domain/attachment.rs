@@ -0,0 +1,151 @@
+use crate::domain::{AttachmentError, ResourceType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Content types attachments may have: photos, documents and plain text exports.
+pub const ATTACHMENT_CONTENT_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+];
+
+/// Kind of record an attachment belongs to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::AttachmentOwner"
+    )
+)]
+pub enum AttachmentOwner {
+    Product,
+    Location,
+    StockMovement,
+    Shipment,
+    ReturnAuthorization,
+    BusinessPartner,
+}
+
+impl AttachmentOwner {
+    /// Resource the permissions of the owner are checked on: reading its attachments needs
+    /// read access to it, and adding or removing them update access.
+    pub fn resource_type(self) -> ResourceType {
+        match self {
+            AttachmentOwner::Product => ResourceType::Product,
+            AttachmentOwner::Location => ResourceType::Location,
+            AttachmentOwner::StockMovement => ResourceType::Stock,
+            AttachmentOwner::Shipment => ResourceType::Shipment,
+            AttachmentOwner::ReturnAuthorization => ResourceType::ReturnAuthorization,
+            AttachmentOwner::BusinessPartner => ResourceType::BusinessPartner,
+        }
+    }
+}
+
+/// Metadata of a file attached to a record. The content is kept in the attachment storage
+/// under `storage_key`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::attachments))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Attachment {
+    pub id: Uuid,
+    pub owner_type: AttachmentOwner,
+    pub owner_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    /// Size of the content in bytes.
+    pub size: i64,
+    pub storage_key: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttachmentData {
+    pub owner_type: AttachmentOwner,
+    pub owner_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
+}
+
+/// The media type of a `Content-Type` header value without its parameters, lowercase.
+pub fn media_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+/// `Content-Disposition` value downloading the attachment under its file name. Names that
+/// are not ASCII get an ASCII fallback in `filename` and the real name, percent-encoded as
+/// UTF-8, in `filename*` (RFC 6266).
+pub fn content_disposition(file_name: &str) -> String {
+    if file_name.is_ascii() {
+        return format!("attachment; filename=\"{file_name}\"");
+    }
+
+    let fallback: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii() { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+
+    format!("attachment; filename=\"{fallback}\"; filename*=UTF-8''{encoded}")
+}
+
+/// Checks the content type is allowed and, for binary formats, that the content starts
+/// with the signature of that format, so that files cannot be passed off as images.
+pub fn check_content(content_type: &str, content: &[u8]) -> Result<(), AttachmentError> {
+    if !ATTACHMENT_CONTENT_TYPES.contains(&content_type) {
+        return Err(AttachmentError::UnsupportedType(content_type.to_string()));
+    }
+
+    let matches = match content_type {
+        "image/jpeg" => content.starts_with(&[0xFF, 0xD8, 0xFF]),
+        "image/png" => content.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/gif" => content.starts_with(b"GIF87a") || content.starts_with(b"GIF89a"),
+        "image/webp" => {
+            content.starts_with(b"RIFF") && content.get(8..12) == Some(b"WEBP".as_slice())
+        }
+        "application/pdf" => content.starts_with(b"%PDF-"),
+        _ => true,
+    };
+    if !matches {
+        return Err(AttachmentError::ContentMismatch(content_type.to_string()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::domain::AttributeError;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// Entity type custom attributes are defined for.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::AttributeEntity"
+    )
+)]
+pub enum AttributeEntity {
+    Product,
+    Location,
+}
+
+/// JSON type a custom attribute value must have. Dates are `YYYY-MM-DD` strings and enum
+/// values one of the options of the definition.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::AttributeKind"
+    )
+)]
+pub enum AttributeKind {
+    String,
+    Number,
+    Enum,
+    Date,
+    Boolean,
+}
+
+/// An extra field of products or locations defined by the organization, e.g. a hazard
+/// class or temperature range. Values are kept in the `attributes` object of the entity
+/// under `key`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::attribute_definitions))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct AttributeDefinition {
+    pub id: Uuid,
+    pub entity: AttributeEntity,
+    pub key: String,
+    pub label: String,
+    pub kind: AttributeKind,
+    pub required: bool,
+    /// Values an enum attribute takes, empty for other kinds.
+    pub options: Vec<String>,
+    /// Order of the attribute in forms.
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttributeDefinitionData {
+    pub entity: AttributeEntity,
+    pub key: String,
+    pub label: String,
+    pub kind: AttributeKind,
+    pub required: bool,
+    pub options: Vec<String>,
+    pub position: i32,
+}
+
+impl AttributeDefinition {
+    pub fn accepts(&self, value: &Value) -> bool {
+        match (self.kind, value) {
+            (AttributeKind::String, Value::String(_)) => true,
+            (AttributeKind::Number, Value::Number(_)) => true,
+            (AttributeKind::Enum, Value::String(value)) => self.options.contains(value),
+            (AttributeKind::Date, Value::String(value)) => {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+            }
+            (AttributeKind::Boolean, Value::Bool(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Checks the values against the definitions of the entity type, dropping null values,
+/// and returns them as the JSON object to store.
+pub fn validate_attributes(
+    definitions: &[AttributeDefinition],
+    values: Map<String, Value>,
+) -> Result<Value, AttributeError> {
+    let values: Map<String, Value> = values
+        .into_iter()
+        .filter(|(_, value)| !value.is_null())
+        .collect();
+
+    for (key, value) in &values {
+        let definition = definitions
+            .iter()
+            .find(|definition| &definition.key == key)
+            .ok_or_else(|| AttributeError::Unknown(key.clone()))?;
+        if !definition.accepts(value) {
+            return Err(AttributeError::InvalidValue(key.clone()));
+        }
+    }
+
+    if let Some(definition) = definitions
+        .iter()
+        .find(|definition| definition.required && !values.contains_key(&definition.key))
+    {
+        return Err(AttributeError::Missing(definition.key.clone()));
+    }
+
+    Ok(Value::Object(values))
+}
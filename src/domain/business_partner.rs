@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Role a business partner plays towards the organization. A partner can play several.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub enum PartnerRole {
+    Supplier,
+    Customer,
+    Carrier,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::AddressKind")
+)]
+pub enum AddressKind {
+    Billing,
+    Shipping,
+}
+
+/// A supplier, customer or carrier purchase and sales documents are made out to.
+/// `normalized_name` is the name stripped to lowercase letters and digits, and `tax_id`
+/// is kept in its normalized form too, so that duplicates are found however they are typed.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::business_partners))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct BusinessPartner {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub normalized_name: String,
+    pub tax_id: Option<String>,
+    pub is_supplier: bool,
+    pub is_customer: bool,
+    pub is_carrier: bool,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BusinessPartner {
+    pub fn roles(&self) -> Vec<PartnerRole> {
+        [
+            (self.is_supplier, PartnerRole::Supplier),
+            (self.is_customer, PartnerRole::Customer),
+            (self.is_carrier, PartnerRole::Carrier),
+        ]
+        .into_iter()
+        .filter_map(|(plays, role)| plays.then_some(role))
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BusinessPartnerData {
+    pub code: String,
+    pub name: String,
+    pub tax_id: Option<String>,
+    pub roles: Vec<PartnerRole>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub addresses: Vec<PartnerAddressData>,
+    pub contacts: Vec<PartnerContactData>,
+    /// Creates the partner even though another one has a similar name. Partners sharing
+    /// a tax id are never created.
+    pub allow_similar_name: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BusinessPartnerQuery {
+    /// Part of the code, name or tax id, case-insensitive.
+    pub search: Option<String>,
+    pub role: Option<PartnerRole>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::partner_addresses))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PartnerAddress {
+    pub id: Uuid,
+    pub partner_id: Uuid,
+    pub kind: AddressKind,
+    pub street: String,
+    pub city: String,
+    pub postal_code: String,
+    /// ISO 3166-1 alpha-2 code.
+    pub country: String,
+    /// Address used for documents of the partner when none is given, one per kind.
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartnerAddressData {
+    pub kind: AddressKind,
+    pub street: String,
+    pub city: String,
+    pub postal_code: String,
+    pub country: String,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::partner_contacts))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PartnerContact {
+    pub id: Uuid,
+    pub partner_id: Uuid,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub position: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartnerContactData {
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub position: Option<String>,
+}
+
+/// Lowercase letters and digits of the name, so that "ACME Corp." and "Acme corp" match.
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Uppercase letters and digits of the tax id, dropping the separators it is written with.
+pub fn normalize_tax_id(tax_id: &str) -> String {
+    tax_id
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
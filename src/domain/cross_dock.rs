@@ -0,0 +1,164 @@
+use crate::domain::DocumentType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// A demand is `Fulfilled` once its whole quantity is allocated.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::DemandStatus")
+)]
+pub enum DemandStatus {
+    Open,
+    Fulfilled,
+    Cancelled,
+}
+
+/// Quantity of a product an outbound document still needs from a warehouse, beyond what
+/// could be reserved from stock. Inbound stock of the product can be cross-docked to it.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::outbound_demands))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct OutboundDemand {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+    /// Part of `quantity` cross-docked so far.
+    pub allocated_quantity: Decimal,
+    /// Time the goods are due to leave the warehouse.
+    pub due_at: Option<DateTime<Utc>>,
+    pub status: DemandStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OutboundDemand {
+    pub fn open_quantity(&self) -> Decimal {
+        match self.status {
+            DemandStatus::Open => self.quantity - self.allocated_quantity,
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// Demands due first: earliest due time, then registered first. Demands without a
+    /// due time come last.
+    pub fn urgency_key(&self) -> (bool, Option<DateTime<Utc>>, DateTime<Utc>) {
+        (self.due_at.is_none(), self.due_at, self.created_at)
+    }
+}
+
+#[derive(Clone)]
+pub struct OutboundDemandData {
+    pub warehouse_id: Uuid,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct OutboundDemandQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub status: Option<DemandStatus>,
+}
+
+/// Offer to cross-dock `quantity` of the stock available at a receiving location to an
+/// open demand.
+#[derive(Clone)]
+pub struct CrossDockOpportunity {
+    pub demand: OutboundDemand,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+}
+
+/// Shares the stock available at the location out among the open demands for it, most
+/// urgent demand first.
+pub fn match_demands(
+    location_id: Uuid,
+    mut available: HashMap<Uuid, Decimal>,
+    mut demands: Vec<OutboundDemand>,
+) -> Vec<CrossDockOpportunity> {
+    demands.sort_by_key(OutboundDemand::urgency_key);
+
+    demands
+        .into_iter()
+        .filter_map(|demand| {
+            let left = available.get_mut(&demand.product_id)?;
+            let quantity = demand.open_quantity().min(*left);
+            if quantity <= Decimal::ZERO {
+                return None;
+            }
+
+            *left -= quantity;
+            Some(CrossDockOpportunity {
+                demand,
+                from_location_id: location_id,
+                quantity,
+            })
+        })
+        .collect()
+}
+
+/// Received stock moved straight from the receiving location to a dock staging location
+/// and reserved there for the document of the demand, instead of being put away.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::cross_docks))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct CrossDock {
+    pub id: Uuid,
+    pub demand_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub staging_location_id: Uuid,
+    pub quantity: Decimal,
+    /// Document the stock was received against, if any.
+    pub inbound_document_type: Option<DocumentType>,
+    pub inbound_document_id: Option<Uuid>,
+    pub reservation_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct CrossDockData {
+    pub demand_id: Uuid,
+    /// Receiving location the stock is taken from.
+    pub from_location_id: Uuid,
+    pub staging_location_id: Uuid,
+    /// The whole quantity open on the demand, up to what is available, when omitted.
+    pub quantity: Option<Decimal>,
+    pub uom: Option<String>,
+    pub inbound_document_type: Option<DocumentType>,
+    pub inbound_document_id: Option<Uuid>,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Cross-docks of the demand, or received against or shipped on the document.
+#[derive(Clone, Default)]
+pub struct CrossDockQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub demand_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+}
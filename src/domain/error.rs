@@ -1,8 +1,13 @@
+use rust_decimal::Decimal;
+
 #[derive(thiserror::Error, Debug)]
 pub enum AuthError {
     #[error("Invalid credentials.")]
     InvalidCredentials(#[source] anyhow::Error),
 
+    #[error("Permission denied.")]
+    PermissionDenied,
+
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
@@ -18,3 +23,402 @@ pub enum RepositoryError {
     #[error(transparent)]
     UnexpectedError(#[from] anyhow::Error),
 }
+
+#[derive(thiserror::Error, Debug)]
+pub enum StockError {
+    #[error("Insufficient stock: requested {requested}, available {available}")]
+    InsufficientStock {
+        requested: Decimal,
+        available: Decimal,
+    },
+
+    #[error("Reservation is not active")]
+    ReservationNotActive,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum LotError {
+    #[error("Lot number is required for lot-tracked products")]
+    LotNumberRequired,
+
+    #[error("Expiry date is required for expiry-tracked products")]
+    ExpiryDateRequired,
+
+    #[error("Product is not lot or expiry tracked")]
+    NotTracked,
+
+    #[error("Lot is blocked or expired")]
+    Blocked,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SerialError {
+    #[error("Product is not serial tracked")]
+    NotTracked,
+
+    #[error("Exactly one distinct serial number is required per unit")]
+    CountMismatch,
+
+    #[error("Serial number {0} is not in stock at the location")]
+    NotInStock(String),
+
+    #[error("Serial number {0} is already in stock")]
+    AlreadyInStock(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum UomError {
+    #[error("Unknown unit of measure {0}")]
+    Unknown(String),
+
+    #[error("Quantity must be a whole number of base units")]
+    FractionalQuantity,
+
+    #[error("Base unit of measure must have a factor of 1")]
+    InvalidBaseFactor,
+
+    #[error("Quantity is too large to convert to base units")]
+    QuantityOutOfRange,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Gs1Error {
+    #[error("GS1 company prefix must be 6 to 12 digits and the extension digit 0 to 9")]
+    InvalidCompanyPrefix,
+
+    #[error("All serial references of the company prefix are allocated")]
+    SerialReferenceExhausted,
+
+    #[error("GS1 element strings may only contain ASCII characters")]
+    NotAscii,
+
+    #[error("Unknown GS1 application identifier at {0:?}")]
+    UnknownApplicationIdentifier(String),
+
+    #[error("Invalid value for GS1 application identifier {0}")]
+    InvalidValue(String),
+
+    #[error("Wrong check digit for GS1 application identifier {0}")]
+    CheckDigitMismatch(String),
+
+    #[error("Scanned GTIN is not a unit of measure of the product")]
+    WrongProduct,
+
+    #[error("Scanned value of GS1 application identifier {0} differs from the one given")]
+    ScanMismatch(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum LabelError {
+    #[error("Character {0:?} cannot be encoded in a barcode")]
+    UnsupportedCharacter(char),
+
+    #[error("Data is too long for a barcode")]
+    DataTooLong,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum TransferOrderError {
+    #[error("Source and destination warehouse must differ")]
+    SameWarehouse,
+
+    #[error("Location does not belong to the warehouse")]
+    LocationNotInWarehouse,
+
+    #[error("Transfer order must have at least one line")]
+    NoLines,
+
+    #[error("Line does not belong to the transfer order")]
+    UnknownLine,
+
+    #[error("Received quantity exceeds the quantity in transit")]
+    ExceedsInTransit,
+
+    #[error("Transfer order is not open")]
+    NotOpen,
+
+    #[error("Transfer order has nothing in transit")]
+    NotInTransit,
+
+    #[error("Transfer order is already received or closed")]
+    Completed,
+
+    #[error("Transfer order was changed by another request")]
+    Changed,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReplenishmentError {
+    #[error("Either a maximum or a reorder quantity is required")]
+    PolicyRequired,
+
+    #[error("Maximum quantity must be above the reorder point")]
+    MaxBelowReorderPoint,
+
+    #[error("Location does not belong to the warehouse")]
+    LocationNotInWarehouse,
+
+    #[error("Suggestion is not pending")]
+    NotPending,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReturnError {
+    #[error("Return authorization must have at least one line")]
+    NoLines,
+
+    #[error("Product was not shipped on the sales order")]
+    NotShipped,
+
+    #[error("Returned quantity exceeds the quantity shipped and not yet returned")]
+    ExceedsShipped,
+
+    #[error("Line does not belong to the return authorization")]
+    UnknownLine,
+
+    #[error("Received quantity exceeds the quantity authorized")]
+    ExceedsAuthorized,
+
+    #[error("Inspected quantity exceeds the quantity awaiting inspection")]
+    ExceedsReceived,
+
+    #[error("Returns must be received into a quarantine location of the warehouse")]
+    NotQuarantine,
+
+    #[error("Returned units were not received into the location")]
+    NotReceivedAtLocation,
+
+    #[error("Location does not belong to the warehouse")]
+    LocationNotInWarehouse,
+
+    #[error("A location is required to restock or refurbish")]
+    LocationRequired,
+
+    #[error("Return authorization is not open")]
+    NotOpen,
+
+    #[error("Received stock is still awaiting inspection")]
+    AwaitingInspection,
+
+    #[error("Return authorization was changed by another request")]
+    Changed,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum KitError {
+    #[error("Bill of materials must have at least one component")]
+    NoComponents,
+
+    #[error("A kit cannot be a component of itself")]
+    SelfComponent,
+
+    #[error("Product has no bill of materials")]
+    NoBillOfMaterials,
+
+    #[error("Kits and their components cannot be serial tracked, nor kits lot or expiry tracked")]
+    TrackedProduct,
+
+    #[error("Kits with lot or expiry tracked components cannot be disassembled")]
+    TrackedComponent,
+
+    #[error("Location does not belong to the warehouse")]
+    LocationNotInWarehouse,
+
+    #[error("Assembly order is not open")]
+    NotOpen,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PutawayError {
+    #[error("Fixed location rules need a location of the warehouse")]
+    LocationRequired,
+
+    #[error("Category zone rules need a category and a zone")]
+    CategoryZoneRequired,
+
+    #[error("Location does not belong to the warehouse")]
+    LocationNotInWarehouse,
+
+    #[error("No stock to put away at the location")]
+    NothingToPutAway,
+
+    #[error("Scanned location {0} is not the suggested destination")]
+    WrongLocation(String),
+
+    #[error("Location cannot hold the volume or weight put away")]
+    ExceedsCapacity,
+
+    #[error("Putaway task is not open")]
+    NotOpen,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum WaveError {
+    #[error("Order has no reserved stock to pick in the warehouse")]
+    NothingToPick,
+
+    #[error("No released order matches the wave criteria")]
+    NoMatchingOrders,
+
+    #[error("Pick order is not released")]
+    OrderNotReleased,
+
+    #[error("Wave is not open")]
+    NotOpen,
+
+    #[error("Pick task is not open")]
+    TaskNotOpen,
+
+    #[error("Picked quantity exceeds the quantity of the task")]
+    ExceedsTask,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum StockHoldError {
+    #[error("Stock can only be held as quarantine, damaged or on hold")]
+    NotAHoldStatus,
+
+    #[error("Putting stock on hold needs a reason")]
+    ReasonRequired,
+
+    #[error("Lot has no available stock to put on hold")]
+    NothingToHold,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum StockCountError {
+    #[error("Count session is not open")]
+    SessionNotOpen,
+
+    #[error("Count task cannot be counted in its current status")]
+    TaskNotCountable,
+
+    #[error("Count task is not waiting for approval")]
+    TaskNotAwaitingApproval,
+
+    #[error("Count session still has tasks that are not approved")]
+    TasksOutstanding,
+
+    #[error("Count task was changed by another request")]
+    TaskChanged,
+
+    #[error("Stock of the count task moved since it was counted, the task must be recounted")]
+    StockMoved,
+
+    #[error("Product and location are outside the scope of the count session")]
+    OutOfScope,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AbcError {
+    #[error("Class B share must not be below the class A share")]
+    SharesOutOfOrder,
+
+    #[error("Slotting recommendation is not pending")]
+    NotPending,
+
+    #[error("Product has no available stock left in the location to move")]
+    NothingToMove,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CrossDockError {
+    #[error("Outbound demand is not open")]
+    DemandNotOpen,
+
+    #[error("Cross-docked quantity exceeds the quantity open on the demand")]
+    ExceedsDemand,
+
+    #[error("Location does not belong to the warehouse of the demand")]
+    LocationNotInWarehouse,
+
+    #[error("Receiving location has no available stock of the product")]
+    NothingToCrossDock,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ShipmentError {
+    #[error("Shipment is not open")]
+    NotOpen,
+
+    #[error("Shipment has no parcels")]
+    NoParcels,
+
+    #[error("Pick order is not picked or belongs to another warehouse")]
+    PickOrderNotPicked,
+
+    #[error("Carrier {0} is not available")]
+    UnknownCarrier(String),
+
+    #[error("Carrier does not offer service {0}")]
+    UnknownService(String),
+
+    #[error("Expected one tracking number per parcel")]
+    TrackingNumbersMismatch,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PartnerError {
+    #[error("Business partner {0} has the same tax id")]
+    DuplicateTaxId(String),
+
+    #[error("Business partner {0} has a similar name")]
+    SimilarName(String),
+
+    #[error("Business partner must play at least one role")]
+    NoRole,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AttributeError {
+    #[error("Attribute {0} is not defined")]
+    Unknown(String),
+
+    #[error("Attribute {0} is required")]
+    Missing(String),
+
+    #[error("Value of attribute {0} does not match its definition")]
+    InvalidValue(String),
+
+    #[error("Enum attributes need options, other attributes take none")]
+    InvalidOptions,
+
+    #[error("Attribute filter must be a JSON object")]
+    InvalidFilter,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AttachmentError {
+    #[error("Attachments of type {0:?} are not allowed")]
+    UnsupportedType(String),
+
+    #[error("Content is not a valid {0} file")]
+    ContentMismatch(String),
+
+    #[error("Attachment exceeds the limit of {0} bytes")]
+    TooLarge(usize),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ListError {
+    #[error("Invalid list parameter {0}")]
+    InvalidParameter(String),
+
+    #[error("Lists take no parameter {0}")]
+    UnknownParameter(String),
+
+    #[error("Lists cannot be filtered by {0}")]
+    UnknownFilter(String),
+
+    #[error("Invalid filter on {0}")]
+    InvalidFilter(String),
+
+    #[error("Lists cannot be sorted by {0}")]
+    UnknownSort(String),
+
+    #[error("Items have no field {0}")]
+    UnknownField(String),
+
+    #[error("Cursor does not point to an item of the list")]
+    InvalidCursor,
+}
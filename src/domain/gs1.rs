@@ -0,0 +1,347 @@
+use crate::domain::Gs1Error;
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// A GS1 application identifier with its data, e.g. `(00)` followed by an SSCC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gs1Element {
+    pub ai: String,
+    pub value: String,
+}
+
+impl Gs1Element {
+    pub fn new(ai: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            ai: ai.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Total length of application identifier and data for the AIs GS1 defines as fixed length,
+/// keyed by the first two digits of the AI. Elements not listed here are terminated by FNC1.
+pub fn gs1_predefined_length(ai: &str) -> Option<usize> {
+    let length = match ai.get(..2)? {
+        "00" => 20,
+        "01" | "02" | "03" => 16,
+        "04" => 18,
+        "11" | "12" | "13" | "14" | "15" | "16" | "17" | "18" | "19" => 8,
+        "20" => 4,
+        "31" | "32" | "33" | "34" | "35" | "36" => 10,
+        "41" => 16,
+        _ => return None,
+    };
+
+    Some(length)
+}
+
+/// Element string in the human readable form printed under barcodes, e.g. `(00)006141410000000017`.
+pub fn gs1_human_readable(elements: &[Gs1Element]) -> String {
+    elements
+        .iter()
+        .map(|element| format!("({}){}", element.ai, element.value))
+        .collect()
+}
+
+/// Mod 10 check digit of a GS1 key, `digits` excluding the check digit itself.
+pub fn gs1_check_digit(digits: &str) -> Option<u8> {
+    let mut sum = 0;
+    for (position, digit) in digits.chars().rev().enumerate() {
+        let digit = digit.to_digit(10)?;
+        sum += if position % 2 == 0 { digit * 3 } else { digit };
+    }
+
+    Some(((10 - sum % 10) % 10) as u8)
+}
+
+/// Builds the 18 digit Serial Shipping Container Code from its extension digit, the company
+/// prefix and a serial reference that fills the remaining digits.
+pub fn build_sscc(
+    extension_digit: u8,
+    company_prefix: &str,
+    serial_reference: u64,
+) -> Result<String, Gs1Error> {
+    if extension_digit > 9
+        || !(6..=12).contains(&company_prefix.len())
+        || !company_prefix.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(Gs1Error::InvalidCompanyPrefix);
+    }
+
+    let width = 16 - company_prefix.len();
+    let serial_reference = format!("{serial_reference:0width$}");
+    if serial_reference.len() > width {
+        return Err(Gs1Error::SerialReferenceExhausted);
+    }
+
+    let digits = format!("{extension_digit}{company_prefix}{serial_reference}");
+    let check = gs1_check_digit(&digits).ok_or(Gs1Error::InvalidCompanyPrefix)?;
+
+    Ok(format!("{digits}{check}"))
+}
+
+/// Group separator that scanners transmit for FNC1 between elements.
+const GROUP_SEPARATOR: char = '\u{1d}';
+
+#[derive(Clone, Copy)]
+enum Format {
+    /// Fixed length number ending in a GS1 check digit.
+    Key(usize),
+    /// YYMMDD, a day of 00 meaning the last day of the month.
+    Date,
+    Numeric(usize),
+    Alphanumeric(usize),
+    /// Six digits with the number of decimals given by the last digit of the AI.
+    Measure,
+}
+
+impl Format {
+    fn fixed_length(&self) -> Option<usize> {
+        match self {
+            Format::Key(length) => Some(*length),
+            Format::Date | Format::Measure => Some(6),
+            Format::Numeric(_) | Format::Alphanumeric(_) => None,
+        }
+    }
+
+    fn max_length(&self) -> usize {
+        match self {
+            Format::Key(length) | Format::Numeric(length) | Format::Alphanumeric(length) => *length,
+            Format::Date | Format::Measure => 6,
+        }
+    }
+}
+
+/// Application identifiers found on supplier labels, with the AI itself and its data format.
+fn application_identifier(data: &str) -> Option<(&str, Format)> {
+    let (length, format) = match data.get(..2)? {
+        "00" => (2, Format::Key(18)),
+        "01" | "02" => (2, Format::Key(14)),
+        "10" | "21" => (2, Format::Alphanumeric(20)),
+        "11" | "13" | "15" | "17" => (2, Format::Date),
+        "30" | "37" => (2, Format::Numeric(8)),
+        "31" if data.get(..3)? == "310" && data.get(3..4)?.as_bytes()[0].is_ascii_digit() => {
+            (4, Format::Measure)
+        }
+        "40" if data.get(..3)? == "400" => (3, Format::Alphanumeric(30)),
+        _ => return None,
+    };
+
+    Some((&data[..length], format))
+}
+
+/// Fields of a scanned GS1 element string, with the product it identifies when known.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gs1Scan {
+    pub elements: Vec<Gs1Element>,
+    pub sscc: Option<String>,
+    pub gtin: Option<String>,
+    /// GTIN of the trade items on a logistic unit, used together with the count in AI 37.
+    pub content_gtin: Option<String>,
+    pub lot_number: Option<String>,
+    pub production_date: Option<NaiveDate>,
+    pub best_before_date: Option<NaiveDate>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_number: Option<String>,
+    pub quantity: Option<Decimal>,
+    pub net_weight_kg: Option<Decimal>,
+    pub product_id: Option<Uuid>,
+    pub uom: Option<String>,
+}
+
+/// Parses a scan, either as transmitted by the scanner with an optional symbology identifier
+/// and group separators for FNC1, or in the human readable form with AIs in parentheses.
+/// Two digit years are resolved around `today` as the GS1 General Specifications prescribe.
+pub fn parse_gs1(input: &str, today: NaiveDate) -> Result<Gs1Scan, Gs1Error> {
+    // GS1 element strings are ASCII, which the splitting below relies on to slice by byte.
+    if !input.is_ascii() {
+        return Err(Gs1Error::NotAscii);
+    }
+
+    let elements = if input.starts_with('(') {
+        split_human_readable(input)?
+    } else {
+        split_raw(input)?
+    };
+
+    let mut scan = Gs1Scan::default();
+    for element in &elements {
+        let (_, format) = application_identifier(&element.ai)
+            .filter(|(ai, _)| *ai == element.ai)
+            .ok_or_else(|| Gs1Error::UnknownApplicationIdentifier(element.ai.clone()))?;
+        check_value(&element.ai, format, &element.value)?;
+
+        let invalid = || Gs1Error::InvalidValue(element.ai.clone());
+        let value = element.value.clone();
+        match &element.ai[..2] {
+            "00" => set(&mut scan.sscc, value),
+            "01" => set(&mut scan.gtin, value),
+            "02" => set(&mut scan.content_gtin, value),
+            "10" => set(&mut scan.lot_number, value),
+            "11" => set(
+                &mut scan.production_date,
+                parse_date(&value, today).ok_or_else(invalid)?,
+            ),
+            "15" => set(
+                &mut scan.best_before_date,
+                parse_date(&value, today).ok_or_else(invalid)?,
+            ),
+            "17" => set(
+                &mut scan.expiry_date,
+                parse_date(&value, today).ok_or_else(invalid)?,
+            ),
+            "21" => set(&mut scan.serial_number, value),
+            "30" | "37" => set(&mut scan.quantity, value.parse().map_err(|_| invalid())?),
+            "31" => {
+                let decimals = element.ai[3..].parse().map_err(|_| invalid())?;
+                let weight =
+                    Decimal::from_i128_with_scale(value.parse().map_err(|_| invalid())?, decimals);
+                set(&mut scan.net_weight_kg, weight)
+            }
+            _ => Ok(()),
+        }
+        .map_err(|_| invalid())?;
+    }
+    scan.elements = elements;
+
+    Ok(scan)
+}
+
+impl Gs1Scan {
+    /// Checks that the scan names `product_id`. Scans without a GTIN, such as lot or serial
+    /// number labels, name no product and pass.
+    pub fn check_product(&self, product_id: Uuid) -> Result<(), Gs1Error> {
+        let gtin = self.gtin.as_ref().or(self.content_gtin.as_ref());
+        if gtin.is_some() && self.product_id != Some(product_id) {
+            return Err(Gs1Error::WrongProduct);
+        }
+
+        Ok(())
+    }
+
+    /// Serial numbers given together with the scan. The scanned serial number is taken when
+    /// none are given, otherwise it must be one of them.
+    pub fn serial_numbers(&self, given: Vec<String>) -> Result<Vec<String>, Gs1Error> {
+        match &self.serial_number {
+            Some(serial) if given.is_empty() => Ok(vec![serial.clone()]),
+            Some(serial) if !given.contains(serial) => Err(Gs1Error::ScanMismatch("21".into())),
+            _ => Ok(given),
+        }
+    }
+}
+
+/// A value given together with a scan, or the one scanned in AI `ai` when none is given.
+pub fn merge_scanned<T: PartialEq>(
+    given: Option<T>,
+    scanned: Option<T>,
+    ai: &str,
+) -> Result<Option<T>, Gs1Error> {
+    match (given, scanned) {
+        (Some(given), Some(scanned)) if given != scanned => {
+            Err(Gs1Error::ScanMismatch(ai.to_string()))
+        }
+        (given, scanned) => Ok(given.or(scanned)),
+    }
+}
+
+/// Stores the field unless the scan already carried a different value for it.
+fn set<T: PartialEq>(field: &mut Option<T>, value: T) -> Result<(), ()> {
+    match field {
+        Some(existing) if *existing != value => Err(()),
+        _ => {
+            *field = Some(value);
+            Ok(())
+        }
+    }
+}
+
+fn split_raw(input: &str) -> Result<Vec<Gs1Element>, Gs1Error> {
+    // Symbology identifiers such as ]C1 (GS1-128), ]d2 (DataMatrix) or ]Q3 (QR code).
+    let mut rest = match input.strip_prefix(']') {
+        Some(rest) => rest.get(2..).unwrap_or_default(),
+        None => input,
+    };
+
+    let mut elements = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(GROUP_SEPARATOR);
+        if rest.is_empty() {
+            break;
+        }
+
+        let (ai, format) = application_identifier(rest)
+            .ok_or_else(|| Gs1Error::UnknownApplicationIdentifier(rest.to_string()))?;
+        let data = &rest[ai.len()..];
+        let length = match format.fixed_length() {
+            Some(length) => length.min(data.len()),
+            None => data.find(GROUP_SEPARATOR).unwrap_or(data.len()),
+        };
+
+        elements.push(Gs1Element::new(ai, &data[..length]));
+        rest = &data[length..];
+    }
+
+    Ok(elements)
+}
+
+fn split_human_readable(input: &str) -> Result<Vec<Gs1Element>, Gs1Error> {
+    input
+        .split('(')
+        .skip(1)
+        .map(|part| {
+            let (ai, value) = part
+                .split_once(')')
+                .ok_or_else(|| Gs1Error::UnknownApplicationIdentifier(part.to_string()))?;
+            Ok(Gs1Element::new(ai, value))
+        })
+        .collect()
+}
+
+fn check_value(ai: &str, format: Format, value: &str) -> Result<(), Gs1Error> {
+    let length_valid = match format.fixed_length() {
+        Some(length) => value.len() == length,
+        None => (1..=format.max_length()).contains(&value.len()),
+    };
+    let characters_valid = match format {
+        Format::Alphanumeric(_) => value.chars().all(|c| c.is_ascii_graphic()),
+        _ => value.chars().all(|c| c.is_ascii_digit()),
+    };
+    if !length_valid || !characters_valid {
+        return Err(Gs1Error::InvalidValue(ai.to_string()));
+    }
+
+    if let Format::Key(length) = format {
+        let (digits, check) = value.split_at(length - 1);
+        if gs1_check_digit(digits)
+            .map(|digit| digit.to_string())
+            .as_deref()
+            != Some(check)
+        {
+            return Err(Gs1Error::CheckDigitMismatch(ai.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves YYMMDD, placing the year within 49 years before and 50 years after today.
+fn parse_date(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let year: i32 = value[..2].parse().ok()?;
+    let month: u32 = value[2..4].parse().ok()?;
+    let day: u32 = value[4..].parse().ok()?;
+
+    let century = today.year() - today.year() % 100;
+    let year = match year - today.year() % 100 {
+        51..=99 => century - 100 + year,
+        -99..=-50 => century + 100 + year,
+        _ => century + year,
+    };
+
+    match day {
+        0 => NaiveDate::from_ymd_opt(year, month, 1)?
+            .checked_add_months(Months::new(1))?
+            .pred_opt(),
+        _ => NaiveDate::from_ymd_opt(year, month, day),
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `Assembly` consumes components and produces the kit, `Disassembly` breaks kits back
+/// down into their components.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::AssemblyKind")
+)]
+pub enum AssemblyKind {
+    Assembly,
+    Disassembly,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::AssemblyOrderStatus"
+    )
+)]
+pub enum AssemblyOrderStatus {
+    Open,
+    Completed,
+    Cancelled,
+}
+
+/// Base units of a component that go into one unit of the kit.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::bom_components))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct BomComponent {
+    pub kit_product_id: Uuid,
+    pub component_product_id: Uuid,
+    pub quantity: Decimal,
+}
+
+#[derive(Clone)]
+pub struct BomComponentData {
+    pub component_product_id: Uuid,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the component's base UoM when omitted.
+    pub uom: Option<String>,
+}
+
+/// Kits the components on hand in a warehouse are enough for.
+#[derive(Clone)]
+pub struct KitAvailability {
+    pub kit_product_id: Uuid,
+    pub warehouse_id: Option<Uuid>,
+    pub buildable: Decimal,
+    pub components: Vec<ComponentAvailability>,
+}
+
+#[derive(Clone)]
+pub struct ComponentAvailability {
+    pub component_product_id: Uuid,
+    pub quantity: Decimal,
+    pub available: Decimal,
+    /// Kits this component alone is enough for.
+    pub buildable: Decimal,
+}
+
+/// Work order assembling kits at `to_location_id` from components picked at
+/// `from_location_id`, or, for disassembly, breaking kits picked at `from_location_id` down
+/// into components put away at `to_location_id`.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::assembly_orders))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct AssemblyOrder {
+    pub id: Uuid,
+    pub kind: AssemblyKind,
+    pub kit_product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub quantity: Decimal,
+    pub status: AssemblyOrderStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Total base units of a component consumed or produced by the order.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::assembly_order_lines))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct AssemblyOrderLine {
+    pub id: Uuid,
+    pub assembly_order_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+}
+
+#[derive(Clone)]
+pub struct AssemblyOrderData {
+    pub kind: AssemblyKind,
+    pub kit_product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the kit's base UoM when omitted.
+    pub uom: Option<String>,
+}
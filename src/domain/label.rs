@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// What a label identifies, which also selects its template.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub enum LabelKind {
+    Location,
+    Product,
+    Pallet,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub enum LabelFormat {
+    #[default]
+    Svg,
+    Png,
+    /// Zebra Programming Language for thermal printers.
+    Zpl,
+}
+
+impl LabelFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            LabelFormat::Svg => "image/svg+xml",
+            LabelFormat::Png => "image/png",
+            LabelFormat::Zpl => "application/zpl",
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::domain::ListError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// Comparison of a list filter, e.g. `gt` in `filter[pick_sequence][gt]=0`. Filters without
+/// one compare for equality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl FromStr for FilterOp {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "eq" => Ok(FilterOp::Eq),
+            "ne" => Ok(FilterOp::Ne),
+            "gt" => Ok(FilterOp::Gt),
+            "gte" => Ok(FilterOp::Gte),
+            "lt" => Ok(FilterOp::Lt),
+            "lte" => Ok(FilterOp::Lte),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A filter of a list on one field, with the value parsed to the type of the field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<T> {
+    pub op: FilterOp,
+    pub value: T,
+}
+
+impl<T: FromStr> Comparison<T> {
+    pub fn parse(field: &str, op: FilterOp, value: &str) -> Result<Self, ListError> {
+        let value = value
+            .parse()
+            .map_err(|_| ListError::InvalidFilter(field.to_string()))?;
+
+        Ok(Self { op, value })
+    }
+}
+
+impl<T: DeserializeOwned> Comparison<T> {
+    /// Parses the value of a field holding one of the variants of an enum, by its name.
+    pub fn parse_variant(field: &str, op: FilterOp, value: &str) -> Result<Self, ListError> {
+        let value = serde_json::from_value(Value::String(value.to_string()))
+            .map_err(|_| ListError::InvalidFilter(field.to_string()))?;
+
+        Ok(Self { op, value })
+    }
+}
+
+/// Value a custom attribute filter matches, e.g. `filter[attributes.hazard_class]=3`.
+/// Values reading as JSON, such as numbers and booleans, match that JSON value, any other
+/// value matches the string. Attributes are only compared for equality.
+pub fn attribute_filter(field: &str, op: FilterOp, value: &str) -> Result<Value, ListError> {
+    if op != FilterOp::Eq {
+        return Err(ListError::InvalidFilter(field.to_string()));
+    }
+
+    Ok(serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string())))
+}
+
+/// Filters a list endpoint accepts, each a field of its items with a typed comparison.
+pub trait ListFilter: Sized {
+    /// Parses `filter[field][op]=value`, failing on unknown fields and values not of the
+    /// type of the field.
+    fn parse(field: &str, op: FilterOp, value: &str) -> Result<Self, ListError>;
+}
+
+/// Fields a list endpoint can be sorted by. The default is the order of lists not sorted
+/// explicitly.
+pub trait ListSort: Sized + Copy + Default {
+    fn parse(field: &str) -> Option<Self>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Sort<S> {
+    pub field: S,
+    pub direction: SortDirection,
+}
+
+/// A page of a list: up to `limit` items matching every filter, in `sort` order and then by
+/// id, following the item `after`.
+#[derive(Debug, Clone)]
+pub struct ListQuery<F, S> {
+    pub filters: Vec<F>,
+    pub sort: Sort<S>,
+    pub after: Option<Uuid>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Id of the last item when more items follow, to pass as `after` for the next page.
+    pub next_cursor: Option<Uuid>,
+}
+
+impl<T> Page<T> {
+    /// Builds the page from up to `limit + 1` rows loaded in list order. The extra row is
+    /// only loaded to tell whether another page follows.
+    pub fn from_rows(mut rows: Vec<T>, limit: i64, id: impl Fn(&T) -> Uuid) -> Self {
+        let limit = usize::try_from(limit).unwrap_or_default();
+        let next_cursor = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last().map(id)
+        } else {
+            None
+        };
+
+        Self {
+            items: rows,
+            next_cursor,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
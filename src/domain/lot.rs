@@ -0,0 +1,48 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// A batch of a product identified by its lot number and/or expiry date.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::lots))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Lot {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub blocked: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Lot {
+    /// Whether stock of the lot can be allocated to demand. A lot is usable up to
+    /// and including its expiry date.
+    pub fn is_usable_on(&self, today: NaiveDate) -> bool {
+        !self.blocked && self.expiry_date.is_none_or(|expiry| expiry >= today)
+    }
+}
+
+/// Stock of a lot at a location, as returned by the expiry query.
+#[derive(Clone)]
+pub struct ExpiringStock {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Uuid,
+    pub lot_number: Option<String>,
+    pub expiry_date: NaiveDate,
+    pub blocked: bool,
+    pub on_hand: Decimal,
+}
+
+/// Lots expiring on or before `until`, already expired ones included.
+#[derive(Clone)]
+pub struct ExpiringStockQuery {
+    pub until: NaiveDate,
+    pub product_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A client company of the deployment, owning its warehouses, products, roles and every
+/// other warehouse entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::organizations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrganizationData {
+    pub name: String,
+}
+
+/// A user belonging to an organization, who can act as it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::user_organizations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct OrganizationMember {
+    pub user_id: Uuid,
+    pub organization_id: Uuid,
+    pub added_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
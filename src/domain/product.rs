@@ -0,0 +1,196 @@
+use crate::domain::{
+    Comparison, CostingMethod, FilterOp, ListError, ListFilter, ListQuery, ListSort, UomError,
+    attribute_filter,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::AbcClass")
+)]
+pub enum AbcClass {
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::products))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Product {
+    pub id: Uuid,
+    pub sku: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub lot_tracked: bool,
+    pub expiry_tracked: bool,
+    pub serial_tracked: bool,
+    pub base_uom: String,
+    pub fractional_quantities: bool,
+    /// Overrides the company costing method when set.
+    pub costing_method: Option<CostingMethod>,
+    pub category: Option<String>,
+    /// Cubic metres per base unit.
+    pub unit_volume: Option<Decimal>,
+    /// Kilograms per base unit.
+    pub unit_weight: Option<Decimal>,
+    /// Values of the custom attributes defined for products, by key.
+    pub attributes: Value,
+}
+
+#[derive(Clone)]
+pub struct ProductData {
+    pub sku: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub lot_tracked: bool,
+    pub expiry_tracked: bool,
+    pub serial_tracked: bool,
+    pub base_uom: String,
+    pub fractional_quantities: bool,
+    /// Overrides the company costing method when set.
+    pub costing_method: Option<CostingMethod>,
+    pub category: Option<String>,
+    /// Cubic metres per base unit.
+    pub unit_volume: Option<Decimal>,
+    /// Kilograms per base unit.
+    pub unit_weight: Option<Decimal>,
+    pub attributes: Map<String, Value>,
+}
+
+#[derive(Clone, Default)]
+pub struct ProductQuery {
+    pub category: Option<String>,
+    /// Products whose attributes contain all of these values.
+    pub attributes: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProductFilter {
+    Sku(Comparison<String>),
+    Name(Comparison<String>),
+    Category(Comparison<String>),
+    AbcClass(Comparison<AbcClass>),
+    LotTracked(Comparison<bool>),
+    SerialTracked(Comparison<bool>),
+    /// Products having the value for the custom attribute with the key.
+    Attribute(String, Value),
+}
+
+impl ListFilter for ProductFilter {
+    fn parse(field: &str, op: FilterOp, value: &str) -> Result<Self, ListError> {
+        if let Some(key) = field.strip_prefix("attributes.") {
+            return Ok(ProductFilter::Attribute(
+                key.to_string(),
+                attribute_filter(field, op, value)?,
+            ));
+        }
+
+        match field {
+            "sku" => Ok(ProductFilter::Sku(Comparison::parse(field, op, value)?)),
+            "name" => Ok(ProductFilter::Name(Comparison::parse(field, op, value)?)),
+            "category" => Ok(ProductFilter::Category(Comparison::parse(
+                field, op, value,
+            )?)),
+            "abc_class" => Ok(ProductFilter::AbcClass(Comparison::parse_variant(
+                field, op, value,
+            )?)),
+            "lot_tracked" => Ok(ProductFilter::LotTracked(Comparison::parse(
+                field, op, value,
+            )?)),
+            "serial_tracked" => Ok(ProductFilter::SerialTracked(Comparison::parse(
+                field, op, value,
+            )?)),
+            _ => Err(ListError::UnknownFilter(field.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProductSort {
+    #[default]
+    Sku,
+    Name,
+}
+
+impl ListSort for ProductSort {
+    fn parse(field: &str) -> Option<Self> {
+        match field {
+            "sku" => Some(ProductSort::Sku),
+            "name" => Some(ProductSort::Name),
+            _ => None,
+        }
+    }
+}
+
+pub type ProductListQuery = ListQuery<ProductFilter, ProductSort>;
+
+/// Decimal places of the stored quantities.
+const QUANTITY_SCALE: u32 = 6;
+
+/// Stored quantities must be less than this in absolute value, the 12 integer digits of
+/// NUMERIC(18, 6).
+const QUANTITY_LIMIT: i64 = 1_000_000_000_000;
+
+impl Product {
+    /// Converts a quantity given in `uom` to base units, `None` meaning the base UoM itself.
+    /// The result must be a whole number unless the product allows fractional quantities,
+    /// and must fit the NUMERIC(18, 6) quantity columns without rounding.
+    pub fn to_base_quantity(
+        &self,
+        quantity: Decimal,
+        uom: Option<&ProductUom>,
+    ) -> Result<Decimal, UomError> {
+        let quantity = match uom {
+            Some(uom) => quantity
+                .checked_mul(uom.factor)
+                .ok_or(UomError::QuantityOutOfRange)?
+                .normalize(),
+            None => quantity.normalize(),
+        };
+
+        if quantity.scale() > QUANTITY_SCALE || quantity.abs() >= Decimal::from(QUANTITY_LIMIT) {
+            return Err(UomError::QuantityOutOfRange);
+        }
+        if !self.fractional_quantities && !quantity.fract().is_zero() {
+            return Err(UomError::FractionalQuantity);
+        }
+
+        Ok(quantity)
+    }
+}
+
+/// Alternate unit of measure of a product. `factor` is the number of base units in one unit.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::product_uoms))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ProductUom {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub code: String,
+    pub factor: Decimal,
+    pub barcode: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ProductUomData {
+    pub code: String,
+    pub factor: Decimal,
+    pub barcode: Option<String>,
+}
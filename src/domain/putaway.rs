@@ -0,0 +1,223 @@
+use crate::domain::{Location, Product};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How a putaway rule picks a destination:
+/// - `FixedLocation`: the product's home location named by the rule.
+/// - `Consolidate`: the nearest location already holding the product.
+/// - `EmptyLocation`: the nearest location holding nothing.
+/// - `CategoryZone`: the nearest location in the zone of the rule.
+/// - `Capacity`: the location with the least volume left once the stock is in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::PutawayStrategy"
+    )
+)]
+pub enum PutawayStrategy {
+    FixedLocation,
+    Consolidate,
+    EmptyLocation,
+    CategoryZone,
+    Capacity,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::PutawayTaskStatus"
+    )
+)]
+pub enum PutawayTaskStatus {
+    Open,
+    Completed,
+    Cancelled,
+}
+
+/// Rules of a warehouse are tried in `sequence` order. A rule applies to the products
+/// matching its product and category, when set, and only proposes locations of its zone.
+/// Locations never receive more volume or weight than they hold.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::putaway_rules))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PutawayRule {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub sequence: i32,
+    pub strategy: PutawayStrategy,
+    pub product_id: Option<Uuid>,
+    pub category: Option<String>,
+    pub location_id: Option<Uuid>,
+    pub zone: Option<String>,
+}
+
+impl PutawayRule {
+    pub fn applies_to(&self, product: &Product) -> bool {
+        self.product_id.is_none_or(|id| id == product.id)
+            && self
+                .category
+                .as_ref()
+                .is_none_or(|category| product.category.as_ref() == Some(category))
+    }
+
+    /// Location the rule puts `quantity` of the product away to, coming from `from`.
+    pub fn propose<'a>(
+        &self,
+        product: &Product,
+        quantity: Decimal,
+        from: &Location,
+        candidates: &'a [LocationOccupancy],
+    ) -> Option<&'a LocationOccupancy> {
+        let mut eligible = candidates.iter().filter(|candidate| {
+            candidate.location.id != from.id
+                && self
+                    .zone
+                    .as_ref()
+                    .is_none_or(|zone| candidate.location.zone.as_ref() == Some(zone))
+                && candidate.fits(product, quantity)
+        });
+
+        match self.strategy {
+            PutawayStrategy::FixedLocation => {
+                eligible.find(|candidate| Some(candidate.location.id) == self.location_id)
+            }
+            PutawayStrategy::Consolidate => nearest(
+                eligible.filter(|candidate| candidate.product_ids.contains(&product.id)),
+                from,
+            ),
+            PutawayStrategy::EmptyLocation => nearest(
+                eligible.filter(|candidate| candidate.product_ids.is_empty()),
+                from,
+            ),
+            PutawayStrategy::CategoryZone => nearest(eligible, from),
+            PutawayStrategy::Capacity => eligible.min_by_key(|candidate| {
+                let left = candidate
+                    .location
+                    .max_volume
+                    .map(|max_volume| max_volume - candidate.volume - volume(product, quantity));
+                (
+                    left.is_none(),
+                    left,
+                    distance_key(from, &candidate.location),
+                )
+            }),
+        }
+    }
+}
+
+/// Destination proposed by the first rule that finds one.
+pub fn suggest_location(
+    rules: &[PutawayRule],
+    product: &Product,
+    quantity: Decimal,
+    from: &Location,
+    candidates: &[LocationOccupancy],
+) -> Option<(Uuid, PutawayStrategy)> {
+    rules
+        .iter()
+        .filter(|rule| rule.applies_to(product))
+        .find_map(|rule| {
+            rule.propose(product, quantity, from, candidates)
+                .map(|candidate| (candidate.location.id, rule.strategy))
+        })
+}
+
+fn volume(product: &Product, quantity: Decimal) -> Decimal {
+    product.unit_volume.unwrap_or_default() * quantity
+}
+
+fn nearest<'a>(
+    candidates: impl Iterator<Item = &'a LocationOccupancy>,
+    from: &Location,
+) -> Option<&'a LocationOccupancy> {
+    candidates.min_by_key(|candidate| distance_key(from, &candidate.location))
+}
+
+/// Locations without a position come last, ties go by location code.
+fn distance_key(from: &Location, to: &Location) -> (bool, Option<Decimal>, String) {
+    let distance = from.distance_to(to);
+    (distance.is_none(), distance, to.code.clone())
+}
+
+#[derive(Clone)]
+pub struct PutawayRuleData {
+    pub strategy: PutawayStrategy,
+    pub product_id: Option<Uuid>,
+    pub category: Option<String>,
+    pub location_id: Option<Uuid>,
+    pub zone: Option<String>,
+}
+
+/// Storage location with the stock it holds or is about to receive from open putaway tasks.
+#[derive(Clone)]
+pub struct LocationOccupancy {
+    pub location: Location,
+    pub volume: Decimal,
+    pub weight: Decimal,
+    pub product_ids: Vec<Uuid>,
+}
+
+impl LocationOccupancy {
+    /// Products without dimensions fit anywhere, as do locations without limits.
+    pub fn fits(&self, product: &Product, quantity: Decimal) -> bool {
+        let within = |max: Option<Decimal>, used: Decimal, unit: Option<Decimal>| match (max, unit)
+        {
+            (Some(max), Some(unit)) => used + unit * quantity <= max,
+            _ => true,
+        };
+
+        within(self.location.max_volume, self.volume, product.unit_volume)
+            && within(self.location.max_weight, self.weight, product.unit_weight)
+    }
+}
+
+/// Stock to move from a receiving location into storage. Confirming the task posts the
+/// transfer to the scanned location, which must be the suggested one if there is any.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::putaway_tasks))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PutawayTask {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+    pub suggested_location_id: Option<Uuid>,
+    pub strategy: Option<PutawayStrategy>,
+    pub to_location_id: Option<Uuid>,
+    pub status: PutawayTaskStatus,
+    pub movement_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_by: Option<Uuid>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct PutawayTaskQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub from_location_id: Option<Uuid>,
+    pub status: Option<PutawayTaskStatus>,
+}
+
+#[derive(Clone)]
+pub struct PutawayConfirmation {
+    pub location_code: String,
+    pub serial_numbers: Vec<String>,
+}
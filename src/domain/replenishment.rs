@@ -0,0 +1,157 @@
+use crate::domain::DocumentType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `Purchase` restocks the warehouse from suppliers, `PickFace` restocks a pick location
+/// from the rest of the warehouse.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::ReplenishmentKind"
+    )
+)]
+pub enum ReplenishmentKind {
+    Purchase,
+    PickFace,
+}
+
+/// A suggestion stays `Pending` until it is reviewed or a later run replaces it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::SuggestionStatus"
+    )
+)]
+pub enum SuggestionStatus {
+    Pending,
+    Accepted,
+    Rejected,
+    Superseded,
+}
+
+/// Restocks once available plus inbound stock drops to `reorder_point`, either up to
+/// `max_quantity` or by multiples of `reorder_quantity`. Rules with a location apply to
+/// that pick face only.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::replenishment_rules))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ReplenishmentRule {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub reorder_point: Decimal,
+    pub max_quantity: Option<Decimal>,
+    pub reorder_quantity: Option<Decimal>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReplenishmentRule {
+    pub fn kind(&self) -> ReplenishmentKind {
+        match self.location_id {
+            Some(_) => ReplenishmentKind::PickFace,
+            None => ReplenishmentKind::Purchase,
+        }
+    }
+
+    /// Quantity to restock for the given stock position, if it reached the reorder point.
+    pub fn shortfall(&self, position: Decimal) -> Option<Decimal> {
+        if position > self.reorder_point {
+            return None;
+        }
+
+        let quantity = match (self.max_quantity, self.reorder_quantity) {
+            (_, Some(lot_size)) => {
+                let lots = ((self.reorder_point - position) / lot_size).floor() + Decimal::ONE;
+                lots * lot_size
+            }
+            (Some(max_quantity), None) => max_quantity - position,
+            (None, None) => return None,
+        };
+
+        (quantity > Decimal::ZERO).then_some(quantity)
+    }
+}
+
+#[derive(Clone)]
+pub struct ReplenishmentRuleData {
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub reorder_point: Decimal,
+    pub max_quantity: Option<Decimal>,
+    pub reorder_quantity: Option<Decimal>,
+    /// Unit the quantities are given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct ReplenishmentRuleQuery {
+    pub product_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// Proposed restock, recorded with the stock position it was computed from. Accepting it
+/// creates the document it proposes.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::replenishment_suggestions))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ReplenishmentSuggestion {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub kind: ReplenishmentKind,
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub from_location_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub available: Decimal,
+    pub inbound: Decimal,
+    pub status: SuggestionStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub document_type: Option<DocumentType>,
+    pub document_id: Option<Uuid>,
+}
+
+#[derive(Clone, Default)]
+pub struct ReplenishmentSuggestionQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub status: Option<SuggestionStatus>,
+}
+
+/// Stock ordered from a supplier. It counts as inbound until receipts posted against it
+/// cover the quantity.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::purchase_orders))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PurchaseOrder {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
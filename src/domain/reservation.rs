@@ -0,0 +1,60 @@
+use crate::domain::DocumentType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::ReservationStatus"
+    )
+)]
+pub enum ReservationStatus {
+    Active,
+    Released,
+    Consumed,
+    Expired,
+}
+
+/// Quantity promised to a demand document. `quantity` is what is still reserved:
+/// partial consumption decreases it until the reservation is fully consumed.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::reservations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Reservation {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    pub status: ReservationStatus,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reservation {
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.status == ReservationStatus::Active && self.expires_at.is_none_or(|exp| exp > now)
+    }
+}
+
+#[derive(Clone)]
+pub struct ReserveData {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    pub uom: Option<String>,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `Completed` once every authorized unit was received and inspected, `Closed` when the
+/// authorization was closed before that and the rest is no longer expected.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::ReturnStatus")
+)]
+pub enum ReturnStatus {
+    Open,
+    Completed,
+    Closed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::ReturnReason")
+)]
+pub enum ReturnReason {
+    Damaged,
+    Defective,
+    WrongItem,
+    NotAsDescribed,
+    NoLongerNeeded,
+    Other,
+}
+
+/// What happens to inspected stock. `Restock` and `Refurbish` move it to a location of the
+/// warehouse, `Scrap` writes it off and `ReturnToVendor` issues it back to the supplier.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::ReturnDisposition"
+    )
+)]
+pub enum ReturnDisposition {
+    Restock,
+    Refurbish,
+    Scrap,
+    ReturnToVendor,
+}
+
+impl ReturnDisposition {
+    pub fn keeps_stock(self) -> bool {
+        matches!(
+            self,
+            ReturnDisposition::Restock | ReturnDisposition::Refurbish
+        )
+    }
+}
+
+/// Return merchandise authorization for goods shipped on a sales order.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::return_authorizations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ReturnAuthorization {
+    pub id: Uuid,
+    pub sales_order_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub status: ReturnStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::return_authorization_lines))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ReturnAuthorizationLine {
+    pub id: Uuid,
+    pub return_authorization_id: Uuid,
+    pub product_id: Uuid,
+    pub reason: ReturnReason,
+    pub note: Option<String>,
+    pub quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub inspected_quantity: Decimal,
+}
+
+impl ReturnAuthorizationLine {
+    pub fn awaiting_receipt(&self) -> Decimal {
+        self.quantity - self.received_quantity
+    }
+
+    pub fn awaiting_inspection(&self) -> Decimal {
+        self.received_quantity - self.inspected_quantity
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::return_inspections))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ReturnInspection {
+    pub id: Uuid,
+    pub line_id: Uuid,
+    pub quantity: Decimal,
+    pub disposition: ReturnDisposition,
+    pub to_location_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub inspected_by: Option<Uuid>,
+    pub inspected_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct ReturnAuthorizationData {
+    pub sales_order_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub lines: Vec<ReturnLineData>,
+}
+
+#[derive(Clone)]
+pub struct ReturnLineData {
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+    pub reason: ReturnReason,
+    pub note: Option<String>,
+}
+
+/// Returned units of a line received into a quarantine location. Tracked products name
+/// the lot the units were shipped from.
+#[derive(Clone)]
+pub struct ReturnReceipt {
+    pub line_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    pub uom: Option<String>,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Outcome of inspecting received units of a line. `to_location_id` is required when the
+/// disposition keeps the stock. Units come out of `from_location_id`, or out of the
+/// quarantine location the product was last received into when it is not given.
+#[derive(Clone)]
+pub struct ReturnInspectionData {
+    pub line_id: Uuid,
+    pub quantity: Decimal,
+    pub uom: Option<String>,
+    pub disposition: ReturnDisposition,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Returned quantity per product, reason and disposition. A `None` disposition is stock
+/// received but not inspected yet.
+#[derive(Clone)]
+pub struct ReturnSummary {
+    pub product_id: Uuid,
+    pub reason: ReturnReason,
+    pub disposition: Option<ReturnDisposition>,
+    pub quantity: Decimal,
+}
+
+#[derive(Clone, Default)]
+pub struct ReturnSummaryQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    /// Authorizations created at or after.
+    pub from: Option<DateTime<Utc>>,
+    /// Authorizations created before.
+    pub to: Option<DateTime<Utc>>,
+}
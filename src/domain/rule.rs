@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum))]
 #[cfg_attr(
     feature = "ssr",
@@ -14,9 +14,10 @@ pub enum ResourceAction {
     List,
     Update,
     Delete,
+    Approve,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum))]
 #[cfg_attr(
     feature = "ssr",
@@ -28,9 +29,36 @@ pub enum ResourceType {
     UserRole,
     Rule,
     RoleRule,
+    Warehouse,
+    Location,
+    Product,
+    Stock,
+    Reservation,
+    StockCount,
+    Lot,
+    SerialNumber,
+    Pallet,
+    TransferOrder,
+    Replenishment,
+    Valuation,
+    ReturnAuthorization,
+    Kit,
+    AssemblyOrder,
+    Putaway,
+    PickOrder,
+    Wave,
+    StockHold,
+    StockSnapshot,
+    AbcAnalysis,
+    CrossDock,
+    Shipment,
+    Organization,
+    BusinessPartner,
+    PartnerContact,
+    AttributeDefinition,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum))]
 #[cfg_attr(
     feature = "ssr",
@@ -54,3 +82,20 @@ pub struct Rule {
     pub resource_type: ResourceType,
     pub effect: RuleEffect,
 }
+
+/// Evaluates the rules granted to a user. An explicit `Deny` always wins over any `Allow`.
+pub fn is_allowed(rules: &[Rule], action: ResourceAction, resource_type: ResourceType) -> bool {
+    let mut allowed = false;
+
+    for rule in rules
+        .iter()
+        .filter(|rule| rule.action == action && rule.resource_type == resource_type)
+    {
+        match rule.effect {
+            RuleEffect::Allow => allowed = true,
+            RuleEffect::Deny => return false,
+        }
+    }
+
+    allowed
+}
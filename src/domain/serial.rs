@@ -0,0 +1,70 @@
+use crate::domain::{Product, SerialError};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::SerialStatus")
+)]
+pub enum SerialStatus {
+    InStock,
+    Issued,
+    WrittenOff,
+}
+
+/// A single unit of a serial-tracked product. `location_id` is where the unit
+/// currently is, or where it was last seen once it left stock.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::serial_numbers))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct SerialNumber {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub serial_number: String,
+    pub status: SerialStatus,
+    pub location_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "ssr", derive(diesel::Insertable))]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::stock_movement_serials))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct MovementSerial {
+    pub movement_id: Uuid,
+    pub serial_number_id: Uuid,
+}
+
+/// Serial-tracked products must move exactly one distinct serial number per unit,
+/// other products cannot carry serial numbers at all.
+pub fn check_serial_numbers(
+    product: &Product,
+    quantity: Decimal,
+    serial_numbers: &[String],
+) -> Result<(), SerialError> {
+    if !product.serial_tracked {
+        if !serial_numbers.is_empty() {
+            return Err(SerialError::NotTracked);
+        }
+        return Ok(());
+    }
+
+    let distinct: HashSet<&String> = serial_numbers.iter().collect();
+    if distinct.len() != serial_numbers.len() || Decimal::from(serial_numbers.len()) != quantity {
+        return Err(SerialError::CountMismatch);
+    }
+
+    Ok(())
+}
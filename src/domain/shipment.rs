@@ -0,0 +1,157 @@
+use crate::domain::LabelFormat;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Volume in cubic centimetres carriers count as one kilogram of billable weight.
+const VOLUMETRIC_DIVISOR: i64 = 5000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::ShipmentStatus"
+    )
+)]
+pub enum ShipmentStatus {
+    Open,
+    Shipped,
+    Cancelled,
+}
+
+/// Packed goods of a warehouse handed to a carrier. `carrier` and `service` are set
+/// when the shipment is shipped, together with the cost quoted by the carrier, if any.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::shipments))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Shipment {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    /// Picked order the goods were packed from, if any.
+    pub pick_order_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub ship_to_name: String,
+    pub ship_to_address: String,
+    pub ship_to_postal_code: String,
+    /// ISO 3166-1 alpha-2 code.
+    pub ship_to_country: String,
+    pub carrier: Option<String>,
+    pub service: Option<String>,
+    pub shipping_cost: Option<Decimal>,
+    pub currency: Option<String>,
+    pub status: ShipmentStatus,
+    pub shipped_by: Option<Uuid>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct ShipmentData {
+    pub warehouse_id: Uuid,
+    /// The warehouse and document are taken from the order when omitted.
+    pub pick_order_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub ship_to_name: String,
+    pub ship_to_address: String,
+    pub ship_to_postal_code: String,
+    pub ship_to_country: String,
+}
+
+#[derive(Clone, Default)]
+pub struct ShipmentQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub pick_order_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub status: Option<ShipmentStatus>,
+}
+
+/// A box of a shipment. The carrier assigns the tracking number and label when the
+/// shipment is shipped; the manual carrier leaves the label empty.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::parcels))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Parcel {
+    pub id: Uuid,
+    pub shipment_id: Uuid,
+    pub length_cm: Decimal,
+    pub width_cm: Decimal,
+    pub height_cm: Decimal,
+    pub weight_kg: Decimal,
+    pub tracking_number: Option<String>,
+    pub label: Option<Vec<u8>>,
+    pub label_content_type: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Parcel {
+    /// The larger of the actual and the volumetric weight.
+    pub fn billable_weight(&self) -> Decimal {
+        let volumetric =
+            self.length_cm * self.width_cm * self.height_cm / Decimal::from(VOLUMETRIC_DIVISOR);
+        self.weight_kg.max(volumetric)
+    }
+}
+
+#[derive(Clone)]
+pub struct ParcelData {
+    pub length_cm: Decimal,
+    pub width_cm: Decimal,
+    pub height_cm: Decimal,
+    pub weight_kg: Decimal,
+}
+
+/// Price a carrier quotes for shipping the parcels of a shipment with one of its services.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rate {
+    pub carrier: String,
+    pub service: String,
+    pub amount: Decimal,
+    /// ISO 4217 code.
+    pub currency: String,
+    /// Business days to delivery, when the carrier commits to one.
+    pub transit_days: Option<i32>,
+}
+
+/// Sorts rates cheapest first, then fastest.
+pub fn rank_rates(rates: &mut [Rate]) {
+    rates.sort_by(|a, b| {
+        a.amount
+            .cmp(&b.amount)
+            .then_with(|| {
+                a.transit_days
+                    .unwrap_or(i32::MAX)
+                    .cmp(&b.transit_days.unwrap_or(i32::MAX))
+            })
+            .then_with(|| (&a.carrier, &a.service).cmp(&(&b.carrier, &b.service)))
+    });
+}
+
+#[derive(Clone)]
+pub struct ShipData {
+    pub carrier: String,
+    pub service: String,
+    /// Tracking numbers of the parcels in the order they were added, for carriers that do
+    /// not assign their own, such as the manual carrier.
+    pub tracking_numbers: Vec<String>,
+    pub label_format: LabelFormat,
+}
+
+/// What a carrier returns for one parcel when booking a service.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarrierLabel {
+    pub tracking_number: Option<String>,
+    pub label: Option<Vec<u8>>,
+    pub content_type: Option<String>,
+}
@@ -0,0 +1,159 @@
+use crate::domain::StockStatus;
+use chrono::{DateTime, Datelike, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::SnapshotKind")
+)]
+pub enum SnapshotKind {
+    Daily,
+    /// Daily snapshot taken at the end of the last day of a month.
+    MonthEnd,
+    Manual,
+}
+
+/// Stock on hand at every location at the moment `as_of`, kept so historical reports do not
+/// have to replay the whole movement history.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::stock_snapshots))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct StockSnapshot {
+    pub id: Uuid,
+    pub kind: SnapshotKind,
+    /// Movements posted at or before this moment are included.
+    pub as_of: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StockSnapshot {
+    /// The scheduled snapshot due at `now`: the end of the previous day, which is a
+    /// month-end snapshot when that day closed a month.
+    pub fn scheduled(now: DateTime<Utc>) -> Self {
+        let as_of = now.date_naive().and_time(NaiveTime::MIN).and_utc();
+        let kind = if as_of.day() == 1 {
+            SnapshotKind::MonthEnd
+        } else {
+            SnapshotKind::Daily
+        };
+
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            as_of,
+            created_by: None,
+            created_at: now,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::stock_snapshot_lines))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct StockSnapshotLine {
+    pub id: Uuid,
+    pub snapshot_id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub status: StockStatus,
+    pub quantity: Decimal,
+}
+
+/// Quantity of a product on hand at a location, per lot and status, at some moment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockPosition {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub status: StockStatus,
+    pub quantity: Decimal,
+}
+
+impl From<StockSnapshotLine> for StockPosition {
+    fn from(val: StockSnapshotLine) -> Self {
+        Self {
+            product_id: val.product_id,
+            location_id: val.location_id,
+            lot_id: val.lot_id,
+            status: val.status,
+            quantity: val.quantity,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct StockPositionQuery {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+#[derive(Clone, Default)]
+pub struct StockSnapshotQuery {
+    pub kind: Option<SnapshotKind>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Change in stock of a product at a location between two snapshots, over all its lots
+/// and statuses.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StockSnapshotDiff {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub from_quantity: Decimal,
+    pub to_quantity: Decimal,
+}
+
+impl StockSnapshotDiff {
+    pub fn change(&self) -> Decimal {
+        self.to_quantity - self.from_quantity
+    }
+}
+
+/// Products and locations whose stock differs between the two sets of positions, ordered by
+/// product and location.
+pub fn diff_positions(from: Vec<StockPosition>, to: Vec<StockPosition>) -> Vec<StockSnapshotDiff> {
+    let mut totals = BTreeMap::<(Uuid, Uuid), (Decimal, Decimal)>::new();
+    for position in from {
+        let total = totals
+            .entry((position.product_id, position.location_id))
+            .or_default();
+        total.0 += position.quantity;
+    }
+    for position in to {
+        let total = totals
+            .entry((position.product_id, position.location_id))
+            .or_default();
+        total.1 += position.quantity;
+    }
+
+    totals
+        .into_iter()
+        .filter(|(_, (from_quantity, to_quantity))| from_quantity != to_quantity)
+        .map(
+            |((product_id, location_id), (from_quantity, to_quantity))| StockSnapshotDiff {
+                product_id,
+                location_id,
+                from_quantity,
+                to_quantity,
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,193 @@
+use crate::domain::{Gs1Error, Gs1Scan, merge_scanned};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::MovementKind")
+)]
+pub enum MovementKind {
+    Receipt,
+    Issue,
+    Adjustment,
+    Transfer,
+    /// Moves stock from one status into another without leaving its location.
+    StatusChange,
+}
+
+/// Condition of stock. Only available stock can be reserved or allocated to demand.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::StockStatus")
+)]
+pub enum StockStatus {
+    #[default]
+    Available,
+    Quarantine,
+    Damaged,
+    OnHold,
+}
+
+/// Kind of the business document a movement or reservation belongs to.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::DocumentType")
+)]
+pub enum DocumentType {
+    SalesOrder,
+    StockCount,
+    TransferOrder,
+    PurchaseOrder,
+    ReturnAuthorization,
+    AssemblyOrder,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::stock_balances))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct StockBalance {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub on_hand: Decimal,
+    pub lot_id: Option<Uuid>,
+    pub status: StockStatus,
+}
+
+/// A single posting against stock. Quantity is always positive: stock leaves
+/// `from_location_id` and arrives at `to_location_id`. Every movement touches a single lot.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::stock_movements))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct StockMovement {
+    pub id: Uuid,
+    pub kind: MovementKind,
+    pub product_id: Uuid,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub document_type: Option<DocumentType>,
+    pub document_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub lot_id: Option<Uuid>,
+    /// Cost per base unit of inbound movements, or of goods issued by outbound movements.
+    pub unit_cost: Option<Decimal>,
+    pub total_cost: Option<Decimal>,
+    /// Status of the stock moved.
+    pub status: StockStatus,
+    /// Status the stock is put in by status changes.
+    pub to_status: Option<StockStatus>,
+    pub reason: Option<String>,
+}
+
+/// Lot identification given with a movement. Inbound movements create the lot if needed,
+/// outbound movements without it are allocated first-expired-first-out.
+#[derive(Clone)]
+pub struct MovementData {
+    pub kind: MovementKind,
+    pub product_id: Uuid,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub document_type: Option<DocumentType>,
+    pub document_id: Option<Uuid>,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_numbers: Vec<String>,
+    /// Cost per unit of `uom` of inbound stock. Defaults to the product's current cost.
+    pub unit_cost: Option<Decimal>,
+    /// GS1 element string scanned from the goods, see [`MovementData::apply_scan`].
+    pub scan: Option<String>,
+}
+
+impl MovementData {
+    /// Takes unit, lot, expiry date and serial number from a scan of the goods where they
+    /// are not given. The scan must be of the product and agree with what is given.
+    pub fn apply_scan(&mut self, scan: &Gs1Scan) -> Result<(), Gs1Error> {
+        scan.check_product(self.product_id)?;
+        self.uom = merge_scanned(self.uom.take(), scan.uom.clone(), "01")?;
+        self.lot_number = merge_scanned(self.lot_number.take(), scan.lot_number.clone(), "10")?;
+        self.expiry_date = merge_scanned(self.expiry_date, scan.expiry_date, "17")?;
+        self.serial_numbers = scan.serial_numbers(std::mem::take(&mut self.serial_numbers))?;
+
+        Ok(())
+    }
+}
+
+/// `blocked` is the part of `on_hand` held in blocked or expired lots or in quarantine
+/// locations, `in_transit` the part shipped by a transfer order and not yet received.
+#[derive(Clone)]
+pub struct StockLevel {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub on_hand: Decimal,
+    pub blocked: Decimal,
+    pub in_transit: Decimal,
+    pub reserved: Decimal,
+    pub available: Decimal,
+}
+
+#[derive(Clone, Default)]
+pub struct StockLevelQuery {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+}
+
+/// Balances matching every filter given. Without statuses, balances of any status match.
+#[derive(Clone, Default)]
+pub struct StockBalanceQuery {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    pub statuses: Vec<StockStatus>,
+}
+
+/// Movements matching every filter given. A location or warehouse matches movements in or
+/// out of it.
+#[derive(Clone, Default)]
+pub struct StockMovementQuery {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    /// Only movements posted after this moment.
+    pub from: Option<DateTime<Utc>>,
+    /// Only movements posted at or before this moment.
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+pub struct AvailableToPromise {
+    pub product_id: Uuid,
+    pub warehouse_id: Option<Uuid>,
+    pub on_hand: Decimal,
+    pub blocked: Decimal,
+    pub in_transit: Decimal,
+    pub reserved: Decimal,
+    pub available: Decimal,
+}
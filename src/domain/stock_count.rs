@@ -0,0 +1,145 @@
+use crate::domain::{AbcClass, Location, Product};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::CountScope")
+)]
+pub enum CountScope {
+    Zone,
+    AbcClass,
+    Full,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::CountSessionStatus"
+    )
+)]
+pub enum CountSessionStatus {
+    Open,
+    Completed,
+    Cancelled,
+}
+
+/// `Recount` is set when the first count falls outside the tolerance, `Counted`
+/// when a recount is still outside it and the variance waits for approval.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::CountTaskStatus"
+    )
+)]
+pub enum CountTaskStatus {
+    Pending,
+    Recount,
+    Counted,
+    Approved,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::count_sessions))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct CountSession {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub scope: CountScope,
+    pub zone: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub blind: bool,
+    pub tolerance_percent: Decimal,
+    pub status: CountSessionStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl CountSession {
+    /// Whether the variance is small enough to be adjusted without approval.
+    pub fn within_tolerance(&self, expected: Decimal, variance: Decimal) -> bool {
+        variance.abs() <= expected.abs() * self.tolerance_percent / Decimal::ONE_HUNDRED
+    }
+
+    /// Whether the scope of the session takes in `product` at `location`.
+    pub fn covers(&self, product: &Product, location: &Location) -> bool {
+        if location.warehouse_id != self.warehouse_id || location.in_transit {
+            return false;
+        }
+
+        match self.scope {
+            CountScope::Zone => location.zone == self.zone,
+            CountScope::AbcClass => product.abc_class == self.abc_class,
+            CountScope::Full => true,
+        }
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(
+        diesel::Queryable,
+        diesel::Selectable,
+        diesel::Insertable,
+        diesel::AsChangeset
+    )
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::count_tasks))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+#[cfg_attr(feature = "ssr", diesel(treat_none_as_null = true))]
+pub struct CountTask {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub expected_quantity: Decimal,
+    pub counted_quantity: Option<Decimal>,
+    pub variance: Option<Decimal>,
+    pub attempts: i16,
+    pub status: CountTaskStatus,
+    pub counted_by: Option<Uuid>,
+    pub counted_at: Option<DateTime<Utc>>,
+    pub approved_by: Option<Uuid>,
+    pub movement_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    /// Users who counted the task on any attempt, none of whom may approve its variance.
+    /// `counted_by` is the last of them.
+    pub counters: Vec<Uuid>,
+}
+
+#[derive(Clone)]
+pub struct CountSessionData {
+    pub warehouse_id: Uuid,
+    pub scope: CountScope,
+    pub zone: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub blind: bool,
+    pub tolerance_percent: Decimal,
+}
+
+/// Stock found where the session has no task for it, e.g. in a bin that was empty when the
+/// session was planned.
+#[derive(Clone)]
+pub struct CountTaskData {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+}
@@ -0,0 +1,27 @@
+use crate::domain::StockStatus;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Stock put on hold, released or scrapped at a location. `status` is the hold status the
+/// stock goes into, or the one it comes out of on release and scrap.
+#[derive(Clone)]
+pub struct StockHoldData {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    /// Any lot of the status when omitted, usable lots only when putting on hold.
+    pub lot_id: Option<Uuid>,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+    pub status: StockStatus,
+    /// Required when putting stock on hold.
+    pub reason: Option<String>,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Puts all available stock of a lot on hold, wherever it is.
+#[derive(Clone)]
+pub struct LotHoldData {
+    pub status: StockStatus,
+    pub reason: String,
+}
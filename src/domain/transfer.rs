@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `Shipped` once all lines left the source warehouse, `Closed` when the order was closed
+/// before everything arrived and the missing quantity was written off.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::TransferOrderStatus"
+    )
+)]
+pub enum TransferOrderStatus {
+    Open,
+    Shipped,
+    PartiallyReceived,
+    Received,
+    Closed,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::transfer_orders))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct TransferOrder {
+    pub id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub transit_location_id: Option<Uuid>,
+    pub status: TransferOrderStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::transfer_order_lines))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct TransferOrderLine {
+    pub id: Uuid,
+    pub transfer_order_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub written_off_quantity: Decimal,
+}
+
+impl TransferOrderLine {
+    /// Quantity shipped but neither received nor written off. Only meaningful once shipped.
+    pub fn outstanding(&self) -> Decimal {
+        self.quantity - self.received_quantity - self.written_off_quantity
+    }
+}
+
+#[derive(Clone)]
+pub struct TransferOrderData {
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub lines: Vec<TransferOrderLineData>,
+}
+
+#[derive(Clone)]
+pub struct TransferOrderLineData {
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+}
+
+/// Units of a serial-tracked line that leave the source warehouse.
+#[derive(Clone)]
+pub struct TransferShipment {
+    pub line_id: Uuid,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Quantity of a line received into a location of the destination warehouse.
+#[derive(Clone)]
+pub struct TransferReceipt {
+    pub line_id: Uuid,
+    pub to_location_id: Uuid,
+    pub quantity: Decimal,
+    pub uom: Option<String>,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Stock of a transfer order line that is on its way.
+#[derive(Clone)]
+pub struct InTransitStock {
+    pub transfer_order_id: Uuid,
+    pub line_id: Uuid,
+    pub product_id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub quantity: Decimal,
+    pub shipped_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct InTransitQuery {
+    pub product_id: Option<Uuid>,
+    /// Matches both the source and the destination warehouse.
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// Quantity of a closed transfer order line that never arrived.
+#[derive(Clone)]
+pub struct TransferDiscrepancy {
+    pub transfer_order_id: Uuid,
+    pub line_id: Uuid,
+    pub product_id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub shipped_quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub missing_quantity: Decimal,
+    pub closed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How issued stock is costed. `Fifo` consumes the oldest receipts first, `WeightedAverage`
+/// issues at the average cost of everything on hand.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::CostingMethod"
+    )
+)]
+pub enum CostingMethod {
+    #[default]
+    Fifo,
+    WeightedAverage,
+}
+
+/// Quantity of a product still valued at the cost it was brought in at.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::cost_layers))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct CostLayer {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub movement_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub remaining_quantity: Decimal,
+    pub remaining_value: Decimal,
+}
+
+impl CostLayer {
+    /// Value of `quantity` taken from the layer. Taking everything takes the whole value,
+    /// so rounding never leaves value behind.
+    pub fn value_of(&self, quantity: Decimal) -> Decimal {
+        if quantity >= self.remaining_quantity {
+            self.remaining_value
+        } else {
+            (quantity * self.remaining_value / self.remaining_quantity).round_dp(6)
+        }
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::AsChangeset)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::valuation_settings))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct ValuationSettings {
+    pub costing_method: CostingMethod,
+}
+
+/// Quantity and value of a product's stock at a point in time.
+#[derive(Clone)]
+pub struct ProductValuation {
+    pub product_id: Uuid,
+    pub costing_method: CostingMethod,
+    pub quantity: Decimal,
+    pub value: Decimal,
+    /// `None` when nothing is on hand.
+    pub unit_cost: Option<Decimal>,
+}
+
+#[derive(Clone, Default)]
+pub struct ValuationQuery {
+    /// Defaults to now.
+    pub as_of: Option<DateTime<Utc>>,
+    pub product_id: Option<Uuid>,
+}
@@ -0,0 +1,147 @@
+use crate::domain::{
+    Comparison, FilterOp, ListError, ListFilter, ListQuery, ListSort, attribute_filter,
+};
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::warehouses))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Warehouse {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+}
+
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::locations))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Location {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub code: String,
+    pub zone: Option<String>,
+    /// Virtual location of a transfer order holding its stock while on the way.
+    pub in_transit: bool,
+    /// Holds stock that must not be used until released, e.g. customer returns awaiting inspection.
+    pub quarantine: bool,
+    /// Cubic metres the location holds, unlimited when unset.
+    pub max_volume: Option<Decimal>,
+    /// Kilograms the location holds, unlimited when unset.
+    pub max_weight: Option<Decimal>,
+    /// Position on the floor plan, in metres.
+    pub position_x: Option<Decimal>,
+    pub position_y: Option<Decimal>,
+    /// Walking order of the location on pick paths, ahead of locations without one.
+    pub pick_sequence: Option<i32>,
+    /// Values of the custom attributes defined for locations, by key.
+    pub attributes: Value,
+}
+
+impl Location {
+    /// Rectilinear distance along the aisles, unknown unless both locations have a position.
+    pub fn distance_to(&self, other: &Location) -> Option<Decimal> {
+        match (
+            self.position_x,
+            self.position_y,
+            other.position_x,
+            other.position_y,
+        ) {
+            (Some(from_x), Some(from_y), Some(to_x), Some(to_y)) => {
+                Some((to_x - from_x).abs() + (to_y - from_y).abs())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WarehouseData {
+    pub code: String,
+    pub name: String,
+}
+
+#[derive(Clone)]
+pub struct LocationData {
+    pub warehouse_id: Uuid,
+    pub code: String,
+    pub zone: Option<String>,
+    pub quarantine: bool,
+    pub max_volume: Option<Decimal>,
+    pub max_weight: Option<Decimal>,
+    pub position_x: Option<Decimal>,
+    pub position_y: Option<Decimal>,
+    pub pick_sequence: Option<i32>,
+    pub attributes: Map<String, Value>,
+}
+
+#[derive(Clone, Default)]
+pub struct LocationQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub zone: Option<String>,
+    /// Locations whose attributes contain all of these values.
+    pub attributes: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationFilter {
+    WarehouseId(Comparison<Uuid>),
+    Code(Comparison<String>),
+    Zone(Comparison<String>),
+    Quarantine(Comparison<bool>),
+    PickSequence(Comparison<i32>),
+    /// Locations having the value for the custom attribute with the key.
+    Attribute(String, Value),
+}
+
+impl ListFilter for LocationFilter {
+    fn parse(field: &str, op: FilterOp, value: &str) -> Result<Self, ListError> {
+        if let Some(key) = field.strip_prefix("attributes.") {
+            return Ok(LocationFilter::Attribute(
+                key.to_string(),
+                attribute_filter(field, op, value)?,
+            ));
+        }
+
+        match field {
+            "warehouse_id" => Ok(LocationFilter::WarehouseId(Comparison::parse(
+                field, op, value,
+            )?)),
+            "code" => Ok(LocationFilter::Code(Comparison::parse(field, op, value)?)),
+            "zone" => Ok(LocationFilter::Zone(Comparison::parse(field, op, value)?)),
+            "quarantine" => Ok(LocationFilter::Quarantine(Comparison::parse(
+                field, op, value,
+            )?)),
+            "pick_sequence" => Ok(LocationFilter::PickSequence(Comparison::parse(
+                field, op, value,
+            )?)),
+            _ => Err(ListError::UnknownFilter(field.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LocationSort {
+    #[default]
+    Code,
+}
+
+impl ListSort for LocationSort {
+    fn parse(field: &str) -> Option<Self> {
+        match field {
+            "code" => Some(LocationSort::Code),
+            _ => None,
+        }
+    }
+}
+
+pub type LocationListQuery = ListQuery<LocationFilter, LocationSort>;
@@ -0,0 +1,254 @@
+use crate::domain::{Gs1Error, Gs1Scan, Location, merge_scanned};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::PickOrderStatus"
+    )
+)]
+pub enum PickOrderStatus {
+    Released,
+    Waved,
+    Picked,
+    Cancelled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(existing_type_path = "crate::repository::postgresql::schema::sql_types::WaveStatus")
+)]
+pub enum WaveStatus {
+    Open,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "ssr", derive(diesel_derive_enum::DbEnum, utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "ssr",
+    db_enum(
+        existing_type_path = "crate::repository::postgresql::schema::sql_types::PickTaskStatus"
+    )
+)]
+pub enum PickTaskStatus {
+    Open,
+    Picked,
+    Cancelled,
+}
+
+/// Sales order released to a warehouse for picking. Its lines are the active reservations
+/// of the order at locations of the warehouse.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::pick_orders))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PickOrder {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub document_id: Uuid,
+    /// Higher goes first among orders with the same cut-off.
+    pub priority: i32,
+    pub carrier: Option<String>,
+    /// Latest time the order can be handed to the carrier.
+    pub cutoff_at: Option<DateTime<Utc>>,
+    pub status: PickOrderStatus,
+    pub wave_id: Option<Uuid>,
+    /// Tote the order is picked into on cluster-picked waves, numbered from 1.
+    pub tote: Option<i32>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PickOrder {
+    /// Orders due first: earliest cut-off, then highest priority, then released first.
+    /// Orders without a cut-off come last.
+    pub fn urgency_key(&self) -> (bool, Option<DateTime<Utc>>, Reverse<i32>, DateTime<Utc>) {
+        (
+            self.cutoff_at.is_none(),
+            self.cutoff_at,
+            Reverse(self.priority),
+            self.created_at,
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct PickOrderData {
+    pub warehouse_id: Uuid,
+    pub document_id: Uuid,
+    pub priority: i32,
+    pub carrier: Option<String>,
+    pub cutoff_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Default)]
+pub struct PickOrderQuery {
+    pub warehouse_id: Option<Uuid>,
+    pub status: Option<PickOrderStatus>,
+}
+
+/// Batch of orders picked together. Waves with `totes` are cluster picked: every order
+/// goes into its own tote in a single trip.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::waves))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct Wave {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub status: WaveStatus,
+    pub totes: Option<i32>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Picks one reservation of an order. Tasks of a wave are numbered along the pick path.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "ssr",
+    derive(diesel::Queryable, diesel::Selectable, diesel::Insertable)
+)]
+#[cfg_attr(feature = "ssr", diesel(table_name = crate::repository::postgresql::schema::pick_tasks))]
+#[cfg_attr(feature = "ssr", diesel(check_for_backend(diesel::pg::Pg)))]
+pub struct PickTask {
+    pub id: Uuid,
+    pub wave_id: Uuid,
+    pub pick_order_id: Uuid,
+    pub reservation_id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    /// Less than `quantity` on short picks.
+    pub picked_quantity: Option<Decimal>,
+    pub sequence: i32,
+    pub tote: Option<i32>,
+    pub status: PickTaskStatus,
+    pub picked_by: Option<Uuid>,
+    pub picked_at: Option<DateTime<Utc>>,
+}
+
+/// Selects the released orders of a warehouse that go into a wave, most urgent first.
+#[derive(Clone)]
+pub struct WaveCriteria {
+    pub warehouse_id: Uuid,
+    pub carrier: Option<String>,
+    /// Only orders with a cut-off at or before this time.
+    pub cutoff_before: Option<DateTime<Utc>>,
+    /// Only orders picked entirely from locations of the zone.
+    pub zone: Option<String>,
+    pub min_priority: Option<i32>,
+    pub max_orders: Option<usize>,
+    /// Cluster picks into this many totes, which also caps the number of orders.
+    pub totes: Option<i32>,
+}
+
+impl WaveCriteria {
+    /// Everything but the zone, which depends on the lines of the order.
+    pub fn matches(&self, order: &PickOrder) -> bool {
+        order.warehouse_id == self.warehouse_id
+            && order.status == PickOrderStatus::Released
+            && self
+                .carrier
+                .as_ref()
+                .is_none_or(|carrier| order.carrier.as_ref() == Some(carrier))
+            && self
+                .cutoff_before
+                .is_none_or(|before| order.cutoff_at.is_some_and(|cutoff| cutoff <= before))
+            && self
+                .min_priority
+                .is_none_or(|min_priority| order.priority >= min_priority)
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        let totes = self.totes.map(|totes| totes.max(0) as usize);
+        match (self.max_orders, totes) {
+            (Some(max_orders), Some(totes)) => Some(max_orders.min(totes)),
+            (max_orders, totes) => max_orders.or(totes),
+        }
+    }
+}
+
+/// Orders the stops of a pick trip. Locations with a configured pick sequence come first,
+/// in that order. The rest follow by nearest neighbour along the aisles, starting from the
+/// last sequenced stop or the floor plan origin, and locations without a position come
+/// last, by code.
+pub fn pick_path(mut stops: Vec<Location>) -> Vec<Location> {
+    stops.sort_by(|a, b| a.code.cmp(&b.code));
+    let (mut path, rest): (Vec<_>, Vec<_>) = stops
+        .into_iter()
+        .partition(|stop| stop.pick_sequence.is_some());
+    path.sort_by_key(|stop| stop.pick_sequence);
+
+    let (mut positioned, unpositioned): (Vec<_>, Vec<_>) = rest
+        .into_iter()
+        .partition(|stop| stop.position_x.is_some() && stop.position_y.is_some());
+
+    let mut current = path
+        .last()
+        .and_then(|stop| stop.position_x.zip(stop.position_y))
+        .unwrap_or_default();
+    while !positioned.is_empty() {
+        let (index, _) = positioned
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, stop)| {
+                let (x, y) = stop.position_x.zip(stop.position_y).unwrap_or_default();
+                (x - current.0).abs() + (y - current.1).abs()
+            })
+            .expect("positioned stops are not empty");
+        let stop = positioned.remove(index);
+        current = stop.position_x.zip(stop.position_y).unwrap_or_default();
+        path.push(stop);
+    }
+
+    path.extend(unpositioned);
+    path
+}
+
+#[derive(Clone)]
+pub struct PickConfirmation {
+    /// Everything the task asks for when omitted, less on a short pick.
+    pub quantity: Option<Decimal>,
+    /// Unit the quantity is given in, the product's base UoM when omitted.
+    pub uom: Option<String>,
+    /// Lot picked, which is otherwise allocated first-expired-first-out.
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_numbers: Vec<String>,
+    /// GS1 element string scanned from the goods, see [`PickConfirmation::apply_scan`].
+    pub scan: Option<String>,
+}
+
+impl PickConfirmation {
+    /// Takes unit, lot, expiry date and serial number from a scan of the goods picked where
+    /// they are not given. The scan must be of `product_id` and agree with what is given.
+    pub fn apply_scan(&mut self, scan: &Gs1Scan, product_id: Uuid) -> Result<(), Gs1Error> {
+        scan.check_product(product_id)?;
+        self.uom = merge_scanned(self.uom.take(), scan.uom.clone(), "01")?;
+        self.lot_number = merge_scanned(self.lot_number.take(), scan.lot_number.clone(), "10")?;
+        self.expiry_date = merge_scanned(self.expiry_date, scan.expiry_date, "17")?;
+        self.serial_numbers = scan.serial_numbers(std::mem::take(&mut self.serial_numbers))?;
+
+        Ok(())
+    }
+}
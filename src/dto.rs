@@ -1,5 +1,61 @@
+mod abc;
+mod attachment;
+mod attribute;
 mod auth;
+mod business_partner;
+mod cross_dock;
 mod error;
+mod kit;
+mod label;
+mod list;
+mod lot;
+mod organization;
+mod pallet;
+mod product;
+mod putaway;
+mod replenishment;
+mod reservation;
+mod returns;
+mod scan;
+mod serial;
+mod shipment;
+mod snapshot;
+mod stock;
+mod stock_count;
+mod stock_hold;
+mod transfer;
+mod validation;
+mod valuation;
+mod warehouse;
+mod wave;
 
+pub use abc::*;
+pub use attachment::*;
+pub use attribute::*;
 pub use auth::*;
+pub use business_partner::*;
+pub use cross_dock::*;
 pub use error::*;
+pub use kit::*;
+pub use label::*;
+pub use list::*;
+pub use lot::*;
+pub use organization::*;
+pub use pallet::*;
+pub use product::*;
+pub use putaway::*;
+pub use replenishment::*;
+pub use reservation::*;
+pub use returns::*;
+pub use scan::*;
+pub use serial::*;
+pub use shipment::*;
+pub use snapshot::*;
+pub use stock::*;
+pub use stock_count::*;
+pub use stock_hold::*;
+pub use transfer::*;
+pub use validation::*;
+pub use valuation::*;
+pub use warehouse::*;
+pub use wave::*;
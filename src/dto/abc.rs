@@ -0,0 +1,268 @@
+use crate::domain::{
+    AbcAnalysis, AbcAnalysisData, AbcAnalysisLine, AbcAnalysisQuery, AbcClass, AbcCriterion,
+    SlottingRecommendation, SlottingRecommendationQuery, SlottingStatus,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+/// Shares are fractions of the total score, e.g. `0.8` for 80%.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[validate(schema(function = "validate_abc_shares"))]
+pub struct RunAbcAnalysisRequest {
+    pub warehouse_id: Uuid,
+
+    pub criterion: AbcCriterion,
+
+    /// Days of issues looked back on from now.
+    #[serde(default = "default_window_days")]
+    #[validate(range(min = 1, max = 3660))]
+    pub window_days: i32,
+
+    #[serde(default = "default_a_share")]
+    pub a_share: Decimal,
+
+    #[serde(default = "default_b_share")]
+    pub b_share: Decimal,
+}
+
+fn default_window_days() -> i32 {
+    90
+}
+
+fn default_a_share() -> Decimal {
+    Decimal::new(80, 2)
+}
+
+fn default_b_share() -> Decimal {
+    Decimal::new(95, 2)
+}
+
+fn validate_abc_shares(req: &RunAbcAnalysisRequest) -> Result<(), ValidationError> {
+    if req.a_share <= Decimal::ZERO || req.a_share > req.b_share || req.b_share > Decimal::ONE {
+        return Err(ValidationError::new("abc_shares"));
+    }
+
+    Ok(())
+}
+
+impl From<RunAbcAnalysisRequest> for AbcAnalysisData {
+    fn from(val: RunAbcAnalysisRequest) -> Self {
+        let RunAbcAnalysisRequest {
+            warehouse_id,
+            criterion,
+            window_days,
+            a_share,
+            b_share,
+        } = val;
+
+        AbcAnalysisData {
+            warehouse_id,
+            criterion,
+            window_days,
+            a_share,
+            b_share,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct AbcAnalysesParams {
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<AbcAnalysesParams> for AbcAnalysisQuery {
+    fn from(val: AbcAnalysesParams) -> Self {
+        let AbcAnalysesParams { warehouse_id } = val;
+
+        AbcAnalysisQuery { warehouse_id }
+    }
+}
+
+/// Issues posted after `period_start` and up to `period_end` were scored. Once
+/// `applied_at` is set, products carry the class of this analysis.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AbcAnalysisResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub criterion: AbcCriterion,
+    pub window_days: i32,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub a_share: Decimal,
+    pub b_share: Decimal,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AbcAnalysis> for AbcAnalysisResponse {
+    fn from(val: AbcAnalysis) -> Self {
+        let AbcAnalysis {
+            id,
+            warehouse_id,
+            criterion,
+            window_days,
+            period_start,
+            period_end,
+            a_share,
+            b_share,
+            applied_at,
+            created_by,
+            created_at,
+        } = val;
+
+        AbcAnalysisResponse {
+            id,
+            warehouse_id,
+            criterion,
+            window_days,
+            period_start,
+            period_end,
+            a_share,
+            b_share,
+            applied_at,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+/// `score` is the number of issues or their cost, depending on the criterion.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AbcAnalysisLineResponse {
+    pub product_id: Uuid,
+    pub rank: i32,
+    pub score: Decimal,
+    pub share: Decimal,
+    pub cumulative_share: Decimal,
+    pub abc_class: AbcClass,
+}
+
+impl From<AbcAnalysisLine> for AbcAnalysisLineResponse {
+    fn from(val: AbcAnalysisLine) -> Self {
+        let AbcAnalysisLine {
+            id: _,
+            analysis_id: _,
+            product_id,
+            rank,
+            score,
+            share,
+            cumulative_share,
+            abc_class,
+        } = val;
+
+        AbcAnalysisLineResponse {
+            product_id,
+            rank,
+            score,
+            share,
+            cumulative_share,
+            abc_class,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct SlottingRecommendationsParams {
+    pub warehouse_id: Option<Uuid>,
+    pub analysis_id: Option<Uuid>,
+    pub status: Option<SlottingStatus>,
+}
+
+impl From<SlottingRecommendationsParams> for SlottingRecommendationQuery {
+    fn from(val: SlottingRecommendationsParams) -> Self {
+        let SlottingRecommendationsParams {
+            warehouse_id,
+            analysis_id,
+            status,
+        } = val;
+
+        SlottingRecommendationQuery {
+            warehouse_id,
+            analysis_id,
+            status,
+        }
+    }
+}
+
+/// Moves the product into `to_location_id`, and `displaced_product_id`, if any, into
+/// `from_location_id`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SlottingRecommendationResponse {
+    pub id: Uuid,
+    pub analysis_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub rank: i32,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub displaced_product_id: Option<Uuid>,
+    pub status: SlottingStatus,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl From<SlottingRecommendation> for SlottingRecommendationResponse {
+    fn from(val: SlottingRecommendation) -> Self {
+        let SlottingRecommendation {
+            id,
+            analysis_id,
+            warehouse_id,
+            product_id,
+            rank,
+            from_location_id,
+            to_location_id,
+            displaced_product_id,
+            status,
+            reviewed_by,
+            reviewed_at,
+        } = val;
+
+        SlottingRecommendationResponse {
+            id,
+            analysis_id,
+            warehouse_id,
+            product_id,
+            rank,
+            from_location_id,
+            to_location_id,
+            displaced_product_id,
+            status,
+            reviewed_by,
+            reviewed_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AbcAnalysisDetailResponse {
+    pub analysis: AbcAnalysisResponse,
+    pub lines: Vec<AbcAnalysisLineResponse>,
+    pub recommendations: Vec<SlottingRecommendationResponse>,
+}
+
+impl AbcAnalysisDetailResponse {
+    pub fn new(
+        analysis: AbcAnalysis,
+        lines: Vec<AbcAnalysisLine>,
+        recommendations: Vec<SlottingRecommendation>,
+    ) -> Self {
+        AbcAnalysisDetailResponse {
+            analysis: analysis.into(),
+            lines: lines.into_iter().map(Into::into).collect(),
+            recommendations: recommendations.into_iter().map(Into::into).collect(),
+        }
+    }
+}
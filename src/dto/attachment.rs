@@ -0,0 +1,66 @@
+use crate::domain::{Attachment, AttachmentOwner};
+use crate::dto::validate_file_name;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// The content is the request body, typed by its `Content-Type` header.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct UploadAttachmentParams {
+    pub owner_type: AttachmentOwner,
+    pub owner_id: Uuid,
+
+    #[validate(length(min = 1, max = 255), custom(function = "validate_file_name"))]
+    pub file_name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct AttachmentsParams {
+    pub owner_type: AttachmentOwner,
+    pub owner_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub owner_type: AttachmentOwner,
+    pub owner_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(val: Attachment) -> Self {
+        let Attachment {
+            id,
+            owner_type,
+            owner_id,
+            file_name,
+            content_type,
+            size,
+            storage_key: _,
+            created_by,
+            created_at,
+        } = val;
+
+        AttachmentResponse {
+            id,
+            owner_type,
+            owner_id,
+            file_name,
+            content_type,
+            size,
+            created_by,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::domain::{
+    AttributeDefinition, AttributeDefinitionData, AttributeEntity, AttributeError, AttributeKind,
+};
+use crate::dto::{validate_attribute_key, validate_attribute_options};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateAttributeDefinitionRequest {
+    pub entity: AttributeEntity,
+
+    /// Key of the value in the `attributes` object of the entity, in snake_case.
+    #[validate(length(min = 1, max = 64), custom(function = "validate_attribute_key"))]
+    pub key: String,
+
+    /// Label of the field in forms.
+    #[validate(length(min = 1, max = 128))]
+    pub label: String,
+
+    pub kind: AttributeKind,
+
+    #[serde(default)]
+    pub required: bool,
+
+    /// Values of an enum attribute. Other kinds take none.
+    #[serde(default)]
+    #[validate(custom(function = "validate_attribute_options"))]
+    pub options: Vec<String>,
+
+    /// Order of the field in forms.
+    #[serde(default)]
+    pub position: i32,
+}
+
+impl From<CreateAttributeDefinitionRequest> for AttributeDefinitionData {
+    fn from(val: CreateAttributeDefinitionRequest) -> Self {
+        let CreateAttributeDefinitionRequest {
+            entity,
+            key,
+            label,
+            kind,
+            required,
+            options,
+            position,
+        } = val;
+
+        AttributeDefinitionData {
+            entity,
+            key,
+            label,
+            kind,
+            required,
+            options,
+            position,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct AttributeDefinitionsParams {
+    pub entity: AttributeEntity,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AttributeDefinitionResponse {
+    pub id: Uuid,
+    pub entity: AttributeEntity,
+    pub key: String,
+    pub label: String,
+    pub kind: AttributeKind,
+    pub required: bool,
+    pub options: Vec<String>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AttributeDefinition> for AttributeDefinitionResponse {
+    fn from(val: AttributeDefinition) -> Self {
+        let AttributeDefinition {
+            id,
+            entity,
+            key,
+            label,
+            kind,
+            required,
+            options,
+            position,
+            created_at,
+        } = val;
+
+        AttributeDefinitionResponse {
+            id,
+            entity,
+            key,
+            label,
+            kind,
+            required,
+            options,
+            position,
+            created_at,
+        }
+    }
+}
+
+/// Replaces every custom attribute value of the entity. Null values are dropped.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SetAttributesRequest {
+    #[cfg_attr(feature = "ssr", schema(value_type = Object))]
+    pub attributes: Map<String, Value>,
+}
+
+/// Parses the `attributes` filter of list endpoints, a JSON object of the values entities
+/// must have, e.g. `{"hazard_class":"3"}`.
+pub(crate) fn attribute_filter(
+    filter: Option<String>,
+) -> Result<Map<String, Value>, AttributeError> {
+    match filter {
+        Some(filter) => match serde_json::from_str(&filter) {
+            Ok(Value::Object(attributes)) => Ok(attributes),
+            _ => Err(AttributeError::InvalidFilter),
+        },
+        None => Ok(Map::new()),
+    }
+}
@@ -65,6 +65,8 @@ pub struct AccessTokenClaims {
     pub iat: i64,
     pub id: Uuid,
     pub email: String,
+    /// Organization the user acts as, whose data every request is restricted to.
+    pub organization_id: Uuid,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
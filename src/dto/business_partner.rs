@@ -0,0 +1,314 @@
+use crate::domain::{
+    AddressKind, BusinessPartner, BusinessPartnerData, BusinessPartnerQuery, PartnerAddress,
+    PartnerAddressData, PartnerContact, PartnerContactData, PartnerRole,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateBusinessPartnerRequest {
+    #[validate(length(min = 1, max = 32))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 256))]
+    pub name: String,
+
+    /// VAT or other tax registration number. Spaces, dots and dashes are ignored.
+    #[validate(length(min = 1, max = 32))]
+    pub tax_id: Option<String>,
+
+    #[validate(length(min = 1))]
+    pub roles: Vec<PartnerRole>,
+
+    #[validate(email, length(max = 256))]
+    pub email: Option<String>,
+
+    #[validate(length(min = 1, max = 32))]
+    pub phone: Option<String>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub addresses: Vec<AddPartnerAddressRequest>,
+
+    #[serde(default)]
+    #[validate(nested)]
+    pub contacts: Vec<AddPartnerContactRequest>,
+
+    /// Creates the partner even though another one has a similar name.
+    #[serde(default)]
+    pub allow_similar_name: bool,
+}
+
+impl From<CreateBusinessPartnerRequest> for BusinessPartnerData {
+    fn from(val: CreateBusinessPartnerRequest) -> Self {
+        let CreateBusinessPartnerRequest {
+            code,
+            name,
+            tax_id,
+            roles,
+            email,
+            phone,
+            addresses,
+            contacts,
+            allow_similar_name,
+        } = val;
+
+        BusinessPartnerData {
+            code,
+            name,
+            tax_id,
+            roles,
+            email,
+            phone,
+            addresses: addresses.into_iter().map(Into::into).collect(),
+            contacts: contacts.into_iter().map(Into::into).collect(),
+            allow_similar_name,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct BusinessPartnersParams {
+    /// Part of the code, name or tax id, case-insensitive.
+    pub q: Option<String>,
+    pub role: Option<PartnerRole>,
+}
+
+impl From<BusinessPartnersParams> for BusinessPartnerQuery {
+    fn from(val: BusinessPartnersParams) -> Self {
+        let BusinessPartnersParams { q, role } = val;
+
+        BusinessPartnerQuery { search: q, role }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BusinessPartnerResponse {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub tax_id: Option<String>,
+    pub roles: Vec<PartnerRole>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BusinessPartner> for BusinessPartnerResponse {
+    fn from(val: BusinessPartner) -> Self {
+        let roles = val.roles();
+        let BusinessPartner {
+            id,
+            code,
+            name,
+            normalized_name: _,
+            tax_id,
+            is_supplier: _,
+            is_customer: _,
+            is_carrier: _,
+            email,
+            phone,
+            created_by,
+            created_at,
+        } = val;
+
+        BusinessPartnerResponse {
+            id,
+            code,
+            name,
+            tax_id,
+            roles,
+            email,
+            phone,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AddPartnerAddressRequest {
+    pub kind: AddressKind,
+
+    /// Street lines, one per line.
+    #[validate(length(min = 1, max = 512))]
+    pub street: String,
+
+    #[validate(length(min = 1, max = 128))]
+    pub city: String,
+
+    #[validate(length(min = 1, max = 16))]
+    pub postal_code: String,
+
+    /// ISO 3166-1 alpha-2 code.
+    #[validate(length(equal = 2))]
+    pub country: String,
+
+    /// Replaces the current default address of the kind.
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl From<AddPartnerAddressRequest> for PartnerAddressData {
+    fn from(val: AddPartnerAddressRequest) -> Self {
+        let AddPartnerAddressRequest {
+            kind,
+            street,
+            city,
+            postal_code,
+            country,
+            is_default,
+        } = val;
+
+        PartnerAddressData {
+            kind,
+            street,
+            city,
+            postal_code,
+            country,
+            is_default,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PartnerAddressResponse {
+    pub id: Uuid,
+    pub partner_id: Uuid,
+    pub kind: AddressKind,
+    pub street: String,
+    pub city: String,
+    pub postal_code: String,
+    pub country: String,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PartnerAddress> for PartnerAddressResponse {
+    fn from(val: PartnerAddress) -> Self {
+        let PartnerAddress {
+            id,
+            partner_id,
+            kind,
+            street,
+            city,
+            postal_code,
+            country,
+            is_default,
+            created_at,
+        } = val;
+
+        PartnerAddressResponse {
+            id,
+            partner_id,
+            kind,
+            street,
+            city,
+            postal_code,
+            country,
+            is_default,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AddPartnerContactRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+
+    #[validate(email, length(max = 256))]
+    pub email: Option<String>,
+
+    #[validate(length(min = 1, max = 32))]
+    pub phone: Option<String>,
+
+    #[validate(length(min = 1, max = 128))]
+    pub position: Option<String>,
+}
+
+impl From<AddPartnerContactRequest> for PartnerContactData {
+    fn from(val: AddPartnerContactRequest) -> Self {
+        let AddPartnerContactRequest {
+            name,
+            email,
+            phone,
+            position,
+        } = val;
+
+        PartnerContactData {
+            name,
+            email,
+            phone,
+            position,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PartnerContactResponse {
+    pub id: Uuid,
+    pub partner_id: Uuid,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub position: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PartnerContact> for PartnerContactResponse {
+    fn from(val: PartnerContact) -> Self {
+        let PartnerContact {
+            id,
+            partner_id,
+            name,
+            email,
+            phone,
+            position,
+            created_at,
+        } = val;
+
+        PartnerContactResponse {
+            id,
+            partner_id,
+            name,
+            email,
+            phone,
+            position,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BusinessPartnerDetailResponse {
+    pub partner: BusinessPartnerResponse,
+    pub addresses: Vec<PartnerAddressResponse>,
+    pub contacts: Vec<PartnerContactResponse>,
+}
+
+impl BusinessPartnerDetailResponse {
+    pub fn new(
+        partner: BusinessPartner,
+        addresses: Vec<PartnerAddress>,
+        contacts: Vec<PartnerContact>,
+    ) -> Self {
+        BusinessPartnerDetailResponse {
+            partner: partner.into(),
+            addresses: addresses.into_iter().map(Into::into).collect(),
+            contacts: contacts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::domain::{
+    CrossDock, CrossDockData, CrossDockOpportunity, CrossDockQuery, DemandStatus, DocumentType,
+    OutboundDemand, OutboundDemandData, OutboundDemandQuery,
+};
+use crate::dto::{validate_positive, validate_serial_numbers};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateOutboundDemandRequest {
+    pub warehouse_id: Uuid,
+
+    #[serde(default = "default_document_type")]
+    pub document_type: DocumentType,
+
+    pub document_id: Uuid,
+
+    pub product_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+fn default_document_type() -> DocumentType {
+    DocumentType::SalesOrder
+}
+
+impl From<CreateOutboundDemandRequest> for OutboundDemandData {
+    fn from(val: CreateOutboundDemandRequest) -> Self {
+        let CreateOutboundDemandRequest {
+            warehouse_id,
+            document_type,
+            document_id,
+            product_id,
+            quantity,
+            uom,
+            due_at,
+        } = val;
+
+        OutboundDemandData {
+            warehouse_id,
+            document_type,
+            document_id,
+            product_id,
+            quantity,
+            uom,
+            due_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct OutboundDemandsParams {
+    pub warehouse_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub status: Option<DemandStatus>,
+}
+
+impl From<OutboundDemandsParams> for OutboundDemandQuery {
+    fn from(val: OutboundDemandsParams) -> Self {
+        let OutboundDemandsParams {
+            warehouse_id,
+            product_id,
+            document_id,
+            status,
+        } = val;
+
+        OutboundDemandQuery {
+            warehouse_id,
+            product_id,
+            document_id,
+            status,
+        }
+    }
+}
+
+/// Quantities are in the product's base UoM. `open_quantity` is what is left to allocate.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct OutboundDemandResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+    pub allocated_quantity: Decimal,
+    pub open_quantity: Decimal,
+    pub due_at: Option<DateTime<Utc>>,
+    pub status: DemandStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<OutboundDemand> for OutboundDemandResponse {
+    fn from(val: OutboundDemand) -> Self {
+        let open_quantity = val.open_quantity();
+        let OutboundDemand {
+            id,
+            warehouse_id,
+            document_type,
+            document_id,
+            product_id,
+            quantity,
+            allocated_quantity,
+            due_at,
+            status,
+            created_by,
+            created_at,
+        } = val;
+
+        OutboundDemandResponse {
+            id,
+            warehouse_id,
+            document_type,
+            document_id,
+            product_id,
+            quantity,
+            allocated_quantity,
+            open_quantity,
+            due_at,
+            status,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct CrossDockOpportunitiesParams {
+    /// Location the stock was received into.
+    pub location_id: Uuid,
+    pub product_id: Option<Uuid>,
+}
+
+/// `quantity` of the stock available at `from_location_id` can go to the demand, most
+/// urgent demands first.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CrossDockOpportunityResponse {
+    pub demand_id: Uuid,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub open_quantity: Decimal,
+    pub quantity: Decimal,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+impl From<CrossDockOpportunity> for CrossDockOpportunityResponse {
+    fn from(val: CrossDockOpportunity) -> Self {
+        let CrossDockOpportunity {
+            demand,
+            from_location_id,
+            quantity,
+        } = val;
+
+        CrossDockOpportunityResponse {
+            demand_id: demand.id,
+            document_type: demand.document_type,
+            document_id: demand.document_id,
+            product_id: demand.product_id,
+            from_location_id,
+            open_quantity: demand.open_quantity(),
+            quantity,
+            due_at: demand.due_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[validate(schema(function = "validate_inbound_document"))]
+pub struct CreateCrossDockRequest {
+    pub demand_id: Uuid,
+
+    /// Receiving location the stock is taken from.
+    pub from_location_id: Uuid,
+
+    pub staging_location_id: Uuid,
+
+    /// Defaults to the quantity open on the demand, up to what is available.
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Option<Decimal>,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// Document the stock was received against, recorded on the cross-dock.
+    pub inbound_document_type: Option<DocumentType>,
+
+    pub inbound_document_id: Option<Uuid>,
+
+    /// Units moved, required for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+fn validate_inbound_document(req: &CreateCrossDockRequest) -> Result<(), ValidationError> {
+    if req.inbound_document_type.is_some() != req.inbound_document_id.is_some() {
+        return Err(ValidationError::new("inbound_document"));
+    }
+
+    Ok(())
+}
+
+impl From<CreateCrossDockRequest> for CrossDockData {
+    fn from(val: CreateCrossDockRequest) -> Self {
+        let CreateCrossDockRequest {
+            demand_id,
+            from_location_id,
+            staging_location_id,
+            quantity,
+            uom,
+            inbound_document_type,
+            inbound_document_id,
+            serial_numbers,
+        } = val;
+
+        CrossDockData {
+            demand_id,
+            from_location_id,
+            staging_location_id,
+            quantity,
+            uom,
+            inbound_document_type,
+            inbound_document_id,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct CrossDocksParams {
+    pub warehouse_id: Option<Uuid>,
+    pub demand_id: Option<Uuid>,
+    /// Matches the inbound document as well as the document of the demand.
+    pub document_id: Option<Uuid>,
+}
+
+impl From<CrossDocksParams> for CrossDockQuery {
+    fn from(val: CrossDocksParams) -> Self {
+        let CrossDocksParams {
+            warehouse_id,
+            demand_id,
+            document_id,
+        } = val;
+
+        CrossDockQuery {
+            warehouse_id,
+            demand_id,
+            document_id,
+        }
+    }
+}
+
+/// The stock is reserved at the staging location by `reservation_id`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CrossDockResponse {
+    pub id: Uuid,
+    pub demand_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub staging_location_id: Uuid,
+    pub quantity: Decimal,
+    pub inbound_document_type: Option<DocumentType>,
+    pub inbound_document_id: Option<Uuid>,
+    pub reservation_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CrossDock> for CrossDockResponse {
+    fn from(val: CrossDock) -> Self {
+        let CrossDock {
+            id,
+            demand_id,
+            warehouse_id,
+            product_id,
+            from_location_id,
+            staging_location_id,
+            quantity,
+            inbound_document_type,
+            inbound_document_id,
+            reservation_id,
+            created_by,
+            created_at,
+        } = val;
+
+        CrossDockResponse {
+            id,
+            demand_id,
+            warehouse_id,
+            product_id,
+            from_location_id,
+            staging_location_id,
+            quantity,
+            inbound_document_type,
+            inbound_document_id,
+            reservation_id,
+            created_by,
+            created_at,
+        }
+    }
+}
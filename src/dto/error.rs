@@ -1,11 +1,24 @@
-use crate::contract::error::ErrorCode;
+use crate::contract::error::{ErrorCode, error_detail};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use tracing_log::log;
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// Prefix of the `type` URI of problem details, followed by the kebab-case error code.
+pub const PROBLEM_TYPE_PREFIX: &str = "urn:warehouse:problem:";
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
 pub struct AppError {
     pub code: ErrorCode,
     pub message: String,
+
+    /// What went wrong in this case, only given for domain errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// Messages of the fields failing validation by path, e.g. `addresses[0].country`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<String>>,
 }
 
 impl<E> From<E> for AppError
@@ -15,23 +28,74 @@ where
     fn from(err: E) -> Self {
         log::error!("{:?}", err);
 
-        Self::from(ErrorCode::from(err.into().chain()))
+        let err = err.into();
+        let errors = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ValidationErrors>())
+            .map(field_errors)
+            .unwrap_or_default();
+        let detail = match errors.is_empty() {
+            true => error_detail(err.chain()),
+            false => Some(format!(
+                "Invalid {}",
+                errors.keys().cloned().collect::<Vec<_>>().join(", ")
+            )),
+        };
+
+        Self {
+            detail,
+            errors,
+            ..Self::from(ErrorCode::from(err.chain()))
+        }
     }
 }
 
 impl From<ErrorCode> for AppError {
     fn from(code: ErrorCode) -> Self {
         Self {
-            message: match code {
-                ErrorCode::Ok => "",
-                ErrorCode::UnexpectedError => "Unknow error",
-                ErrorCode::ValidationFailed => "Invalid arguments",
-                ErrorCode::AuthenticationFailed => "Invalid login or password",
-                ErrorCode::ObjectNotFound => "Requested object not found",
-                ErrorCode::ObjectAlreadyExists => "Provided object already exist",
-            }
-            .to_string(),
+            message: code.title().to_string(),
+            code,
+            detail: None,
+            errors: BTreeMap::new(),
+        }
+    }
+}
+
+/// RFC 7807 problem details of an [`AppError`], sent as `application/problem+json` to
+/// clients asking for it in `Accept`. `code` is the [`ErrorCode`] of the plain response.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub code: ErrorCode,
+    /// Id of the request in the server logs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<String>>,
+}
+
+impl ProblemDetails {
+    pub fn new(error: AppError, trace_id: Option<String>) -> Self {
+        let AppError {
             code,
+            message,
+            detail,
+            errors,
+        } = error;
+
+        Self {
+            problem_type: format!("{PROBLEM_TYPE_PREFIX}{}", code.slug()),
+            title: message,
+            status: code.status_code().as_u16(),
+            detail,
+            code,
+            trace_id,
+            errors,
         }
     }
 }
@@ -45,6 +109,93 @@ impl ErrorCode {
             ErrorCode::AuthenticationFailed => http::StatusCode::UNAUTHORIZED,
             ErrorCode::ObjectNotFound => http::StatusCode::NOT_FOUND,
             ErrorCode::ObjectAlreadyExists => http::StatusCode::CONFLICT,
+            ErrorCode::PermissionDenied => http::StatusCode::FORBIDDEN,
+            ErrorCode::InsufficientStock => http::StatusCode::CONFLICT,
+            ErrorCode::InvalidState => http::StatusCode::CONFLICT,
         }
     }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            ErrorCode::Ok => "",
+            ErrorCode::UnexpectedError => "Unknow error",
+            ErrorCode::ValidationFailed => "Invalid arguments",
+            ErrorCode::AuthenticationFailed => "Invalid login or password",
+            ErrorCode::ObjectNotFound => "Requested object not found",
+            ErrorCode::ObjectAlreadyExists => "Provided object already exist",
+            ErrorCode::PermissionDenied => "Permission denied",
+            ErrorCode::InsufficientStock => "Insufficient stock",
+            ErrorCode::InvalidState => "Operation is not allowed in the current state",
+        }
+    }
+
+    /// Name of the code in problem type URIs.
+    pub fn slug(&self) -> &'static str {
+        match self {
+            ErrorCode::Ok => "ok",
+            ErrorCode::UnexpectedError => "unexpected-error",
+            ErrorCode::ValidationFailed => "validation-failed",
+            ErrorCode::AuthenticationFailed => "authentication-failed",
+            ErrorCode::ObjectNotFound => "object-not-found",
+            ErrorCode::ObjectAlreadyExists => "object-already-exists",
+            ErrorCode::PermissionDenied => "permission-denied",
+            ErrorCode::InsufficientStock => "insufficient-stock",
+            ErrorCode::InvalidState => "invalid-state",
+        }
+    }
+}
+
+/// Messages of the failed validations by field path, with nested structs and list items
+/// written as `contacts[1].email`.
+pub fn field_errors(errors: &ValidationErrors) -> BTreeMap<String, Vec<String>> {
+    let mut messages = BTreeMap::new();
+    collect_field_errors(errors, "", &mut messages);
+    messages
+}
+
+fn collect_field_errors(
+    errors: &ValidationErrors,
+    prefix: &str,
+    messages: &mut BTreeMap<String, Vec<String>>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = match prefix {
+            "" => field.to_string(),
+            prefix => format!("{prefix}.{field}"),
+        };
+        match kind {
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(errors, &path, messages),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_field_errors(errors, &format!("{path}[{index}]"), messages);
+                }
+            }
+            ValidationErrorsKind::Field(errors) => messages
+                .entry(path)
+                .or_default()
+                .extend(errors.iter().map(describe)),
+        }
+    }
+}
+
+fn describe(error: &ValidationError) -> String {
+    if let Some(message) = &error.message {
+        return message.to_string();
+    }
+
+    let min = error.params.get("min");
+    let max = error.params.get("max");
+    match (&*error.code, min, max) {
+        ("length", Some(min), Some(max)) => format!("must have a length between {min} and {max}"),
+        ("length", Some(min), None) => format!("must have a length of at least {min}"),
+        ("length", None, Some(max)) => format!("must have a length of at most {max}"),
+        ("range", Some(min), Some(max)) => format!("must be between {min} and {max}"),
+        ("range", Some(min), None) => format!("must be at least {min}"),
+        ("range", None, Some(max)) => format!("must be at most {max}"),
+        ("email", ..) => "must be an email address".to_string(),
+        ("required", ..) => "is required".to_string(),
+        ("positive", ..) => "must be positive".to_string(),
+        ("non_negative", ..) => "must not be negative".to_string(),
+        (code, ..) => format!("is invalid ({code})"),
+    }
 }
@@ -0,0 +1,275 @@
+use crate::domain::{
+    AssemblyKind, AssemblyOrder, AssemblyOrderData, AssemblyOrderLine, AssemblyOrderStatus,
+    BomComponent, BomComponentData, ComponentAvailability, KitAvailability,
+};
+use crate::dto::{StockMovementResponse, validate_positive};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SetBomRequest {
+    #[validate(length(min = 1), nested)]
+    pub components: Vec<BomComponentRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BomComponentRequest {
+    pub component_product_id: Uuid,
+
+    /// Quantity of the component that goes into one kit.
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the component's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+}
+
+impl From<BomComponentRequest> for BomComponentData {
+    fn from(val: BomComponentRequest) -> Self {
+        let BomComponentRequest {
+            component_product_id,
+            quantity,
+            uom,
+        } = val;
+
+        BomComponentData {
+            component_product_id,
+            quantity,
+            uom,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BomComponentResponse {
+    pub component_product_id: Uuid,
+    /// Base units that go into one kit.
+    pub quantity: Decimal,
+}
+
+impl From<BomComponent> for BomComponentResponse {
+    fn from(val: BomComponent) -> Self {
+        let BomComponent {
+            kit_product_id: _,
+            component_product_id,
+            quantity,
+        } = val;
+
+        BomComponentResponse {
+            component_product_id,
+            quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BomResponse {
+    pub kit_product_id: Uuid,
+    pub components: Vec<BomComponentResponse>,
+}
+
+impl BomResponse {
+    pub fn new(kit_product_id: Uuid, components: Vec<BomComponent>) -> Self {
+        BomResponse {
+            kit_product_id,
+            components: components.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct KitAvailabilityParams {
+    pub warehouse_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ComponentAvailabilityResponse {
+    pub component_product_id: Uuid,
+    pub quantity: Decimal,
+    pub available: Decimal,
+    pub buildable: Decimal,
+}
+
+impl From<ComponentAvailability> for ComponentAvailabilityResponse {
+    fn from(val: ComponentAvailability) -> Self {
+        let ComponentAvailability {
+            component_product_id,
+            quantity,
+            available,
+            buildable,
+        } = val;
+
+        ComponentAvailabilityResponse {
+            component_product_id,
+            quantity,
+            available,
+            buildable,
+        }
+    }
+}
+
+/// `buildable` is limited by the scarcest component.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct KitAvailabilityResponse {
+    pub kit_product_id: Uuid,
+    pub warehouse_id: Option<Uuid>,
+    pub buildable: Decimal,
+    pub components: Vec<ComponentAvailabilityResponse>,
+}
+
+impl From<KitAvailability> for KitAvailabilityResponse {
+    fn from(val: KitAvailability) -> Self {
+        let KitAvailability {
+            kit_product_id,
+            warehouse_id,
+            buildable,
+            components,
+        } = val;
+
+        KitAvailabilityResponse {
+            kit_product_id,
+            warehouse_id,
+            buildable,
+            components: components.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateAssemblyOrderRequest {
+    pub kind: AssemblyKind,
+
+    pub kit_product_id: Uuid,
+
+    /// Location the components, or the kits to disassemble, are picked from.
+    pub from_location_id: Uuid,
+
+    /// Location the assembled kits, or the recovered components, are put away to.
+    pub to_location_id: Uuid,
+
+    /// Number of kits to assemble or disassemble.
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the kit's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+}
+
+impl From<CreateAssemblyOrderRequest> for AssemblyOrderData {
+    fn from(val: CreateAssemblyOrderRequest) -> Self {
+        let CreateAssemblyOrderRequest {
+            kind,
+            kit_product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            uom,
+        } = val;
+
+        AssemblyOrderData {
+            kind,
+            kit_product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            uom,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AssemblyOrderLineResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub quantity: Decimal,
+}
+
+impl From<AssemblyOrderLine> for AssemblyOrderLineResponse {
+    fn from(val: AssemblyOrderLine) -> Self {
+        let AssemblyOrderLine {
+            id,
+            assembly_order_id: _,
+            product_id,
+            quantity,
+        } = val;
+
+        AssemblyOrderLineResponse {
+            id,
+            product_id,
+            quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AssemblyOrderResponse {
+    pub id: Uuid,
+    pub kind: AssemblyKind,
+    pub kit_product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub quantity: Decimal,
+    pub status: AssemblyOrderStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Components consumed by assembly or recovered by disassembly.
+    pub lines: Vec<AssemblyOrderLineResponse>,
+}
+
+impl AssemblyOrderResponse {
+    pub fn new(order: AssemblyOrder, lines: Vec<AssemblyOrderLine>) -> Self {
+        let AssemblyOrder {
+            id,
+            kind,
+            kit_product_id,
+            warehouse_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            status,
+            created_by,
+            created_at,
+            completed_at,
+        } = order;
+
+        AssemblyOrderResponse {
+            id,
+            kind,
+            kit_product_id,
+            warehouse_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            status,
+            created_by,
+            created_at,
+            completed_at,
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CompleteAssemblyOrderResponse {
+    pub order: AssemblyOrderResponse,
+    pub movements: Vec<StockMovementResponse>,
+}
@@ -0,0 +1,11 @@
+use crate::domain::LabelFormat;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct LabelParams {
+    /// Output format, SVG when omitted.
+    #[serde(default)]
+    pub format: LabelFormat,
+}
@@ -0,0 +1,201 @@
+use crate::domain::{
+    FilterOp, ListError, ListFilter, ListQuery, ListSort, Page, Sort, SortDirection,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Items returned when a list request gives no `limit`.
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Most items a list request can ask for.
+pub const MAX_PAGE_SIZE: i64 = 200;
+
+/// Query parameters every list endpoint takes. They are parsed from the raw query string,
+/// since filters are nested parameters such as `filter[pick_sequence][gt]=0`.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ListParams {
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<Uuid>,
+
+    /// Items per page, 50 by default.
+    #[validate(range(min = 1, max = MAX_PAGE_SIZE))]
+    pub limit: Option<i64>,
+
+    /// Field to sort by, descending when prefixed with `-`, e.g. `-name`.
+    pub sort: Option<String>,
+
+    /// Comma-separated fields to return of every item, e.g. `id,sku`. All by default.
+    pub fields: Option<String>,
+
+    /// Filters on fields of the items as `filter[field]=value`, or
+    /// `filter[field][op]=value` with op one of `eq`, `ne`, `gt`, `gte`, `lt` and `lte`.
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "ssr",
+        param(style = DeepObject, explode, value_type = Option<Object>)
+    )]
+    pub filter: Vec<FilterParam>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FilterParam {
+    pub field: String,
+    pub op: Option<String>,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PageResponse<T> {
+    pub items: Vec<T>,
+
+    /// Cursor of the next page, absent on the last page.
+    pub next_cursor: Option<Uuid>,
+}
+
+impl ListParams {
+    /// Parses the list parameters of a query string, failing on any other parameter so that
+    /// a misspelt or unsupported filter is not silently left out.
+    pub fn from_query(query: &str) -> Result<Self, ListError> {
+        let mut params = ListParams::default();
+
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "cursor" => {
+                    params.cursor = Some(
+                        value
+                            .parse()
+                            .map_err(|_| ListError::InvalidParameter("cursor".to_string()))?,
+                    );
+                }
+                "limit" => {
+                    params.limit = Some(
+                        value
+                            .parse()
+                            .map_err(|_| ListError::InvalidParameter("limit".to_string()))?,
+                    );
+                }
+                "sort" => params.sort = Some(value.into_owned()),
+                "fields" => params.fields = Some(value.into_owned()),
+                key if key.starts_with("filter[") => {
+                    params
+                        .filter
+                        .push(parse_filter(&key["filter".len()..], value.into_owned())?);
+                }
+                key => return Err(ListError::UnknownParameter(key.to_string())),
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// The typed query of the list, failing on filters and sort fields the items lack.
+    pub fn query<F: ListFilter, S: ListSort>(&self) -> Result<ListQuery<F, S>, ListError> {
+        let filters = self
+            .filter
+            .iter()
+            .map(|filter| {
+                let op = match &filter.op {
+                    Some(op) => op
+                        .parse()
+                        .map_err(|_| ListError::InvalidFilter(filter.field.clone()))?,
+                    None => FilterOp::Eq,
+                };
+                F::parse(&filter.field, op, &filter.value)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let sort = match self.sort.as_deref() {
+            Some(sort) => {
+                let (field, direction) = match sort.strip_prefix('-') {
+                    Some(field) => (field, SortDirection::Desc),
+                    None => (sort, SortDirection::Asc),
+                };
+                Sort {
+                    field: S::parse(field)
+                        .ok_or_else(|| ListError::UnknownSort(field.to_string()))?,
+                    direction,
+                }
+            }
+            None => Sort::default(),
+        };
+
+        Ok(ListQuery {
+            filters,
+            sort,
+            after: self.cursor,
+            limit: self
+                .limit
+                .unwrap_or(DEFAULT_PAGE_SIZE)
+                .clamp(1, MAX_PAGE_SIZE),
+        })
+    }
+
+    /// The response of the page, with items converted to `R` and reduced to the requested
+    /// `fields`.
+    pub fn page<T, R>(&self, page: Page<T>) -> anyhow::Result<PageResponse<Value>>
+    where
+        R: From<T> + Serialize,
+    {
+        let fields: Option<Vec<&str>> = self
+            .fields
+            .as_deref()
+            .map(|fields| fields.split(',').map(str::trim).collect());
+
+        let items = page
+            .items
+            .into_iter()
+            .map(|item| -> anyhow::Result<Value> {
+                let item = serde_json::to_value(R::from(item))?;
+                let item = match (&fields, item) {
+                    (Some(fields), Value::Object(mut item)) => fields
+                        .iter()
+                        .map(|field| {
+                            item.remove(*field)
+                                .map(|value| (field.to_string(), value))
+                                .ok_or_else(|| ListError::UnknownField(field.to_string()))
+                        })
+                        .collect::<Result<Map<_, _>, _>>()
+                        .map(Value::Object)?,
+                    (_, item) => item,
+                };
+                Ok(item)
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(PageResponse {
+            items,
+            next_cursor: page.next_cursor,
+        })
+    }
+}
+
+/// Parses the `[field]` or `[field][op]` following `filter` in a parameter name.
+fn parse_filter(key: &str, value: String) -> Result<FilterParam, ListError> {
+    let invalid = || ListError::InvalidParameter(format!("filter{key}"));
+
+    let rest = key.strip_prefix('[').ok_or_else(invalid)?;
+    let (field, rest) = rest.split_once(']').ok_or_else(invalid)?;
+    let op = match rest {
+        "" => None,
+        rest => Some(
+            rest.strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+                .ok_or_else(invalid)?
+                .to_string(),
+        ),
+    };
+    if field.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(FilterParam {
+        field: field.to_string(),
+        op,
+        value,
+    })
+}
@@ -0,0 +1,90 @@
+use crate::domain::{ExpiringStock, Lot};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct LotResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub lot_number: Option<String>,
+    pub expiry_date: Option<NaiveDate>,
+    pub blocked: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Lot> for LotResponse {
+    fn from(val: Lot) -> Self {
+        let Lot {
+            id,
+            product_id,
+            lot_number,
+            expiry_date,
+            blocked,
+            created_at,
+        } = val;
+
+        LotResponse {
+            id,
+            product_id,
+            lot_number,
+            expiry_date,
+            blocked,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ExpiringStockParams {
+    /// Look-ahead window in days; lots that already expired are always included.
+    pub days: u32,
+    pub product_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ExpiringStockResponse {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Uuid,
+    pub lot_number: Option<String>,
+    pub expiry_date: NaiveDate,
+    pub blocked: bool,
+    pub on_hand: Decimal,
+}
+
+impl From<ExpiringStock> for ExpiringStockResponse {
+    fn from(val: ExpiringStock) -> Self {
+        let ExpiringStock {
+            product_id,
+            location_id,
+            lot_id,
+            lot_number,
+            expiry_date,
+            blocked,
+            on_hand,
+        } = val;
+
+        ExpiringStockResponse {
+            product_id,
+            location_id,
+            lot_id,
+            lot_number,
+            expiry_date,
+            blocked,
+            on_hand,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct BlockExpiredLotsResponse {
+    pub blocked: usize,
+}
@@ -0,0 +1,81 @@
+use crate::domain::{Organization, OrganizationData, OrganizationMember};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateOrganizationRequest {
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+}
+
+impl From<CreateOrganizationRequest> for OrganizationData {
+    fn from(val: CreateOrganizationRequest) -> Self {
+        let CreateOrganizationRequest { name } = val;
+
+        OrganizationData { name }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct OrganizationResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Organization> for OrganizationResponse {
+    fn from(val: Organization) -> Self {
+        let Organization {
+            id,
+            name,
+            created_at,
+        } = val;
+
+        OrganizationResponse {
+            id,
+            name,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AddMemberRequest {
+    /// Email of a signed-up user.
+    #[validate(email, length(min = 3, max = 256))]
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct OrganizationMemberResponse {
+    pub user_id: Uuid,
+    pub email: String,
+    pub organization_id: Uuid,
+    pub added_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OrganizationMemberResponse {
+    pub fn new(member: OrganizationMember, email: String) -> Self {
+        let OrganizationMember {
+            user_id,
+            organization_id,
+            added_by,
+            created_at,
+        } = member;
+
+        OrganizationMemberResponse {
+            user_id,
+            email,
+            organization_id,
+            added_by,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::domain::{Pallet, PalletData};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreatePalletRequest {
+    pub location_id: Option<Uuid>,
+}
+
+impl From<CreatePalletRequest> for PalletData {
+    fn from(val: CreatePalletRequest) -> Self {
+        let CreatePalletRequest { location_id } = val;
+
+        PalletData { location_id }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PalletResponse {
+    pub id: Uuid,
+    pub sscc: String,
+    pub location_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Pallet> for PalletResponse {
+    fn from(val: Pallet) -> Self {
+        let Pallet {
+            id,
+            sscc,
+            location_id,
+            created_at,
+        } = val;
+
+        PalletResponse {
+            id,
+            sscc,
+            location_id,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::domain::{
+    AbcClass, AttributeError, CostingMethod, Product, ProductData, ProductQuery, ProductUom,
+    ProductUomData,
+};
+use crate::dto::{attribute_filter, validate_non_negative, validate_positive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateProductRequest {
+    #[validate(length(min = 1, max = 64))]
+    pub sku: String,
+
+    #[validate(length(min = 1, max = 256))]
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub abc_class: Option<AbcClass>,
+
+    /// Receipts must carry a lot number.
+    #[serde(default)]
+    pub lot_tracked: bool,
+
+    /// Receipts must carry an expiry date.
+    #[serde(default)]
+    pub expiry_tracked: bool,
+
+    /// Every unit moved must be identified by its serial number.
+    #[serde(default)]
+    pub serial_tracked: bool,
+
+    /// Unit stock is kept in, e.g. `ea` or `kg`.
+    #[serde(default = "default_base_uom")]
+    #[validate(length(min = 1, max = 16))]
+    pub base_uom: String,
+
+    /// Allows quantities in the base UoM to have a fractional part.
+    #[serde(default)]
+    pub fractional_quantities: bool,
+
+    /// Values the product differently from the company costing method.
+    pub costing_method: Option<CostingMethod>,
+
+    /// Groups products for putaway rules, e.g. `frozen` or `hazmat`.
+    #[validate(length(min = 1, max = 64))]
+    pub category: Option<String>,
+
+    /// Cubic metres per base unit.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub unit_volume: Option<Decimal>,
+
+    /// Kilograms per base unit.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub unit_weight: Option<Decimal>,
+
+    /// Values of the custom attributes defined for products, by key.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", schema(value_type = Object))]
+    pub attributes: Map<String, Value>,
+}
+
+fn default_base_uom() -> String {
+    "ea".to_string()
+}
+
+impl From<CreateProductRequest> for ProductData {
+    fn from(val: CreateProductRequest) -> Self {
+        let CreateProductRequest {
+            sku,
+            name,
+            description,
+            abc_class,
+            lot_tracked,
+            expiry_tracked,
+            serial_tracked,
+            base_uom,
+            fractional_quantities,
+            costing_method,
+            category,
+            unit_volume,
+            unit_weight,
+            attributes,
+        } = val;
+
+        ProductData {
+            sku,
+            name,
+            description,
+            abc_class,
+            lot_tracked,
+            expiry_tracked,
+            serial_tracked,
+            base_uom,
+            fractional_quantities,
+            costing_method,
+            category,
+            unit_volume,
+            unit_weight,
+            attributes,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ProductsParams {
+    pub category: Option<String>,
+    /// JSON object of custom attribute values the products must have, e.g.
+    /// `{"brand":"Acme"}`.
+    pub attributes: Option<String>,
+}
+
+impl TryFrom<ProductsParams> for ProductQuery {
+    type Error = AttributeError;
+
+    fn try_from(val: ProductsParams) -> Result<Self, Self::Error> {
+        let ProductsParams {
+            category,
+            attributes,
+        } = val;
+
+        Ok(ProductQuery {
+            category,
+            attributes: attribute_filter(attributes)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ProductResponse {
+    pub id: Uuid,
+    pub sku: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub lot_tracked: bool,
+    pub expiry_tracked: bool,
+    pub serial_tracked: bool,
+    pub base_uom: String,
+    pub fractional_quantities: bool,
+    pub costing_method: Option<CostingMethod>,
+    pub category: Option<String>,
+    pub unit_volume: Option<Decimal>,
+    pub unit_weight: Option<Decimal>,
+    #[cfg_attr(feature = "ssr", schema(value_type = Object))]
+    pub attributes: Value,
+}
+
+impl From<Product> for ProductResponse {
+    fn from(val: Product) -> Self {
+        let Product {
+            id,
+            sku,
+            name,
+            description,
+            abc_class,
+            lot_tracked,
+            expiry_tracked,
+            serial_tracked,
+            base_uom,
+            fractional_quantities,
+            costing_method,
+            category,
+            unit_volume,
+            unit_weight,
+            attributes,
+        } = val;
+
+        ProductResponse {
+            id,
+            sku,
+            name,
+            description,
+            abc_class,
+            lot_tracked,
+            expiry_tracked,
+            serial_tracked,
+            base_uom,
+            fractional_quantities,
+            costing_method,
+            category,
+            unit_volume,
+            unit_weight,
+            attributes,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateProductUomRequest {
+    #[validate(length(min = 1, max = 16))]
+    pub code: String,
+
+    /// Number of base units in one of this unit.
+    #[validate(custom(function = "validate_positive"))]
+    pub factor: Decimal,
+
+    #[validate(length(min = 1, max = 64))]
+    pub barcode: Option<String>,
+}
+
+impl From<CreateProductUomRequest> for ProductUomData {
+    fn from(val: CreateProductUomRequest) -> Self {
+        let CreateProductUomRequest {
+            code,
+            factor,
+            barcode,
+        } = val;
+
+        ProductUomData {
+            code,
+            factor,
+            barcode,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ProductUomResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub code: String,
+    pub factor: Decimal,
+    pub barcode: Option<String>,
+}
+
+impl From<ProductUom> for ProductUomResponse {
+    fn from(val: ProductUom) -> Self {
+        let ProductUom {
+            id,
+            product_id,
+            code,
+            factor,
+            barcode,
+        } = val;
+
+        ProductUomResponse {
+            id,
+            product_id,
+            code,
+            factor,
+            barcode,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::domain::{
+    PutawayConfirmation, PutawayRule, PutawayRuleData, PutawayStrategy, PutawayTask,
+    PutawayTaskQuery, PutawayTaskStatus,
+};
+use crate::dto::{StockMovementResponse, validate_serial_numbers};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SetPutawayRulesRequest {
+    /// Tried in order until one finds a location.
+    #[validate(nested)]
+    pub rules: Vec<PutawayRuleRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PutawayRuleRequest {
+    pub strategy: PutawayStrategy,
+
+    /// Limits the rule to one product.
+    pub product_id: Option<Uuid>,
+
+    /// Limits the rule to products of the category. Required by `category_zone`.
+    #[validate(length(min = 1, max = 64))]
+    pub category: Option<String>,
+
+    /// Home location of `fixed_location` rules.
+    pub location_id: Option<Uuid>,
+
+    /// Limits the locations the rule proposes to the zone. Required by `category_zone`.
+    #[validate(length(min = 1, max = 32))]
+    pub zone: Option<String>,
+}
+
+impl From<PutawayRuleRequest> for PutawayRuleData {
+    fn from(val: PutawayRuleRequest) -> Self {
+        let PutawayRuleRequest {
+            strategy,
+            product_id,
+            category,
+            location_id,
+            zone,
+        } = val;
+
+        PutawayRuleData {
+            strategy,
+            product_id,
+            category,
+            location_id,
+            zone,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PutawayRuleResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub sequence: i32,
+    pub strategy: PutawayStrategy,
+    pub product_id: Option<Uuid>,
+    pub category: Option<String>,
+    pub location_id: Option<Uuid>,
+    pub zone: Option<String>,
+}
+
+impl From<PutawayRule> for PutawayRuleResponse {
+    fn from(val: PutawayRule) -> Self {
+        let PutawayRule {
+            id,
+            warehouse_id,
+            sequence,
+            strategy,
+            product_id,
+            category,
+            location_id,
+            zone,
+        } = val;
+
+        PutawayRuleResponse {
+            id,
+            warehouse_id,
+            sequence,
+            strategy,
+            product_id,
+            category,
+            location_id,
+            zone,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct GeneratePutawayTasksRequest {
+    /// Receiving location holding the stock to put away.
+    pub location_id: Uuid,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct PutawayTasksParams {
+    pub warehouse_id: Option<Uuid>,
+    pub from_location_id: Option<Uuid>,
+    pub status: Option<PutawayTaskStatus>,
+}
+
+impl From<PutawayTasksParams> for PutawayTaskQuery {
+    fn from(val: PutawayTasksParams) -> Self {
+        let PutawayTasksParams {
+            warehouse_id,
+            from_location_id,
+            status,
+        } = val;
+
+        PutawayTaskQuery {
+            warehouse_id,
+            from_location_id,
+            status,
+        }
+    }
+}
+
+/// `suggested_location_id` is `null` when no putaway rule found a location.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PutawayTaskResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+    pub suggested_location_id: Option<Uuid>,
+    pub strategy: Option<PutawayStrategy>,
+    pub to_location_id: Option<Uuid>,
+    pub status: PutawayTaskStatus,
+    pub movement_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_by: Option<Uuid>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<PutawayTask> for PutawayTaskResponse {
+    fn from(val: PutawayTask) -> Self {
+        let PutawayTask {
+            id,
+            warehouse_id,
+            product_id,
+            from_location_id,
+            quantity,
+            suggested_location_id,
+            strategy,
+            to_location_id,
+            status,
+            movement_id,
+            created_by,
+            created_at,
+            completed_by,
+            completed_at,
+        } = val;
+
+        PutawayTaskResponse {
+            id,
+            warehouse_id,
+            product_id,
+            from_location_id,
+            quantity,
+            suggested_location_id,
+            strategy,
+            to_location_id,
+            status,
+            movement_id,
+            created_by,
+            created_at,
+            completed_by,
+            completed_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ConfirmPutawayRequest {
+    /// Code of the location scanned at the destination.
+    #[validate(length(min = 1, max = 64))]
+    pub location_code: String,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<ConfirmPutawayRequest> for PutawayConfirmation {
+    fn from(val: ConfirmPutawayRequest) -> Self {
+        let ConfirmPutawayRequest {
+            location_code,
+            serial_numbers,
+        } = val;
+
+        PutawayConfirmation {
+            location_code,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ConfirmPutawayResponse {
+    pub task: PutawayTaskResponse,
+    pub movements: Vec<StockMovementResponse>,
+}
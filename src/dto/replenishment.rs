@@ -0,0 +1,240 @@
+use crate::domain::{
+    DocumentType, ReplenishmentKind, ReplenishmentRule, ReplenishmentRuleData,
+    ReplenishmentRuleQuery, ReplenishmentSuggestion, ReplenishmentSuggestionQuery,
+    SuggestionStatus,
+};
+use crate::dto::{validate_non_negative, validate_positive};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+/// Either `max_quantity` (min/max) or `reorder_quantity` (fixed order quantity) must be given.
+/// With both, the fixed quantity wins. Quantities are in `uom` and stored in base units.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[validate(schema(function = "validate_replenishment_policy"))]
+pub struct SaveReplenishmentRuleRequest {
+    pub product_id: Uuid,
+
+    pub warehouse_id: Uuid,
+
+    /// Pick face to keep stocked from the rest of the warehouse. Without it the rule
+    /// restocks the warehouse through purchasing.
+    pub location_id: Option<Uuid>,
+
+    #[validate(custom(function = "validate_non_negative"))]
+    pub reorder_point: Decimal,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub max_quantity: Option<Decimal>,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub reorder_quantity: Option<Decimal>,
+
+    /// Unit of measure the quantities are given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+}
+
+fn validate_replenishment_policy(
+    req: &SaveReplenishmentRuleRequest,
+) -> Result<(), ValidationError> {
+    let valid = match (req.max_quantity, req.reorder_quantity) {
+        (Some(max_quantity), _) => max_quantity > req.reorder_point,
+        (None, Some(_)) => true,
+        (None, None) => false,
+    };
+
+    if !valid {
+        return Err(ValidationError::new("replenishment_policy"));
+    }
+
+    Ok(())
+}
+
+impl From<SaveReplenishmentRuleRequest> for ReplenishmentRuleData {
+    fn from(val: SaveReplenishmentRuleRequest) -> Self {
+        let SaveReplenishmentRuleRequest {
+            product_id,
+            warehouse_id,
+            location_id,
+            reorder_point,
+            max_quantity,
+            reorder_quantity,
+            uom,
+        } = val;
+
+        ReplenishmentRuleData {
+            product_id,
+            warehouse_id,
+            location_id,
+            reorder_point,
+            max_quantity,
+            reorder_quantity,
+            uom,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReplenishmentRuleResponse {
+    pub id: Uuid,
+    pub kind: ReplenishmentKind,
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub reorder_point: Decimal,
+    pub max_quantity: Option<Decimal>,
+    pub reorder_quantity: Option<Decimal>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ReplenishmentRule> for ReplenishmentRuleResponse {
+    fn from(val: ReplenishmentRule) -> Self {
+        let kind = val.kind();
+        let ReplenishmentRule {
+            id,
+            product_id,
+            warehouse_id,
+            location_id,
+            reorder_point,
+            max_quantity,
+            reorder_quantity,
+            updated_at,
+        } = val;
+
+        ReplenishmentRuleResponse {
+            id,
+            kind,
+            product_id,
+            warehouse_id,
+            location_id,
+            reorder_point,
+            max_quantity,
+            reorder_quantity,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ReplenishmentRulesParams {
+    pub product_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<ReplenishmentRulesParams> for ReplenishmentRuleQuery {
+    fn from(val: ReplenishmentRulesParams) -> Self {
+        let ReplenishmentRulesParams {
+            product_id,
+            warehouse_id,
+        } = val;
+
+        ReplenishmentRuleQuery {
+            product_id,
+            warehouse_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct RunReplenishmentRequest {
+    /// Evaluates the rules of every warehouse when omitted.
+    pub warehouse_id: Option<Uuid>,
+}
+
+/// `available` and `inbound` are the stock position the suggestion was computed from.
+/// Once accepted, `document_id` is the purchase order or, for pick faces, the stock movement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReplenishmentSuggestionResponse {
+    pub id: Uuid,
+    pub rule_id: Uuid,
+    pub kind: ReplenishmentKind,
+    pub product_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub location_id: Option<Uuid>,
+    pub from_location_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub available: Decimal,
+    pub inbound: Decimal,
+    pub status: SuggestionStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub document_type: Option<DocumentType>,
+    pub document_id: Option<Uuid>,
+}
+
+impl From<ReplenishmentSuggestion> for ReplenishmentSuggestionResponse {
+    fn from(val: ReplenishmentSuggestion) -> Self {
+        let ReplenishmentSuggestion {
+            id,
+            rule_id,
+            kind,
+            product_id,
+            warehouse_id,
+            location_id,
+            from_location_id,
+            quantity,
+            available,
+            inbound,
+            status,
+            created_by,
+            created_at,
+            reviewed_by,
+            reviewed_at,
+            document_type,
+            document_id,
+        } = val;
+
+        ReplenishmentSuggestionResponse {
+            id,
+            rule_id,
+            kind,
+            product_id,
+            warehouse_id,
+            location_id,
+            from_location_id,
+            quantity,
+            available,
+            inbound,
+            status,
+            created_by,
+            created_at,
+            reviewed_by,
+            reviewed_at,
+            document_type,
+            document_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ReplenishmentSuggestionsParams {
+    pub warehouse_id: Option<Uuid>,
+    pub status: Option<SuggestionStatus>,
+}
+
+impl From<ReplenishmentSuggestionsParams> for ReplenishmentSuggestionQuery {
+    fn from(val: ReplenishmentSuggestionsParams) -> Self {
+        let ReplenishmentSuggestionsParams {
+            warehouse_id,
+            status,
+        } = val;
+
+        ReplenishmentSuggestionQuery {
+            warehouse_id,
+            status,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::domain::{DocumentType, Reservation, ReservationStatus, ReserveData};
+use crate::dto::{validate_positive, validate_serial_numbers};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReserveRequest {
+    pub product_id: Uuid,
+
+    pub location_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    pub document_type: DocumentType,
+
+    pub document_id: Uuid,
+
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReserveRequest> for ReserveData {
+    fn from(val: ReserveRequest) -> Self {
+        let ReserveRequest {
+            product_id,
+            location_id,
+            quantity,
+            uom,
+            document_type,
+            document_id,
+            expires_at,
+        } = val;
+
+        ReserveData {
+            product_id,
+            location_id,
+            quantity,
+            uom,
+            document_type,
+            document_id,
+            expires_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ConsumeReservationRequest {
+    /// Quantity to issue. Everything still reserved is consumed when omitted.
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Option<Decimal>,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// Units picked, required for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReservationResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    pub status: ReservationStatus,
+    pub document_type: DocumentType,
+    pub document_id: Uuid,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Reservation> for ReservationResponse {
+    fn from(val: Reservation) -> Self {
+        let Reservation {
+            id,
+            product_id,
+            location_id,
+            quantity,
+            status,
+            document_type,
+            document_id,
+            expires_at,
+            created_by,
+            created_at,
+        } = val;
+
+        ReservationResponse {
+            id,
+            product_id,
+            location_id,
+            quantity,
+            status,
+            document_type,
+            document_id,
+            expires_at,
+            created_by,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use crate::domain::{
+    ReturnAuthorization, ReturnAuthorizationData, ReturnAuthorizationLine, ReturnDisposition,
+    ReturnInspection, ReturnInspectionData, ReturnLineData, ReturnReason, ReturnReceipt,
+    ReturnStatus, ReturnSummary, ReturnSummaryQuery,
+};
+use crate::dto::{StockMovementResponse, validate_positive, validate_serial_numbers};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateReturnAuthorizationRequest {
+    /// Sales order the goods were issued on.
+    pub sales_order_id: Uuid,
+
+    /// Warehouse the goods are returned to.
+    pub warehouse_id: Uuid,
+
+    #[validate(length(min = 1), nested)]
+    pub lines: Vec<CreateReturnAuthorizationLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateReturnAuthorizationLineRequest {
+    pub product_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    pub reason: ReturnReason,
+
+    #[validate(length(min = 1, max = 512))]
+    pub note: Option<String>,
+}
+
+impl From<CreateReturnAuthorizationRequest> for ReturnAuthorizationData {
+    fn from(val: CreateReturnAuthorizationRequest) -> Self {
+        let CreateReturnAuthorizationRequest {
+            sales_order_id,
+            warehouse_id,
+            lines,
+        } = val;
+
+        ReturnAuthorizationData {
+            sales_order_id,
+            warehouse_id,
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<CreateReturnAuthorizationLineRequest> for ReturnLineData {
+    fn from(val: CreateReturnAuthorizationLineRequest) -> Self {
+        let CreateReturnAuthorizationLineRequest {
+            product_id,
+            quantity,
+            uom,
+            reason,
+            note,
+        } = val;
+
+        ReturnLineData {
+            product_id,
+            quantity,
+            uom,
+            reason,
+            note,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveReturnRequest {
+    #[validate(length(min = 1), nested)]
+    pub lines: Vec<ReceiveReturnLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveReturnLineRequest {
+    pub line_id: Uuid,
+
+    /// Quarantine location of the warehouse the returned units wait in for inspection.
+    pub location_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// Lot the units were shipped from, required for lot-tracked products.
+    #[validate(length(min = 1, max = 64))]
+    pub lot_number: Option<String>,
+
+    /// Required for expiry-tracked products.
+    pub expiry_date: Option<NaiveDate>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<ReceiveReturnLineRequest> for ReturnReceipt {
+    fn from(val: ReceiveReturnLineRequest) -> Self {
+        let ReceiveReturnLineRequest {
+            line_id,
+            location_id,
+            quantity,
+            uom,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+        } = val;
+
+        ReturnReceipt {
+            line_id,
+            location_id,
+            quantity,
+            uom,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct InspectReturnRequest {
+    #[validate(length(min = 1), nested)]
+    pub lines: Vec<InspectReturnLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct InspectReturnLineRequest {
+    pub line_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    pub disposition: ReturnDisposition,
+
+    /// Quarantine location the units are taken from. Defaults to the one the product was
+    /// last received into.
+    pub from_location_id: Option<Uuid>,
+
+    /// Location the units are moved to, required to restock or refurbish.
+    pub to_location_id: Option<Uuid>,
+
+    #[validate(length(min = 1, max = 512))]
+    pub note: Option<String>,
+
+    /// Picks a specific lot, which is otherwise allocated first-expired-first-out.
+    #[validate(length(min = 1, max = 64))]
+    pub lot_number: Option<String>,
+
+    pub expiry_date: Option<NaiveDate>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<InspectReturnLineRequest> for ReturnInspectionData {
+    fn from(val: InspectReturnLineRequest) -> Self {
+        let InspectReturnLineRequest {
+            line_id,
+            quantity,
+            uom,
+            disposition,
+            from_location_id,
+            to_location_id,
+            note,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+        } = val;
+
+        ReturnInspectionData {
+            line_id,
+            quantity,
+            uom,
+            disposition,
+            from_location_id,
+            to_location_id,
+            note,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReturnAuthorizationLineResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub reason: ReturnReason,
+    pub note: Option<String>,
+    pub quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub inspected_quantity: Decimal,
+}
+
+impl From<ReturnAuthorizationLine> for ReturnAuthorizationLineResponse {
+    fn from(val: ReturnAuthorizationLine) -> Self {
+        let ReturnAuthorizationLine {
+            id,
+            return_authorization_id: _,
+            product_id,
+            reason,
+            note,
+            quantity,
+            received_quantity,
+            inspected_quantity,
+        } = val;
+
+        ReturnAuthorizationLineResponse {
+            id,
+            product_id,
+            reason,
+            note,
+            quantity,
+            received_quantity,
+            inspected_quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReturnInspectionResponse {
+    pub id: Uuid,
+    pub line_id: Uuid,
+    pub quantity: Decimal,
+    pub disposition: ReturnDisposition,
+    pub to_location_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub inspected_by: Option<Uuid>,
+    pub inspected_at: DateTime<Utc>,
+}
+
+impl From<ReturnInspection> for ReturnInspectionResponse {
+    fn from(val: ReturnInspection) -> Self {
+        let ReturnInspection {
+            id,
+            line_id,
+            quantity,
+            disposition,
+            to_location_id,
+            note,
+            inspected_by,
+            inspected_at,
+        } = val;
+
+        ReturnInspectionResponse {
+            id,
+            line_id,
+            quantity,
+            disposition,
+            to_location_id,
+            note,
+            inspected_by,
+            inspected_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReturnAuthorizationResponse {
+    pub id: Uuid,
+    pub sales_order_id: Uuid,
+    pub warehouse_id: Uuid,
+    pub status: ReturnStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub lines: Vec<ReturnAuthorizationLineResponse>,
+    pub inspections: Vec<ReturnInspectionResponse>,
+}
+
+impl ReturnAuthorizationResponse {
+    pub fn new(
+        authorization: ReturnAuthorization,
+        lines: Vec<ReturnAuthorizationLine>,
+        inspections: Vec<ReturnInspection>,
+    ) -> Self {
+        let ReturnAuthorization {
+            id,
+            sales_order_id,
+            warehouse_id,
+            status,
+            created_by,
+            created_at,
+            closed_at,
+        } = authorization;
+
+        ReturnAuthorizationResponse {
+            id,
+            sales_order_id,
+            warehouse_id,
+            status,
+            created_by,
+            created_at,
+            closed_at,
+            lines: lines.into_iter().map(Into::into).collect(),
+            inspections: inspections.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveReturnResponse {
+    pub authorization: ReturnAuthorizationResponse,
+    pub movements: Vec<StockMovementResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ReturnSummaryParams {
+    pub warehouse_id: Option<Uuid>,
+    pub product_id: Option<Uuid>,
+    /// Authorizations created at or after.
+    pub from: Option<DateTime<Utc>>,
+    /// Authorizations created before.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl From<ReturnSummaryParams> for ReturnSummaryQuery {
+    fn from(val: ReturnSummaryParams) -> Self {
+        let ReturnSummaryParams {
+            warehouse_id,
+            product_id,
+            from,
+            to,
+        } = val;
+
+        ReturnSummaryQuery {
+            warehouse_id,
+            product_id,
+            from,
+            to,
+        }
+    }
+}
+
+/// `disposition` is `null` for units received but not inspected yet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReturnSummaryResponse {
+    pub product_id: Uuid,
+    pub reason: ReturnReason,
+    pub disposition: Option<ReturnDisposition>,
+    pub quantity: Decimal,
+}
+
+impl From<ReturnSummary> for ReturnSummaryResponse {
+    fn from(val: ReturnSummary) -> Self {
+        let ReturnSummary {
+            product_id,
+            reason,
+            disposition,
+            quantity,
+        } = val;
+
+        ReturnSummaryResponse {
+            product_id,
+            reason,
+            disposition,
+            quantity,
+        }
+    }
+}
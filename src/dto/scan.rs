@@ -0,0 +1,86 @@
+use crate::domain::{Gs1Element, Gs1Scan};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct Gs1ScanRequest {
+    /// Scanner output, with FNC1 transmitted as the group separator character, or the
+    /// human readable form with application identifiers in parentheses.
+    #[validate(length(min = 1, max = 512))]
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct Gs1ElementResponse {
+    pub ai: String,
+    pub value: String,
+}
+
+impl From<Gs1Element> for Gs1ElementResponse {
+    fn from(val: Gs1Element) -> Self {
+        let Gs1Element { ai, value } = val;
+
+        Gs1ElementResponse { ai, value }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct Gs1ScanResponse {
+    pub elements: Vec<Gs1ElementResponse>,
+    pub sscc: Option<String>,
+    pub gtin: Option<String>,
+    pub content_gtin: Option<String>,
+    pub lot_number: Option<String>,
+    pub production_date: Option<NaiveDate>,
+    pub best_before_date: Option<NaiveDate>,
+    pub expiry_date: Option<NaiveDate>,
+    pub serial_number: Option<String>,
+    /// Count of items from AI 30 or 37, in `uom` when the GTIN is known.
+    pub quantity: Option<Decimal>,
+    pub net_weight_kg: Option<Decimal>,
+    /// Product whose unit of measure carries the GTIN as barcode.
+    pub product_id: Option<Uuid>,
+    pub uom: Option<String>,
+}
+
+impl From<Gs1Scan> for Gs1ScanResponse {
+    fn from(val: Gs1Scan) -> Self {
+        let Gs1Scan {
+            elements,
+            sscc,
+            gtin,
+            content_gtin,
+            lot_number,
+            production_date,
+            best_before_date,
+            expiry_date,
+            serial_number,
+            quantity,
+            net_weight_kg,
+            product_id,
+            uom,
+        } = val;
+
+        Gs1ScanResponse {
+            elements: elements.into_iter().map(Into::into).collect(),
+            sscc,
+            gtin,
+            content_gtin,
+            lot_number,
+            production_date,
+            best_before_date,
+            expiry_date,
+            serial_number,
+            quantity,
+            net_weight_kg,
+            product_id,
+            uom,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::domain::{SerialNumber, SerialStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SerialNumberResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub serial_number: String,
+    pub status: SerialStatus,
+    /// Current location while in stock, otherwise the location the unit left from.
+    pub location_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SerialNumber> for SerialNumberResponse {
+    fn from(val: SerialNumber) -> Self {
+        let SerialNumber {
+            id,
+            product_id,
+            serial_number,
+            status,
+            location_id,
+            lot_id,
+            created_at,
+            updated_at,
+        } = val;
+
+        SerialNumberResponse {
+            id,
+            product_id,
+            serial_number,
+            status,
+            location_id,
+            lot_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use crate::domain::{
+    LabelFormat, Parcel, ParcelData, Rate, ShipData, Shipment, ShipmentData, ShipmentQuery,
+    ShipmentStatus,
+};
+use crate::dto::validate_positive;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateShipmentRequest {
+    pub warehouse_id: Uuid,
+
+    /// Picked order the goods were packed from. Its document is used when `document_id`
+    /// is omitted.
+    pub pick_order_id: Option<Uuid>,
+
+    pub document_id: Option<Uuid>,
+
+    #[validate(length(min = 1, max = 128))]
+    pub ship_to_name: String,
+
+    /// Street lines, one per line.
+    #[validate(length(min = 1, max = 512))]
+    pub ship_to_address: String,
+
+    #[validate(length(min = 1, max = 16))]
+    pub ship_to_postal_code: String,
+
+    /// ISO 3166-1 alpha-2 code.
+    #[validate(length(equal = 2))]
+    pub ship_to_country: String,
+}
+
+impl From<CreateShipmentRequest> for ShipmentData {
+    fn from(val: CreateShipmentRequest) -> Self {
+        let CreateShipmentRequest {
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            ship_to_name,
+            ship_to_address,
+            ship_to_postal_code,
+            ship_to_country,
+        } = val;
+
+        ShipmentData {
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            ship_to_name,
+            ship_to_address,
+            ship_to_postal_code,
+            ship_to_country,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ShipmentsParams {
+    pub warehouse_id: Option<Uuid>,
+    pub pick_order_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub status: Option<ShipmentStatus>,
+}
+
+impl From<ShipmentsParams> for ShipmentQuery {
+    fn from(val: ShipmentsParams) -> Self {
+        let ShipmentsParams {
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            status,
+        } = val;
+
+        ShipmentQuery {
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            status,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ShipmentResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub pick_order_id: Option<Uuid>,
+    pub document_id: Option<Uuid>,
+    pub ship_to_name: String,
+    pub ship_to_address: String,
+    pub ship_to_postal_code: String,
+    pub ship_to_country: String,
+    pub carrier: Option<String>,
+    pub service: Option<String>,
+    pub shipping_cost: Option<Decimal>,
+    pub currency: Option<String>,
+    pub status: ShipmentStatus,
+    pub shipped_by: Option<Uuid>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Shipment> for ShipmentResponse {
+    fn from(val: Shipment) -> Self {
+        let Shipment {
+            id,
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            ship_to_name,
+            ship_to_address,
+            ship_to_postal_code,
+            ship_to_country,
+            carrier,
+            service,
+            shipping_cost,
+            currency,
+            status,
+            shipped_by,
+            shipped_at,
+            created_by,
+            created_at,
+        } = val;
+
+        ShipmentResponse {
+            id,
+            warehouse_id,
+            pick_order_id,
+            document_id,
+            ship_to_name,
+            ship_to_address,
+            ship_to_postal_code,
+            ship_to_country,
+            carrier,
+            service,
+            shipping_cost,
+            currency,
+            status,
+            shipped_by,
+            shipped_at,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AddParcelRequest {
+    #[validate(custom(function = "validate_positive"))]
+    pub length_cm: Decimal,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub width_cm: Decimal,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub height_cm: Decimal,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub weight_kg: Decimal,
+}
+
+impl From<AddParcelRequest> for ParcelData {
+    fn from(val: AddParcelRequest) -> Self {
+        let AddParcelRequest {
+            length_cm,
+            width_cm,
+            height_cm,
+            weight_kg,
+        } = val;
+
+        ParcelData {
+            length_cm,
+            width_cm,
+            height_cm,
+            weight_kg,
+        }
+    }
+}
+
+/// `has_label` tells whether `/parcels/{id}/label` has a label printed by the carrier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ParcelResponse {
+    pub id: Uuid,
+    pub shipment_id: Uuid,
+    pub length_cm: Decimal,
+    pub width_cm: Decimal,
+    pub height_cm: Decimal,
+    pub weight_kg: Decimal,
+    pub billable_weight_kg: Decimal,
+    pub tracking_number: Option<String>,
+    pub has_label: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Parcel> for ParcelResponse {
+    fn from(val: Parcel) -> Self {
+        let billable_weight_kg = val.billable_weight();
+        let Parcel {
+            id,
+            shipment_id,
+            length_cm,
+            width_cm,
+            height_cm,
+            weight_kg,
+            tracking_number,
+            label,
+            label_content_type: _,
+            created_at,
+        } = val;
+
+        ParcelResponse {
+            id,
+            shipment_id,
+            length_cm,
+            width_cm,
+            height_cm,
+            weight_kg,
+            billable_weight_kg,
+            tracking_number,
+            has_label: label.is_some(),
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ShipmentDetailResponse {
+    pub shipment: ShipmentResponse,
+    pub parcels: Vec<ParcelResponse>,
+}
+
+impl ShipmentDetailResponse {
+    pub fn new(shipment: Shipment, parcels: Vec<Parcel>) -> Self {
+        ShipmentDetailResponse {
+            shipment: shipment.into(),
+            parcels: parcels.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct RatesParams {
+    /// Only rates of this carrier, of every carrier when omitted.
+    pub carrier: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct RateResponse {
+    pub carrier: String,
+    pub service: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub transit_days: Option<i32>,
+}
+
+impl From<Rate> for RateResponse {
+    fn from(val: Rate) -> Self {
+        let Rate {
+            carrier,
+            service,
+            amount,
+            currency,
+            transit_days,
+        } = val;
+
+        RateResponse {
+            carrier,
+            service,
+            amount,
+            currency,
+            transit_days,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ShipRequest {
+    /// Carrier code, e.g. `manual`.
+    #[validate(length(min = 1, max = 64))]
+    pub carrier: String,
+
+    #[validate(length(min = 1, max = 64))]
+    pub service: String,
+
+    /// One per parcel in the order they were added, for the manual carrier.
+    #[serde(default)]
+    #[validate(custom(function = "validate_tracking_numbers"))]
+    pub tracking_numbers: Vec<String>,
+
+    /// Format of the labels printed by the carrier, SVG when omitted.
+    #[serde(default)]
+    pub label_format: LabelFormat,
+}
+
+fn validate_tracking_numbers(value: &[String]) -> Result<(), ValidationError> {
+    if value
+        .iter()
+        .any(|tracking_number| tracking_number.is_empty() || tracking_number.len() > 64)
+    {
+        return Err(ValidationError::new("tracking_numbers"));
+    }
+
+    Ok(())
+}
+
+impl From<ShipRequest> for ShipData {
+    fn from(val: ShipRequest) -> Self {
+        let ShipRequest {
+            carrier,
+            service,
+            tracking_numbers,
+            label_format,
+        } = val;
+
+        ShipData {
+            carrier,
+            service,
+            tracking_numbers,
+            label_format,
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::domain::{
+    SnapshotKind, StockPosition, StockPositionQuery, StockSnapshot, StockSnapshotDiff,
+    StockSnapshotQuery, StockStatus,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockAsOfParams {
+    /// Movements posted at or before this moment are included.
+    pub as_of: DateTime<Utc>,
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<StockAsOfParams> for StockPositionQuery {
+    fn from(val: StockAsOfParams) -> Self {
+        let StockAsOfParams {
+            as_of: _,
+            product_id,
+            location_id,
+            warehouse_id,
+        } = val;
+
+        StockPositionQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockPositionsParams {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<StockPositionsParams> for StockPositionQuery {
+    fn from(val: StockPositionsParams) -> Self {
+        let StockPositionsParams {
+            product_id,
+            location_id,
+            warehouse_id,
+        } = val;
+
+        StockPositionQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockPositionResponse {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub status: StockStatus,
+    pub quantity: Decimal,
+}
+
+impl From<StockPosition> for StockPositionResponse {
+    fn from(val: StockPosition) -> Self {
+        let StockPosition {
+            product_id,
+            location_id,
+            lot_id,
+            status,
+            quantity,
+        } = val;
+
+        StockPositionResponse {
+            product_id,
+            location_id,
+            lot_id,
+            status,
+            quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockSnapshotsParams {
+    pub kind: Option<SnapshotKind>,
+    /// Only snapshots as of this moment or later.
+    pub from: Option<DateTime<Utc>>,
+    /// Only snapshots as of this moment or earlier.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl From<StockSnapshotsParams> for StockSnapshotQuery {
+    fn from(val: StockSnapshotsParams) -> Self {
+        let StockSnapshotsParams { kind, from, to } = val;
+
+        StockSnapshotQuery { kind, from, to }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockSnapshotResponse {
+    pub id: Uuid,
+    pub kind: SnapshotKind,
+    pub as_of: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<StockSnapshot> for StockSnapshotResponse {
+    fn from(val: StockSnapshot) -> Self {
+        let StockSnapshot {
+            id,
+            kind,
+            as_of,
+            created_by,
+            created_at,
+        } = val;
+
+        StockSnapshotResponse {
+            id,
+            kind,
+            as_of,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockSnapshotDetailResponse {
+    pub snapshot: StockSnapshotResponse,
+    pub lines: Vec<StockPositionResponse>,
+}
+
+impl StockSnapshotDetailResponse {
+    pub fn new(snapshot: StockSnapshot, lines: Vec<StockPosition>) -> Self {
+        StockSnapshotDetailResponse {
+            snapshot: snapshot.into(),
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockSnapshotDiffParams {
+    /// Snapshot compared against.
+    pub from: Uuid,
+    pub to: Uuid,
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<StockSnapshotDiffParams> for StockPositionQuery {
+    fn from(val: StockSnapshotDiffParams) -> Self {
+        let StockSnapshotDiffParams {
+            from: _,
+            to: _,
+            product_id,
+            location_id,
+            warehouse_id,
+        } = val;
+
+        StockPositionQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockSnapshotDiffResponse {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub from_quantity: Decimal,
+    pub to_quantity: Decimal,
+    /// `to_quantity` less `from_quantity`.
+    pub change: Decimal,
+}
+
+impl From<StockSnapshotDiff> for StockSnapshotDiffResponse {
+    fn from(val: StockSnapshotDiff) -> Self {
+        let change = val.change();
+        let StockSnapshotDiff {
+            product_id,
+            location_id,
+            from_quantity,
+            to_quantity,
+        } = val;
+
+        StockSnapshotDiffResponse {
+            product_id,
+            location_id,
+            from_quantity,
+            to_quantity,
+            change,
+        }
+    }
+}
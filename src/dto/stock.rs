@@ -0,0 +1,334 @@
+use crate::domain::{
+    AvailableToPromise, DocumentType, MovementData, MovementKind, StockLevel, StockLevelQuery,
+    StockMovement, StockMovementQuery, StockStatus,
+};
+use crate::dto::{validate_non_negative, validate_positive, validate_serial_numbers};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[validate(schema(function = "validate_movement_locations"))]
+pub struct CreateMovementRequest {
+    pub kind: MovementKind,
+
+    pub product_id: Uuid,
+
+    pub from_location_id: Option<Uuid>,
+
+    pub to_location_id: Option<Uuid>,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    pub document_type: Option<DocumentType>,
+
+    pub document_id: Option<Uuid>,
+
+    /// Required on receipts of lot-tracked products. Picks a specific lot on outbound
+    /// movements, which are otherwise allocated first-expired-first-out.
+    #[validate(length(min = 1, max = 64))]
+    pub lot_number: Option<String>,
+
+    /// Required on receipts of expiry-tracked products.
+    pub expiry_date: Option<NaiveDate>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+
+    /// Cost per unit of `uom` of stock brought in by receipts and positive adjustments.
+    /// Defaults to the product's current cost.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub unit_cost: Option<Decimal>,
+
+    /// GS1 element string scanned from the goods. Unit, lot, expiry date and serial number
+    /// are taken from it where not given; its GTIN must be a barcode of the product.
+    #[validate(length(min = 1, max = 512))]
+    pub scan: Option<String>,
+}
+
+fn validate_movement_locations(req: &CreateMovementRequest) -> Result<(), ValidationError> {
+    let valid = match (req.kind, req.from_location_id, req.to_location_id) {
+        (MovementKind::Receipt, None, Some(_)) => true,
+        (MovementKind::Issue, Some(_), None) => true,
+        (MovementKind::Adjustment, Some(_), None) | (MovementKind::Adjustment, None, Some(_)) => {
+            true
+        }
+        (MovementKind::Transfer, Some(from), Some(to)) => from != to,
+        _ => false,
+    };
+
+    if !valid {
+        return Err(ValidationError::new("movement_locations"));
+    }
+
+    if req.document_type.is_some() != req.document_id.is_some() {
+        return Err(ValidationError::new("movement_document"));
+    }
+
+    if req.unit_cost.is_some() && req.from_location_id.is_some() {
+        return Err(ValidationError::new("movement_cost"));
+    }
+
+    Ok(())
+}
+
+impl From<CreateMovementRequest> for MovementData {
+    fn from(val: CreateMovementRequest) -> Self {
+        let CreateMovementRequest {
+            kind,
+            product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            uom,
+            document_type,
+            document_id,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+            unit_cost,
+            scan,
+        } = val;
+
+        MovementData {
+            kind,
+            product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            uom,
+            document_type,
+            document_id,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+            unit_cost,
+            scan,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockMovementResponse {
+    pub id: Uuid,
+    pub kind: MovementKind,
+    pub product_id: Uuid,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub quantity: Decimal,
+    pub document_type: Option<DocumentType>,
+    pub document_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub lot_id: Option<Uuid>,
+    /// Cost per base unit of stock brought in, or of goods issued. Moves between
+    /// locations are not valued.
+    pub unit_cost: Option<Decimal>,
+    pub total_cost: Option<Decimal>,
+    /// Status of the stock moved.
+    pub status: StockStatus,
+    /// Status the stock was put in, on status changes only.
+    pub to_status: Option<StockStatus>,
+    pub reason: Option<String>,
+}
+
+impl From<StockMovement> for StockMovementResponse {
+    fn from(val: StockMovement) -> Self {
+        let StockMovement {
+            id,
+            kind,
+            product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            document_type,
+            document_id,
+            created_by,
+            created_at,
+            lot_id,
+            unit_cost,
+            total_cost,
+            status,
+            to_status,
+            reason,
+        } = val;
+
+        StockMovementResponse {
+            id,
+            kind,
+            product_id,
+            from_location_id,
+            to_location_id,
+            quantity,
+            document_type,
+            document_id,
+            created_by,
+            created_at,
+            lot_id,
+            unit_cost,
+            total_cost,
+            status,
+            to_status,
+            reason,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockMovementsParams {
+    pub product_id: Option<Uuid>,
+    /// Movements in or out of the location.
+    pub location_id: Option<Uuid>,
+    /// Movements in or out of locations of the warehouse.
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    /// Only movements posted after this moment.
+    pub from: Option<DateTime<Utc>>,
+    /// Only movements posted at or before this moment.
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl From<StockMovementsParams> for StockMovementQuery {
+    fn from(val: StockMovementsParams) -> Self {
+        let StockMovementsParams {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+            from,
+            to,
+        } = val;
+
+        StockMovementQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+            from,
+            to,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct StockLevelsParams {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+}
+
+impl From<StockLevelsParams> for StockLevelQuery {
+    fn from(val: StockLevelsParams) -> Self {
+        let StockLevelsParams {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+        } = val;
+
+        StockLevelQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockLevelResponse {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub on_hand: Decimal,
+    /// Part of `on_hand` held in blocked or expired lots, in quarantine locations or under
+    /// a hold status.
+    pub blocked: Decimal,
+    /// Part of `on_hand` shipped by a transfer order and not yet received.
+    pub in_transit: Decimal,
+    pub reserved: Decimal,
+    pub available: Decimal,
+}
+
+impl From<StockLevel> for StockLevelResponse {
+    fn from(val: StockLevel) -> Self {
+        let StockLevel {
+            product_id,
+            location_id,
+            on_hand,
+            blocked,
+            in_transit,
+            reserved,
+            available,
+        } = val;
+
+        StockLevelResponse {
+            product_id,
+            location_id,
+            on_hand,
+            blocked,
+            in_transit,
+            reserved,
+            available,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct AvailableToPromiseParams {
+    pub warehouse_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AvailableToPromiseResponse {
+    pub product_id: Uuid,
+    pub warehouse_id: Option<Uuid>,
+    pub on_hand: Decimal,
+    pub blocked: Decimal,
+    pub in_transit: Decimal,
+    pub reserved: Decimal,
+    pub available: Decimal,
+}
+
+impl From<AvailableToPromise> for AvailableToPromiseResponse {
+    fn from(val: AvailableToPromise) -> Self {
+        let AvailableToPromise {
+            product_id,
+            warehouse_id,
+            on_hand,
+            blocked,
+            in_transit,
+            reserved,
+            available,
+        } = val;
+
+        AvailableToPromiseResponse {
+            product_id,
+            warehouse_id,
+            on_hand,
+            blocked,
+            in_transit,
+            reserved,
+            available,
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::domain::{
+    AbcClass, CountScope, CountSession, CountSessionData, CountSessionStatus, CountTask,
+    CountTaskData, CountTaskStatus,
+};
+use crate::dto::validate_non_negative;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[validate(schema(function = "validate_count_scope"))]
+pub struct CreateCountSessionRequest {
+    pub warehouse_id: Uuid,
+
+    pub scope: CountScope,
+
+    #[validate(length(min = 1, max = 32))]
+    pub zone: Option<String>,
+
+    pub abc_class: Option<AbcClass>,
+
+    #[serde(default)]
+    pub blind: bool,
+
+    /// Allowed variance, in percent of the expected quantity, that is adjusted without approval.
+    #[serde(default)]
+    #[validate(custom(function = "validate_non_negative"))]
+    pub tolerance_percent: Decimal,
+}
+
+fn validate_count_scope(req: &CreateCountSessionRequest) -> Result<(), ValidationError> {
+    let valid = match req.scope {
+        CountScope::Zone => req.zone.is_some(),
+        CountScope::AbcClass => req.abc_class.is_some(),
+        CountScope::Full => true,
+    };
+
+    if !valid {
+        return Err(ValidationError::new("count_scope"));
+    }
+
+    Ok(())
+}
+
+impl From<CreateCountSessionRequest> for CountSessionData {
+    fn from(val: CreateCountSessionRequest) -> Self {
+        let CreateCountSessionRequest {
+            warehouse_id,
+            scope,
+            zone,
+            abc_class,
+            blind,
+            tolerance_percent,
+        } = val;
+
+        CountSessionData {
+            warehouse_id,
+            scope,
+            zone,
+            abc_class,
+            blind,
+            tolerance_percent,
+        }
+    }
+}
+
+/// Stock found where the session has no task for it, e.g. in a bin that was empty when the
+/// session was planned. Lot and expiry date are required as for a receipt of the product.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct AddCountTaskRequest {
+    pub product_id: Uuid,
+
+    pub location_id: Uuid,
+
+    #[validate(length(min = 1, max = 64))]
+    pub lot_number: Option<String>,
+
+    pub expiry_date: Option<NaiveDate>,
+}
+
+impl From<AddCountTaskRequest> for CountTaskData {
+    fn from(val: AddCountTaskRequest) -> Self {
+        let AddCountTaskRequest {
+            product_id,
+            location_id,
+            lot_number,
+            expiry_date,
+        } = val;
+
+        CountTaskData {
+            product_id,
+            location_id,
+            lot_number,
+            expiry_date,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct RecordCountRequest {
+    #[validate(custom(function = "validate_non_negative"))]
+    pub counted_quantity: Decimal,
+
+    /// Unit of measure the quantity was counted in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CountTaskResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    /// Hidden from counters until the task is counted when the session is blind.
+    pub expected_quantity: Option<Decimal>,
+    pub counted_quantity: Option<Decimal>,
+    pub variance: Option<Decimal>,
+    pub attempts: i16,
+    pub status: CountTaskStatus,
+    pub counted_by: Option<Uuid>,
+    pub counted_at: Option<DateTime<Utc>>,
+    pub approved_by: Option<Uuid>,
+    pub movement_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    /// Users who counted the task on any attempt.
+    pub counters: Vec<Uuid>,
+}
+
+impl CountTaskResponse {
+    pub fn new(task: CountTask, blind: bool) -> Self {
+        let CountTask {
+            id,
+            session_id: _,
+            product_id,
+            location_id,
+            expected_quantity,
+            counted_quantity,
+            variance,
+            attempts,
+            status,
+            counted_by,
+            counted_at,
+            approved_by,
+            movement_id,
+            lot_id,
+            counters,
+        } = task;
+
+        let hidden = blind && matches!(status, CountTaskStatus::Pending | CountTaskStatus::Recount);
+
+        CountTaskResponse {
+            id,
+            product_id,
+            location_id,
+            expected_quantity: (!hidden).then_some(expected_quantity),
+            counted_quantity,
+            variance: variance.filter(|_| !hidden),
+            attempts,
+            status,
+            counted_by,
+            counted_at,
+            approved_by,
+            movement_id,
+            lot_id,
+            counters,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CountSessionResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub scope: CountScope,
+    pub zone: Option<String>,
+    pub abc_class: Option<AbcClass>,
+    pub blind: bool,
+    pub tolerance_percent: Decimal,
+    pub status: CountSessionStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub tasks: Vec<CountTaskResponse>,
+}
+
+impl CountSessionResponse {
+    pub fn new(session: CountSession, tasks: Vec<CountTask>) -> Self {
+        let CountSession {
+            id,
+            warehouse_id,
+            scope,
+            zone,
+            abc_class,
+            blind,
+            tolerance_percent,
+            status,
+            created_by,
+            created_at,
+            completed_at,
+        } = session;
+
+        CountSessionResponse {
+            id,
+            warehouse_id,
+            scope,
+            zone,
+            abc_class,
+            blind,
+            tolerance_percent,
+            status,
+            created_by,
+            created_at,
+            completed_at,
+            tasks: tasks
+                .into_iter()
+                .map(|task| CountTaskResponse::new(task, blind))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::domain::{LotHoldData, StockBalance, StockBalanceQuery, StockHoldData, StockStatus};
+use crate::dto::{validate_positive, validate_serial_numbers};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockHoldRequest {
+    pub product_id: Uuid,
+
+    pub location_id: Uuid,
+
+    /// Takes the stock out of this lot only.
+    pub lot_id: Option<Uuid>,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// Status the stock is put on hold in, or released or scrapped out of.
+    pub status: StockStatus,
+
+    /// Required when putting stock on hold.
+    #[validate(length(min = 1, max = 256))]
+    pub reason: Option<String>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<StockHoldRequest> for StockHoldData {
+    fn from(val: StockHoldRequest) -> Self {
+        let StockHoldRequest {
+            product_id,
+            location_id,
+            lot_id,
+            quantity,
+            uom,
+            status,
+            reason,
+            serial_numbers,
+        } = val;
+
+        StockHoldData {
+            product_id,
+            location_id,
+            lot_id,
+            quantity,
+            uom,
+            status,
+            reason,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct HoldLotRequest {
+    pub status: StockStatus,
+
+    #[validate(length(min = 1, max = 256))]
+    pub reason: String,
+}
+
+impl From<HoldLotRequest> for LotHoldData {
+    fn from(val: HoldLotRequest) -> Self {
+        let HoldLotRequest { status, reason } = val;
+
+        LotHoldData { status, reason }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct HeldStockParams {
+    pub product_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub warehouse_id: Option<Uuid>,
+    pub lot_id: Option<Uuid>,
+    /// Any hold status when omitted.
+    pub status: Option<StockStatus>,
+}
+
+impl From<HeldStockParams> for StockBalanceQuery {
+    fn from(val: HeldStockParams) -> Self {
+        let HeldStockParams {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+            status,
+        } = val;
+
+        StockBalanceQuery {
+            product_id,
+            location_id,
+            warehouse_id,
+            lot_id,
+            statuses: status.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct StockBalanceResponse {
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub lot_id: Option<Uuid>,
+    pub status: StockStatus,
+    pub on_hand: Decimal,
+}
+
+impl From<StockBalance> for StockBalanceResponse {
+    fn from(val: StockBalance) -> Self {
+        let StockBalance {
+            id: _,
+            product_id,
+            location_id,
+            on_hand,
+            lot_id,
+            status,
+        } = val;
+
+        StockBalanceResponse {
+            product_id,
+            location_id,
+            lot_id,
+            status,
+            on_hand,
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::domain::{
+    InTransitQuery, InTransitStock, TransferDiscrepancy, TransferOrder, TransferOrderData,
+    TransferOrderLine, TransferOrderLineData, TransferOrderStatus, TransferReceipt,
+    TransferShipment,
+};
+use crate::dto::{StockMovementResponse, validate_positive, validate_serial_numbers};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateTransferOrderRequest {
+    pub source_warehouse_id: Uuid,
+
+    pub destination_warehouse_id: Uuid,
+
+    #[validate(length(min = 1), nested)]
+    pub lines: Vec<CreateTransferOrderLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateTransferOrderLineRequest {
+    pub product_id: Uuid,
+
+    /// Location of the source warehouse the stock is picked from.
+    pub from_location_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+}
+
+impl From<CreateTransferOrderRequest> for TransferOrderData {
+    fn from(val: CreateTransferOrderRequest) -> Self {
+        let CreateTransferOrderRequest {
+            source_warehouse_id,
+            destination_warehouse_id,
+            lines,
+        } = val;
+
+        TransferOrderData {
+            source_warehouse_id,
+            destination_warehouse_id,
+            lines: lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<CreateTransferOrderLineRequest> for TransferOrderLineData {
+    fn from(val: CreateTransferOrderLineRequest) -> Self {
+        let CreateTransferOrderLineRequest {
+            product_id,
+            from_location_id,
+            quantity,
+            uom,
+        } = val;
+
+        TransferOrderLineData {
+            product_id,
+            from_location_id,
+            quantity,
+            uom,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ShipTransferOrderRequest {
+    /// Units shipped for serial-tracked lines, one entry per line.
+    #[serde(default)]
+    #[validate(nested)]
+    pub lines: Vec<ShipTransferOrderLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ShipTransferOrderLineRequest {
+    pub line_id: Uuid,
+
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<ShipTransferOrderLineRequest> for TransferShipment {
+    fn from(val: ShipTransferOrderLineRequest) -> Self {
+        let ShipTransferOrderLineRequest {
+            line_id,
+            serial_numbers,
+        } = val;
+
+        TransferShipment {
+            line_id,
+            serial_numbers,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveTransferOrderRequest {
+    #[validate(length(min = 1), nested)]
+    pub lines: Vec<ReceiveTransferOrderLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveTransferOrderLineRequest {
+    pub line_id: Uuid,
+
+    /// Location of the destination warehouse the stock is put away to.
+    pub to_location_id: Uuid,
+
+    #[validate(custom(function = "validate_positive"))]
+    pub quantity: Decimal,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+}
+
+impl From<ReceiveTransferOrderLineRequest> for TransferReceipt {
+    fn from(val: ReceiveTransferOrderLineRequest) -> Self {
+        let ReceiveTransferOrderLineRequest {
+            line_id,
+            to_location_id,
+            quantity,
+            uom,
+            serial_numbers,
+        } = val;
+
+        TransferReceipt {
+            line_id,
+            to_location_id,
+            quantity,
+            uom,
+            serial_numbers,
+        }
+    }
+}
+
+/// `in_transit` is the shipped quantity neither received nor written off, `written_off`
+/// the quantity reported missing when the order was closed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct TransferOrderLineResponse {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub from_location_id: Uuid,
+    pub quantity: Decimal,
+    pub shipped_quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub in_transit: Decimal,
+    pub written_off_quantity: Decimal,
+}
+
+impl TransferOrderLineResponse {
+    pub fn new(line: TransferOrderLine, shipped: bool) -> Self {
+        let in_transit = if shipped {
+            line.outstanding()
+        } else {
+            Decimal::ZERO
+        };
+        let TransferOrderLine {
+            id,
+            transfer_order_id: _,
+            product_id,
+            from_location_id,
+            quantity,
+            received_quantity,
+            written_off_quantity,
+        } = line;
+
+        TransferOrderLineResponse {
+            id,
+            product_id,
+            from_location_id,
+            quantity,
+            shipped_quantity: if shipped { quantity } else { Decimal::ZERO },
+            received_quantity,
+            in_transit,
+            written_off_quantity,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct TransferOrderResponse {
+    pub id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub transit_location_id: Option<Uuid>,
+    pub status: TransferOrderStatus,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub shipped_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub lines: Vec<TransferOrderLineResponse>,
+}
+
+impl TransferOrderResponse {
+    pub fn new(order: TransferOrder, lines: Vec<TransferOrderLine>) -> Self {
+        let TransferOrder {
+            id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            transit_location_id,
+            status,
+            created_by,
+            created_at,
+            shipped_at,
+            closed_at,
+        } = order;
+
+        TransferOrderResponse {
+            id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            transit_location_id,
+            status,
+            created_by,
+            created_at,
+            shipped_at,
+            closed_at,
+            lines: lines
+                .into_iter()
+                .map(|line| TransferOrderLineResponse::new(line, shipped_at.is_some()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReceiveTransferOrderResponse {
+    pub order: TransferOrderResponse,
+    pub movements: Vec<StockMovementResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct InTransitStockResponse {
+    pub transfer_order_id: Uuid,
+    pub line_id: Uuid,
+    pub product_id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub quantity: Decimal,
+    pub shipped_at: Option<DateTime<Utc>>,
+}
+
+impl From<InTransitStock> for InTransitStockResponse {
+    fn from(val: InTransitStock) -> Self {
+        let InTransitStock {
+            transfer_order_id,
+            line_id,
+            product_id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            quantity,
+            shipped_at,
+        } = val;
+
+        InTransitStockResponse {
+            transfer_order_id,
+            line_id,
+            product_id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            quantity,
+            shipped_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct InTransitParams {
+    pub product_id: Option<Uuid>,
+    /// Matches both the source and the destination warehouse.
+    pub warehouse_id: Option<Uuid>,
+}
+
+impl From<InTransitParams> for InTransitQuery {
+    fn from(val: InTransitParams) -> Self {
+        let InTransitParams {
+            product_id,
+            warehouse_id,
+        } = val;
+
+        InTransitQuery {
+            product_id,
+            warehouse_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct TransferDiscrepancyResponse {
+    pub transfer_order_id: Uuid,
+    pub line_id: Uuid,
+    pub product_id: Uuid,
+    pub source_warehouse_id: Uuid,
+    pub destination_warehouse_id: Uuid,
+    pub shipped_quantity: Decimal,
+    pub received_quantity: Decimal,
+    pub missing_quantity: Decimal,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl From<TransferDiscrepancy> for TransferDiscrepancyResponse {
+    fn from(val: TransferDiscrepancy) -> Self {
+        let TransferDiscrepancy {
+            transfer_order_id,
+            line_id,
+            product_id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            shipped_quantity,
+            received_quantity,
+            missing_quantity,
+            closed_at,
+        } = val;
+
+        TransferDiscrepancyResponse {
+            transfer_order_id,
+            line_id,
+            product_id,
+            source_warehouse_id,
+            destination_warehouse_id,
+            shipped_quantity,
+            received_quantity,
+            missing_quantity,
+            closed_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct TransferDiscrepanciesParams {
+    /// Matches both the source and the destination warehouse.
+    pub warehouse_id: Option<Uuid>,
+}
@@ -0,0 +1,67 @@
+use rust_decimal::Decimal;
+use validator::ValidationError;
+
+pub fn validate_positive(value: &Decimal) -> Result<(), ValidationError> {
+    if value.is_sign_negative() || value.is_zero() {
+        return Err(ValidationError::new("positive"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_non_negative(value: &Decimal) -> Result<(), ValidationError> {
+    if value.is_sign_negative() && !value.is_zero() {
+        return Err(ValidationError::new("non_negative"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_serial_numbers(value: &[String]) -> Result<(), ValidationError> {
+    if value
+        .iter()
+        .any(|serial_number| serial_number.is_empty() || serial_number.len() > 128)
+    {
+        return Err(ValidationError::new("serial_numbers"));
+    }
+
+    Ok(())
+}
+
+/// Keys of custom attributes are snake_case identifiers, e.g. `hazard_class`.
+pub fn validate_attribute_key(value: &str) -> Result<(), ValidationError> {
+    let mut chars = value.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        || !chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(ValidationError::new("attribute_key"));
+    }
+
+    Ok(())
+}
+
+pub fn validate_attribute_options(value: &[String]) -> Result<(), ValidationError> {
+    if value
+        .iter()
+        .any(|option| option.is_empty() || option.len() > 128)
+    {
+        return Err(ValidationError::new("attribute_options"));
+    }
+
+    Ok(())
+}
+
+/// File names are sent back in `Content-Disposition` headers, so they hold no path
+/// separators, quotes or control characters.
+pub fn validate_file_name(value: &str) -> Result<(), ValidationError> {
+    if value == "."
+        || value == ".."
+        || value
+            .chars()
+            .any(|c| c.is_control() || matches!(c, '/' | '\\' | '"'))
+    {
+        return Err(ValidationError::new("file_name"));
+    }
+
+    Ok(())
+}
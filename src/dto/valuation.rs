@@ -0,0 +1,90 @@
+use crate::domain::{CostingMethod, ProductValuation, ValuationQuery, ValuationSettings};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ValuationSettingsRequest {
+    /// Costing method of products that do not override it.
+    pub costing_method: CostingMethod,
+}
+
+impl From<ValuationSettingsRequest> for ValuationSettings {
+    fn from(val: ValuationSettingsRequest) -> Self {
+        let ValuationSettingsRequest { costing_method } = val;
+
+        ValuationSettings { costing_method }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ValuationSettingsResponse {
+    pub costing_method: CostingMethod,
+}
+
+impl From<ValuationSettings> for ValuationSettingsResponse {
+    fn from(val: ValuationSettings) -> Self {
+        let ValuationSettings { costing_method } = val;
+
+        ValuationSettingsResponse { costing_method }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SetCostingMethodRequest {
+    /// `null` makes the product follow the company costing method.
+    pub costing_method: Option<CostingMethod>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct ValuationParams {
+    /// Point in time to value stock at. Defaults to now.
+    pub as_of: Option<DateTime<Utc>>,
+    pub product_id: Option<Uuid>,
+}
+
+impl From<ValuationParams> for ValuationQuery {
+    fn from(val: ValuationParams) -> Self {
+        let ValuationParams { as_of, product_id } = val;
+
+        ValuationQuery { as_of, product_id }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ProductValuationResponse {
+    pub product_id: Uuid,
+    pub costing_method: CostingMethod,
+    /// In base units.
+    pub quantity: Decimal,
+    pub value: Decimal,
+    /// Value per base unit, `null` when nothing is on hand.
+    pub unit_cost: Option<Decimal>,
+}
+
+impl From<ProductValuation> for ProductValuationResponse {
+    fn from(val: ProductValuation) -> Self {
+        let ProductValuation {
+            product_id,
+            costing_method,
+            quantity,
+            value,
+            unit_cost,
+        } = val;
+
+        ProductValuationResponse {
+            product_id,
+            costing_method,
+            quantity,
+            value,
+            unit_cost,
+        }
+    }
+}
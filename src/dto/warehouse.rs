@@ -0,0 +1,191 @@
+use crate::domain::{
+    AttributeError, Location, LocationData, LocationQuery, Warehouse, WarehouseData,
+};
+use crate::dto::{attribute_filter, validate_non_negative};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateWarehouseRequest {
+    #[validate(length(min = 1, max = 32))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 256))]
+    pub name: String,
+}
+
+impl From<CreateWarehouseRequest> for WarehouseData {
+    fn from(val: CreateWarehouseRequest) -> Self {
+        let CreateWarehouseRequest { code, name } = val;
+
+        WarehouseData { code, name }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct WarehouseResponse {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+}
+
+impl From<Warehouse> for WarehouseResponse {
+    fn from(val: Warehouse) -> Self {
+        let Warehouse { id, code, name } = val;
+
+        WarehouseResponse { id, code, name }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateLocationRequest {
+    pub warehouse_id: Uuid,
+
+    #[validate(length(min = 1, max = 64))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 32))]
+    pub zone: Option<String>,
+
+    /// Stock in quarantine locations cannot be promised or reserved.
+    #[serde(default)]
+    pub quarantine: bool,
+
+    /// Cubic metres the location holds, unlimited when omitted.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub max_volume: Option<Decimal>,
+
+    /// Kilograms the location holds, unlimited when omitted.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub max_weight: Option<Decimal>,
+
+    /// Position on the floor plan in metres, used to find the nearest location.
+    pub position_x: Option<Decimal>,
+
+    pub position_y: Option<Decimal>,
+
+    /// Walking order of the location on pick paths, ahead of locations without one.
+    pub pick_sequence: Option<i32>,
+
+    /// Values of the custom attributes defined for locations, by key.
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", schema(value_type = Object))]
+    pub attributes: Map<String, Value>,
+}
+
+impl From<CreateLocationRequest> for LocationData {
+    fn from(val: CreateLocationRequest) -> Self {
+        let CreateLocationRequest {
+            warehouse_id,
+            code,
+            zone,
+            quarantine,
+            max_volume,
+            max_weight,
+            position_x,
+            position_y,
+            pick_sequence,
+            attributes,
+        } = val;
+
+        LocationData {
+            warehouse_id,
+            code,
+            zone,
+            quarantine,
+            max_volume,
+            max_weight,
+            position_x,
+            position_y,
+            pick_sequence,
+            attributes,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct LocationsParams {
+    pub warehouse_id: Option<Uuid>,
+    pub zone: Option<String>,
+    /// JSON object of custom attribute values the locations must have, e.g.
+    /// `{"temperature_zone":"frozen"}`.
+    pub attributes: Option<String>,
+}
+
+impl TryFrom<LocationsParams> for LocationQuery {
+    type Error = AttributeError;
+
+    fn try_from(val: LocationsParams) -> Result<Self, Self::Error> {
+        let LocationsParams {
+            warehouse_id,
+            zone,
+            attributes,
+        } = val;
+
+        Ok(LocationQuery {
+            warehouse_id,
+            zone,
+            attributes: attribute_filter(attributes)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct LocationResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub code: String,
+    pub zone: Option<String>,
+    pub in_transit: bool,
+    pub quarantine: bool,
+    pub max_volume: Option<Decimal>,
+    pub max_weight: Option<Decimal>,
+    pub position_x: Option<Decimal>,
+    pub position_y: Option<Decimal>,
+    pub pick_sequence: Option<i32>,
+    #[cfg_attr(feature = "ssr", schema(value_type = Object))]
+    pub attributes: Value,
+}
+
+impl From<Location> for LocationResponse {
+    fn from(val: Location) -> Self {
+        let Location {
+            id,
+            warehouse_id,
+            code,
+            zone,
+            in_transit,
+            quarantine,
+            max_volume,
+            max_weight,
+            position_x,
+            position_y,
+            pick_sequence,
+            attributes,
+        } = val;
+
+        LocationResponse {
+            id,
+            warehouse_id,
+            code,
+            zone,
+            in_transit,
+            quarantine,
+            max_volume,
+            max_weight,
+            position_x,
+            position_y,
+            pick_sequence,
+            attributes,
+        }
+    }
+}
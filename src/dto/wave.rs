@@ -0,0 +1,324 @@
+use crate::domain::{
+    PickConfirmation, PickOrder, PickOrderData, PickOrderQuery, PickOrderStatus, PickTask,
+    PickTaskStatus, Wave, WaveCriteria, WaveStatus,
+};
+use crate::dto::{StockMovementResponse, validate_non_negative, validate_serial_numbers};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ReleasePickOrderRequest {
+    pub warehouse_id: Uuid,
+
+    /// Sales order whose reservations are picked.
+    pub document_id: Uuid,
+
+    /// Higher goes first among orders with the same cut-off.
+    #[serde(default)]
+    pub priority: i32,
+
+    #[validate(length(min = 1, max = 64))]
+    pub carrier: Option<String>,
+
+    /// Latest time the order can be handed to the carrier.
+    pub cutoff_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReleasePickOrderRequest> for PickOrderData {
+    fn from(val: ReleasePickOrderRequest) -> Self {
+        let ReleasePickOrderRequest {
+            warehouse_id,
+            document_id,
+            priority,
+            carrier,
+            cutoff_at,
+        } = val;
+
+        PickOrderData {
+            warehouse_id,
+            document_id,
+            priority,
+            carrier,
+            cutoff_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "ssr", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "ssr", into_params(parameter_in = Query))]
+pub struct PickOrdersParams {
+    pub warehouse_id: Option<Uuid>,
+    pub status: Option<PickOrderStatus>,
+}
+
+impl From<PickOrdersParams> for PickOrderQuery {
+    fn from(val: PickOrdersParams) -> Self {
+        let PickOrdersParams {
+            warehouse_id,
+            status,
+        } = val;
+
+        PickOrderQuery {
+            warehouse_id,
+            status,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PickOrderResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub document_id: Uuid,
+    pub priority: i32,
+    pub carrier: Option<String>,
+    pub cutoff_at: Option<DateTime<Utc>>,
+    pub status: PickOrderStatus,
+    pub wave_id: Option<Uuid>,
+    pub tote: Option<i32>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PickOrder> for PickOrderResponse {
+    fn from(val: PickOrder) -> Self {
+        let PickOrder {
+            id,
+            warehouse_id,
+            document_id,
+            priority,
+            carrier,
+            cutoff_at,
+            status,
+            wave_id,
+            tote,
+            created_by,
+            created_at,
+        } = val;
+
+        PickOrderResponse {
+            id,
+            warehouse_id,
+            document_id,
+            priority,
+            carrier,
+            cutoff_at,
+            status,
+            wave_id,
+            tote,
+            created_by,
+            created_at,
+        }
+    }
+}
+
+/// Every criterion given must match. Orders are taken most urgent first: earliest
+/// cut-off, then highest priority.
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct CreateWaveRequest {
+    pub warehouse_id: Uuid,
+
+    #[validate(length(min = 1, max = 64))]
+    pub carrier: Option<String>,
+
+    /// Only orders with a carrier cut-off at or before this time.
+    pub cutoff_before: Option<DateTime<Utc>>,
+
+    /// Only orders picked entirely from locations of the zone.
+    #[validate(length(min = 1, max = 32))]
+    pub zone: Option<String>,
+
+    pub min_priority: Option<i32>,
+
+    #[validate(range(min = 1))]
+    pub max_orders: Option<u32>,
+
+    /// Cluster picks the wave into this many totes, one order per tote.
+    #[validate(range(min = 1, max = 99))]
+    pub totes: Option<i32>,
+}
+
+impl From<CreateWaveRequest> for WaveCriteria {
+    fn from(val: CreateWaveRequest) -> Self {
+        let CreateWaveRequest {
+            warehouse_id,
+            carrier,
+            cutoff_before,
+            zone,
+            min_priority,
+            max_orders,
+            totes,
+        } = val;
+
+        WaveCriteria {
+            warehouse_id,
+            carrier,
+            cutoff_before,
+            zone,
+            min_priority,
+            max_orders: max_orders.map(|max_orders| max_orders as usize),
+            totes,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct PickTaskResponse {
+    pub id: Uuid,
+    pub wave_id: Uuid,
+    pub pick_order_id: Uuid,
+    pub reservation_id: Uuid,
+    pub product_id: Uuid,
+    pub location_id: Uuid,
+    pub quantity: Decimal,
+    pub picked_quantity: Option<Decimal>,
+    /// Position of the task along the pick path, from 1.
+    pub sequence: i32,
+    pub tote: Option<i32>,
+    pub status: PickTaskStatus,
+    pub picked_by: Option<Uuid>,
+    pub picked_at: Option<DateTime<Utc>>,
+}
+
+impl From<PickTask> for PickTaskResponse {
+    fn from(val: PickTask) -> Self {
+        let PickTask {
+            id,
+            wave_id,
+            pick_order_id,
+            reservation_id,
+            product_id,
+            location_id,
+            quantity,
+            picked_quantity,
+            sequence,
+            tote,
+            status,
+            picked_by,
+            picked_at,
+        } = val;
+
+        PickTaskResponse {
+            id,
+            wave_id,
+            pick_order_id,
+            reservation_id,
+            product_id,
+            location_id,
+            quantity,
+            picked_quantity,
+            sequence,
+            tote,
+            status,
+            picked_by,
+            picked_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct WaveResponse {
+    pub id: Uuid,
+    pub warehouse_id: Uuid,
+    pub status: WaveStatus,
+    pub totes: Option<i32>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub orders: Vec<PickOrderResponse>,
+    /// In pick path order.
+    pub tasks: Vec<PickTaskResponse>,
+}
+
+impl WaveResponse {
+    pub fn new(wave: Wave, orders: Vec<PickOrder>, tasks: Vec<PickTask>) -> Self {
+        let Wave {
+            id,
+            warehouse_id,
+            status,
+            totes,
+            created_by,
+            created_at,
+            completed_at,
+        } = wave;
+
+        WaveResponse {
+            id,
+            warehouse_id,
+            status,
+            totes,
+            created_by,
+            created_at,
+            completed_at,
+            orders: orders.into_iter().map(Into::into).collect(),
+            tasks: tasks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ConfirmPickRequest {
+    /// Quantity picked. Defaults to the quantity of the task, less is a short pick.
+    #[validate(custom(function = "validate_non_negative"))]
+    pub quantity: Option<Decimal>,
+
+    /// Unit of measure the quantity is given in. Defaults to the product's base UoM.
+    #[validate(length(min = 1, max = 16))]
+    pub uom: Option<String>,
+
+    /// Picks a specific lot, which is otherwise allocated first-expired-first-out.
+    #[validate(length(min = 1, max = 64))]
+    pub lot_number: Option<String>,
+
+    pub expiry_date: Option<NaiveDate>,
+
+    /// One per unit for serial-tracked products.
+    #[serde(default)]
+    #[validate(custom(function = "validate_serial_numbers"))]
+    pub serial_numbers: Vec<String>,
+
+    /// GS1 element string scanned from the goods picked. Unit, lot, expiry date and serial
+    /// number are taken from it where not given; its GTIN must be a barcode of the task's
+    /// product.
+    #[validate(length(min = 1, max = 512))]
+    pub scan: Option<String>,
+}
+
+impl From<ConfirmPickRequest> for PickConfirmation {
+    fn from(val: ConfirmPickRequest) -> Self {
+        let ConfirmPickRequest {
+            quantity,
+            uom,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+            scan,
+        } = val;
+
+        PickConfirmation {
+            quantity,
+            uom,
+            lot_number,
+            expiry_date,
+            serial_numbers,
+            scan,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ConfirmPickResponse {
+    pub task: PickTaskResponse,
+    pub movements: Vec<StockMovementResponse>,
+}
@@ -0,0 +1,63 @@
+//! Printable labels: a template per label kind lays out text and barcodes in printer dots,
+//! which are then rendered as SVG, PNG or ZPL. Rendering is deterministic, the same label
+//! always produces the same bytes.
+
+use crate::domain::{Gs1Element, LabelFormat};
+use anyhow::Result;
+
+mod code128;
+mod qr;
+mod render;
+mod template;
+
+pub use template::*;
+
+/// A label laid out in dots at 203 dpi, the resolution of common thermal printers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Label {
+    pub width: u32,
+    pub height: u32,
+    pub elements: Vec<Element>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Element {
+    /// A single line of text, `x` and `y` being its top left corner.
+    Text {
+        x: u32,
+        y: u32,
+        height: u32,
+        text: String,
+    },
+    /// A linear barcode with its quiet zone starting at `x`, bars being `module` dots wide.
+    Barcode {
+        x: u32,
+        y: u32,
+        height: u32,
+        module: u32,
+        symbology: Symbology,
+    },
+    /// A QR code with its quiet zone starting at `x` and `y`.
+    QrCode {
+        x: u32,
+        y: u32,
+        module: u32,
+        data: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Symbology {
+    Code128(String),
+    Gs1_128(Vec<Gs1Element>),
+}
+
+impl Label {
+    pub fn render(&self, format: LabelFormat) -> Result<Vec<u8>> {
+        match format {
+            LabelFormat::Svg => Ok(render::svg(self)?.into_bytes()),
+            LabelFormat::Png => render::png(self),
+            LabelFormat::Zpl => Ok(render::zpl(self).into_bytes()),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::domain::{Gs1Element, LabelError, gs1_predefined_length};
+use crate::label::Symbology;
+
+/// Modules of white space required on either side of the symbol.
+pub(super) const QUIET_ZONE: u32 = 10;
+
+/// Bar and space widths of every symbol value, starting with a bar.
+const PATTERNS: [&[u8]; 107] = [
+    b"212222", b"222122", b"222221", b"121223", b"121322", b"131222", b"122213", b"122312",
+    b"132212", b"221213", b"221312", b"231212", b"112232", b"122132", b"122231", b"113222",
+    b"123122", b"123221", b"223211", b"221132", b"221231", b"213212", b"223112", b"312131",
+    b"311222", b"321122", b"321221", b"312212", b"322112", b"322211", b"212123", b"212321",
+    b"232121", b"111323", b"131123", b"131321", b"112313", b"132113", b"132311", b"211313",
+    b"231113", b"231311", b"112133", b"112331", b"132131", b"113123", b"113321", b"133121",
+    b"313121", b"211331", b"231131", b"213113", b"213311", b"213131", b"311123", b"311321",
+    b"331121", b"312113", b"312311", b"332111", b"314111", b"221411", b"431111", b"111224",
+    b"111422", b"121124", b"121421", b"141122", b"141221", b"112214", b"112412", b"122114",
+    b"122411", b"142112", b"142211", b"241211", b"221114", b"413111", b"241112", b"134111",
+    b"111242", b"121142", b"121241", b"114212", b"124112", b"124211", b"411212", b"421112",
+    b"421211", b"212141", b"214121", b"412121", b"111143", b"111341", b"131141", b"114113",
+    b"114311", b"411113", b"411311", b"113141", b"114131", b"311141", b"411131", b"211412",
+    b"211214", b"211232", b"2331112",
+];
+
+const CODE_C: u8 = 99;
+const CODE_B: u8 = 100;
+const FNC1: u8 = 102;
+const START_B: u8 = 104;
+const START_C: u8 = 105;
+const STOP: u8 = 106;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symbol {
+    Fnc1,
+    Char(u8),
+}
+
+/// Encodes the data as Code 128 modules, `true` being a bar. The quiet zone is not included.
+pub(super) fn encode(symbology: &Symbology) -> Result<Vec<bool>, LabelError> {
+    let symbols = match symbology {
+        Symbology::Code128(data) => characters(data)?,
+        Symbology::Gs1_128(elements) => gs1_symbols(elements)?,
+    };
+
+    let mut codes = codes(&symbols);
+    let checksum = codes
+        .iter()
+        .enumerate()
+        .map(|(position, code)| position.max(1) as u32 * *code as u32)
+        .sum::<u32>()
+        % 103;
+    codes.push(checksum as u8);
+    codes.push(STOP);
+
+    let mut modules = Vec::new();
+    for code in codes {
+        for (position, width) in PATTERNS[code as usize].iter().enumerate() {
+            let bar = position % 2 == 0;
+            modules.extend(std::iter::repeat_n(bar, (width - b'0') as usize));
+        }
+    }
+
+    Ok(modules)
+}
+
+fn characters(data: &str) -> Result<Vec<Symbol>, LabelError> {
+    data.chars()
+        .map(|c| match c {
+            ' '..='~' => Ok(Symbol::Char(c as u8)),
+            _ => Err(LabelError::UnsupportedCharacter(c)),
+        })
+        .collect()
+}
+
+/// GS1-128 starts with FNC1 and separates elements of variable length with another FNC1.
+fn gs1_symbols(elements: &[Gs1Element]) -> Result<Vec<Symbol>, LabelError> {
+    let mut symbols = vec![Symbol::Fnc1];
+    for (position, element) in elements.iter().enumerate() {
+        symbols.extend(characters(&element.ai)?);
+        symbols.extend(characters(&element.value)?);
+        if position + 1 < elements.len() && gs1_predefined_length(&element.ai).is_none() {
+            symbols.push(Symbol::Fnc1);
+        }
+    }
+
+    Ok(symbols)
+}
+
+fn digit_run(symbols: &[Symbol]) -> usize {
+    symbols
+        .iter()
+        .take_while(|symbol| matches!(symbol, Symbol::Char(b'0'..=b'9')))
+        .count()
+}
+
+/// Picks code set C for runs of digits long enough to pay for the switch and B otherwise.
+fn codes(symbols: &[Symbol]) -> Vec<u8> {
+    let lead = symbols
+        .iter()
+        .take_while(|symbol| **symbol == Symbol::Fnc1)
+        .count();
+    let run = digit_run(&symbols[lead..]);
+    let mut code_c = run >= 4 || (run == 2 && lead + run == symbols.len());
+
+    let mut codes = vec![if code_c { START_C } else { START_B }];
+    let mut position = 0;
+    while position < symbols.len() {
+        let run = digit_run(&symbols[position..]);
+        match symbols[position] {
+            Symbol::Fnc1 => {
+                codes.push(FNC1);
+                position += 1;
+            }
+            Symbol::Char(first) if code_c => {
+                if run >= 2 {
+                    let Symbol::Char(second) = symbols[position + 1] else {
+                        unreachable!("digit run continues");
+                    };
+                    codes.push((first - b'0') * 10 + (second - b'0'));
+                    position += 2;
+                } else {
+                    codes.push(CODE_B);
+                    code_c = false;
+                }
+            }
+            Symbol::Char(c) => {
+                if run >= 6 || (run >= 4 && position + run == symbols.len()) {
+                    if run % 2 == 1 {
+                        codes.push(c - b' ');
+                        position += 1;
+                    }
+                    codes.push(CODE_C);
+                    code_c = true;
+                } else {
+                    codes.push(c - b' ');
+                    position += 1;
+                }
+            }
+        }
+    }
+
+    codes
+}
@@ -0,0 +1,17 @@
+use crate::domain::LabelError;
+use qrcode::{Color, EcLevel, QrCode};
+
+/// Modules of white space required around the symbol.
+pub(super) const QUIET_ZONE: u32 = 4;
+
+/// Encodes the data as rows of QR modules, `true` being dark. The quiet zone is not included.
+pub(super) fn encode(data: &str) -> Result<Vec<Vec<bool>>, LabelError> {
+    let code = QrCode::with_error_correction_level(data, EcLevel::M)
+        .map_err(|_| LabelError::DataTooLong)?;
+
+    Ok(code
+        .to_colors()
+        .chunks(code.width())
+        .map(|row| row.iter().map(|color| *color == Color::Dark).collect())
+        .collect())
+}
@@ -0,0 +1,222 @@
+use crate::domain::{LabelError, gs1_human_readable};
+use crate::label::{Element, Label, Symbology, code128, qr};
+use anyhow::{Context, Result};
+use std::fmt::Write;
+
+/// A filled rectangle in dots.
+struct Rect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// Dark areas of the label's barcodes, each run of adjacent dark modules in a row merged.
+fn rects(element: &Element) -> Result<Vec<Rect>, LabelError> {
+    match element {
+        Element::Text { .. } => Ok(Vec::new()),
+        Element::Barcode {
+            x,
+            y,
+            height,
+            module,
+            symbology,
+        } => {
+            let modules = code128::encode(symbology)?;
+            Ok(runs(&modules)
+                .map(|(start, len)| Rect {
+                    x: x + (code128::QUIET_ZONE + start) * module,
+                    y: *y,
+                    width: len * module,
+                    height: *height,
+                })
+                .collect())
+        }
+        Element::QrCode { x, y, module, data } => {
+            let rows = qr::encode(data)?;
+            Ok(rows
+                .iter()
+                .enumerate()
+                .flat_map(|(row, modules)| {
+                    runs(modules)
+                        .map(move |(start, len)| Rect {
+                            x: x + (qr::QUIET_ZONE + start) * module,
+                            y: y + (qr::QUIET_ZONE + row as u32) * module,
+                            width: len * module,
+                            height: *module,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect())
+        }
+    }
+}
+
+/// Start and length of every run of `true`.
+fn runs(modules: &[bool]) -> impl Iterator<Item = (u32, u32)> + '_ {
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        let start = position + modules[position..].iter().position(|dark| *dark)?;
+        let len = modules[start..].iter().take_while(|dark| **dark).count();
+        position = start + len;
+        Some((start as u32, len as u32))
+    })
+}
+
+fn escape_xml(text: &str) -> String {
+    text.chars().fold(String::new(), |mut out, c| {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+        out
+    })
+}
+
+pub(super) fn svg(label: &Label) -> Result<String, LabelError> {
+    let Label {
+        width,
+        height,
+        elements,
+    } = label;
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+    );
+    let _ = write!(
+        out,
+        r##"<rect width="{width}" height="{height}" fill="#fff"/>"##
+    );
+
+    for element in elements {
+        if let Element::Text { x, y, height, text } = element {
+            let _ = write!(
+                out,
+                r#"<text x="{x}" y="{y}" font-family="monospace" font-size="{height}" dominant-baseline="hanging">{}</text>"#,
+                escape_xml(text)
+            );
+        }
+
+        for Rect {
+            x,
+            y,
+            width,
+            height,
+        } in rects(element)?
+        {
+            let _ = write!(
+                out,
+                r#"<rect x="{x}" y="{y}" width="{width}" height="{height}"/>"#
+            );
+        }
+    }
+    out.push_str("</svg>");
+
+    Ok(out)
+}
+
+/// Grayscale image of the barcodes. Text is left out, it needs a font and is better set by
+/// whatever embeds the image.
+pub(super) fn png(label: &Label) -> Result<Vec<u8>> {
+    let (width, height) = (label.width as usize, label.height as usize);
+    let mut pixels = vec![u8::MAX; width * height];
+
+    for element in &label.elements {
+        for rect in rects(element)? {
+            for y in rect.y as usize..((rect.y + rect.height) as usize).min(height) {
+                let row = y * width;
+                let end = ((rect.x + rect.width) as usize).min(width);
+                if let Some(pixels) = pixels.get_mut(row + rect.x as usize..row + end) {
+                    pixels.fill(0);
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, label.width, label.height);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| {
+            writer.write_image_data(&pixels)?;
+            writer.finish()
+        })
+        .context("Failed to encode PNG")?;
+
+    Ok(out)
+}
+
+/// Field data with the characters ZPL treats as commands hex-escaped, for use after `^FH`.
+fn escape_zpl(text: &str) -> String {
+    text.chars().fold(String::new(), |mut out, c| {
+        match c {
+            '_' => out.push_str("_5F"),
+            '^' => out.push_str("_5E"),
+            '~' => out.push_str("_7E"),
+            _ => out.push(c),
+        }
+        out
+    })
+}
+
+/// Barcodes are left to the printer's own symbologies, it prints them sharper than an image.
+pub(super) fn zpl(label: &Label) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "^XA");
+    let _ = writeln!(out, "^CI28");
+    let _ = writeln!(out, "^PW{}", label.width);
+    let _ = writeln!(out, "^LL{}", label.height);
+
+    for element in &label.elements {
+        let _ = match element {
+            Element::Text { x, y, height, text } => writeln!(
+                out,
+                "^FO{x},{y}^A0N,{height},{height}^FH^FD{}^FS",
+                escape_zpl(text)
+            ),
+            Element::Barcode {
+                x,
+                y,
+                height,
+                module,
+                symbology: Symbology::Code128(data),
+            } => writeln!(
+                out,
+                "^FO{},{y}^BY{module}^BCN,{height},N,N,N,A^FH^FD{}^FS",
+                x + code128::QUIET_ZONE * module,
+                escape_zpl(data)
+            ),
+            Element::Barcode {
+                x,
+                y,
+                height,
+                module,
+                symbology: Symbology::Gs1_128(elements),
+            } => writeln!(
+                out,
+                "^FO{},{y}^BY{module}^BCN,{height},N,N,N,D^FH^FD{}^FS",
+                x + code128::QUIET_ZONE * module,
+                escape_zpl(&gs1_human_readable(elements))
+            ),
+            Element::QrCode { x, y, module, data } => writeln!(
+                out,
+                "^FO{},{}^BQN,2,{}^FH^FDMA,{}^FS",
+                x + qr::QUIET_ZONE * module,
+                y + qr::QUIET_ZONE * module,
+                module.min(&10),
+                escape_zpl(data)
+            ),
+        };
+    }
+
+    let _ = writeln!(out, "^XZ");
+    out
+}
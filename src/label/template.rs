@@ -0,0 +1,180 @@
+use crate::domain::{
+    Gs1Element, LabelError, Location, Pallet, Product, Shipment, Warehouse, gs1_human_readable,
+};
+use crate::label::{Element, Label, Symbology, code128, qr};
+
+/// Four inches at 203 dpi, the common width of thermal labels.
+const WIDTH: u32 = 812;
+const MARGIN: u32 = 20;
+
+/// Fits a linear barcode into `max_width` with the widest bars that allow it.
+fn barcode(
+    x: u32,
+    y: u32,
+    height: u32,
+    max_width: u32,
+    symbology: Symbology,
+) -> Result<Element, LabelError> {
+    let modules = code128::encode(&symbology)?.len() as u32 + 2 * code128::QUIET_ZONE;
+
+    Ok(Element::Barcode {
+        x,
+        y,
+        height,
+        module: (max_width / modules).clamp(1, 4),
+        symbology,
+    })
+}
+
+/// Fits a QR code into a square of `max_size`.
+fn qr_code(x: u32, y: u32, max_size: u32, data: String) -> Result<Element, LabelError> {
+    let modules = qr::encode(&data)?.len() as u32 + 2 * qr::QUIET_ZONE;
+
+    Ok(Element::QrCode {
+        x,
+        y,
+        module: (max_size / modules).clamp(1, 10),
+        data,
+    })
+}
+
+fn text(x: u32, y: u32, height: u32, text: impl Into<String>) -> Element {
+    Element::Text {
+        x,
+        y,
+        height,
+        text: text.into(),
+    }
+}
+
+/// 4x2" shelf label with the location code as Code 128.
+pub fn location_label(warehouse: &Warehouse, location: &Location) -> Result<Label, LabelError> {
+    let heading = match &location.zone {
+        Some(zone) => format!("{} / {}", warehouse.code, zone),
+        None => warehouse.code.clone(),
+    };
+
+    Ok(Label {
+        width: WIDTH,
+        height: 406,
+        elements: vec![
+            text(MARGIN, MARGIN, 30, heading),
+            text(MARGIN, 70, 90, &location.code),
+            barcode(
+                0,
+                190,
+                180,
+                WIDTH,
+                Symbology::Code128(location.code.clone()),
+            )?,
+        ],
+    })
+}
+
+/// 4x2" item label with the SKU both as Code 128 and QR code.
+pub fn product_label(product: &Product) -> Result<Label, LabelError> {
+    let qr_size = 230;
+
+    Ok(Label {
+        width: WIDTH,
+        height: 406,
+        elements: vec![
+            text(
+                MARGIN,
+                MARGIN,
+                40,
+                product.name.chars().take(36).collect::<String>(),
+            ),
+            text(MARGIN, 80, 60, &product.sku),
+            barcode(
+                0,
+                180,
+                200,
+                WIDTH - qr_size,
+                Symbology::Code128(product.sku.clone()),
+            )?,
+            qr_code(WIDTH - qr_size, 160, qr_size, product.sku.clone())?,
+        ],
+    })
+}
+
+/// 4x6" logistic label with the SSCC as GS1-128, following the GS1 logistic label layout
+/// of text on top and the barcode at the bottom.
+pub fn pallet_label(pallet: &Pallet, location: Option<&Location>) -> Result<Label, LabelError> {
+    let sscc = vec![Gs1Element::new("00", &pallet.sscc)];
+    let human_readable = gs1_human_readable(&sscc);
+
+    let mut elements = vec![
+        text(MARGIN, MARGIN, 40, "SSCC"),
+        text(MARGIN, 80, 60, &human_readable),
+    ];
+    if let Some(location) = location {
+        elements.push(text(MARGIN, 180, 40, format!("Location {}", location.code)));
+    }
+    elements.push(barcode(0, 850, 300, WIDTH, Symbology::Gs1_128(sscc))?);
+    elements.push(text(MARGIN, 1160, 40, human_readable));
+
+    Ok(Label {
+        width: WIDTH,
+        height: 1218,
+        elements,
+    })
+}
+
+/// 4x6" parcel label with the ship-to address on top and the tracking number as Code 128
+/// at the bottom.
+pub fn parcel_label(
+    shipment: &Shipment,
+    carrier: &str,
+    service: &str,
+    tracking_number: &str,
+) -> Result<Label, LabelError> {
+    let mut elements = vec![
+        text(
+            MARGIN,
+            MARGIN,
+            40,
+            format!("{} {}", carrier, service).to_uppercase(),
+        ),
+        text(MARGIN, 120, 30, "SHIP TO"),
+        text(
+            MARGIN,
+            160,
+            40,
+            shipment.ship_to_name.chars().take(36).collect::<String>(),
+        ),
+    ];
+    let mut y = 210;
+    for line in shipment.ship_to_address.lines().take(4) {
+        elements.push(text(
+            MARGIN,
+            y,
+            40,
+            line.chars().take(36).collect::<String>(),
+        ));
+        y += 50;
+    }
+    elements.push(text(
+        MARGIN,
+        y,
+        40,
+        format!(
+            "{} {}",
+            shipment.ship_to_postal_code, shipment.ship_to_country
+        ),
+    ));
+    elements.push(barcode(
+        0,
+        850,
+        300,
+        WIDTH,
+        Symbology::Code128(tracking_number.to_string()),
+    )?);
+    elements.push(text(MARGIN, 1160, 40, tracking_number));
+
+    Ok(Label {
+        width: WIDTH,
+        height: 1218,
+        elements,
+    })
+}
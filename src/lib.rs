@@ -1,6 +1,8 @@
 #[cfg(feature = "ssr")]
 pub mod apidoc;
 #[cfg(feature = "ssr")]
+pub mod carrier;
+#[cfg(feature = "ssr")]
 pub mod config;
 pub mod contract;
 #[cfg(feature = "ssr")]
@@ -10,6 +12,8 @@ pub mod dependency;
 pub mod domain;
 pub mod dto;
 #[cfg(feature = "ssr")]
+pub mod label;
+#[cfg(feature = "ssr")]
 pub mod repository;
 #[cfg(feature = "ssr")]
 pub mod rest;
@@ -19,5 +23,7 @@ pub mod server;
 pub mod service;
 #[cfg(feature = "ssr")]
 pub mod state;
+#[cfg(feature = "ssr")]
+pub mod storage;
 pub mod telemetry;
 pub mod web;
@@ -1,27 +1,32 @@
 #[cfg(feature = "ssr")]
 #[tokio::main]
-async fn main() {
+async fn main() -> anyhow::Result<()> {
+    use anyhow::Context;
     use tokio::net::TcpListener;
     use warehouse::config::get_configuration;
     use warehouse::dependency::AppContainer;
     use warehouse::server;
+    use warehouse::storage::storage;
     use warehouse::telemetry::{get_subscriber, init_subscriber};
 
     let subscriber = get_subscriber("warehouse".into(), "info".into(), std::io::stdout);
     init_subscriber(subscriber);
 
-    let conf = get_configuration().expect("Failed to read configuration");
+    let conf = get_configuration().context("Failed to read configuration")?;
+    let storage = storage(&conf.storage).context("Failed to configure attachment storage")?;
 
-    let dependency = AppContainer::new(conf);
+    let dependency = AppContainer::new(conf, storage);
     let leptos_options = leptos::config::get_configuration(None)
-        .expect("leptos configuration")
+        .context("leptos configuration")?
         .leptos_options;
 
     let listener = TcpListener::bind(leptos_options.site_addr)
         .await
-        .expect("Failed to bind");
+        .context("Failed to bind")?;
 
     server::run(leptos_options, dependency, listener).await;
+
+    Ok(())
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -2,15 +2,115 @@ use crate::domain::RepositoryError;
 use anyhow::anyhow;
 use diesel::result::{DatabaseErrorKind, Error};
 
+/// Narrows a boxed list query by a `Comparison` of a list filter on the column.
+macro_rules! compare {
+    ($query:expr, $column:expr, $comparison:expr) => {{
+        let comparison = $comparison;
+        match comparison.op {
+            $crate::domain::FilterOp::Eq => $query.filter($column.eq(comparison.value)),
+            $crate::domain::FilterOp::Ne => $query.filter($column.ne(comparison.value)),
+            $crate::domain::FilterOp::Gt => $query.filter($column.gt(comparison.value)),
+            $crate::domain::FilterOp::Gte => $query.filter($column.ge(comparison.value)),
+            $crate::domain::FilterOp::Lt => $query.filter($column.lt(comparison.value)),
+            $crate::domain::FilterOp::Lte => $query.filter($column.le(comparison.value)),
+        }
+    }};
+}
+
+/// Orders a boxed list query by the sort column, of Rust type `$ty`, and then by id, and
+/// limits it to the rows following the cursor row plus one telling whether another page
+/// follows. Sort columns must not be nullable.
+macro_rules! paginate {
+    ($query:expr, $table:expr, $column:expr, $ty:ty, $id:expr, $list:expr, $conn:expr) => {{
+        let mut paged = $query;
+        let direction = $list.sort.direction;
+
+        if let Some(after) = $list.after {
+            let value = $table
+                .find(after)
+                .select($column)
+                .first::<$ty>($conn)
+                .await
+                .optional()
+                .map_err($crate::repository::postgresql::map_diesel_error)?
+                .ok_or($crate::domain::ListError::InvalidCursor)?;
+            paged = match direction {
+                $crate::domain::SortDirection::Asc => paged.filter(
+                    $column
+                        .gt(value.clone())
+                        .or($column.eq(value).and($id.gt(after))),
+                ),
+                $crate::domain::SortDirection::Desc => paged.filter(
+                    $column
+                        .lt(value.clone())
+                        .or($column.eq(value).and($id.lt(after))),
+                ),
+            };
+        }
+
+        match direction {
+            $crate::domain::SortDirection::Asc => paged.order(($column.asc(), $id.asc())),
+            $crate::domain::SortDirection::Desc => paged.order(($column.desc(), $id.desc())),
+        }
+        .limit($list.limit + 1)
+    }};
+}
+
+mod abc;
+mod attachment;
+mod attribute;
+mod business_partner;
+mod cross_dock;
+mod kit;
+mod lot;
 pub mod models;
+mod organization;
+mod pallet;
+mod product;
+mod putaway;
+mod replenishment;
+mod reservation;
+mod returns;
 mod role;
 mod rule;
 pub mod schema;
+mod serial;
+mod shipment;
+mod snapshot;
+mod stock;
+mod stock_count;
+mod transfer;
 mod user;
+mod valuation;
+mod warehouse;
+mod wave;
 
+pub use abc::*;
+pub use attachment::*;
+pub use attribute::*;
+pub use business_partner::*;
+pub use cross_dock::*;
+pub use kit::*;
+pub use lot::*;
+pub use organization::*;
+pub use pallet::*;
+pub use product::*;
+pub use putaway::*;
+pub use replenishment::*;
+pub use reservation::*;
+pub use returns::*;
 pub use role::*;
 pub use rule::*;
+pub use serial::*;
+pub use shipment::*;
+pub use snapshot::*;
+pub use stock::*;
+pub use stock_count::*;
+pub use transfer::*;
 pub use user::*;
+pub use valuation::*;
+pub use warehouse::*;
+pub use wave::*;
 
 pub fn map_diesel_error(err: Error) -> anyhow::Error {
     match err {
@@ -0,0 +1,303 @@
+use crate::contract::repository::{AbcRepository, Repository};
+use crate::domain::{AbcClass, AbcCriterion, MovementKind, SlottingStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    abc_analyses, abc_analysis_lines, locations, products, slotting_recommendations,
+    stock_movements,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use diesel::dsl::{self, count};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Stays well below the bind parameter limit of a single insert.
+const LINE_BATCH_SIZE: usize = 1000;
+
+pub struct PostgresAbcRepository {
+    pool: db::Pool,
+}
+
+impl PostgresAbcRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::AbcAnalysis> for PostgresAbcRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::AbcAnalysis) -> Result<domain::AbcAnalysis> {
+        diesel::insert_into(abc_analyses::table)
+            .values(val)
+            .returning(domain::AbcAnalysis::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::AbcAnalysis> {
+        abc_analyses::table
+            .find(id)
+            .select(domain::AbcAnalysis::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl AbcRepository for PostgresAbcRepository {
+    #[tracing::instrument(skip(self))]
+    async fn product_scores(
+        &self,
+        warehouse_id: Uuid,
+        criterion: AbcCriterion,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<domain::ProductScore>> {
+        let conn = &mut self.get_connection().await?;
+        let issues = stock_movements::table
+            .filter(stock_movements::kind.eq(MovementKind::Issue))
+            .filter(stock_movements::created_at.gt(from))
+            .filter(stock_movements::created_at.le(to))
+            .filter(
+                stock_movements::from_location_id.eq_any(
+                    locations::table
+                        .filter(locations::warehouse_id.eq(warehouse_id))
+                        .select(locations::id.nullable()),
+                ),
+            )
+            .group_by(stock_movements::product_id);
+
+        let scores = match criterion {
+            AbcCriterion::PickFrequency => issues
+                .select((stock_movements::product_id, count(stock_movements::id)))
+                .load::<(Uuid, i64)>(conn)
+                .await
+                .map_err(map_diesel_error)?
+                .into_iter()
+                .map(|(product_id, picks)| domain::ProductScore {
+                    product_id,
+                    score: Decimal::from(picks),
+                })
+                .collect(),
+            AbcCriterion::Value => issues
+                .select((
+                    stock_movements::product_id,
+                    dsl::sum(stock_movements::total_cost),
+                ))
+                .load::<(Uuid, Option<Decimal>)>(conn)
+                .await
+                .map_err(map_diesel_error)?
+                .into_iter()
+                .map(|(product_id, value)| domain::ProductScore {
+                    product_id,
+                    score: value.unwrap_or_default(),
+                })
+                .collect(),
+        };
+
+        Ok(scores)
+    }
+
+    #[tracing::instrument(skip(self, analysis, lines, recommendations), fields(id = %analysis.id))]
+    async fn save(
+        &self,
+        analysis: domain::AbcAnalysis,
+        lines: Vec<domain::AbcAnalysisLine>,
+        recommendations: Vec<domain::SlottingRecommendation>,
+    ) -> Result<domain::AbcAnalysis> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                diesel::update(
+                    slotting_recommendations::table
+                        .filter(slotting_recommendations::warehouse_id.eq(analysis.warehouse_id))
+                        .filter(slotting_recommendations::status.eq(SlottingStatus::Pending)),
+                )
+                .set(slotting_recommendations::status.eq(SlottingStatus::Superseded))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                let analysis = diesel::insert_into(abc_analyses::table)
+                    .values(analysis)
+                    .returning(domain::AbcAnalysis::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                for batch in lines.chunks(LINE_BATCH_SIZE) {
+                    diesel::insert_into(abc_analysis_lines::table)
+                        .values(batch)
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+                if !recommendations.is_empty() {
+                    diesel::insert_into(slotting_recommendations::table)
+                        .values(recommendations)
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                Ok(analysis)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::AbcAnalysisQuery) -> Result<Vec<domain::AbcAnalysis>> {
+        let mut analyses = abc_analyses::table
+            .select(domain::AbcAnalysis::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            analyses = analyses.filter(abc_analyses::warehouse_id.eq(warehouse_id));
+        }
+
+        analyses
+            .order((abc_analyses::created_at.desc(), abc_analyses::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_lines(&self, analysis_id: Uuid) -> Result<Vec<domain::AbcAnalysisLine>> {
+        abc_analysis_lines::table
+            .filter(abc_analysis_lines::analysis_id.eq(analysis_id))
+            .order(abc_analysis_lines::rank)
+            .select(domain::AbcAnalysisLine::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, analysis), fields(id = %analysis.id))]
+    async fn apply(&self, analysis: domain::AbcAnalysis) -> Result<domain::AbcAnalysis> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                for abc_class in [AbcClass::A, AbcClass::B, AbcClass::C] {
+                    diesel::update(
+                        products::table.filter(
+                            products::id.eq_any(
+                                abc_analysis_lines::table
+                                    .filter(abc_analysis_lines::analysis_id.eq(analysis.id))
+                                    .filter(abc_analysis_lines::abc_class.eq(abc_class))
+                                    .select(abc_analysis_lines::product_id),
+                            ),
+                        ),
+                    )
+                    .set(products::abc_class.eq(Some(abc_class)))
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                }
+
+                diesel::update(abc_analyses::table.find(analysis.id))
+                    .set(abc_analyses::applied_at.eq(analysis.applied_at))
+                    .returning(domain::AbcAnalysis::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_recommendation(&self, id: Uuid) -> Result<domain::SlottingRecommendation> {
+        slotting_recommendations::table
+            .find(id)
+            .select(domain::SlottingRecommendation::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_recommendations(
+        &self,
+        query: domain::SlottingRecommendationQuery,
+    ) -> Result<Vec<domain::SlottingRecommendation>> {
+        let mut recommendations = slotting_recommendations::table
+            .select(domain::SlottingRecommendation::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            recommendations =
+                recommendations.filter(slotting_recommendations::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(analysis_id) = query.analysis_id {
+            recommendations =
+                recommendations.filter(slotting_recommendations::analysis_id.eq(analysis_id));
+        }
+        if let Some(status) = query.status {
+            recommendations = recommendations.filter(slotting_recommendations::status.eq(status));
+        }
+
+        recommendations
+            .order((slotting_recommendations::rank, slotting_recommendations::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, recommendation, movements), fields(id = %recommendation.id))]
+    async fn accept_recommendation(
+        &self,
+        recommendation: domain::SlottingRecommendation,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<domain::SlottingRecommendation> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                for movement in movements {
+                    apply_movement(conn, movement, &[], true).await?;
+                }
+
+                // Fails with `NotFound` if the recommendation is no longer pending, so
+                // concurrent reviews cannot both succeed.
+                diesel::update(
+                    slotting_recommendations::table
+                        .find(recommendation.id)
+                        .filter(slotting_recommendations::status.eq(SlottingStatus::Pending)),
+                )
+                .set((
+                    slotting_recommendations::status.eq(recommendation.status),
+                    slotting_recommendations::reviewed_by.eq(recommendation.reviewed_by),
+                    slotting_recommendations::reviewed_at.eq(recommendation.reviewed_at),
+                ))
+                .returning(domain::SlottingRecommendation::as_returning())
+                .get_result(conn)
+                .await
+                .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
@@ -0,0 +1,118 @@
+use crate::contract::repository::{AttachmentRepository, Repository};
+use crate::domain::AttachmentOwner;
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    attachments, business_partners, locations, products, return_authorizations, shipments,
+    stock_movements,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::dsl::exists;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+pub struct PostgresAttachmentRepository {
+    pool: db::Pool,
+}
+
+impl PostgresAttachmentRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Attachment> for PostgresAttachmentRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Attachment) -> Result<domain::Attachment> {
+        diesel::insert_into(attachments::table)
+            .values(val)
+            .returning(domain::Attachment::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Attachment> {
+        attachments::table
+            .find(id)
+            .select(domain::Attachment::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    #[tracing::instrument(skip(self))]
+    async fn list(
+        &self,
+        owner_type: AttachmentOwner,
+        owner_id: Uuid,
+    ) -> Result<Vec<domain::Attachment>> {
+        attachments::table
+            .filter(attachments::owner_type.eq(owner_type))
+            .filter(attachments::owner_id.eq(owner_id))
+            .order((attachments::created_at, attachments::id))
+            .select(domain::Attachment::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn owner_exists(&self, owner_type: AttachmentOwner, owner_id: Uuid) -> Result<bool> {
+        let mut conn = self.get_connection().await?;
+
+        let query = match owner_type {
+            AttachmentOwner::Product => {
+                diesel::select(exists(products::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+            AttachmentOwner::Location => {
+                diesel::select(exists(locations::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+            AttachmentOwner::StockMovement => {
+                diesel::select(exists(stock_movements::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+            AttachmentOwner::Shipment => {
+                diesel::select(exists(shipments::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+            AttachmentOwner::ReturnAuthorization => {
+                diesel::select(exists(return_authorizations::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+            AttachmentOwner::BusinessPartner => {
+                diesel::select(exists(business_partners::table.find(owner_id)))
+                    .get_result(&mut conn)
+                    .await
+            }
+        };
+
+        query.map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove(&self, id: Uuid) -> Result<domain::Attachment> {
+        diesel::delete(attachments::table.find(id))
+            .returning(domain::Attachment::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
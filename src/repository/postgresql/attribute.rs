@@ -0,0 +1,105 @@
+use crate::contract::repository::{AttributeRepository, Repository};
+use crate::domain::AttributeEntity;
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{attribute_definitions, locations, products};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+pub struct PostgresAttributeRepository {
+    pool: db::Pool,
+}
+
+impl PostgresAttributeRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::AttributeDefinition> for PostgresAttributeRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(
+        &self,
+        val: domain::AttributeDefinition,
+    ) -> Result<domain::AttributeDefinition> {
+        diesel::insert_into(attribute_definitions::table)
+            .values(val)
+            .returning(domain::AttributeDefinition::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::AttributeDefinition> {
+        attribute_definitions::table
+            .find(id)
+            .select(domain::AttributeDefinition::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl AttributeRepository for PostgresAttributeRepository {
+    #[tracing::instrument(skip(self))]
+    async fn list(
+        &self,
+        entity: domain::AttributeEntity,
+    ) -> Result<Vec<domain::AttributeDefinition>> {
+        attribute_definitions::table
+            .filter(attribute_definitions::entity.eq(entity))
+            .order((attribute_definitions::position, attribute_definitions::key))
+            .select(domain::AttributeDefinition::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove(&self, id: Uuid) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let definition = diesel::delete(attribute_definitions::table.find(id))
+                    .returning(domain::AttributeDefinition::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                let key = definition.key.as_str();
+
+                match definition.entity {
+                    AttributeEntity::Product => {
+                        diesel::update(products::table.filter(products::attributes.has_key(key)))
+                            .set(products::attributes.eq(products::attributes.remove(key)))
+                            .execute(conn)
+                            .await
+                            .map_err(map_diesel_error)?;
+                    }
+                    AttributeEntity::Location => {
+                        diesel::update(locations::table.filter(locations::attributes.has_key(key)))
+                            .set(locations::attributes.eq(locations::attributes.remove(key)))
+                            .execute(conn)
+                            .await
+                            .map_err(map_diesel_error)?;
+                    }
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
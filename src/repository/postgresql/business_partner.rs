@@ -0,0 +1,255 @@
+use crate::contract::repository::{BusinessPartnerRepository, Repository};
+use crate::domain::{PartnerRole, RepositoryError};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    business_partners, partner_addresses, partner_contacts,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+pub struct PostgresBusinessPartnerRepository {
+    pool: db::Pool,
+}
+
+impl PostgresBusinessPartnerRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+/// Inserts the address, clearing the previous default address of its kind first if it is
+/// the new default.
+async fn insert_address(
+    conn: &mut AsyncPgConnection,
+    address: domain::PartnerAddress,
+) -> Result<domain::PartnerAddress> {
+    if address.is_default {
+        diesel::update(
+            partner_addresses::table
+                .filter(partner_addresses::partner_id.eq(address.partner_id))
+                .filter(partner_addresses::kind.eq(address.kind))
+                .filter(partner_addresses::is_default),
+        )
+        .set(partner_addresses::is_default.eq(false))
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+    }
+
+    diesel::insert_into(partner_addresses::table)
+        .values(address)
+        .returning(domain::PartnerAddress::as_returning())
+        .get_result(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::BusinessPartner> for PostgresBusinessPartnerRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::BusinessPartner) -> Result<domain::BusinessPartner> {
+        diesel::insert_into(business_partners::table)
+            .values(val)
+            .returning(domain::BusinessPartner::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::BusinessPartner> {
+        business_partners::table
+            .find(id)
+            .select(domain::BusinessPartner::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl BusinessPartnerRepository for PostgresBusinessPartnerRepository {
+    #[tracing::instrument(skip(self, partner, addresses, contacts), fields(id = %partner.id))]
+    async fn create_with_details(
+        &self,
+        partner: domain::BusinessPartner,
+        addresses: Vec<domain::PartnerAddress>,
+        contacts: Vec<domain::PartnerContact>,
+    ) -> Result<domain::BusinessPartner> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let partner = diesel::insert_into(business_partners::table)
+                    .values(partner)
+                    .returning(domain::BusinessPartner::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                for address in addresses {
+                    insert_address(conn, address).await?;
+                }
+
+                diesel::insert_into(partner_contacts::table)
+                    .values(contacts)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(partner)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_duplicates(
+        &self,
+        tax_id: Option<&str>,
+        normalized_name: &str,
+    ) -> Result<Vec<domain::BusinessPartner>> {
+        business_partners::table
+            .filter(
+                business_partners::normalized_name
+                    .eq(normalized_name)
+                    .nullable()
+                    .or(business_partners::tax_id.eq(tax_id)),
+            )
+            .order(business_partners::code)
+            .select(domain::BusinessPartner::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn search(
+        &self,
+        query: domain::BusinessPartnerQuery,
+    ) -> Result<Vec<domain::BusinessPartner>> {
+        let mut partners = business_partners::table
+            .select(domain::BusinessPartner::as_select())
+            .into_boxed();
+
+        if let Some(search) = query.search {
+            let pattern = format!("%{search}%");
+            partners = partners.filter(
+                business_partners::code
+                    .ilike(pattern.clone())
+                    .or(business_partners::name.ilike(pattern.clone()))
+                    .nullable()
+                    .or(business_partners::tax_id.ilike(pattern)),
+            );
+        }
+        match query.role {
+            Some(PartnerRole::Supplier) => {
+                partners = partners.filter(business_partners::is_supplier);
+            }
+            Some(PartnerRole::Customer) => {
+                partners = partners.filter(business_partners::is_customer);
+            }
+            Some(PartnerRole::Carrier) => {
+                partners = partners.filter(business_partners::is_carrier);
+            }
+            None => {}
+        }
+
+        partners
+            .order(business_partners::code)
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_addresses(&self, partner_id: Uuid) -> Result<Vec<domain::PartnerAddress>> {
+        partner_addresses::table
+            .filter(partner_addresses::partner_id.eq(partner_id))
+            .order((
+                partner_addresses::is_default.desc(),
+                partner_addresses::kind,
+                partner_addresses::created_at,
+            ))
+            .select(domain::PartnerAddress::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, address), fields(id = %address.id))]
+    async fn add_address(&self, address: domain::PartnerAddress) -> Result<domain::PartnerAddress> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move { insert_address(conn, address).await }.scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_address(&self, partner_id: Uuid, address_id: Uuid) -> Result<()> {
+        let deleted = diesel::delete(
+            partner_addresses::table
+                .find(address_id)
+                .filter(partner_addresses::partner_id.eq(partner_id)),
+        )
+        .execute(&mut self.get_connection().await?)
+        .await
+        .map_err(map_diesel_error)?;
+        if deleted == 0 {
+            return Err(RepositoryError::NotFound.into());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_contacts(&self, partner_id: Uuid) -> Result<Vec<domain::PartnerContact>> {
+        partner_contacts::table
+            .filter(partner_contacts::partner_id.eq(partner_id))
+            .order((partner_contacts::name, partner_contacts::id))
+            .select(domain::PartnerContact::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, contact), fields(id = %contact.id))]
+    async fn add_contact(&self, contact: domain::PartnerContact) -> Result<domain::PartnerContact> {
+        diesel::insert_into(partner_contacts::table)
+            .values(contact)
+            .returning(domain::PartnerContact::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_contact(&self, partner_id: Uuid, contact_id: Uuid) -> Result<()> {
+        let deleted = diesel::delete(
+            partner_contacts::table
+                .find(contact_id)
+                .filter(partner_contacts::partner_id.eq(partner_id)),
+        )
+        .execute(&mut self.get_connection().await?)
+        .await
+        .map_err(map_diesel_error)?;
+        if deleted == 0 {
+            return Err(RepositoryError::NotFound.into());
+        }
+
+        Ok(())
+    }
+}
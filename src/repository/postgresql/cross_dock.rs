@@ -0,0 +1,233 @@
+use crate::contract::repository::{CrossDockRepository, Repository};
+use crate::domain::{CrossDockError, DemandStatus, PutawayTaskStatus, ReservationStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    cross_docks, outbound_demands, putaway_tasks, reservations,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresCrossDockRepository {
+    pool: db::Pool,
+}
+
+impl PostgresCrossDockRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::OutboundDemand> for PostgresCrossDockRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::OutboundDemand) -> Result<domain::OutboundDemand> {
+        diesel::insert_into(outbound_demands::table)
+            .values(val)
+            .returning(domain::OutboundDemand::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::OutboundDemand> {
+        outbound_demands::table
+            .find(id)
+            .select(domain::OutboundDemand::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl CrossDockRepository for PostgresCrossDockRepository {
+    #[tracing::instrument(skip(self, query))]
+    async fn list_demands(
+        &self,
+        query: domain::OutboundDemandQuery,
+    ) -> Result<Vec<domain::OutboundDemand>> {
+        let mut demands = outbound_demands::table
+            .select(domain::OutboundDemand::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            demands = demands.filter(outbound_demands::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(product_id) = query.product_id {
+            demands = demands.filter(outbound_demands::product_id.eq(product_id));
+        }
+        if let Some(document_id) = query.document_id {
+            demands = demands.filter(outbound_demands::document_id.eq(document_id));
+        }
+        if let Some(status) = query.status {
+            demands = demands.filter(outbound_demands::status.eq(status));
+        }
+
+        let mut demands: Vec<domain::OutboundDemand> = demands
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)?;
+        demands.sort_by_key(domain::OutboundDemand::urgency_key);
+
+        Ok(demands)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn cancel_demand(&self, id: Uuid) -> Result<domain::OutboundDemand> {
+        diesel::update(
+            outbound_demands::table
+                .find(id)
+                .filter(outbound_demands::status.eq(DemandStatus::Open)),
+        )
+        .set(outbound_demands::status.eq(DemandStatus::Cancelled))
+        .returning(domain::OutboundDemand::as_returning())
+        .get_result(&mut self.get_connection().await?)
+        .await
+        .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, cross_dock, movement, serial_numbers), fields(id = %cross_dock.id))]
+    async fn cross_dock(
+        &self,
+        cross_dock: domain::CrossDock,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<domain::CrossDock> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let demand = outbound_demands::table
+                    .find(cross_dock.demand_id)
+                    .select(domain::OutboundDemand::as_select())
+                    .for_update()
+                    .first(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                if demand.status != DemandStatus::Open {
+                    return Err(CrossDockError::DemandNotOpen.into());
+                }
+                if cross_dock.quantity > demand.open_quantity() {
+                    return Err(CrossDockError::ExceedsDemand.into());
+                }
+
+                apply_movement(conn, movement, &serial_numbers, true).await?;
+
+                // The stock just moved in, so the staging location has it available.
+                diesel::insert_into(reservations::table)
+                    .values(domain::Reservation {
+                        id: cross_dock.reservation_id,
+                        product_id: cross_dock.product_id,
+                        location_id: cross_dock.staging_location_id,
+                        quantity: cross_dock.quantity,
+                        status: ReservationStatus::Active,
+                        document_type: demand.document_type,
+                        document_id: demand.document_id,
+                        expires_at: None,
+                        created_by: cross_dock.created_by,
+                        created_at: cross_dock.created_at,
+                    })
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                let allocated = demand.allocated_quantity + cross_dock.quantity;
+                let status = if allocated >= demand.quantity {
+                    DemandStatus::Fulfilled
+                } else {
+                    DemandStatus::Open
+                };
+                diesel::update(outbound_demands::table.find(demand.id))
+                    .set((
+                        outbound_demands::allocated_quantity.eq(allocated),
+                        outbound_demands::status.eq(status),
+                    ))
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                let tasks: Vec<domain::PutawayTask> = putaway_tasks::table
+                    .filter(putaway_tasks::from_location_id.eq(cross_dock.from_location_id))
+                    .filter(putaway_tasks::product_id.eq(cross_dock.product_id))
+                    .filter(putaway_tasks::status.eq(PutawayTaskStatus::Open))
+                    .order(putaway_tasks::created_at)
+                    .select(domain::PutawayTask::as_select())
+                    .for_update()
+                    .load(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                let mut skipped = cross_dock.quantity;
+                for task in tasks {
+                    if skipped.is_zero() {
+                        break;
+                    }
+                    if task.quantity <= skipped {
+                        diesel::update(putaway_tasks::table.find(task.id))
+                            .set(putaway_tasks::status.eq(PutawayTaskStatus::Cancelled))
+                            .execute(conn)
+                            .await
+                            .map_err(map_diesel_error)?;
+                        skipped -= task.quantity;
+                    } else {
+                        diesel::update(putaway_tasks::table.find(task.id))
+                            .set(putaway_tasks::quantity.eq(task.quantity - skipped))
+                            .execute(conn)
+                            .await
+                            .map_err(map_diesel_error)?;
+                        skipped = Decimal::ZERO;
+                    }
+                }
+
+                diesel::insert_into(cross_docks::table)
+                    .values(cross_dock)
+                    .returning(domain::CrossDock::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::CrossDockQuery) -> Result<Vec<domain::CrossDock>> {
+        let mut docks = cross_docks::table
+            .inner_join(outbound_demands::table)
+            .select(domain::CrossDock::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            docks = docks.filter(cross_docks::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(demand_id) = query.demand_id {
+            docks = docks.filter(cross_docks::demand_id.eq(demand_id));
+        }
+        if let Some(document_id) = query.document_id {
+            docks = docks.filter(
+                outbound_demands::document_id
+                    .nullable()
+                    .eq(document_id)
+                    .or(cross_docks::inbound_document_id.eq(document_id)),
+            );
+        }
+
+        docks
+            .order((cross_docks::created_at.desc(), cross_docks::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
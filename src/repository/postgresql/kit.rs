@@ -0,0 +1,253 @@
+use crate::contract::repository::{KitRepository, Repository};
+use crate::domain::{AssemblyOrderStatus, KitError};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    assembly_order_lines, assembly_orders, bom_components, stock_movements,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::repository::postgresql::valuation::current_cost;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresKitRepository {
+    pool: db::Pool,
+}
+
+impl PostgresKitRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::AssemblyOrder> for PostgresKitRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::AssemblyOrder) -> Result<domain::AssemblyOrder> {
+        diesel::insert_into(assembly_orders::table)
+            .values(val)
+            .returning(domain::AssemblyOrder::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::AssemblyOrder> {
+        assembly_orders::table
+            .find(id)
+            .select(domain::AssemblyOrder::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl KitRepository for PostgresKitRepository {
+    #[tracing::instrument(skip(self, components))]
+    async fn replace_components(
+        &self,
+        kit_product_id: Uuid,
+        components: Vec<domain::BomComponent>,
+    ) -> Result<Vec<domain::BomComponent>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                diesel::delete(
+                    bom_components::table.filter(bom_components::kit_product_id.eq(kit_product_id)),
+                )
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                diesel::insert_into(bom_components::table)
+                    .values(components)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                load_components(conn, kit_product_id).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_components(&self, kit_product_id: Uuid) -> Result<Vec<domain::BomComponent>> {
+        let conn = &mut self.get_connection().await?;
+        load_components(conn, kit_product_id).await
+    }
+
+    #[tracing::instrument(skip(self, order, lines), fields(id = %order.id))]
+    async fn create_with_lines(
+        &self,
+        order: domain::AssemblyOrder,
+        lines: Vec<domain::AssemblyOrderLine>,
+    ) -> Result<domain::AssemblyOrder> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let order = diesel::insert_into(assembly_orders::table)
+                    .values(order)
+                    .returning(domain::AssemblyOrder::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                diesel::insert_into(assembly_order_lines::table)
+                    .values(lines)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(order)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_lines(&self, order_id: Uuid) -> Result<Vec<domain::AssemblyOrderLine>> {
+        assembly_order_lines::table
+            .filter(assembly_order_lines::assembly_order_id.eq(order_id))
+            .order(assembly_order_lines::id)
+            .select(domain::AssemblyOrderLine::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, order, movements), fields(id = %order.id))]
+    async fn update_with_movements(
+        &self,
+        order: domain::AssemblyOrder,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<(domain::AssemblyOrder, Vec<domain::StockMovement>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                // Completions and cancellations of the order run one after the other.
+                assembly_orders::table
+                    .find(order.id)
+                    .filter(assembly_orders::status.eq(AssemblyOrderStatus::Open))
+                    .select(assembly_orders::id)
+                    .for_update()
+                    .first::<Uuid>(conn)
+                    .await
+                    .optional()
+                    .map_err(map_diesel_error)?
+                    .ok_or(KitError::NotOpen)?;
+
+                let (outbound, inbound): (Vec<_>, Vec<_>) = movements
+                    .into_iter()
+                    .partition(|movement| movement.from_location_id.is_some());
+
+                let mut posted = Vec::new();
+                for movement in outbound {
+                    posted.extend(apply_movement(conn, movement, &[], true).await?);
+                }
+
+                let consumed: Decimal = posted
+                    .iter()
+                    .filter_map(|movement| movement.total_cost)
+                    .sum();
+                for movement in share_cost(conn, inbound, consumed).await? {
+                    posted.extend(apply_movement(conn, movement, &[], true).await?);
+                }
+
+                let order = diesel::update(assembly_orders::table.find(order.id))
+                    .set((
+                        assembly_orders::status.eq(order.status),
+                        assembly_orders::completed_at.eq(order.completed_at),
+                    ))
+                    .returning(domain::AssemblyOrder::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok((order, posted))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_movements(&self, order_id: Uuid) -> Result<Vec<domain::StockMovement>> {
+        stock_movements::table
+            .filter(stock_movements::document_type.eq(domain::DocumentType::AssemblyOrder))
+            .filter(stock_movements::document_id.eq(order_id))
+            .order((stock_movements::created_at, stock_movements::id))
+            .select(domain::StockMovement::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+async fn load_components(
+    conn: &mut AsyncPgConnection,
+    kit_product_id: Uuid,
+) -> Result<Vec<domain::BomComponent>> {
+    bom_components::table
+        .filter(bom_components::kit_product_id.eq(kit_product_id))
+        .order(bom_components::component_product_id)
+        .select(domain::BomComponent::as_select())
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+/// Values inbound movements without a unit cost at their share of `consumed`, weighted by
+/// their quantity at the current cost of their products. Products without a cost yet are
+/// weighted by quantity alone.
+async fn share_cost(
+    conn: &mut AsyncPgConnection,
+    mut movements: Vec<domain::StockMovement>,
+    consumed: Decimal,
+) -> Result<Vec<domain::StockMovement>> {
+    let mut weights = Vec::with_capacity(movements.len());
+    for movement in movements.iter() {
+        let weight = match movement.unit_cost {
+            Some(_) => Decimal::ZERO,
+            None => current_cost(conn, movement.product_id).await? * movement.quantity,
+        };
+        weights.push(weight);
+    }
+    if weights.iter().all(Decimal::is_zero) {
+        for (weight, movement) in weights.iter_mut().zip(movements.iter()) {
+            if movement.unit_cost.is_none() {
+                *weight = movement.quantity;
+            }
+        }
+    }
+
+    let total: Decimal = weights.iter().sum();
+    if total.is_zero() {
+        return Ok(movements);
+    }
+    for (weight, movement) in weights.into_iter().zip(movements.iter_mut()) {
+        if movement.unit_cost.is_none() {
+            movement.unit_cost = Some((consumed * weight / total / movement.quantity).round_dp(6));
+        }
+    }
+
+    Ok(movements)
+}
@@ -0,0 +1,170 @@
+use crate::contract::repository::{LotRepository, Repository};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{locations, lots, stock_balances};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresLotRepository {
+    pool: db::Pool,
+}
+
+impl PostgresLotRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Lot> for PostgresLotRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Lot) -> Result<domain::Lot> {
+        diesel::insert_into(lots::table)
+            .values(val)
+            .returning(domain::Lot::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Lot> {
+        lots::table
+            .find(id)
+            .select(domain::Lot::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl LotRepository for PostgresLotRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn get_or_create(&self, val: domain::Lot) -> Result<domain::Lot> {
+        let conn = &mut self.get_connection().await?;
+        let (product_id, lot_number, expiry_date) =
+            (val.product_id, val.lot_number.clone(), val.expiry_date);
+
+        diesel::insert_into(lots::table)
+            .values(val)
+            .on_conflict((lots::product_id, lots::lot_number, lots::expiry_date))
+            .do_nothing()
+            .execute(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        lots::table
+            .filter(lots::product_id.eq(product_id))
+            .filter(lots::lot_number.is_not_distinct_from(lot_number))
+            .filter(lots::expiry_date.is_not_distinct_from(expiry_date))
+            .select(domain::Lot::as_select())
+            .first(conn)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find(
+        &self,
+        product_id: Uuid,
+        lot_number: Option<String>,
+        expiry_date: Option<NaiveDate>,
+    ) -> Result<domain::Lot> {
+        let mut query = lots::table
+            .filter(lots::product_id.eq(product_id))
+            .select(domain::Lot::as_select())
+            .into_boxed();
+
+        if let Some(lot_number) = lot_number {
+            query = query.filter(lots::lot_number.eq(lot_number));
+        }
+        if let Some(expiry_date) = expiry_date {
+            query = query.filter(lots::expiry_date.eq(expiry_date));
+        }
+
+        query
+            .order(lots::expiry_date.asc().nulls_last())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_blocked(&self, id: Uuid, blocked: bool) -> Result<domain::Lot> {
+        diesel::update(lots::table.find(id))
+            .set(lots::blocked.eq(blocked))
+            .returning(domain::Lot::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn block_expired(&self, today: NaiveDate) -> Result<usize> {
+        diesel::update(
+            lots::table
+                .filter(lots::blocked.eq(false))
+                .filter(lots::expiry_date.lt(today)),
+        )
+        .set(lots::blocked.eq(true))
+        .execute(&mut self.get_connection().await?)
+        .await
+        .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_expiring(
+        &self,
+        query: domain::ExpiringStockQuery,
+    ) -> Result<Vec<domain::ExpiringStock>> {
+        let mut rows = stock_balances::table
+            .inner_join(lots::table)
+            .inner_join(locations::table)
+            .filter(stock_balances::on_hand.gt(Decimal::ZERO))
+            .filter(lots::expiry_date.le(query.until))
+            .select((
+                stock_balances::product_id,
+                stock_balances::location_id,
+                domain::Lot::as_select(),
+                stock_balances::on_hand,
+            ))
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            rows = rows.filter(stock_balances::product_id.eq(product_id));
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            rows = rows.filter(locations::warehouse_id.eq(warehouse_id));
+        }
+
+        let rows: Vec<(Uuid, Uuid, domain::Lot, Decimal)> = rows
+            .order((lots::expiry_date, stock_balances::product_id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(product_id, location_id, lot, on_hand)| {
+                Some(domain::ExpiringStock {
+                    product_id,
+                    location_id,
+                    lot_id: lot.id,
+                    expiry_date: lot.expiry_date?,
+                    lot_number: lot.lot_number,
+                    blocked: lot.blocked,
+                    on_hand,
+                })
+            })
+            .collect())
+    }
+}
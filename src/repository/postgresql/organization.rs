@@ -0,0 +1,246 @@
+use crate::contract::repository::{OrganizationRepository, Repository};
+use crate::domain::RepositoryError;
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    organizations, role_rules, roles, rules, user_organizations, user_roles, users,
+    valuation_settings,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel::sql_types::{Bool, Text};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+define_sql_function!(fn set_config(setting: Text, value: Text, is_local: Bool) -> Text);
+
+pub struct PostgresOrganizationRepository {
+    pool: db::Pool,
+}
+
+impl PostgresOrganizationRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+/// Inserts the organization and makes the rest of the transaction act as it, so the rows
+/// inserted next belong to it.
+async fn insert_organization(
+    conn: &mut AsyncPgConnection,
+    organization: domain::Organization,
+) -> Result<domain::Organization> {
+    let organization = diesel::insert_into(organizations::table)
+        .values(organization)
+        .returning(domain::Organization::as_returning())
+        .get_result(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    diesel::select(set_config(
+        "app.organization_id",
+        organization.id.to_string(),
+        true,
+    ))
+    .execute(conn)
+    .await
+    .map_err(map_diesel_error)?;
+
+    diesel::insert_into(valuation_settings::table)
+        .default_values()
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    Ok(organization)
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Organization> for PostgresOrganizationRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Organization) -> Result<domain::Organization> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move { insert_organization(conn, val).await }.scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Organization> {
+        organizations::table
+            .find(id)
+            .select(domain::Organization::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    #[tracing::instrument(skip(self, organization, owner, role, rules), fields(id = %organization.id))]
+    async fn create_with_owner(
+        &self,
+        organization: domain::Organization,
+        owner: domain::OrganizationMember,
+        role: domain::Role,
+        rules: Vec<domain::Rule>,
+    ) -> Result<domain::Organization> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let organization = insert_organization(conn, organization).await?;
+
+                let role_rules: Vec<domain::RoleRule> = rules
+                    .iter()
+                    .map(|rule| domain::RoleRule {
+                        role_id: role.id,
+                        rule_id: rule.id,
+                        assigned_by: Some(owner.user_id),
+                    })
+                    .collect();
+                let user_role = domain::UserRole {
+                    user_id: owner.user_id,
+                    role_id: role.id,
+                    assigned_by: owner.added_by,
+                };
+
+                diesel::insert_into(user_organizations::table)
+                    .values(owner)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                diesel::insert_into(roles::table)
+                    .values(role)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                diesel::insert_into(rules::table)
+                    .values(rules)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                diesel::insert_into(role_rules::table)
+                    .values(role_rules)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                diesel::insert_into(user_roles::table)
+                    .values(user_role)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(organization)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list(&self) -> Result<Vec<domain::Organization>> {
+        organizations::table
+            .order((organizations::created_at, organizations::id))
+            .select(domain::Organization::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<domain::Organization>> {
+        organizations::table
+            .inner_join(user_organizations::table)
+            .filter(user_organizations::user_id.eq(user_id))
+            .order((user_organizations::created_at, organizations::id))
+            .select(domain::Organization::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<domain::OrganizationMember> {
+        user_organizations::table
+            .find((user_id, organization_id))
+            .select(domain::OrganizationMember::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<(domain::OrganizationMember, String)>> {
+        user_organizations::table
+            .inner_join(users::table.on(users::id.eq(user_organizations::user_id)))
+            .filter(user_organizations::organization_id.eq(organization_id))
+            .order((user_organizations::created_at, users::email))
+            .select((domain::OrganizationMember::as_select(), users::email))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, member), fields(user_id = %member.user_id))]
+    async fn add_member(
+        &self,
+        member: domain::OrganizationMember,
+    ) -> Result<domain::OrganizationMember> {
+        diesel::insert_into(user_organizations::table)
+            .values(member)
+            .returning(domain::OrganizationMember::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let deleted =
+                    diesel::delete(user_organizations::table.find((user_id, organization_id)))
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                if deleted == 0 {
+                    return Err(RepositoryError::NotFound.into());
+                }
+
+                diesel::delete(
+                    user_roles::table
+                        .filter(user_roles::user_id.eq(user_id))
+                        .filter(user_roles::organization_id.eq(organization_id)),
+                )
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
@@ -0,0 +1,59 @@
+use crate::contract::repository::{PalletRepository, Repository};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::pallets;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+define_sql_function!(fn nextval(sequence: Text) -> BigInt);
+
+pub struct PostgresPalletRepository {
+    pool: db::Pool,
+}
+
+impl PostgresPalletRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Pallet> for PostgresPalletRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Pallet) -> Result<domain::Pallet> {
+        diesel::insert_into(pallets::table)
+            .values(val)
+            .returning(domain::Pallet::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Pallet> {
+        pallets::table
+            .find(id)
+            .select(domain::Pallet::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl PalletRepository for PostgresPalletRepository {
+    #[tracing::instrument(skip(self))]
+    async fn next_serial_reference(&self) -> Result<i64> {
+        diesel::select(nextval("pallet_serial_reference"))
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::contract::repository::{ProductRepository, Repository};
+use crate::domain::{ProductFilter, ProductSort};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{product_uoms, products};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+pub struct PostgresProductRepository {
+    pool: db::Pool,
+}
+
+impl PostgresProductRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Product> for PostgresProductRepository {
+    #[tracing::instrument(skip(self, val))]
+    async fn create(&self, val: domain::Product) -> Result<domain::Product> {
+        diesel::insert_into(products::table)
+            .values(val)
+            .returning(domain::Product::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Product> {
+        products::table
+            .find(id)
+            .select(domain::Product::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProductRepository for PostgresProductRepository {
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::ProductQuery) -> Result<Vec<domain::Product>> {
+        let mut products = products::table
+            .select(domain::Product::as_select())
+            .into_boxed();
+
+        if let Some(category) = query.category {
+            products = products.filter(products::category.eq(category));
+        }
+        if !query.attributes.is_empty() {
+            products = products
+                .filter(products::attributes.contains(serde_json::Value::Object(query.attributes)));
+        }
+
+        products
+            .order(products::sku)
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn page(&self, query: domain::ProductListQuery) -> Result<domain::Page<domain::Product>> {
+        let mut conn = self.get_connection().await?;
+        let mut products = products::table
+            .select(domain::Product::as_select())
+            .into_boxed();
+
+        for filter in query.filters.iter().cloned() {
+            products = match filter {
+                ProductFilter::Sku(comparison) => compare!(products, products::sku, comparison),
+                ProductFilter::Name(comparison) => compare!(products, products::name, comparison),
+                ProductFilter::Category(comparison) => {
+                    compare!(products, products::category, comparison)
+                }
+                ProductFilter::AbcClass(comparison) => {
+                    compare!(products, products::abc_class, comparison)
+                }
+                ProductFilter::LotTracked(comparison) => {
+                    compare!(products, products::lot_tracked, comparison)
+                }
+                ProductFilter::SerialTracked(comparison) => {
+                    compare!(products, products::serial_tracked, comparison)
+                }
+                ProductFilter::Attribute(key, value) => {
+                    products.filter(products::attributes.contains(serde_json::Value::Object(
+                        serde_json::Map::from_iter([(key, value)]),
+                    )))
+                }
+            };
+        }
+
+        let products = match query.sort.field {
+            ProductSort::Sku => paginate!(
+                products,
+                products::table,
+                products::sku,
+                String,
+                products::id,
+                query,
+                &mut conn
+            ),
+            ProductSort::Name => paginate!(
+                products,
+                products::table,
+                products::name,
+                String,
+                products::id,
+                query,
+                &mut conn
+            ),
+        };
+
+        let rows = products.load(&mut conn).await.map_err(map_diesel_error)?;
+        Ok(domain::Page::from_rows(rows, query.limit, |product| {
+            product.id
+        }))
+    }
+
+    #[tracing::instrument(skip(self, attributes))]
+    async fn set_attributes(
+        &self,
+        id: Uuid,
+        attributes: serde_json::Value,
+    ) -> Result<domain::Product> {
+        diesel::update(products::table.find(id))
+            .set(products::attributes.eq(attributes))
+            .returning(domain::Product::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn add_uom(&self, val: domain::ProductUom) -> Result<domain::ProductUom> {
+        diesel::insert_into(product_uoms::table)
+            .values(val)
+            .returning(domain::ProductUom::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_uoms(&self, product_id: Uuid) -> Result<Vec<domain::ProductUom>> {
+        product_uoms::table
+            .filter(product_uoms::product_id.eq(product_id))
+            .order(product_uoms::factor)
+            .select(domain::ProductUom::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_uom_by_barcode(&self, barcode: &str) -> Result<domain::ProductUom> {
+        product_uoms::table
+            .filter(product_uoms::barcode.eq(barcode))
+            .select(domain::ProductUom::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::contract::repository::{PutawayRepository, Repository};
+use crate::domain::{PutawayError, PutawayTaskStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    locations, products, putaway_rules, putaway_tasks, stock_balances,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PostgresPutawayRepository {
+    pool: db::Pool,
+}
+
+impl PostgresPutawayRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::PutawayTask> for PostgresPutawayRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::PutawayTask) -> Result<domain::PutawayTask> {
+        diesel::insert_into(putaway_tasks::table)
+            .values(val)
+            .returning(domain::PutawayTask::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::PutawayTask> {
+        putaway_tasks::table
+            .find(id)
+            .select(domain::PutawayTask::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl PutawayRepository for PostgresPutawayRepository {
+    #[tracing::instrument(skip(self, rules))]
+    async fn replace_rules(
+        &self,
+        warehouse_id: Uuid,
+        rules: Vec<domain::PutawayRule>,
+    ) -> Result<Vec<domain::PutawayRule>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                diesel::delete(
+                    putaway_rules::table.filter(putaway_rules::warehouse_id.eq(warehouse_id)),
+                )
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+                if rules.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                diesel::insert_into(putaway_rules::table)
+                    .values(rules)
+                    .returning(domain::PutawayRule::as_returning())
+                    .get_results(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_rules(&self, warehouse_id: Uuid) -> Result<Vec<domain::PutawayRule>> {
+        putaway_rules::table
+            .filter(putaway_rules::warehouse_id.eq(warehouse_id))
+            .order(putaway_rules::sequence)
+            .select(domain::PutawayRule::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_occupancy(&self, warehouse_id: Uuid) -> Result<Vec<domain::LocationOccupancy>> {
+        let mut conn = self.get_connection().await?;
+
+        let storage: Vec<domain::Location> = locations::table
+            .filter(locations::warehouse_id.eq(warehouse_id))
+            .filter(locations::in_transit.eq(false))
+            .filter(locations::quarantine.eq(false))
+            .order(locations::code)
+            .select(domain::Location::as_select())
+            .load(&mut conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        let stored: Vec<(Uuid, Uuid, Decimal, Option<Decimal>, Option<Decimal>)> =
+            stock_balances::table
+                .inner_join(products::table)
+                .inner_join(locations::table)
+                .filter(locations::warehouse_id.eq(warehouse_id))
+                .filter(stock_balances::on_hand.gt(Decimal::ZERO))
+                .select((
+                    stock_balances::location_id,
+                    stock_balances::product_id,
+                    stock_balances::on_hand,
+                    products::unit_volume,
+                    products::unit_weight,
+                ))
+                .load(&mut conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+        let incoming: Vec<(
+            Option<Uuid>,
+            Uuid,
+            Decimal,
+            Option<Decimal>,
+            Option<Decimal>,
+        )> = putaway_tasks::table
+            .inner_join(products::table)
+            .filter(putaway_tasks::warehouse_id.eq(warehouse_id))
+            .filter(putaway_tasks::status.eq(PutawayTaskStatus::Open))
+            .filter(putaway_tasks::suggested_location_id.is_not_null())
+            .select((
+                putaway_tasks::suggested_location_id,
+                putaway_tasks::product_id,
+                putaway_tasks::quantity,
+                products::unit_volume,
+                products::unit_weight,
+            ))
+            .load(&mut conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        let mut occupancy: HashMap<Uuid, domain::LocationOccupancy> = storage
+            .iter()
+            .map(|location| {
+                (
+                    location.id,
+                    domain::LocationOccupancy {
+                        location: location.clone(),
+                        volume: Decimal::ZERO,
+                        weight: Decimal::ZERO,
+                        product_ids: Vec::new(),
+                    },
+                )
+            })
+            .collect();
+
+        let incoming = incoming.into_iter().filter_map(
+            |(location_id, product_id, quantity, unit_volume, unit_weight)| {
+                Some((location_id?, product_id, quantity, unit_volume, unit_weight))
+            },
+        );
+        for (location_id, product_id, quantity, unit_volume, unit_weight) in
+            stored.into_iter().chain(incoming)
+        {
+            let Some(location) = occupancy.get_mut(&location_id) else {
+                continue;
+            };
+            location.volume += unit_volume.unwrap_or_default() * quantity;
+            location.weight += unit_weight.unwrap_or_default() * quantity;
+            if !location.product_ids.contains(&product_id) {
+                location.product_ids.push(product_id);
+            }
+        }
+
+        Ok(storage
+            .into_iter()
+            .filter_map(|location| occupancy.remove(&location.id))
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, tasks))]
+    async fn create_tasks(
+        &self,
+        tasks: Vec<domain::PutawayTask>,
+    ) -> Result<Vec<domain::PutawayTask>> {
+        diesel::insert_into(putaway_tasks::table)
+            .values(tasks)
+            .returning(domain::PutawayTask::as_returning())
+            .get_results(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_tasks(
+        &self,
+        query: domain::PutawayTaskQuery,
+    ) -> Result<Vec<domain::PutawayTask>> {
+        let mut tasks = putaway_tasks::table
+            .select(domain::PutawayTask::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            tasks = tasks.filter(putaway_tasks::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(from_location_id) = query.from_location_id {
+            tasks = tasks.filter(putaway_tasks::from_location_id.eq(from_location_id));
+        }
+        if let Some(status) = query.status {
+            tasks = tasks.filter(putaway_tasks::status.eq(status));
+        }
+
+        tasks
+            .order((putaway_tasks::created_at, putaway_tasks::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, task, movement, serial_numbers), fields(id = %task.id))]
+    async fn complete(
+        &self,
+        task: domain::PutawayTask,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<(domain::PutawayTask, Vec<domain::StockMovement>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                // Confirmations of the task run one after the other.
+                putaway_tasks::table
+                    .find(task.id)
+                    .filter(putaway_tasks::status.eq(PutawayTaskStatus::Open))
+                    .select(putaway_tasks::id)
+                    .for_update()
+                    .first::<Uuid>(conn)
+                    .await
+                    .optional()
+                    .map_err(map_diesel_error)?
+                    .ok_or(PutawayError::NotOpen)?;
+
+                let movements = apply_movement(conn, movement, &serial_numbers, true).await?;
+                let task = update_task(conn, &task).await?;
+
+                Ok((task, movements))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, task), fields(id = %task.id))]
+    async fn update(&self, task: domain::PutawayTask) -> Result<domain::PutawayTask> {
+        let conn = &mut self.get_connection().await?;
+        update_task(conn, &task).await
+    }
+}
+
+/// Fails with `NotOpen` if the task is no longer open.
+async fn update_task(
+    conn: &mut AsyncPgConnection,
+    task: &domain::PutawayTask,
+) -> Result<domain::PutawayTask> {
+    let task = diesel::update(
+        putaway_tasks::table
+            .find(task.id)
+            .filter(putaway_tasks::status.eq(PutawayTaskStatus::Open)),
+    )
+    .set((
+        putaway_tasks::to_location_id.eq(task.to_location_id),
+        putaway_tasks::status.eq(task.status),
+        putaway_tasks::movement_id.eq(task.movement_id),
+        putaway_tasks::completed_by.eq(task.completed_by),
+        putaway_tasks::completed_at.eq(task.completed_at),
+    ))
+    .returning(domain::PutawayTask::as_returning())
+    .get_result(conn)
+    .await
+    .optional()
+    .map_err(map_diesel_error)?
+    .ok_or(PutawayError::NotOpen)?;
+
+    Ok(task)
+}
@@ -0,0 +1,305 @@
+use crate::contract::repository::{ReplenishmentRepository, Repository};
+use crate::domain::{DocumentType, MovementKind, SuggestionStatus, TransferOrderStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    purchase_orders, replenishment_rules, replenishment_suggestions, stock_movements,
+    transfer_order_lines, transfer_orders,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PostgresReplenishmentRepository {
+    pool: db::Pool,
+}
+
+impl PostgresReplenishmentRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::ReplenishmentRule> for PostgresReplenishmentRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::ReplenishmentRule) -> Result<domain::ReplenishmentRule> {
+        diesel::insert_into(replenishment_rules::table)
+            .values(val)
+            .on_conflict((
+                replenishment_rules::product_id,
+                replenishment_rules::warehouse_id,
+                replenishment_rules::location_id,
+            ))
+            .do_update()
+            .set((
+                replenishment_rules::reorder_point.eq(excluded(replenishment_rules::reorder_point)),
+                replenishment_rules::max_quantity.eq(excluded(replenishment_rules::max_quantity)),
+                replenishment_rules::reorder_quantity
+                    .eq(excluded(replenishment_rules::reorder_quantity)),
+                replenishment_rules::updated_at.eq(excluded(replenishment_rules::updated_at)),
+            ))
+            .returning(domain::ReplenishmentRule::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::ReplenishmentRule> {
+        replenishment_rules::table
+            .find(id)
+            .select(domain::ReplenishmentRule::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplenishmentRepository for PostgresReplenishmentRepository {
+    #[tracing::instrument(skip(self, query))]
+    async fn list_rules(
+        &self,
+        query: domain::ReplenishmentRuleQuery,
+    ) -> Result<Vec<domain::ReplenishmentRule>> {
+        let mut rules = replenishment_rules::table
+            .select(domain::ReplenishmentRule::as_select())
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            rules = rules.filter(replenishment_rules::product_id.eq(product_id));
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            rules = rules.filter(replenishment_rules::warehouse_id.eq(warehouse_id));
+        }
+
+        rules
+            .order((
+                replenishment_rules::warehouse_id,
+                replenishment_rules::product_id,
+                replenishment_rules::location_id.asc().nulls_first(),
+            ))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn inbound_quantity(&self, product_id: Uuid, warehouse_id: Uuid) -> Result<Decimal> {
+        let conn = &mut self.get_connection().await?;
+
+        let orders: Vec<(Uuid, Decimal)> = purchase_orders::table
+            .filter(purchase_orders::product_id.eq(product_id))
+            .filter(purchase_orders::warehouse_id.eq(warehouse_id))
+            .select((purchase_orders::id, purchase_orders::quantity))
+            .load(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        let order_ids: Vec<Uuid> = orders.iter().map(|(id, _)| *id).collect();
+        let received: HashMap<Uuid, Decimal> = stock_movements::table
+            .filter(stock_movements::kind.eq(MovementKind::Receipt))
+            .filter(stock_movements::document_type.eq(DocumentType::PurchaseOrder))
+            .filter(stock_movements::document_id.eq_any(order_ids))
+            .group_by(stock_movements::document_id)
+            .select((
+                stock_movements::document_id,
+                diesel::dsl::sum(stock_movements::quantity),
+            ))
+            .load::<(Option<Uuid>, Option<Decimal>)>(conn)
+            .await
+            .map_err(map_diesel_error)?
+            .into_iter()
+            .filter_map(|(id, quantity)| Some((id?, quantity.unwrap_or_default())))
+            .collect();
+
+        let ordered: Decimal = orders
+            .into_iter()
+            .map(|(id, quantity)| {
+                let received = received.get(&id).copied().unwrap_or_default();
+                (quantity - received).max(Decimal::ZERO)
+            })
+            .sum();
+
+        let transferred: Decimal = transfer_order_lines::table
+            .inner_join(transfer_orders::table)
+            .filter(transfer_orders::destination_warehouse_id.eq(warehouse_id))
+            .filter(transfer_orders::status.eq_any([
+                TransferOrderStatus::Open,
+                TransferOrderStatus::Shipped,
+                TransferOrderStatus::PartiallyReceived,
+            ]))
+            .filter(transfer_order_lines::product_id.eq(product_id))
+            .select(domain::TransferOrderLine::as_select())
+            .load::<domain::TransferOrderLine>(conn)
+            .await
+            .map_err(map_diesel_error)?
+            .iter()
+            .map(domain::TransferOrderLine::outstanding)
+            .sum();
+
+        Ok(ordered + transferred)
+    }
+
+    #[tracing::instrument(skip(self, rule_ids, suggestions))]
+    async fn replace_suggestions(
+        &self,
+        rule_ids: Vec<Uuid>,
+        suggestions: Vec<domain::ReplenishmentSuggestion>,
+    ) -> Result<Vec<domain::ReplenishmentSuggestion>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                diesel::update(
+                    replenishment_suggestions::table
+                        .filter(replenishment_suggestions::rule_id.eq_any(rule_ids))
+                        .filter(replenishment_suggestions::status.eq(SuggestionStatus::Pending)),
+                )
+                .set(replenishment_suggestions::status.eq(SuggestionStatus::Superseded))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                if suggestions.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                diesel::insert_into(replenishment_suggestions::table)
+                    .values(suggestions)
+                    .returning(domain::ReplenishmentSuggestion::as_returning())
+                    .get_results(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_suggestion(&self, id: Uuid) -> Result<domain::ReplenishmentSuggestion> {
+        replenishment_suggestions::table
+            .find(id)
+            .select(domain::ReplenishmentSuggestion::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_suggestions(
+        &self,
+        query: domain::ReplenishmentSuggestionQuery,
+    ) -> Result<Vec<domain::ReplenishmentSuggestion>> {
+        let mut suggestions = replenishment_suggestions::table
+            .select(domain::ReplenishmentSuggestion::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            suggestions =
+                suggestions.filter(replenishment_suggestions::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(status) = query.status {
+            suggestions = suggestions.filter(replenishment_suggestions::status.eq(status));
+        }
+
+        suggestions
+            .order((
+                replenishment_suggestions::created_at.desc(),
+                replenishment_suggestions::id,
+            ))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, suggestion, order), fields(id = %suggestion.id))]
+    async fn accept_as_purchase_order(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+        order: domain::PurchaseOrder,
+    ) -> Result<domain::ReplenishmentSuggestion> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                diesel::insert_into(purchase_orders::table)
+                    .values(order)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                review(conn, &suggestion).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, suggestion, movement), fields(id = %suggestion.id))]
+    async fn accept_as_movement(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+        movement: domain::StockMovement,
+    ) -> Result<domain::ReplenishmentSuggestion> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                apply_movement(conn, movement, &[], true).await?;
+
+                review(conn, &suggestion).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, suggestion), fields(id = %suggestion.id))]
+    async fn update_suggestion(
+        &self,
+        suggestion: domain::ReplenishmentSuggestion,
+    ) -> Result<domain::ReplenishmentSuggestion> {
+        let mut conn = self.get_connection().await?;
+        review(&mut conn, &suggestion).await
+    }
+}
+
+/// Saves the outcome of a review. Fails with `NotFound` if the suggestion is no longer
+/// pending, so concurrent reviews cannot both succeed.
+async fn review(
+    conn: &mut AsyncPgConnection,
+    suggestion: &domain::ReplenishmentSuggestion,
+) -> Result<domain::ReplenishmentSuggestion> {
+    diesel::update(
+        replenishment_suggestions::table
+            .find(suggestion.id)
+            .filter(replenishment_suggestions::status.eq(SuggestionStatus::Pending)),
+    )
+    .set((
+        replenishment_suggestions::status.eq(suggestion.status),
+        replenishment_suggestions::reviewed_by.eq(suggestion.reviewed_by),
+        replenishment_suggestions::reviewed_at.eq(suggestion.reviewed_at),
+        replenishment_suggestions::document_type.eq(suggestion.document_type),
+        replenishment_suggestions::document_id.eq(suggestion.document_id),
+    ))
+    .returning(domain::ReplenishmentSuggestion::as_returning())
+    .get_result(conn)
+    .await
+    .map_err(map_diesel_error)
+}
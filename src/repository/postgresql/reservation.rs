@@ -0,0 +1,257 @@
+use crate::contract::repository::{Repository, ReservationRepository};
+use crate::domain::{MovementKind, ReservationStatus, SerialError, StockError, StockStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{locations, reservations};
+use crate::repository::postgresql::stock::{
+    apply_movement, lock_balances, reserved_quantity, usable_on_hand,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresReservationRepository {
+    pool: db::Pool,
+}
+
+impl PostgresReservationRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+pub(super) async fn lock_reservation(
+    conn: &mut AsyncPgConnection,
+    id: Uuid,
+) -> Result<domain::Reservation> {
+    reservations::table
+        .find(id)
+        .select(domain::Reservation::as_select())
+        .for_update()
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+/// Issues `quantity` of the reserved stock out of its location, from `lot_id` when given and
+/// first-expired-first-out otherwise, and decreases the reservation accordingly.
+pub(super) async fn consume_reservation(
+    conn: &mut AsyncPgConnection,
+    id: Uuid,
+    quantity: Decimal,
+    lot_id: Option<Uuid>,
+    serial_numbers: &[String],
+    created_by: Option<Uuid>,
+) -> Result<Vec<domain::StockMovement>> {
+    let now = Utc::now();
+    let reservation = lock_reservation(conn, id).await?;
+    if !reservation.is_active_at(now) {
+        return Err(StockError::ReservationNotActive.into());
+    }
+    if quantity > reservation.quantity {
+        return Err(StockError::InsufficientStock {
+            requested: quantity,
+            available: reservation.quantity,
+        }
+        .into());
+    }
+
+    let remaining = reservation.quantity - quantity;
+    let status = if remaining.is_zero() {
+        ReservationStatus::Consumed
+    } else {
+        ReservationStatus::Active
+    };
+
+    diesel::update(reservations::table.find(id))
+        .set((
+            reservations::quantity.eq(remaining),
+            reservations::status.eq(status),
+        ))
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let movement = domain::StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::Issue,
+        product_id: reservation.product_id,
+        from_location_id: Some(reservation.location_id),
+        to_location_id: None,
+        quantity,
+        document_type: Some(reservation.document_type),
+        document_id: Some(reservation.document_id),
+        created_by,
+        created_at: now,
+        lot_id,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    };
+
+    // The consumed quantity is covered by this very reservation.
+    apply_movement(conn, movement, serial_numbers, false).await
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Reservation> for PostgresReservationRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Reservation) -> Result<domain::Reservation> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let balances = lock_balances(conn, val.product_id, val.location_id)
+                    .await
+                    .map_err(map_diesel_error)?;
+                let reserved =
+                    reserved_quantity(conn, val.product_id, val.location_id, val.created_at)
+                        .await
+                        .map_err(map_diesel_error)?;
+
+                let quarantine: bool = locations::table
+                    .find(val.location_id)
+                    .select(locations::quarantine)
+                    .first(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                // Quarantined stock cannot be promised to anyone.
+                let available = if quarantine {
+                    Decimal::ZERO
+                } else {
+                    (usable_on_hand(&balances, val.created_at.date_naive()) - reserved)
+                        .max(Decimal::ZERO)
+                };
+
+                if available < val.quantity {
+                    return Err(StockError::InsufficientStock {
+                        requested: val.quantity,
+                        available,
+                    }
+                    .into());
+                }
+
+                diesel::insert_into(reservations::table)
+                    .values(val)
+                    .returning(domain::Reservation::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Reservation> {
+        reservations::table
+            .find(id)
+            .select(domain::Reservation::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationRepository for PostgresReservationRepository {
+    #[tracing::instrument(skip(self))]
+    async fn release(&self, id: Uuid) -> Result<domain::Reservation> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let reservation = lock_reservation(conn, id).await?;
+                if reservation.status != ReservationStatus::Active {
+                    return Err(StockError::ReservationNotActive.into());
+                }
+
+                diesel::update(reservations::table.find(id))
+                    .set(reservations::status.eq(ReservationStatus::Released))
+                    .returning(domain::Reservation::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn consume(
+        &self,
+        id: Uuid,
+        quantity: Option<Decimal>,
+        serial_numbers: Vec<String>,
+        created_by: Option<Uuid>,
+    ) -> Result<Vec<domain::StockMovement>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let quantity = match quantity {
+                    Some(quantity) => quantity,
+                    None => {
+                        // What is still reserved once concurrent consumptions are through.
+                        let quantity = lock_reservation(conn, id).await?.quantity;
+                        if !serial_numbers.is_empty()
+                            && Decimal::from(serial_numbers.len()) != quantity
+                        {
+                            return Err(SerialError::CountMismatch.into());
+                        }
+                        quantity
+                    }
+                };
+
+                consume_reservation(conn, id, quantity, None, &serial_numbers, created_by).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_by_document(
+        &self,
+        document_type: domain::DocumentType,
+        document_id: Uuid,
+    ) -> Result<Vec<domain::Reservation>> {
+        reservations::table
+            .filter(reservations::document_type.eq(document_type))
+            .filter(reservations::document_id.eq(document_id))
+            .order((reservations::created_at, reservations::id))
+            .select(domain::Reservation::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn expire_overdue(&self, now: DateTime<Utc>) -> Result<usize> {
+        diesel::update(
+            reservations::table
+                .filter(reservations::status.eq(ReservationStatus::Active))
+                .filter(reservations::expires_at.le(now)),
+        )
+        .set(reservations::status.eq(ReservationStatus::Expired))
+        .execute(&mut self.get_connection().await?)
+        .await
+        .map_err(map_diesel_error)
+    }
+}
@@ -0,0 +1,417 @@
+use crate::contract::repository::{Repository, ReturnAuthorizationRepository};
+use crate::domain::{DocumentType, MovementKind, ReturnError, ReturnStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    return_authorization_lines, return_authorizations, return_inspections, stock_movements,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::dsl;
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+// Lives here rather than in the generated schema, which `diesel print-schema` rewrites.
+diesel::allow_columns_to_appear_in_same_group_by_clause!(
+    return_authorization_lines::product_id,
+    return_authorization_lines::reason,
+    return_inspections::disposition,
+);
+
+pub struct PostgresReturnAuthorizationRepository {
+    pool: db::Pool,
+}
+
+impl PostgresReturnAuthorizationRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::ReturnAuthorization> for PostgresReturnAuthorizationRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(
+        &self,
+        val: domain::ReturnAuthorization,
+    ) -> Result<domain::ReturnAuthorization> {
+        diesel::insert_into(return_authorizations::table)
+            .values(val)
+            .returning(domain::ReturnAuthorization::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::ReturnAuthorization> {
+        return_authorizations::table
+            .find(id)
+            .select(domain::ReturnAuthorization::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl ReturnAuthorizationRepository for PostgresReturnAuthorizationRepository {
+    #[tracing::instrument(skip(self, authorization, lines), fields(id = %authorization.id))]
+    async fn create_with_lines(
+        &self,
+        authorization: domain::ReturnAuthorization,
+        lines: Vec<domain::ReturnAuthorizationLine>,
+    ) -> Result<domain::ReturnAuthorization> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                // Authorizations of the same sales order are created one after the other, so
+                // they cannot together cover more than was shipped.
+                stock_movements::table
+                    .filter(stock_movements::kind.eq(MovementKind::Issue))
+                    .filter(stock_movements::document_type.eq(DocumentType::SalesOrder))
+                    .filter(stock_movements::document_id.eq(authorization.sales_order_id))
+                    .order(stock_movements::id)
+                    .select(stock_movements::id)
+                    .for_update()
+                    .load::<Uuid>(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                let mut requested = BTreeMap::<Uuid, Decimal>::new();
+                for line in &lines {
+                    *requested.entry(line.product_id).or_default() += line.quantity;
+                }
+                for (product_id, quantity) in requested {
+                    let shipped = shipped_quantity(conn, authorization.sales_order_id, product_id)
+                        .await?
+                        .ok_or(ReturnError::NotShipped)?;
+                    let authorized =
+                        authorized_quantity(conn, authorization.sales_order_id, product_id).await?;
+                    if authorized + quantity > shipped {
+                        return Err(ReturnError::ExceedsShipped.into());
+                    }
+                }
+
+                let authorization = diesel::insert_into(return_authorizations::table)
+                    .values(authorization)
+                    .returning(domain::ReturnAuthorization::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                diesel::insert_into(return_authorization_lines::table)
+                    .values(lines)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(authorization)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_lines(&self, id: Uuid) -> Result<Vec<domain::ReturnAuthorizationLine>> {
+        return_authorization_lines::table
+            .filter(return_authorization_lines::return_authorization_id.eq(id))
+            .order(return_authorization_lines::id)
+            .select(domain::ReturnAuthorizationLine::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_inspections(&self, id: Uuid) -> Result<Vec<domain::ReturnInspection>> {
+        return_inspections::table
+            .inner_join(return_authorization_lines::table)
+            .filter(return_authorization_lines::return_authorization_id.eq(id))
+            .order((return_inspections::inspected_at, return_inspections::id))
+            .select(domain::ReturnInspection::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn shipped_quantity(
+        &self,
+        sales_order_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Option<Decimal>> {
+        let conn = &mut self.get_connection().await?;
+        shipped_quantity(conn, sales_order_id, product_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn authorized_quantity(&self, sales_order_id: Uuid, product_id: Uuid) -> Result<Decimal> {
+        let conn = &mut self.get_connection().await?;
+        authorized_quantity(conn, sales_order_id, product_id).await
+    }
+
+    #[tracing::instrument(
+        skip(self, authorization, lines, read_lines, inspections, movements),
+        fields(id = %authorization.id)
+    )]
+    async fn update_with_movements(
+        &self,
+        authorization: domain::ReturnAuthorization,
+        lines: Vec<domain::ReturnAuthorizationLine>,
+        read_lines: Vec<domain::ReturnAuthorizationLine>,
+        inspections: Vec<domain::ReturnInspection>,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<(domain::ReturnAuthorization, Vec<domain::StockMovement>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_authorization(conn, authorization.id, &read_lines).await?;
+
+                let mut posted = Vec::new();
+                for (movement, serial_numbers) in movements {
+                    posted.extend(apply_movement(conn, movement, &serial_numbers, true).await?);
+                }
+
+                for line in lines {
+                    diesel::update(return_authorization_lines::table.find(line.id))
+                        .set((
+                            return_authorization_lines::received_quantity
+                                .eq(line.received_quantity),
+                            return_authorization_lines::inspected_quantity
+                                .eq(line.inspected_quantity),
+                        ))
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                if !inspections.is_empty() {
+                    diesel::insert_into(return_inspections::table)
+                        .values(inspections)
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                let authorization =
+                    diesel::update(return_authorizations::table.find(authorization.id))
+                        .set((
+                            return_authorizations::status.eq(authorization.status),
+                            return_authorizations::closed_at.eq(authorization.closed_at),
+                        ))
+                        .returning(domain::ReturnAuthorization::as_returning())
+                        .get_result(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+
+                Ok((authorization, posted))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_movements(&self, id: Uuid) -> Result<Vec<domain::StockMovement>> {
+        stock_movements::table
+            .filter(stock_movements::document_type.eq(DocumentType::ReturnAuthorization))
+            .filter(stock_movements::document_id.eq(id))
+            .order((stock_movements::created_at, stock_movements::id))
+            .select(domain::StockMovement::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn summarize(
+        &self,
+        query: domain::ReturnSummaryQuery,
+    ) -> Result<Vec<domain::ReturnSummary>> {
+        let conn = &mut self.get_connection().await?;
+
+        let mut inspected = return_inspections::table
+            .inner_join(return_authorization_lines::table.inner_join(return_authorizations::table))
+            .group_by((
+                return_authorization_lines::product_id,
+                return_authorization_lines::reason,
+                return_inspections::disposition,
+            ))
+            .select((
+                return_authorization_lines::product_id,
+                return_authorization_lines::reason,
+                return_inspections::disposition,
+                dsl::sum(return_inspections::quantity),
+            ))
+            .into_boxed();
+        let mut awaiting = return_authorization_lines::table
+            .inner_join(return_authorizations::table)
+            .filter(
+                return_authorization_lines::received_quantity
+                    .gt(return_authorization_lines::inspected_quantity),
+            )
+            .group_by((
+                return_authorization_lines::product_id,
+                return_authorization_lines::reason,
+            ))
+            .select((
+                return_authorization_lines::product_id,
+                return_authorization_lines::reason,
+                dsl::sum(
+                    return_authorization_lines::received_quantity
+                        - return_authorization_lines::inspected_quantity,
+                ),
+            ))
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            inspected = inspected.filter(return_authorizations::warehouse_id.eq(warehouse_id));
+            awaiting = awaiting.filter(return_authorizations::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(product_id) = query.product_id {
+            inspected = inspected.filter(return_authorization_lines::product_id.eq(product_id));
+            awaiting = awaiting.filter(return_authorization_lines::product_id.eq(product_id));
+        }
+        if let Some(from) = query.from {
+            inspected = inspected.filter(return_authorizations::created_at.ge(from));
+            awaiting = awaiting.filter(return_authorizations::created_at.ge(from));
+        }
+        if let Some(to) = query.to {
+            inspected = inspected.filter(return_authorizations::created_at.lt(to));
+            awaiting = awaiting.filter(return_authorizations::created_at.lt(to));
+        }
+
+        let mut summaries: Vec<domain::ReturnSummary> = inspected
+            .load::<(
+                Uuid,
+                domain::ReturnReason,
+                domain::ReturnDisposition,
+                Option<Decimal>,
+            )>(conn)
+            .await
+            .map_err(map_diesel_error)?
+            .into_iter()
+            .map(
+                |(product_id, reason, disposition, quantity)| domain::ReturnSummary {
+                    product_id,
+                    reason,
+                    disposition: Some(disposition),
+                    quantity: quantity.unwrap_or_default(),
+                },
+            )
+            .collect();
+        summaries.extend(
+            awaiting
+                .load::<(Uuid, domain::ReturnReason, Option<Decimal>)>(conn)
+                .await
+                .map_err(map_diesel_error)?
+                .into_iter()
+                .map(|(product_id, reason, quantity)| domain::ReturnSummary {
+                    product_id,
+                    reason,
+                    disposition: None,
+                    quantity: quantity.unwrap_or_default(),
+                }),
+        );
+
+        summaries.sort_by_key(|summary| (summary.product_id, summary.reason, summary.disposition));
+
+        Ok(summaries)
+    }
+}
+
+/// Locks the authorization, so updates of it run one after the other, and checks that it is
+/// still open and that what its lines received or inspected is still as it was read.
+async fn lock_authorization(
+    conn: &mut AsyncPgConnection,
+    id: Uuid,
+    read_lines: &[domain::ReturnAuthorizationLine],
+) -> Result<()> {
+    let status: ReturnStatus = return_authorizations::table
+        .find(id)
+        .select(return_authorizations::status)
+        .for_update()
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)?;
+    if status != ReturnStatus::Open {
+        return Err(ReturnError::NotOpen.into());
+    }
+
+    let lines: Vec<(Uuid, Decimal, Decimal)> = return_authorization_lines::table
+        .filter(return_authorization_lines::return_authorization_id.eq(id))
+        .select((
+            return_authorization_lines::id,
+            return_authorization_lines::received_quantity,
+            return_authorization_lines::inspected_quantity,
+        ))
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let unchanged = read_lines
+        .iter()
+        .all(|read| lines.contains(&(read.id, read.received_quantity, read.inspected_quantity)));
+    if !unchanged {
+        return Err(ReturnError::Changed.into());
+    }
+
+    Ok(())
+}
+
+async fn shipped_quantity(
+    conn: &mut AsyncPgConnection,
+    sales_order_id: Uuid,
+    product_id: Uuid,
+) -> Result<Option<Decimal>> {
+    stock_movements::table
+        .filter(stock_movements::kind.eq(MovementKind::Issue))
+        .filter(stock_movements::document_type.eq(DocumentType::SalesOrder))
+        .filter(stock_movements::document_id.eq(sales_order_id))
+        .filter(stock_movements::product_id.eq(product_id))
+        .select(dsl::sum(stock_movements::quantity))
+        .get_result(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+async fn authorized_quantity(
+    conn: &mut AsyncPgConnection,
+    sales_order_id: Uuid,
+    product_id: Uuid,
+) -> Result<Decimal> {
+    let lines: Vec<(domain::ReturnAuthorizationLine, ReturnStatus)> =
+        return_authorization_lines::table
+            .inner_join(return_authorizations::table)
+            .filter(return_authorizations::sales_order_id.eq(sales_order_id))
+            .filter(return_authorization_lines::product_id.eq(product_id))
+            .select((
+                domain::ReturnAuthorizationLine::as_select(),
+                return_authorizations::status,
+            ))
+            .load(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+    Ok(lines
+        .into_iter()
+        .map(|(line, status)| match status {
+            ReturnStatus::Closed => line.received_quantity,
+            _ => line.quantity,
+        })
+        .sum())
+}
@@ -1,6 +1,6 @@
 use crate::contract::repository::{Repository, RuleRepository};
 use crate::repository::postgresql::map_diesel_error;
-use crate::repository::postgresql::schema::rules;
+use crate::repository::postgresql::schema::{role_rules, rules, user_roles};
 use crate::{db, domain};
 use anyhow::{Context, Result};
 use diesel::prelude::*;
@@ -45,4 +45,17 @@ impl Repository<domain::Rule> for PostgresRuleRepository {
 }
 
 #[async_trait::async_trait]
-impl RuleRepository for PostgresRuleRepository {}
+impl RuleRepository for PostgresRuleRepository {
+    #[tracing::instrument(skip(self))]
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<domain::Rule>> {
+        rules::table
+            .inner_join(role_rules::table.on(role_rules::rule_id.eq(rules::id)))
+            .inner_join(user_roles::table.on(user_roles::role_id.eq(role_rules::role_id)))
+            .filter(user_roles::user_id.eq(user_id))
+            .select(domain::Rule::as_select())
+            .distinct()
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
@@ -1,6 +1,90 @@
 // @generated automatically by Diesel CLI.
 
 pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "abc_class"))]
+    pub struct AbcClass;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "abc_criterion"))]
+    pub struct AbcCriterion;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "address_kind"))]
+    pub struct AddressKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "assembly_kind"))]
+    pub struct AssemblyKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "assembly_order_status"))]
+    pub struct AssemblyOrderStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "attachment_owner"))]
+    pub struct AttachmentOwner;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "attribute_entity"))]
+    pub struct AttributeEntity;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "attribute_kind"))]
+    pub struct AttributeKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "costing_method"))]
+    pub struct CostingMethod;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "count_scope"))]
+    pub struct CountScope;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "count_session_status"))]
+    pub struct CountSessionStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "count_task_status"))]
+    pub struct CountTaskStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "demand_status"))]
+    pub struct DemandStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "document_type"))]
+    pub struct DocumentType;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "movement_kind"))]
+    pub struct MovementKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "pick_order_status"))]
+    pub struct PickOrderStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "pick_task_status"))]
+    pub struct PickTaskStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "putaway_strategy"))]
+    pub struct PutawayStrategy;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "putaway_task_status"))]
+    pub struct PutawayTaskStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "replenishment_kind"))]
+    pub struct ReplenishmentKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "reservation_status"))]
+    pub struct ReservationStatus;
+
     #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "resource_action"))]
     pub struct ResourceAction;
@@ -9,9 +93,661 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "resource_type"))]
     pub struct ResourceType;
 
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "return_disposition"))]
+    pub struct ReturnDisposition;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "return_reason"))]
+    pub struct ReturnReason;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "return_status"))]
+    pub struct ReturnStatus;
+
     #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "rule_effect"))]
     pub struct RuleEffect;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "serial_status"))]
+    pub struct SerialStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "shipment_status"))]
+    pub struct ShipmentStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "slotting_status"))]
+    pub struct SlottingStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "snapshot_kind"))]
+    pub struct SnapshotKind;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "stock_status"))]
+    pub struct StockStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "suggestion_status"))]
+    pub struct SuggestionStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "transfer_order_status"))]
+    pub struct TransferOrderStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "wave_status"))]
+    pub struct WaveStatus;
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AbcCriterion;
+
+    abc_analyses (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        criterion -> AbcCriterion,
+        window_days -> Int4,
+        period_start -> Timestamptz,
+        period_end -> Timestamptz,
+        a_share -> Numeric,
+        b_share -> Numeric,
+        applied_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AbcClass;
+
+    abc_analysis_lines (id) {
+        id -> Uuid,
+        analysis_id -> Uuid,
+        product_id -> Uuid,
+        rank -> Int4,
+        score -> Numeric,
+        share -> Numeric,
+        cumulative_share -> Numeric,
+        abc_class -> AbcClass,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    assembly_order_lines (id) {
+        id -> Uuid,
+        assembly_order_id -> Uuid,
+        product_id -> Uuid,
+        quantity -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AssemblyKind;
+    use super::sql_types::AssemblyOrderStatus;
+
+    assembly_orders (id) {
+        id -> Uuid,
+        kind -> AssemblyKind,
+        kit_product_id -> Uuid,
+        warehouse_id -> Uuid,
+        from_location_id -> Uuid,
+        to_location_id -> Uuid,
+        quantity -> Numeric,
+        status -> AssemblyOrderStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AttachmentOwner;
+
+    attachments (id) {
+        id -> Uuid,
+        owner_type -> AttachmentOwner,
+        owner_id -> Uuid,
+        #[max_length = 255]
+        file_name -> Varchar,
+        #[max_length = 127]
+        content_type -> Varchar,
+        size -> Int8,
+        #[max_length = 255]
+        storage_key -> Varchar,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AttributeEntity;
+    use super::sql_types::AttributeKind;
+
+    attribute_definitions (id) {
+        id -> Uuid,
+        entity -> AttributeEntity,
+        #[max_length = 64]
+        key -> Varchar,
+        #[max_length = 128]
+        label -> Varchar,
+        kind -> AttributeKind,
+        required -> Bool,
+        options -> Array<Text>,
+        position -> Int4,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    bom_components (kit_product_id, component_product_id) {
+        kit_product_id -> Uuid,
+        component_product_id -> Uuid,
+        quantity -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    business_partners (id) {
+        id -> Uuid,
+        #[max_length = 32]
+        code -> Varchar,
+        #[max_length = 256]
+        name -> Varchar,
+        #[max_length = 256]
+        normalized_name -> Varchar,
+        #[max_length = 32]
+        tax_id -> Nullable<Varchar>,
+        is_supplier -> Bool,
+        is_customer -> Bool,
+        is_carrier -> Bool,
+        #[max_length = 256]
+        email -> Nullable<Varchar>,
+        #[max_length = 32]
+        phone -> Nullable<Varchar>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    cost_layers (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        movement_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        remaining_quantity -> Numeric,
+        remaining_value -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CountScope;
+    use super::sql_types::AbcClass;
+    use super::sql_types::CountSessionStatus;
+
+    count_sessions (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        scope -> CountScope,
+        #[max_length = 32]
+        zone -> Nullable<Varchar>,
+        abc_class -> Nullable<AbcClass>,
+        blind -> Bool,
+        tolerance_percent -> Numeric,
+        status -> CountSessionStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CountTaskStatus;
+
+    count_tasks (id) {
+        id -> Uuid,
+        session_id -> Uuid,
+        product_id -> Uuid,
+        location_id -> Uuid,
+        expected_quantity -> Numeric,
+        counted_quantity -> Nullable<Numeric>,
+        variance -> Nullable<Numeric>,
+        attempts -> Int2,
+        status -> CountTaskStatus,
+        counted_by -> Nullable<Uuid>,
+        counted_at -> Nullable<Timestamptz>,
+        approved_by -> Nullable<Uuid>,
+        movement_id -> Nullable<Uuid>,
+        lot_id -> Nullable<Uuid>,
+        organization_id -> Uuid,
+        counters -> Array<Uuid>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::DocumentType;
+
+    cross_docks (id) {
+        id -> Uuid,
+        demand_id -> Uuid,
+        warehouse_id -> Uuid,
+        product_id -> Uuid,
+        from_location_id -> Uuid,
+        staging_location_id -> Uuid,
+        quantity -> Numeric,
+        inbound_document_type -> Nullable<DocumentType>,
+        inbound_document_id -> Nullable<Uuid>,
+        reservation_id -> Uuid,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    lots (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        #[max_length = 64]
+        lot_number -> Nullable<Varchar>,
+        expiry_date -> Nullable<Date>,
+        blocked -> Bool,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    locations (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        #[max_length = 64]
+        code -> Varchar,
+        #[max_length = 32]
+        zone -> Nullable<Varchar>,
+        in_transit -> Bool,
+        quarantine -> Bool,
+        max_volume -> Nullable<Numeric>,
+        max_weight -> Nullable<Numeric>,
+        position_x -> Nullable<Numeric>,
+        position_y -> Nullable<Numeric>,
+        pick_sequence -> Nullable<Int4>,
+        organization_id -> Uuid,
+        attributes -> Jsonb,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AbcClass;
+    use super::sql_types::CostingMethod;
+
+    products (id) {
+        id -> Uuid,
+        #[max_length = 64]
+        sku -> Varchar,
+        #[max_length = 256]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        abc_class -> Nullable<AbcClass>,
+        lot_tracked -> Bool,
+        expiry_tracked -> Bool,
+        serial_tracked -> Bool,
+        #[max_length = 16]
+        base_uom -> Varchar,
+        fractional_quantities -> Bool,
+        costing_method -> Nullable<CostingMethod>,
+        #[max_length = 64]
+        category -> Nullable<Varchar>,
+        unit_volume -> Nullable<Numeric>,
+        unit_weight -> Nullable<Numeric>,
+        organization_id -> Uuid,
+        attributes -> Jsonb,
+    }
+}
+
+diesel::table! {
+    organizations (id) {
+        id -> Uuid,
+        #[max_length = 128]
+        name -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::DocumentType;
+    use super::sql_types::DemandStatus;
+
+    outbound_demands (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        document_type -> DocumentType,
+        document_id -> Uuid,
+        product_id -> Uuid,
+        quantity -> Numeric,
+        allocated_quantity -> Numeric,
+        due_at -> Nullable<Timestamptz>,
+        status -> DemandStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    pallets (id) {
+        id -> Uuid,
+        #[max_length = 18]
+        sscc -> Bpchar,
+        location_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    parcels (id) {
+        id -> Uuid,
+        shipment_id -> Uuid,
+        length_cm -> Numeric,
+        width_cm -> Numeric,
+        height_cm -> Numeric,
+        weight_kg -> Numeric,
+        #[max_length = 64]
+        tracking_number -> Nullable<Varchar>,
+        label -> Nullable<Bytea>,
+        #[max_length = 64]
+        label_content_type -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AddressKind;
+
+    partner_addresses (id) {
+        id -> Uuid,
+        partner_id -> Uuid,
+        kind -> AddressKind,
+        #[max_length = 512]
+        street -> Varchar,
+        #[max_length = 128]
+        city -> Varchar,
+        #[max_length = 16]
+        postal_code -> Varchar,
+        #[max_length = 2]
+        country -> Bpchar,
+        is_default -> Bool,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    partner_contacts (id) {
+        id -> Uuid,
+        partner_id -> Uuid,
+        #[max_length = 128]
+        name -> Varchar,
+        #[max_length = 256]
+        email -> Nullable<Varchar>,
+        #[max_length = 32]
+        phone -> Nullable<Varchar>,
+        #[max_length = 128]
+        position -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    product_uoms (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        #[max_length = 16]
+        code -> Varchar,
+        factor -> Numeric,
+        #[max_length = 64]
+        barcode -> Nullable<Varchar>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PickOrderStatus;
+
+    pick_orders (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        document_id -> Uuid,
+        priority -> Int4,
+        #[max_length = 64]
+        carrier -> Nullable<Varchar>,
+        cutoff_at -> Nullable<Timestamptz>,
+        status -> PickOrderStatus,
+        wave_id -> Nullable<Uuid>,
+        tote -> Nullable<Int4>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PickTaskStatus;
+
+    pick_tasks (id) {
+        id -> Uuid,
+        wave_id -> Uuid,
+        pick_order_id -> Uuid,
+        reservation_id -> Uuid,
+        product_id -> Uuid,
+        location_id -> Uuid,
+        quantity -> Numeric,
+        picked_quantity -> Nullable<Numeric>,
+        sequence -> Int4,
+        tote -> Nullable<Int4>,
+        status -> PickTaskStatus,
+        picked_by -> Nullable<Uuid>,
+        picked_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    purchase_orders (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        product_id -> Uuid,
+        quantity -> Numeric,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PutawayStrategy;
+
+    putaway_rules (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        sequence -> Int4,
+        strategy -> PutawayStrategy,
+        product_id -> Nullable<Uuid>,
+        #[max_length = 64]
+        category -> Nullable<Varchar>,
+        location_id -> Nullable<Uuid>,
+        #[max_length = 32]
+        zone -> Nullable<Varchar>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PutawayStrategy;
+    use super::sql_types::PutawayTaskStatus;
+
+    putaway_tasks (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        product_id -> Uuid,
+        from_location_id -> Uuid,
+        quantity -> Numeric,
+        suggested_location_id -> Nullable<Uuid>,
+        strategy -> Nullable<PutawayStrategy>,
+        to_location_id -> Nullable<Uuid>,
+        status -> PutawayTaskStatus,
+        movement_id -> Nullable<Uuid>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        completed_by -> Nullable<Uuid>,
+        completed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    replenishment_rules (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        warehouse_id -> Uuid,
+        location_id -> Nullable<Uuid>,
+        reorder_point -> Numeric,
+        max_quantity -> Nullable<Numeric>,
+        reorder_quantity -> Nullable<Numeric>,
+        updated_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReplenishmentKind;
+    use super::sql_types::SuggestionStatus;
+    use super::sql_types::DocumentType;
+
+    replenishment_suggestions (id) {
+        id -> Uuid,
+        rule_id -> Uuid,
+        kind -> ReplenishmentKind,
+        product_id -> Uuid,
+        warehouse_id -> Uuid,
+        location_id -> Nullable<Uuid>,
+        from_location_id -> Nullable<Uuid>,
+        quantity -> Numeric,
+        available -> Numeric,
+        inbound -> Numeric,
+        status -> SuggestionStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        reviewed_by -> Nullable<Uuid>,
+        reviewed_at -> Nullable<Timestamptz>,
+        document_type -> Nullable<DocumentType>,
+        document_id -> Nullable<Uuid>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReservationStatus;
+    use super::sql_types::DocumentType;
+
+    reservations (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        location_id -> Uuid,
+        quantity -> Numeric,
+        status -> ReservationStatus,
+        document_type -> DocumentType,
+        document_id -> Uuid,
+        expires_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReturnReason;
+
+    return_authorization_lines (id) {
+        id -> Uuid,
+        return_authorization_id -> Uuid,
+        product_id -> Uuid,
+        reason -> ReturnReason,
+        #[max_length = 512]
+        note -> Nullable<Varchar>,
+        quantity -> Numeric,
+        received_quantity -> Numeric,
+        inspected_quantity -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReturnStatus;
+
+    return_authorizations (id) {
+        id -> Uuid,
+        sales_order_id -> Uuid,
+        warehouse_id -> Uuid,
+        status -> ReturnStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        closed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ReturnDisposition;
+
+    return_inspections (id) {
+        id -> Uuid,
+        line_id -> Uuid,
+        quantity -> Numeric,
+        disposition -> ReturnDisposition,
+        to_location_id -> Nullable<Uuid>,
+        #[max_length = 512]
+        note -> Nullable<Varchar>,
+        inspected_by -> Nullable<Uuid>,
+        inspected_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
 }
 
 diesel::table! {
@@ -19,6 +755,7 @@ diesel::table! {
         role_id -> Uuid,
         rule_id -> Uuid,
         assigned_by -> Nullable<Uuid>,
+        organization_id -> Uuid,
     }
 }
 
@@ -28,6 +765,7 @@ diesel::table! {
         #[max_length = 100]
         name -> Varchar,
         description -> Nullable<Text>,
+        organization_id -> Uuid,
     }
 }
 
@@ -42,6 +780,199 @@ diesel::table! {
         action -> ResourceAction,
         resource_type -> ResourceType,
         effect -> RuleEffect,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SerialStatus;
+
+    serial_numbers (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        #[max_length = 128]
+        serial_number -> Varchar,
+        status -> SerialStatus,
+        location_id -> Nullable<Uuid>,
+        lot_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ShipmentStatus;
+
+    shipments (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        pick_order_id -> Nullable<Uuid>,
+        document_id -> Nullable<Uuid>,
+        #[max_length = 128]
+        ship_to_name -> Varchar,
+        #[max_length = 512]
+        ship_to_address -> Varchar,
+        #[max_length = 16]
+        ship_to_postal_code -> Varchar,
+        #[max_length = 2]
+        ship_to_country -> Bpchar,
+        #[max_length = 64]
+        carrier -> Nullable<Varchar>,
+        #[max_length = 64]
+        service -> Nullable<Varchar>,
+        shipping_cost -> Nullable<Numeric>,
+        #[max_length = 3]
+        currency -> Nullable<Bpchar>,
+        status -> ShipmentStatus,
+        shipped_by -> Nullable<Uuid>,
+        shipped_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SlottingStatus;
+
+    slotting_recommendations (id) {
+        id -> Uuid,
+        analysis_id -> Uuid,
+        warehouse_id -> Uuid,
+        product_id -> Uuid,
+        rank -> Int4,
+        from_location_id -> Uuid,
+        to_location_id -> Uuid,
+        displaced_product_id -> Nullable<Uuid>,
+        status -> SlottingStatus,
+        reviewed_by -> Nullable<Uuid>,
+        reviewed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::StockStatus;
+
+    stock_balances (id) {
+        id -> Uuid,
+        product_id -> Uuid,
+        location_id -> Uuid,
+        on_hand -> Numeric,
+        lot_id -> Nullable<Uuid>,
+        status -> StockStatus,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MovementKind;
+    use super::sql_types::DocumentType;
+    use super::sql_types::StockStatus;
+
+    stock_movements (id) {
+        id -> Uuid,
+        kind -> MovementKind,
+        product_id -> Uuid,
+        from_location_id -> Nullable<Uuid>,
+        to_location_id -> Nullable<Uuid>,
+        quantity -> Numeric,
+        document_type -> Nullable<DocumentType>,
+        document_id -> Nullable<Uuid>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        lot_id -> Nullable<Uuid>,
+        unit_cost -> Nullable<Numeric>,
+        total_cost -> Nullable<Numeric>,
+        status -> StockStatus,
+        to_status -> Nullable<StockStatus>,
+        #[max_length = 256]
+        reason -> Nullable<Varchar>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    stock_movement_serials (movement_id, serial_number_id) {
+        movement_id -> Uuid,
+        serial_number_id -> Uuid,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::StockStatus;
+
+    stock_snapshot_lines (id) {
+        id -> Uuid,
+        snapshot_id -> Uuid,
+        product_id -> Uuid,
+        location_id -> Uuid,
+        lot_id -> Nullable<Uuid>,
+        status -> StockStatus,
+        quantity -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SnapshotKind;
+
+    stock_snapshots (id) {
+        id -> Uuid,
+        kind -> SnapshotKind,
+        as_of -> Timestamptz,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    transfer_order_lines (id) {
+        id -> Uuid,
+        transfer_order_id -> Uuid,
+        product_id -> Uuid,
+        from_location_id -> Uuid,
+        quantity -> Numeric,
+        received_quantity -> Numeric,
+        written_off_quantity -> Numeric,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TransferOrderStatus;
+
+    transfer_orders (id) {
+        id -> Uuid,
+        source_warehouse_id -> Uuid,
+        destination_warehouse_id -> Uuid,
+        transit_location_id -> Nullable<Uuid>,
+        status -> TransferOrderStatus,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        shipped_at -> Nullable<Timestamptz>,
+        closed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    user_organizations (user_id, organization_id) {
+        user_id -> Uuid,
+        organization_id -> Uuid,
+        added_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -50,6 +981,7 @@ diesel::table! {
         user_id -> Uuid,
         role_id -> Uuid,
         assigned_by -> Nullable<Uuid>,
+        organization_id -> Uuid,
     }
 }
 
@@ -67,8 +999,245 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CostingMethod;
+
+    valuation_settings (organization_id) {
+        id -> Bool,
+        costing_method -> CostingMethod,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WaveStatus;
+
+    waves (id) {
+        id -> Uuid,
+        warehouse_id -> Uuid,
+        status -> WaveStatus,
+        totes -> Nullable<Int4>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        completed_at -> Nullable<Timestamptz>,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    warehouses (id) {
+        id -> Uuid,
+        #[max_length = 32]
+        code -> Varchar,
+        #[max_length = 256]
+        name -> Varchar,
+        organization_id -> Uuid,
+    }
+}
+
+diesel::joinable!(abc_analyses -> organizations (organization_id));
+diesel::joinable!(abc_analyses -> users (created_by));
+diesel::joinable!(abc_analyses -> warehouses (warehouse_id));
+diesel::joinable!(abc_analysis_lines -> abc_analyses (analysis_id));
+diesel::joinable!(abc_analysis_lines -> organizations (organization_id));
+diesel::joinable!(abc_analysis_lines -> products (product_id));
+diesel::joinable!(assembly_order_lines -> assembly_orders (assembly_order_id));
+diesel::joinable!(assembly_order_lines -> organizations (organization_id));
+diesel::joinable!(assembly_order_lines -> products (product_id));
+diesel::joinable!(assembly_orders -> organizations (organization_id));
+diesel::joinable!(assembly_orders -> users (created_by));
+diesel::joinable!(assembly_orders -> warehouses (warehouse_id));
+diesel::joinable!(attachments -> organizations (organization_id));
+diesel::joinable!(attachments -> users (created_by));
+diesel::joinable!(attribute_definitions -> organizations (organization_id));
+diesel::joinable!(bom_components -> organizations (organization_id));
+diesel::joinable!(business_partners -> organizations (organization_id));
+diesel::joinable!(business_partners -> users (created_by));
+diesel::joinable!(cost_layers -> organizations (organization_id));
+diesel::joinable!(cost_layers -> products (product_id));
+diesel::joinable!(cost_layers -> stock_movements (movement_id));
+diesel::joinable!(count_sessions -> organizations (organization_id));
+diesel::joinable!(count_sessions -> users (created_by));
+diesel::joinable!(count_sessions -> warehouses (warehouse_id));
+diesel::joinable!(count_tasks -> count_sessions (session_id));
+diesel::joinable!(count_tasks -> locations (location_id));
+diesel::joinable!(count_tasks -> lots (lot_id));
+diesel::joinable!(count_tasks -> organizations (organization_id));
+diesel::joinable!(count_tasks -> products (product_id));
+diesel::joinable!(count_tasks -> stock_movements (movement_id));
+diesel::joinable!(locations -> organizations (organization_id));
+diesel::joinable!(locations -> warehouses (warehouse_id));
+diesel::joinable!(cross_docks -> organizations (organization_id));
+diesel::joinable!(cross_docks -> outbound_demands (demand_id));
+diesel::joinable!(cross_docks -> products (product_id));
+diesel::joinable!(cross_docks -> reservations (reservation_id));
+diesel::joinable!(cross_docks -> users (created_by));
+diesel::joinable!(cross_docks -> warehouses (warehouse_id));
+diesel::joinable!(lots -> organizations (organization_id));
+diesel::joinable!(lots -> products (product_id));
+diesel::joinable!(outbound_demands -> organizations (organization_id));
+diesel::joinable!(outbound_demands -> products (product_id));
+diesel::joinable!(outbound_demands -> users (created_by));
+diesel::joinable!(outbound_demands -> warehouses (warehouse_id));
+diesel::joinable!(pallets -> locations (location_id));
+diesel::joinable!(pallets -> organizations (organization_id));
+diesel::joinable!(parcels -> organizations (organization_id));
+diesel::joinable!(parcels -> shipments (shipment_id));
+diesel::joinable!(partner_addresses -> business_partners (partner_id));
+diesel::joinable!(partner_addresses -> organizations (organization_id));
+diesel::joinable!(partner_contacts -> business_partners (partner_id));
+diesel::joinable!(partner_contacts -> organizations (organization_id));
+diesel::joinable!(pick_orders -> organizations (organization_id));
+diesel::joinable!(pick_orders -> users (created_by));
+diesel::joinable!(pick_orders -> warehouses (warehouse_id));
+diesel::joinable!(pick_orders -> waves (wave_id));
+diesel::joinable!(pick_tasks -> locations (location_id));
+diesel::joinable!(pick_tasks -> organizations (organization_id));
+diesel::joinable!(pick_tasks -> pick_orders (pick_order_id));
+diesel::joinable!(pick_tasks -> products (product_id));
+diesel::joinable!(pick_tasks -> reservations (reservation_id));
+diesel::joinable!(pick_tasks -> users (picked_by));
+diesel::joinable!(pick_tasks -> waves (wave_id));
+diesel::joinable!(product_uoms -> organizations (organization_id));
+diesel::joinable!(product_uoms -> products (product_id));
+diesel::joinable!(products -> organizations (organization_id));
+diesel::joinable!(purchase_orders -> organizations (organization_id));
+diesel::joinable!(purchase_orders -> products (product_id));
+diesel::joinable!(purchase_orders -> users (created_by));
+diesel::joinable!(purchase_orders -> warehouses (warehouse_id));
+diesel::joinable!(putaway_rules -> locations (location_id));
+diesel::joinable!(putaway_rules -> organizations (organization_id));
+diesel::joinable!(putaway_rules -> products (product_id));
+diesel::joinable!(putaway_rules -> warehouses (warehouse_id));
+diesel::joinable!(putaway_tasks -> organizations (organization_id));
+diesel::joinable!(putaway_tasks -> products (product_id));
+diesel::joinable!(putaway_tasks -> stock_movements (movement_id));
+diesel::joinable!(putaway_tasks -> warehouses (warehouse_id));
+diesel::joinable!(replenishment_rules -> locations (location_id));
+diesel::joinable!(replenishment_rules -> organizations (organization_id));
+diesel::joinable!(replenishment_rules -> products (product_id));
+diesel::joinable!(replenishment_rules -> warehouses (warehouse_id));
+diesel::joinable!(replenishment_suggestions -> organizations (organization_id));
+diesel::joinable!(replenishment_suggestions -> products (product_id));
+diesel::joinable!(replenishment_suggestions -> replenishment_rules (rule_id));
+diesel::joinable!(replenishment_suggestions -> warehouses (warehouse_id));
+diesel::joinable!(reservations -> locations (location_id));
+diesel::joinable!(reservations -> organizations (organization_id));
+diesel::joinable!(reservations -> products (product_id));
+diesel::joinable!(reservations -> users (created_by));
+diesel::joinable!(return_authorization_lines -> organizations (organization_id));
+diesel::joinable!(return_authorization_lines -> products (product_id));
+diesel::joinable!(return_authorization_lines -> return_authorizations (return_authorization_id));
+diesel::joinable!(return_authorizations -> organizations (organization_id));
+diesel::joinable!(return_authorizations -> users (created_by));
+diesel::joinable!(return_authorizations -> warehouses (warehouse_id));
+diesel::joinable!(return_inspections -> locations (to_location_id));
+diesel::joinable!(return_inspections -> organizations (organization_id));
+diesel::joinable!(return_inspections -> return_authorization_lines (line_id));
+diesel::joinable!(return_inspections -> users (inspected_by));
+diesel::joinable!(role_rules -> organizations (organization_id));
 diesel::joinable!(role_rules -> roles (role_id));
 diesel::joinable!(role_rules -> rules (rule_id));
+diesel::joinable!(roles -> organizations (organization_id));
+diesel::joinable!(rules -> organizations (organization_id));
+diesel::joinable!(serial_numbers -> locations (location_id));
+diesel::joinable!(serial_numbers -> lots (lot_id));
+diesel::joinable!(serial_numbers -> organizations (organization_id));
+diesel::joinable!(serial_numbers -> products (product_id));
+diesel::joinable!(shipments -> organizations (organization_id));
+diesel::joinable!(shipments -> pick_orders (pick_order_id));
+diesel::joinable!(shipments -> warehouses (warehouse_id));
+diesel::joinable!(slotting_recommendations -> abc_analyses (analysis_id));
+diesel::joinable!(slotting_recommendations -> organizations (organization_id));
+diesel::joinable!(slotting_recommendations -> users (reviewed_by));
+diesel::joinable!(slotting_recommendations -> warehouses (warehouse_id));
+diesel::joinable!(stock_balances -> locations (location_id));
+diesel::joinable!(stock_balances -> lots (lot_id));
+diesel::joinable!(stock_balances -> organizations (organization_id));
+diesel::joinable!(stock_balances -> products (product_id));
+diesel::joinable!(stock_movement_serials -> organizations (organization_id));
+diesel::joinable!(stock_movement_serials -> serial_numbers (serial_number_id));
+diesel::joinable!(stock_movement_serials -> stock_movements (movement_id));
+diesel::joinable!(stock_movements -> lots (lot_id));
+diesel::joinable!(stock_movements -> organizations (organization_id));
+diesel::joinable!(stock_movements -> products (product_id));
+diesel::joinable!(stock_movements -> users (created_by));
+diesel::joinable!(stock_snapshot_lines -> locations (location_id));
+diesel::joinable!(stock_snapshot_lines -> lots (lot_id));
+diesel::joinable!(stock_snapshot_lines -> organizations (organization_id));
+diesel::joinable!(stock_snapshot_lines -> products (product_id));
+diesel::joinable!(stock_snapshot_lines -> stock_snapshots (snapshot_id));
+diesel::joinable!(stock_snapshots -> organizations (organization_id));
+diesel::joinable!(stock_snapshots -> users (created_by));
+diesel::joinable!(transfer_order_lines -> locations (from_location_id));
+diesel::joinable!(transfer_order_lines -> organizations (organization_id));
+diesel::joinable!(transfer_order_lines -> products (product_id));
+diesel::joinable!(transfer_order_lines -> transfer_orders (transfer_order_id));
+diesel::joinable!(transfer_orders -> locations (transit_location_id));
+diesel::joinable!(transfer_orders -> organizations (organization_id));
+diesel::joinable!(transfer_orders -> users (created_by));
+diesel::joinable!(user_organizations -> organizations (organization_id));
+diesel::joinable!(user_roles -> organizations (organization_id));
 diesel::joinable!(user_roles -> roles (role_id));
+diesel::joinable!(valuation_settings -> organizations (organization_id));
+diesel::joinable!(warehouses -> organizations (organization_id));
+diesel::joinable!(waves -> organizations (organization_id));
+diesel::joinable!(waves -> users (created_by));
+diesel::joinable!(waves -> warehouses (warehouse_id));
 
-diesel::allow_tables_to_appear_in_same_query!(role_rules, roles, rules, user_roles, users,);
+diesel::allow_tables_to_appear_in_same_query!(
+    abc_analyses,
+    abc_analysis_lines,
+    assembly_order_lines,
+    assembly_orders,
+    attachments,
+    attribute_definitions,
+    bom_components,
+    business_partners,
+    cost_layers,
+    count_sessions,
+    count_tasks,
+    cross_docks,
+    locations,
+    lots,
+    organizations,
+    outbound_demands,
+    pallets,
+    parcels,
+    partner_addresses,
+    partner_contacts,
+    pick_orders,
+    pick_tasks,
+    product_uoms,
+    products,
+    purchase_orders,
+    putaway_rules,
+    putaway_tasks,
+    replenishment_rules,
+    replenishment_suggestions,
+    reservations,
+    return_authorization_lines,
+    return_authorizations,
+    return_inspections,
+    role_rules,
+    roles,
+    rules,
+    serial_numbers,
+    shipments,
+    slotting_recommendations,
+    stock_balances,
+    stock_movement_serials,
+    stock_movements,
+    stock_snapshot_lines,
+    stock_snapshots,
+    transfer_order_lines,
+    transfer_orders,
+    user_organizations,
+    user_roles,
+    users,
+    valuation_settings,
+    warehouses,
+    waves,
+);
@@ -0,0 +1,194 @@
+use crate::contract::repository::{Repository, SerialNumberRepository};
+use crate::domain::{MovementKind, SerialError, SerialStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    serial_numbers, stock_movement_serials, stock_movements,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+pub struct PostgresSerialNumberRepository {
+    pool: db::Pool,
+}
+
+impl PostgresSerialNumberRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+/// Locks the serial numbers a movement carries. Outbound units must be in stock at the
+/// source location; inbound units must not be in stock anywhere and are registered on
+/// first receipt. Must be called inside a transaction.
+pub(super) async fn lock_serials(
+    conn: &mut AsyncPgConnection,
+    movement: &domain::StockMovement,
+    serial_numbers: &[String],
+) -> Result<Vec<domain::SerialNumber>> {
+    if serial_numbers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut serials: Vec<domain::SerialNumber> = serial_numbers::table
+        .filter(serial_numbers::product_id.eq(movement.product_id))
+        .filter(serial_numbers::serial_number.eq_any(serial_numbers))
+        .select(domain::SerialNumber::as_select())
+        .for_update()
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    if let Some(location_id) = movement.from_location_id {
+        for serial_number in serial_numbers {
+            let in_stock = serials.iter().any(|serial| {
+                &serial.serial_number == serial_number
+                    && serial.status == SerialStatus::InStock
+                    && serial.location_id == Some(location_id)
+            });
+            if !in_stock {
+                return Err(SerialError::NotInStock(serial_number.clone()).into());
+            }
+        }
+
+        return Ok(serials);
+    }
+
+    if let Some(serial) = serials
+        .iter()
+        .find(|serial| serial.status == SerialStatus::InStock)
+    {
+        return Err(SerialError::AlreadyInStock(serial.serial_number.clone()).into());
+    }
+
+    // Units that left stock before, e.g. returns, come back under their original record.
+    let known: HashSet<&str> = serials
+        .iter()
+        .map(|serial| serial.serial_number.as_str())
+        .collect();
+    let new: Vec<domain::SerialNumber> = serial_numbers
+        .iter()
+        .filter(|serial_number| !known.contains(serial_number.as_str()))
+        .map(|serial_number| domain::SerialNumber {
+            id: Uuid::new_v4(),
+            product_id: movement.product_id,
+            serial_number: serial_number.clone(),
+            status: SerialStatus::InStock,
+            location_id: movement.to_location_id,
+            lot_id: movement.lot_id,
+            created_at: movement.created_at,
+            updated_at: movement.created_at,
+        })
+        .collect();
+
+    let created: Vec<domain::SerialNumber> = diesel::insert_into(serial_numbers::table)
+        .values(new)
+        .returning(domain::SerialNumber::as_returning())
+        .get_results(conn)
+        .await
+        .map_err(map_diesel_error)?;
+    serials.extend(created);
+
+    Ok(serials)
+}
+
+/// Updates where the units are after the movement and links them to it.
+pub(super) async fn move_serials(
+    conn: &mut AsyncPgConnection,
+    movement: &domain::StockMovement,
+    serials: &[&domain::SerialNumber],
+) -> Result<()> {
+    if serials.is_empty() {
+        return Ok(());
+    }
+
+    let status = match (movement.to_location_id, movement.kind) {
+        (Some(_), _) => SerialStatus::InStock,
+        (None, MovementKind::Issue) => SerialStatus::Issued,
+        (None, _) => SerialStatus::WrittenOff,
+    };
+    let ids: Vec<Uuid> = serials.iter().map(|serial| serial.id).collect();
+
+    diesel::update(serial_numbers::table.filter(serial_numbers::id.eq_any(&ids)))
+        .set((
+            serial_numbers::status.eq(status),
+            serial_numbers::location_id.eq(movement.to_location_id.or(movement.from_location_id)),
+            serial_numbers::lot_id.eq(movement.lot_id),
+            serial_numbers::updated_at.eq(movement.created_at),
+        ))
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let links: Vec<domain::MovementSerial> = ids
+        .into_iter()
+        .map(|serial_number_id| domain::MovementSerial {
+            movement_id: movement.id,
+            serial_number_id,
+        })
+        .collect();
+
+    diesel::insert_into(stock_movement_serials::table)
+        .values(links)
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::SerialNumber> for PostgresSerialNumberRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::SerialNumber) -> Result<domain::SerialNumber> {
+        diesel::insert_into(serial_numbers::table)
+            .values(val)
+            .returning(domain::SerialNumber::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::SerialNumber> {
+        serial_numbers::table
+            .find(id)
+            .select(domain::SerialNumber::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl SerialNumberRepository for PostgresSerialNumberRepository {
+    #[tracing::instrument(skip(self))]
+    async fn find(&self, product_id: Uuid, serial_number: &str) -> Result<domain::SerialNumber> {
+        serial_numbers::table
+            .filter(serial_numbers::product_id.eq(product_id))
+            .filter(serial_numbers::serial_number.eq(serial_number))
+            .select(domain::SerialNumber::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_movements(&self, id: Uuid) -> Result<Vec<domain::StockMovement>> {
+        stock_movement_serials::table
+            .inner_join(stock_movements::table)
+            .filter(stock_movement_serials::serial_number_id.eq(id))
+            .order(stock_movements::created_at)
+            .select(domain::StockMovement::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
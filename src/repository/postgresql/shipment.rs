@@ -0,0 +1,228 @@
+use crate::contract::repository::{Repository, ShipmentRepository};
+use crate::domain::{RepositoryError, ShipmentError, ShipmentStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{parcels, shipments};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use uuid::Uuid;
+
+pub struct PostgresShipmentRepository {
+    pool: db::Pool,
+}
+
+impl PostgresShipmentRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+/// Locks the shipment, failing unless it is still open.
+async fn lock_open_shipment(conn: &mut AsyncPgConnection, id: Uuid) -> Result<domain::Shipment> {
+    let shipment = shipments::table
+        .find(id)
+        .select(domain::Shipment::as_select())
+        .for_update()
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)?;
+    if shipment.status != ShipmentStatus::Open {
+        return Err(ShipmentError::NotOpen.into());
+    }
+
+    Ok(shipment)
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Shipment> for PostgresShipmentRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Shipment) -> Result<domain::Shipment> {
+        diesel::insert_into(shipments::table)
+            .values(val)
+            .returning(domain::Shipment::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Shipment> {
+        shipments::table
+            .find(id)
+            .select(domain::Shipment::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl ShipmentRepository for PostgresShipmentRepository {
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::ShipmentQuery) -> Result<Vec<domain::Shipment>> {
+        let mut shipments = shipments::table
+            .select(domain::Shipment::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            shipments = shipments.filter(shipments::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(pick_order_id) = query.pick_order_id {
+            shipments = shipments.filter(shipments::pick_order_id.eq(pick_order_id));
+        }
+        if let Some(document_id) = query.document_id {
+            shipments = shipments.filter(shipments::document_id.eq(document_id));
+        }
+        if let Some(status) = query.status {
+            shipments = shipments.filter(shipments::status.eq(status));
+        }
+
+        shipments
+            .order((shipments::created_at.desc(), shipments::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_parcels(&self, shipment_id: Uuid) -> Result<Vec<domain::Parcel>> {
+        parcels::table
+            .filter(parcels::shipment_id.eq(shipment_id))
+            .order((parcels::created_at, parcels::id))
+            .select(domain::Parcel::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_parcel(&self, id: Uuid) -> Result<domain::Parcel> {
+        parcels::table
+            .find(id)
+            .select(domain::Parcel::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, parcel), fields(id = %parcel.id))]
+    async fn add_parcel(&self, parcel: domain::Parcel) -> Result<domain::Parcel> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_open_shipment(conn, parcel.shipment_id).await?;
+
+                diesel::insert_into(parcels::table)
+                    .values(parcel)
+                    .returning(domain::Parcel::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_parcel(&self, shipment_id: Uuid, parcel_id: Uuid) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_open_shipment(conn, shipment_id).await?;
+
+                let deleted = diesel::delete(
+                    parcels::table
+                        .find(parcel_id)
+                        .filter(parcels::shipment_id.eq(shipment_id)),
+                )
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+                if deleted == 0 {
+                    return Err(RepositoryError::NotFound.into());
+                }
+
+                Ok(())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, shipment, parcels), fields(id = %shipment.id))]
+    async fn ship(
+        &self,
+        shipment: domain::Shipment,
+        parcels: Vec<domain::Parcel>,
+    ) -> Result<domain::Shipment> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_open_shipment(conn, shipment.id).await?;
+
+                for parcel in parcels {
+                    diesel::update(parcels::table.find(parcel.id))
+                        .set((
+                            parcels::tracking_number.eq(parcel.tracking_number),
+                            parcels::label.eq(parcel.label),
+                            parcels::label_content_type.eq(parcel.label_content_type),
+                        ))
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                diesel::update(shipments::table.find(shipment.id))
+                    .set((
+                        shipments::carrier.eq(shipment.carrier),
+                        shipments::service.eq(shipment.service),
+                        shipments::shipping_cost.eq(shipment.shipping_cost),
+                        shipments::currency.eq(shipment.currency),
+                        shipments::status.eq(shipment.status),
+                        shipments::shipped_by.eq(shipment.shipped_by),
+                        shipments::shipped_at.eq(shipment.shipped_at),
+                    ))
+                    .returning(domain::Shipment::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn cancel(&self, id: Uuid) -> Result<domain::Shipment> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_open_shipment(conn, id).await?;
+
+                diesel::update(shipments::table.find(id))
+                    .set(shipments::status.eq(ShipmentStatus::Cancelled))
+                    .returning(domain::Shipment::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
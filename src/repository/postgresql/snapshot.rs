@@ -0,0 +1,339 @@
+use crate::contract::repository::{Repository, SnapshotRepository};
+use crate::domain::{SnapshotKind, StockStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    locations, stock_movements, stock_snapshot_lines, stock_snapshots,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use diesel::dsl;
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Stays well below the bind parameter limit of a single insert.
+const LINE_BATCH_SIZE: usize = 1000;
+
+pub struct PostgresSnapshotRepository {
+    pool: db::Pool,
+}
+
+impl PostgresSnapshotRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::StockSnapshot> for PostgresSnapshotRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::StockSnapshot) -> Result<domain::StockSnapshot> {
+        diesel::insert_into(stock_snapshots::table)
+            .values(val)
+            .returning(domain::StockSnapshot::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::StockSnapshot> {
+        stock_snapshots::table
+            .find(id)
+            .select(domain::StockSnapshot::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl SnapshotRepository for PostgresSnapshotRepository {
+    #[tracing::instrument(skip(self, snapshot), fields(id = %snapshot.id))]
+    async fn take(&self, snapshot: domain::StockSnapshot) -> Result<domain::StockSnapshot> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let positions =
+                    positions_as_of(conn, snapshot.as_of, &domain::StockPositionQuery::default())
+                        .await?;
+
+                let snapshot = diesel::insert_into(stock_snapshots::table)
+                    .values(snapshot)
+                    .returning(domain::StockSnapshot::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                let lines = positions
+                    .into_iter()
+                    .map(|position| domain::StockSnapshotLine {
+                        id: Uuid::new_v4(),
+                        snapshot_id: snapshot.id,
+                        product_id: position.product_id,
+                        location_id: position.location_id,
+                        lot_id: position.lot_id,
+                        status: position.status,
+                        quantity: position.quantity,
+                    })
+                    .collect::<Vec<_>>();
+                for batch in lines.chunks(LINE_BATCH_SIZE) {
+                    diesel::insert_into(stock_snapshot_lines::table)
+                        .values(batch)
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                Ok(snapshot)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_scheduled(&self, as_of: DateTime<Utc>) -> Result<Option<domain::StockSnapshot>> {
+        stock_snapshots::table
+            .filter(stock_snapshots::as_of.eq(as_of))
+            .filter(stock_snapshots::kind.ne(SnapshotKind::Manual))
+            .select(domain::StockSnapshot::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .optional()
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::StockSnapshotQuery) -> Result<Vec<domain::StockSnapshot>> {
+        let mut snapshots = stock_snapshots::table
+            .select(domain::StockSnapshot::as_select())
+            .into_boxed();
+
+        if let Some(kind) = query.kind {
+            snapshots = snapshots.filter(stock_snapshots::kind.eq(kind));
+        }
+        if let Some(from) = query.from {
+            snapshots = snapshots.filter(stock_snapshots::as_of.ge(from));
+        }
+        if let Some(to) = query.to {
+            snapshots = snapshots.filter(stock_snapshots::as_of.le(to));
+        }
+
+        snapshots
+            .order((
+                stock_snapshots::as_of.desc(),
+                stock_snapshots::created_at.desc(),
+            ))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_lines(
+        &self,
+        snapshot_id: Uuid,
+        query: domain::StockPositionQuery,
+    ) -> Result<Vec<domain::StockPosition>> {
+        let conn = &mut self.get_connection().await?;
+        snapshot_lines(conn, snapshot_id, &query).await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn positions_as_of(
+        &self,
+        as_of: DateTime<Utc>,
+        query: domain::StockPositionQuery,
+    ) -> Result<Vec<domain::StockPosition>> {
+        let conn = &mut self.get_connection().await?;
+        positions_as_of(conn, as_of, &query).await
+    }
+}
+
+async fn snapshot_lines(
+    conn: &mut AsyncPgConnection,
+    snapshot_id: Uuid,
+    query: &domain::StockPositionQuery,
+) -> Result<Vec<domain::StockPosition>> {
+    let mut lines = stock_snapshot_lines::table
+        .inner_join(locations::table)
+        .filter(stock_snapshot_lines::snapshot_id.eq(snapshot_id))
+        .select(domain::StockSnapshotLine::as_select())
+        .into_boxed();
+
+    if let Some(product_id) = query.product_id {
+        lines = lines.filter(stock_snapshot_lines::product_id.eq(product_id));
+    }
+    if let Some(location_id) = query.location_id {
+        lines = lines.filter(stock_snapshot_lines::location_id.eq(location_id));
+    }
+    if let Some(warehouse_id) = query.warehouse_id {
+        lines = lines.filter(locations::warehouse_id.eq(warehouse_id));
+    }
+
+    Ok(lines
+        .order((
+            stock_snapshot_lines::product_id,
+            stock_snapshot_lines::location_id,
+            stock_snapshot_lines::lot_id,
+            stock_snapshot_lines::status,
+        ))
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
+
+/// Replays the movements posted after the latest scheduled snapshot up to `as_of` on top of
+/// its lines. Manual snapshots are not used as a starting point: movements being posted
+/// while one was taken may have been committed after it.
+async fn positions_as_of(
+    conn: &mut AsyncPgConnection,
+    as_of: DateTime<Utc>,
+    query: &domain::StockPositionQuery,
+) -> Result<Vec<domain::StockPosition>> {
+    let base = stock_snapshots::table
+        .filter(stock_snapshots::as_of.le(as_of))
+        .filter(stock_snapshots::kind.ne(SnapshotKind::Manual))
+        .order(stock_snapshots::as_of.desc())
+        .select(domain::StockSnapshot::as_select())
+        .first(conn)
+        .await
+        .optional()
+        .map_err(map_diesel_error)?;
+
+    let mut positions = BTreeMap::<(Uuid, Uuid, Option<Uuid>, StockStatus), Decimal>::new();
+    if let Some(base) = &base {
+        for line in snapshot_lines(conn, base.id, query).await? {
+            *positions
+                .entry((line.product_id, line.location_id, line.lot_id, line.status))
+                .or_default() += line.quantity;
+        }
+    }
+
+    let mut inbound = stock_movements::table
+        .filter(stock_movements::created_at.le(as_of))
+        .filter(stock_movements::to_location_id.is_not_null())
+        .group_by((
+            stock_movements::product_id,
+            stock_movements::to_location_id,
+            stock_movements::lot_id,
+            stock_movements::status,
+            stock_movements::to_status,
+        ))
+        .select((
+            stock_movements::product_id,
+            stock_movements::to_location_id,
+            stock_movements::lot_id,
+            stock_movements::status,
+            stock_movements::to_status,
+            dsl::sum(stock_movements::quantity),
+        ))
+        .into_boxed();
+    let mut outbound = stock_movements::table
+        .filter(stock_movements::created_at.le(as_of))
+        .filter(stock_movements::from_location_id.is_not_null())
+        .group_by((
+            stock_movements::product_id,
+            stock_movements::from_location_id,
+            stock_movements::lot_id,
+            stock_movements::status,
+        ))
+        .select((
+            stock_movements::product_id,
+            stock_movements::from_location_id,
+            stock_movements::lot_id,
+            stock_movements::status,
+            dsl::sum(stock_movements::quantity),
+        ))
+        .into_boxed();
+
+    if let Some(base) = &base {
+        inbound = inbound.filter(stock_movements::created_at.gt(base.as_of));
+        outbound = outbound.filter(stock_movements::created_at.gt(base.as_of));
+    }
+    if let Some(product_id) = query.product_id {
+        inbound = inbound.filter(stock_movements::product_id.eq(product_id));
+        outbound = outbound.filter(stock_movements::product_id.eq(product_id));
+    }
+    if let Some(location_id) = query.location_id {
+        inbound = inbound.filter(stock_movements::to_location_id.eq(location_id));
+        outbound = outbound.filter(stock_movements::from_location_id.eq(location_id));
+    }
+    if let Some(warehouse_id) = query.warehouse_id {
+        let warehouse_locations = || {
+            locations::table
+                .filter(locations::warehouse_id.eq(warehouse_id))
+                .select(locations::id.nullable())
+        };
+        inbound = inbound.filter(stock_movements::to_location_id.eq_any(warehouse_locations()));
+        outbound = outbound.filter(stock_movements::from_location_id.eq_any(warehouse_locations()));
+    }
+
+    for (product_id, location_id, lot_id, status, to_status, quantity) in inbound
+        .load::<(
+            Uuid,
+            Option<Uuid>,
+            Option<Uuid>,
+            StockStatus,
+            Option<StockStatus>,
+            Option<Decimal>,
+        )>(conn)
+        .await
+        .map_err(map_diesel_error)?
+    {
+        let Some(location_id) = location_id else {
+            continue;
+        };
+        // Stock arrives in the status it is changed to, if any.
+        let status = to_status.unwrap_or(status);
+        *positions
+            .entry((product_id, location_id, lot_id, status))
+            .or_default() += quantity.unwrap_or_default();
+    }
+    for (product_id, location_id, lot_id, status, quantity) in outbound
+        .load::<(
+            Uuid,
+            Option<Uuid>,
+            Option<Uuid>,
+            StockStatus,
+            Option<Decimal>,
+        )>(conn)
+        .await
+        .map_err(map_diesel_error)?
+    {
+        let Some(location_id) = location_id else {
+            continue;
+        };
+        *positions
+            .entry((product_id, location_id, lot_id, status))
+            .or_default() -= quantity.unwrap_or_default();
+    }
+
+    Ok(positions
+        .into_iter()
+        .filter(|(_, quantity)| !quantity.is_zero())
+        .map(
+            |((product_id, location_id, lot_id, status), quantity)| domain::StockPosition {
+                product_id,
+                location_id,
+                lot_id,
+                status,
+                quantity,
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,628 @@
+use crate::contract::repository::StockRepository;
+use crate::domain::{ReservationStatus, StockError, StockStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    locations, lots, reservations, stock_balances, stock_movements,
+};
+use crate::repository::postgresql::serial::{lock_serials, move_serials};
+use crate::repository::postgresql::valuation::{add_cost_layer, valuate};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct PostgresStockRepository {
+    pool: db::Pool,
+}
+
+impl PostgresStockRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl StockRepository for PostgresStockRepository {
+    #[tracing::instrument(skip(self, movement), fields(id = %movement.id))]
+    async fn post_movement(
+        &self,
+        movement: domain::StockMovement,
+        serial_numbers: Vec<String>,
+    ) -> Result<Vec<domain::StockMovement>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move { apply_movement(conn, movement, &serial_numbers, true).await }.scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, movements))]
+    async fn post_movements(
+        &self,
+        movements: Vec<domain::StockMovement>,
+        respect_reservations: bool,
+    ) -> Result<Vec<domain::StockMovement>> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let mut posted = Vec::with_capacity(movements.len());
+                for movement in movements {
+                    posted.extend(apply_movement(conn, movement, &[], respect_reservations).await?);
+                }
+                Ok(posted)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, movements))]
+    async fn post_movements_releasing_reservations(
+        &self,
+        movements: Vec<domain::StockMovement>,
+    ) -> Result<(Vec<domain::StockMovement>, Vec<domain::Reservation>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let mut posted = Vec::with_capacity(movements.len());
+                for movement in movements {
+                    posted.extend(apply_movement(conn, movement, &[], false).await?);
+                }
+
+                let mut sources: Vec<(Uuid, Uuid)> = posted
+                    .iter()
+                    .filter_map(|movement| {
+                        movement
+                            .from_location_id
+                            .map(|location_id| (movement.product_id, location_id))
+                    })
+                    .collect();
+                sources.sort();
+                sources.dedup();
+
+                let now = Utc::now();
+                let mut released = Vec::new();
+                for (product_id, location_id) in sources {
+                    released.extend(
+                        release_unbacked_reservations(conn, product_id, location_id, now)
+                            .await
+                            .map_err(map_diesel_error)?,
+                    );
+                }
+
+                Ok((posted, released))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn get_levels(&self, query: domain::StockLevelQuery) -> Result<Vec<domain::StockLevel>> {
+        let conn = &mut self.get_connection().await?;
+
+        let mut balances = stock_balances::table
+            .inner_join(locations::table)
+            .left_join(lots::table)
+            .select((
+                domain::StockBalance::as_select(),
+                Option::<domain::Lot>::as_select(),
+                locations::in_transit,
+                locations::quarantine,
+            ))
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            balances = balances.filter(stock_balances::product_id.eq(product_id));
+        }
+        if let Some(location_id) = query.location_id {
+            balances = balances.filter(stock_balances::location_id.eq(location_id));
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            balances = balances.filter(locations::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(lot_id) = query.lot_id {
+            balances = balances.filter(stock_balances::lot_id.eq(lot_id));
+        }
+
+        let balances: Vec<(domain::StockBalance, Option<domain::Lot>, bool, bool)> = balances
+            .order((stock_balances::product_id, stock_balances::location_id))
+            .load(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        let product_ids: Vec<Uuid> = balances.iter().map(|(b, ..)| b.product_id).collect();
+        let location_ids: Vec<Uuid> = balances.iter().map(|(b, ..)| b.location_id).collect();
+
+        let now = Utc::now();
+        let active: Vec<(Uuid, Uuid, Decimal)> = active_reservations(now)
+            .filter(reservations::product_id.eq_any(product_ids))
+            .filter(reservations::location_id.eq_any(location_ids))
+            .select((
+                reservations::product_id,
+                reservations::location_id,
+                reservations::quantity,
+            ))
+            .load(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        let mut reserved = HashMap::<(Uuid, Uuid), Decimal>::new();
+        for (product_id, location_id, quantity) in active {
+            *reserved.entry((product_id, location_id)).or_default() += quantity;
+        }
+
+        // Lots of the same product and location are summed up into a single level.
+        let today = now.date_naive();
+        let mut levels = Vec::<domain::StockLevel>::new();
+        for (balance, lot, in_transit, quarantine) in balances {
+            let blocked = if !quarantine && is_allocatable(&balance, lot.as_ref(), today) {
+                Decimal::ZERO
+            } else {
+                balance.on_hand
+            };
+            let in_transit = if in_transit {
+                balance.on_hand - blocked
+            } else {
+                Decimal::ZERO
+            };
+
+            match levels.last_mut() {
+                Some(level)
+                    if level.product_id == balance.product_id
+                        && level.location_id == balance.location_id =>
+                {
+                    level.on_hand += balance.on_hand;
+                    level.blocked += blocked;
+                    level.in_transit += in_transit;
+                }
+                _ => levels.push(domain::StockLevel {
+                    product_id: balance.product_id,
+                    location_id: balance.location_id,
+                    on_hand: balance.on_hand,
+                    blocked,
+                    in_transit,
+                    reserved: reserved
+                        .get(&(balance.product_id, balance.location_id))
+                        .copied()
+                        .unwrap_or_default(),
+                    available: Decimal::ZERO,
+                }),
+            }
+        }
+
+        for level in levels.iter_mut() {
+            level.available = (level.on_hand - level.blocked - level.in_transit - level.reserved)
+                .max(Decimal::ZERO);
+        }
+
+        Ok(levels)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_balances(
+        &self,
+        query: domain::StockBalanceQuery,
+    ) -> Result<Vec<domain::StockBalance>> {
+        let mut balances = stock_balances::table
+            .inner_join(locations::table)
+            .filter(stock_balances::on_hand.gt(Decimal::ZERO))
+            .select(domain::StockBalance::as_select())
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            balances = balances.filter(stock_balances::product_id.eq(product_id));
+        }
+        if let Some(location_id) = query.location_id {
+            balances = balances.filter(stock_balances::location_id.eq(location_id));
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            balances = balances.filter(locations::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(lot_id) = query.lot_id {
+            balances = balances.filter(stock_balances::lot_id.eq(lot_id));
+        }
+        if !query.statuses.is_empty() {
+            balances = balances.filter(stock_balances::status.eq_any(query.statuses));
+        }
+
+        balances
+            .order((
+                stock_balances::product_id,
+                locations::code,
+                stock_balances::lot_id,
+                stock_balances::status,
+            ))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_movements(
+        &self,
+        query: domain::StockMovementQuery,
+    ) -> Result<Vec<domain::StockMovement>> {
+        let mut movements = stock_movements::table
+            .select(domain::StockMovement::as_select())
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            movements = movements.filter(stock_movements::product_id.eq(product_id));
+        }
+        if let Some(location_id) = query.location_id {
+            movements = movements.filter(
+                stock_movements::from_location_id
+                    .eq(location_id)
+                    .or(stock_movements::to_location_id.eq(location_id)),
+            );
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            let warehouse_locations = || {
+                locations::table
+                    .filter(locations::warehouse_id.eq(warehouse_id))
+                    .select(locations::id.nullable())
+            };
+            movements = movements.filter(
+                stock_movements::from_location_id
+                    .eq_any(warehouse_locations())
+                    .or(stock_movements::to_location_id.eq_any(warehouse_locations())),
+            );
+        }
+        if let Some(lot_id) = query.lot_id {
+            movements = movements.filter(stock_movements::lot_id.eq(lot_id));
+        }
+        if let Some(from) = query.from {
+            movements = movements.filter(stock_movements::created_at.gt(from));
+        }
+        if let Some(to) = query.to {
+            movements = movements.filter(stock_movements::created_at.le(to));
+        }
+
+        movements
+            .order((stock_movements::created_at, stock_movements::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+/// Reservations that still hold stock at the given moment.
+pub(super) fn active_reservations(
+    now: DateTime<Utc>,
+) -> reservations::BoxedQuery<'static, diesel::pg::Pg> {
+    reservations::table
+        .filter(reservations::status.eq(ReservationStatus::Active))
+        .filter(
+            reservations::expires_at
+                .is_null()
+                .or(reservations::expires_at.gt(now)),
+        )
+        .into_boxed()
+}
+
+/// Locks the balances of the product at the location so concurrent reservations and
+/// movements are serialised until the surrounding transaction ends. Balances are
+/// returned with their lots in first-expired-first-out order.
+pub(super) async fn lock_balances(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+    location_id: Uuid,
+) -> QueryResult<Vec<(domain::StockBalance, Option<domain::Lot>)>> {
+    let balances: Vec<domain::StockBalance> = stock_balances::table
+        .filter(stock_balances::product_id.eq(product_id))
+        .filter(stock_balances::location_id.eq(location_id))
+        .select(domain::StockBalance::as_select())
+        .order(stock_balances::id)
+        .for_update()
+        .load(conn)
+        .await?;
+
+    let lot_ids: Vec<Uuid> = balances.iter().filter_map(|b| b.lot_id).collect();
+    let lots: HashMap<Uuid, domain::Lot> = lots::table
+        .filter(lots::id.eq_any(lot_ids))
+        .select(domain::Lot::as_select())
+        .load::<domain::Lot>(conn)
+        .await?
+        .into_iter()
+        .map(|lot| (lot.id, lot))
+        .collect();
+
+    let mut balances: Vec<(domain::StockBalance, Option<domain::Lot>)> = balances
+        .into_iter()
+        .map(|balance| {
+            let lot = balance.lot_id.and_then(|id| lots.get(&id).cloned());
+            (balance, lot)
+        })
+        .collect();
+
+    balances.sort_by_key(|(_, lot)| {
+        let expiry_date = lot.as_ref().and_then(|lot| lot.expiry_date);
+        (
+            expiry_date.is_none(),
+            expiry_date,
+            lot.as_ref().map(|lot| lot.created_at),
+        )
+    });
+
+    Ok(balances)
+}
+
+/// Stock without a lot is always usable.
+pub(super) fn is_usable(lot: Option<&domain::Lot>, today: NaiveDate) -> bool {
+    lot.is_none_or(|lot| lot.is_usable_on(today))
+}
+
+/// Available stock of a usable lot.
+pub(super) fn is_allocatable(
+    balance: &domain::StockBalance,
+    lot: Option<&domain::Lot>,
+    today: NaiveDate,
+) -> bool {
+    balance.status == StockStatus::Available && is_usable(lot, today)
+}
+
+/// On hand quantity that is neither on hold nor held in blocked or expired lots.
+pub(super) fn usable_on_hand(
+    balances: &[(domain::StockBalance, Option<domain::Lot>)],
+    today: NaiveDate,
+) -> Decimal {
+    balances
+        .iter()
+        .filter(|(balance, lot)| is_allocatable(balance, lot.as_ref(), today))
+        .map(|(balance, _)| balance.on_hand)
+        .sum()
+}
+
+pub(super) async fn reserved_quantity(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+    location_id: Uuid,
+    now: DateTime<Utc>,
+) -> QueryResult<Decimal> {
+    active_reservations(now)
+        .filter(reservations::product_id.eq(product_id))
+        .filter(reservations::location_id.eq(location_id))
+        .select(diesel::dsl::sum(reservations::quantity))
+        .get_result::<Option<Decimal>>(conn)
+        .await
+        .map(Option::unwrap_or_default)
+}
+
+/// Releases the newest active reservations of the product at the location until the
+/// remaining ones are covered by its usable on hand quantity. Must be called inside a
+/// transaction.
+async fn release_unbacked_reservations(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+    location_id: Uuid,
+    now: DateTime<Utc>,
+) -> QueryResult<Vec<domain::Reservation>> {
+    // Reservations lock the balances too, so none can be added in between.
+    let balances = lock_balances(conn, product_id, location_id).await?;
+    let usable = usable_on_hand(&balances, now.date_naive());
+
+    let reservations: Vec<domain::Reservation> = active_reservations(now)
+        .filter(reservations::product_id.eq(product_id))
+        .filter(reservations::location_id.eq(location_id))
+        .select(domain::Reservation::as_select())
+        .order((reservations::created_at.desc(), reservations::id.desc()))
+        .load(conn)
+        .await?;
+
+    let mut reserved: Decimal = reservations.iter().map(|r| r.quantity).sum();
+    let mut released = Vec::new();
+    for reservation in reservations {
+        if reserved <= usable {
+            break;
+        }
+
+        reserved -= reservation.quantity;
+        released.push(
+            diesel::update(reservations::table.find(reservation.id))
+                .set(reservations::status.eq(ReservationStatus::Released))
+                .returning(domain::Reservation::as_returning())
+                .get_result(conn)
+                .await?,
+        );
+    }
+
+    Ok(released)
+}
+
+/// Applies the movement to the balances and records it. Must be called inside a transaction.
+/// When `respect_reservations` is set, reserved quantity at the source location cannot be moved.
+///
+/// A movement without a lot that leaves a location is allocated over the usable lots
+/// first-expired-first-out and recorded as one movement per lot. The first of them keeps
+/// the id of the given movement. Units of given serial numbers are taken from the lots
+/// they were received in.
+pub(super) async fn apply_movement(
+    conn: &mut AsyncPgConnection,
+    movement: domain::StockMovement,
+    serial_numbers: &[String],
+    respect_reservations: bool,
+) -> Result<Vec<domain::StockMovement>> {
+    let serials = lock_serials(conn, &movement, serial_numbers).await?;
+
+    let allocations = match movement.from_location_id {
+        Some(location_id) if !serials.is_empty() => {
+            let mut by_lot = HashMap::<Option<Uuid>, Decimal>::new();
+            for serial in serials.iter() {
+                *by_lot.entry(serial.lot_id).or_default() += Decimal::ONE;
+            }
+
+            let mut allocations = Vec::new();
+            for (lot_id, quantity) in by_lot {
+                let part = domain::StockMovement {
+                    lot_id,
+                    quantity,
+                    ..movement.clone()
+                };
+                allocations
+                    .extend(take_stock(conn, &part, location_id, respect_reservations).await?);
+            }
+            allocations
+        }
+        Some(location_id) => take_stock(conn, &movement, location_id, respect_reservations).await?,
+        None => vec![(movement.lot_id, movement.quantity)],
+    };
+
+    let mut movements = Vec::with_capacity(allocations.len());
+    for (index, (lot_id, quantity)) in allocations.into_iter().enumerate() {
+        let movement = domain::StockMovement {
+            id: if index == 0 {
+                movement.id
+            } else {
+                Uuid::new_v4()
+            },
+            quantity,
+            lot_id,
+            ..movement.clone()
+        };
+
+        if let Some(location_id) = movement.to_location_id {
+            diesel::insert_into(stock_balances::table)
+                .values(domain::StockBalance {
+                    id: Uuid::new_v4(),
+                    product_id: movement.product_id,
+                    location_id,
+                    on_hand: movement.quantity,
+                    lot_id: movement.lot_id,
+                    status: movement.to_status.unwrap_or(movement.status),
+                })
+                .on_conflict((
+                    stock_balances::product_id,
+                    stock_balances::location_id,
+                    stock_balances::lot_id,
+                    stock_balances::status,
+                ))
+                .do_update()
+                .set(
+                    stock_balances::on_hand
+                        .eq(stock_balances::on_hand + excluded(stock_balances::on_hand)),
+                )
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+        }
+
+        let movement = valuate(conn, movement).await?;
+        let movement = diesel::insert_into(stock_movements::table)
+            .values(movement)
+            .returning(domain::StockMovement::as_returning())
+            .get_result(conn)
+            .await
+            .map_err(map_diesel_error)?;
+        add_cost_layer(conn, &movement).await?;
+
+        let moved: Vec<&domain::SerialNumber> = serials
+            .iter()
+            .filter(|serial| {
+                movement.from_location_id.is_none() || serial.lot_id == movement.lot_id
+            })
+            .collect();
+        move_serials(conn, &movement, &moved).await?;
+
+        movements.push(movement);
+    }
+
+    Ok(movements)
+}
+
+/// Decreases the source balances by the movement quantity and returns how much was taken
+/// out of which lot.
+async fn take_stock(
+    conn: &mut AsyncPgConnection,
+    movement: &domain::StockMovement,
+    location_id: Uuid,
+    respect_reservations: bool,
+) -> Result<Vec<(Option<Uuid>, Decimal)>> {
+    let today = movement.created_at.date_naive();
+    let balances = lock_balances(conn, movement.product_id, location_id)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let reserved = if respect_reservations {
+        reserved_quantity(conn, movement.product_id, location_id, movement.created_at)
+            .await
+            .map_err(map_diesel_error)?
+    } else {
+        Decimal::ZERO
+    };
+    let unreserved = (usable_on_hand(&balances, today) - reserved).max(Decimal::ZERO);
+
+    // Held stock is taken out of any of its lots, available stock out of usable lots
+    // unless the lot is named.
+    let candidates: Vec<&(domain::StockBalance, Option<domain::Lot>)> = balances
+        .iter()
+        .filter(|(balance, lot)| {
+            balance.status == movement.status
+                && match movement.lot_id {
+                    Some(lot_id) => balance.lot_id == Some(lot_id),
+                    None => {
+                        movement.status != StockStatus::Available || is_usable(lot.as_ref(), today)
+                    }
+                }
+        })
+        .collect();
+
+    let on_hand: Decimal = candidates.iter().map(|(balance, _)| balance.on_hand).sum();
+    // Reservations are only ever backed by available stock of usable lots, so they cannot
+    // hold held stock or a blocked lot back.
+    let available = if candidates
+        .iter()
+        .all(|(balance, lot)| is_allocatable(balance, lot.as_ref(), today))
+    {
+        on_hand.min(unreserved)
+    } else {
+        on_hand
+    };
+
+    if available < movement.quantity {
+        return Err(StockError::InsufficientStock {
+            requested: movement.quantity,
+            available,
+        }
+        .into());
+    }
+
+    let mut remaining = movement.quantity;
+    let mut allocations = Vec::new();
+    for (balance, _) in candidates {
+        let quantity = remaining.min(balance.on_hand);
+        if quantity.is_zero() {
+            continue;
+        }
+
+        diesel::update(stock_balances::table.find(balance.id))
+            .set(stock_balances::on_hand.eq(stock_balances::on_hand - quantity))
+            .execute(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        allocations.push((balance.lot_id, quantity));
+        remaining -= quantity;
+        if remaining.is_zero() {
+            break;
+        }
+    }
+
+    Ok(allocations)
+}
@@ -0,0 +1,241 @@
+use crate::contract::repository::{Repository, StockCountRepository};
+use crate::domain::{
+    CountScope, CountSessionStatus, CountTaskStatus, StockCountError, StockStatus,
+};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    count_sessions, count_tasks, locations, products, stock_balances,
+};
+use crate::repository::postgresql::stock::apply_movement;
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresStockCountRepository {
+    pool: db::Pool,
+}
+
+impl PostgresStockCountRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::CountSession> for PostgresStockCountRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::CountSession) -> Result<domain::CountSession> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let session = diesel::insert_into(count_sessions::table)
+                    .values(val)
+                    .returning(domain::CountSession::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                let mut balances = stock_balances::table
+                    .inner_join(locations::table)
+                    .inner_join(products::table)
+                    .filter(locations::warehouse_id.eq(session.warehouse_id))
+                    .filter(locations::in_transit.eq(false))
+                    // Held stock is kept apart until released or scrapped, so only
+                    // available stock is counted and adjusted.
+                    .filter(stock_balances::status.eq(StockStatus::Available))
+                    .select(domain::StockBalance::as_select())
+                    .into_boxed();
+
+                match session.scope {
+                    CountScope::Zone => {
+                        balances = balances.filter(locations::zone.eq(session.zone.clone()));
+                    }
+                    CountScope::AbcClass => {
+                        balances = balances.filter(products::abc_class.eq(session.abc_class));
+                    }
+                    CountScope::Full => {}
+                }
+
+                let tasks: Vec<domain::CountTask> = balances
+                    .load::<domain::StockBalance>(conn)
+                    .await
+                    .map_err(map_diesel_error)?
+                    .into_iter()
+                    .map(|balance| domain::CountTask {
+                        id: Uuid::new_v4(),
+                        session_id: session.id,
+                        product_id: balance.product_id,
+                        location_id: balance.location_id,
+                        expected_quantity: balance.on_hand,
+                        counted_quantity: None,
+                        variance: None,
+                        attempts: 0,
+                        status: CountTaskStatus::Pending,
+                        counted_by: None,
+                        counted_at: None,
+                        approved_by: None,
+                        movement_id: None,
+                        lot_id: balance.lot_id,
+                        counters: Vec::new(),
+                    })
+                    .collect();
+
+                diesel::insert_into(count_tasks::table)
+                    .values(tasks)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(session)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::CountSession> {
+        count_sessions::table
+            .find(id)
+            .select(domain::CountSession::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl StockCountRepository for PostgresStockCountRepository {
+    #[tracing::instrument(skip(self))]
+    async fn list_tasks(&self, session_id: Uuid) -> Result<Vec<domain::CountTask>> {
+        count_tasks::table
+            .filter(count_tasks::session_id.eq(session_id))
+            .order((count_tasks::location_id, count_tasks::product_id))
+            .select(domain::CountTask::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_task(&self, id: Uuid) -> Result<domain::CountTask> {
+        count_tasks::table
+            .find(id)
+            .select(domain::CountTask::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, task), fields(id = %task.id))]
+    async fn create_task(&self, task: domain::CountTask) -> Result<domain::CountTask> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                count_sessions::table
+                    .find(task.session_id)
+                    .filter(count_sessions::status.eq(CountSessionStatus::Open))
+                    .select(count_sessions::id)
+                    .for_update()
+                    .first::<Uuid>(conn)
+                    .await
+                    .optional()
+                    .map_err(map_diesel_error)?
+                    .ok_or(StockCountError::SessionNotOpen)?;
+
+                diesel::insert_into(count_tasks::table)
+                    .values(task)
+                    .returning(domain::CountTask::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, task, adjustment), fields(id = %task.id))]
+    async fn update_task(
+        &self,
+        task: domain::CountTask,
+        expected: &[CountTaskStatus],
+        adjustment: Option<domain::StockMovement>,
+    ) -> Result<domain::CountTask> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+        let expected = expected.to_vec();
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                count_tasks::table
+                    .find(task.id)
+                    .filter(count_tasks::status.eq_any(expected))
+                    .select(count_tasks::id)
+                    .for_update()
+                    .first::<Uuid>(conn)
+                    .await
+                    .optional()
+                    .map_err(map_diesel_error)?
+                    .ok_or(StockCountError::TaskChanged)?;
+
+                if let Some(adjustment) = adjustment {
+                    // Balances stay locked until the adjustment is posted, so the variance
+                    // cannot go stale in between.
+                    let on_hand: Decimal = stock_balances::table
+                        .filter(stock_balances::product_id.eq(task.product_id))
+                        .filter(stock_balances::location_id.eq(task.location_id))
+                        .filter(stock_balances::lot_id.is_not_distinct_from(task.lot_id))
+                        .filter(stock_balances::status.eq(StockStatus::Available))
+                        .select(stock_balances::on_hand)
+                        .for_update()
+                        .load::<Decimal>(conn)
+                        .await
+                        .map_err(map_diesel_error)?
+                        .into_iter()
+                        .sum();
+                    if on_hand != task.expected_quantity {
+                        return Err(StockCountError::StockMoved.into());
+                    }
+
+                    // The count is the truth about the bin, reservations cannot hold it back.
+                    apply_movement(conn, adjustment, &[], false).await?;
+                }
+
+                diesel::update(count_tasks::table.find(task.id))
+                    .set(&task)
+                    .returning(domain::CountTask::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, session), fields(id = %session.id))]
+    async fn update_session(&self, session: domain::CountSession) -> Result<domain::CountSession> {
+        diesel::update(count_sessions::table.find(session.id))
+            .set((
+                count_sessions::status.eq(session.status),
+                count_sessions::completed_at.eq(session.completed_at),
+            ))
+            .returning(domain::CountSession::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
@@ -0,0 +1,417 @@
+use crate::contract::repository::{Repository, TransferOrderRepository};
+use crate::domain::{SerialStatus, TransferOrderError, TransferOrderStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    locations, serial_numbers, stock_movements, transfer_order_lines, transfer_orders,
+};
+use crate::repository::postgresql::stock::{apply_movement, lock_balances};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresTransferOrderRepository {
+    pool: db::Pool,
+}
+
+impl PostgresTransferOrderRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+/// Active orders are the ones with stock on the way.
+const IN_TRANSIT_STATUSES: [TransferOrderStatus; 2] = [
+    TransferOrderStatus::Shipped,
+    TransferOrderStatus::PartiallyReceived,
+];
+
+#[async_trait::async_trait]
+impl Repository<domain::TransferOrder> for PostgresTransferOrderRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::TransferOrder) -> Result<domain::TransferOrder> {
+        diesel::insert_into(transfer_orders::table)
+            .values(val)
+            .returning(domain::TransferOrder::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::TransferOrder> {
+        transfer_orders::table
+            .find(id)
+            .select(domain::TransferOrder::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferOrderRepository for PostgresTransferOrderRepository {
+    #[tracing::instrument(skip(self, order, lines), fields(id = %order.id))]
+    async fn create_with_lines(
+        &self,
+        order: domain::TransferOrder,
+        lines: Vec<domain::TransferOrderLine>,
+    ) -> Result<domain::TransferOrder> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let order = diesel::insert_into(transfer_orders::table)
+                    .values(order)
+                    .returning(domain::TransferOrder::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                diesel::insert_into(transfer_order_lines::table)
+                    .values(lines)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(order)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_lines(&self, order_id: Uuid) -> Result<Vec<domain::TransferOrderLine>> {
+        transfer_order_lines::table
+            .filter(transfer_order_lines::transfer_order_id.eq(order_id))
+            .order(transfer_order_lines::id)
+            .select(domain::TransferOrderLine::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, order, transit_location, movements), fields(id = %order.id))]
+    async fn ship(
+        &self,
+        order: domain::TransferOrder,
+        transit_location: domain::Location,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<domain::TransferOrder> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_order(conn, order.id, TransferOrderStatus::Open, &[]).await?;
+
+                diesel::insert_into(locations::table)
+                    .values(transit_location)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                for (movement, serial_numbers) in movements {
+                    apply_movement(conn, movement, &serial_numbers, true).await?;
+                }
+
+                update_order(conn, &order).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(
+        skip(self, order, lines, read_lines, movements),
+        fields(id = %order.id)
+    )]
+    async fn update_with_movements(
+        &self,
+        order: domain::TransferOrder,
+        lines: Vec<domain::TransferOrderLine>,
+        read_status: TransferOrderStatus,
+        read_lines: Vec<domain::TransferOrderLine>,
+        movements: Vec<(domain::StockMovement, Vec<String>)>,
+    ) -> Result<(domain::TransferOrder, Vec<domain::StockMovement>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                lock_order(conn, order.id, read_status, &read_lines).await?;
+
+                let mut posted = Vec::new();
+                for (movement, serial_numbers) in movements {
+                    posted.extend(take_from_transit(conn, movement, serial_numbers).await?);
+                }
+
+                for line in lines {
+                    diesel::update(transfer_order_lines::table.find(line.id))
+                        .set((
+                            transfer_order_lines::received_quantity.eq(line.received_quantity),
+                            transfer_order_lines::written_off_quantity
+                                .eq(line.written_off_quantity),
+                        ))
+                        .execute(conn)
+                        .await
+                        .map_err(map_diesel_error)?;
+                }
+
+                let order = update_order(conn, &order).await?;
+
+                Ok((order, posted))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_movements(&self, order_id: Uuid) -> Result<Vec<domain::StockMovement>> {
+        stock_movements::table
+            .filter(stock_movements::document_type.eq(domain::DocumentType::TransferOrder))
+            .filter(stock_movements::document_id.eq(order_id))
+            .order((stock_movements::created_at, stock_movements::id))
+            .select(domain::StockMovement::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_in_transit_serials(
+        &self,
+        transit_location_id: Uuid,
+        product_id: Uuid,
+    ) -> Result<Vec<String>> {
+        serial_numbers::table
+            .filter(serial_numbers::product_id.eq(product_id))
+            .filter(serial_numbers::location_id.eq(transit_location_id))
+            .filter(serial_numbers::status.eq(SerialStatus::InStock))
+            .order(serial_numbers::serial_number)
+            .select(serial_numbers::serial_number)
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_in_transit(
+        &self,
+        query: domain::InTransitQuery,
+    ) -> Result<Vec<domain::InTransitStock>> {
+        let mut lines = transfer_order_lines::table
+            .inner_join(transfer_orders::table)
+            .filter(transfer_orders::status.eq_any(IN_TRANSIT_STATUSES))
+            .filter(
+                transfer_order_lines::quantity.gt(transfer_order_lines::received_quantity
+                    + transfer_order_lines::written_off_quantity),
+            )
+            .select((
+                domain::TransferOrderLine::as_select(),
+                domain::TransferOrder::as_select(),
+            ))
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            lines = lines.filter(transfer_order_lines::product_id.eq(product_id));
+        }
+        if let Some(warehouse_id) = query.warehouse_id {
+            lines = lines.filter(
+                transfer_orders::source_warehouse_id
+                    .eq(warehouse_id)
+                    .or(transfer_orders::destination_warehouse_id.eq(warehouse_id)),
+            );
+        }
+
+        let lines: Vec<(domain::TransferOrderLine, domain::TransferOrder)> = lines
+            .order((transfer_orders::shipped_at, transfer_order_lines::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)?;
+
+        Ok(lines
+            .into_iter()
+            .map(|(line, order)| domain::InTransitStock {
+                transfer_order_id: order.id,
+                line_id: line.id,
+                product_id: line.product_id,
+                source_warehouse_id: order.source_warehouse_id,
+                destination_warehouse_id: order.destination_warehouse_id,
+                quantity: line.outstanding(),
+                shipped_at: order.shipped_at,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_discrepancies(
+        &self,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<domain::TransferDiscrepancy>> {
+        let mut lines = transfer_order_lines::table
+            .inner_join(transfer_orders::table)
+            .filter(transfer_orders::status.eq(TransferOrderStatus::Closed))
+            .filter(transfer_order_lines::written_off_quantity.gt(Decimal::ZERO))
+            .select((
+                domain::TransferOrderLine::as_select(),
+                domain::TransferOrder::as_select(),
+            ))
+            .into_boxed();
+
+        if let Some(warehouse_id) = warehouse_id {
+            lines = lines.filter(
+                transfer_orders::source_warehouse_id
+                    .eq(warehouse_id)
+                    .or(transfer_orders::destination_warehouse_id.eq(warehouse_id)),
+            );
+        }
+
+        let lines: Vec<(domain::TransferOrderLine, domain::TransferOrder)> = lines
+            .order((transfer_orders::closed_at.desc(), transfer_order_lines::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)?;
+
+        Ok(lines
+            .into_iter()
+            .map(|(line, order)| domain::TransferDiscrepancy {
+                transfer_order_id: order.id,
+                line_id: line.id,
+                product_id: line.product_id,
+                source_warehouse_id: order.source_warehouse_id,
+                destination_warehouse_id: order.destination_warehouse_id,
+                shipped_quantity: line.quantity,
+                received_quantity: line.received_quantity,
+                missing_quantity: line.written_off_quantity,
+                closed_at: order.closed_at,
+            })
+            .collect())
+    }
+}
+
+/// Locks the order, so updates of it run one after the other, and checks that its status
+/// and what its lines received or wrote off are still as they were read.
+async fn lock_order(
+    conn: &mut AsyncPgConnection,
+    order_id: Uuid,
+    read_status: TransferOrderStatus,
+    read_lines: &[domain::TransferOrderLine],
+) -> Result<()> {
+    let status: TransferOrderStatus = transfer_orders::table
+        .find(order_id)
+        .select(transfer_orders::status)
+        .for_update()
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let lines: Vec<(Uuid, Decimal, Decimal)> = transfer_order_lines::table
+        .filter(transfer_order_lines::transfer_order_id.eq(order_id))
+        .select((
+            transfer_order_lines::id,
+            transfer_order_lines::received_quantity,
+            transfer_order_lines::written_off_quantity,
+        ))
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let unchanged = read_lines
+        .iter()
+        .all(|read| lines.contains(&(read.id, read.received_quantity, read.written_off_quantity)));
+    if status != read_status || !unchanged {
+        return Err(TransferOrderError::Changed.into());
+    }
+
+    Ok(())
+}
+
+async fn update_order(
+    conn: &mut AsyncPgConnection,
+    order: &domain::TransferOrder,
+) -> Result<domain::TransferOrder> {
+    diesel::update(transfer_orders::table.find(order.id))
+        .set((
+            transfer_orders::transit_location_id.eq(order.transit_location_id),
+            transfer_orders::status.eq(order.status),
+            transfer_orders::shipped_at.eq(order.shipped_at),
+            transfer_orders::closed_at.eq(order.closed_at),
+        ))
+        .returning(domain::TransferOrder::as_returning())
+        .get_result(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+/// Moves stock out of the transit location. Nothing is promised out of transit, so
+/// reservations are ignored, and shipped stock arrives even if its lot was blocked on the
+/// way.
+async fn take_from_transit(
+    conn: &mut AsyncPgConnection,
+    movement: domain::StockMovement,
+    serial_numbers: Vec<String>,
+) -> Result<Vec<domain::StockMovement>> {
+    let Some(location_id) = movement.from_location_id else {
+        return apply_movement(conn, movement, &serial_numbers, false).await;
+    };
+
+    if !serial_numbers.is_empty() {
+        return apply_movement(conn, movement, &serial_numbers, false).await;
+    }
+
+    let balances = lock_balances(conn, movement.product_id, location_id)
+        .await
+        .map_err(map_diesel_error)?;
+
+    let mut remaining = movement.quantity;
+    let mut movements = Vec::new();
+    for (balance, _) in balances {
+        if balance.status != movement.status {
+            continue;
+        }
+        let quantity = remaining.min(balance.on_hand);
+        if quantity.is_zero() {
+            continue;
+        }
+
+        let part = domain::StockMovement {
+            id: if movements.is_empty() {
+                movement.id
+            } else {
+                Uuid::new_v4()
+            },
+            quantity,
+            lot_id: balance.lot_id,
+            ..movement.clone()
+        };
+        movements.extend(apply_movement(conn, part, &[], false).await?);
+
+        remaining -= quantity;
+        if remaining.is_zero() {
+            break;
+        }
+    }
+
+    if !remaining.is_zero() {
+        // Let the regular allocation report the shortage.
+        let rest = domain::StockMovement {
+            id: Uuid::new_v4(),
+            quantity: remaining,
+            ..movement
+        };
+        movements.extend(apply_movement(conn, rest, &[], false).await?);
+    }
+
+    Ok(movements)
+}
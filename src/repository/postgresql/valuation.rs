@@ -0,0 +1,325 @@
+use crate::contract::repository::ValuationRepository;
+use crate::domain::CostingMethod;
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{
+    cost_layers, products, stock_movements, valuation_settings,
+};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use diesel::dsl;
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+pub struct PostgresValuationRepository {
+    pool: db::Pool,
+}
+
+impl PostgresValuationRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl ValuationRepository for PostgresValuationRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_settings(&self) -> Result<domain::ValuationSettings> {
+        valuation_settings::table
+            .select(domain::ValuationSettings::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, settings))]
+    async fn update_settings(
+        &self,
+        settings: domain::ValuationSettings,
+    ) -> Result<domain::ValuationSettings> {
+        diesel::update(valuation_settings::table)
+            .set(&settings)
+            .returning(domain::ValuationSettings::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_costing_method(
+        &self,
+        product_id: Uuid,
+        costing_method: Option<CostingMethod>,
+    ) -> Result<domain::Product> {
+        diesel::update(products::table.find(product_id))
+            .set(products::costing_method.eq(costing_method))
+            .returning(domain::Product::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_valuations(
+        &self,
+        query: domain::ValuationQuery,
+    ) -> Result<Vec<domain::ProductValuation>> {
+        let conn = &mut self.get_connection().await?;
+        let as_of = query.as_of.unwrap_or_else(Utc::now);
+
+        let mut inbound = stock_movements::table
+            .filter(stock_movements::created_at.le(as_of))
+            .filter(stock_movements::from_location_id.is_null())
+            .filter(stock_movements::to_location_id.is_not_null())
+            .group_by(stock_movements::product_id)
+            .select((
+                stock_movements::product_id,
+                dsl::sum(stock_movements::quantity),
+                dsl::sum(stock_movements::total_cost),
+            ))
+            .into_boxed();
+        let mut outbound = stock_movements::table
+            .filter(stock_movements::created_at.le(as_of))
+            .filter(stock_movements::from_location_id.is_not_null())
+            .filter(stock_movements::to_location_id.is_null())
+            .group_by(stock_movements::product_id)
+            .select((
+                stock_movements::product_id,
+                dsl::sum(stock_movements::quantity),
+                dsl::sum(stock_movements::total_cost),
+            ))
+            .into_boxed();
+
+        if let Some(product_id) = query.product_id {
+            inbound = inbound.filter(stock_movements::product_id.eq(product_id));
+            outbound = outbound.filter(stock_movements::product_id.eq(product_id));
+        }
+
+        let mut totals = BTreeMap::<Uuid, (Decimal, Decimal)>::new();
+        for (product_id, quantity, value) in inbound
+            .load::<(Uuid, Option<Decimal>, Option<Decimal>)>(conn)
+            .await
+            .map_err(map_diesel_error)?
+        {
+            let total = totals.entry(product_id).or_default();
+            total.0 += quantity.unwrap_or_default();
+            total.1 += value.unwrap_or_default();
+        }
+        for (product_id, quantity, value) in outbound
+            .load::<(Uuid, Option<Decimal>, Option<Decimal>)>(conn)
+            .await
+            .map_err(map_diesel_error)?
+        {
+            let total = totals.entry(product_id).or_default();
+            total.0 -= quantity.unwrap_or_default();
+            total.1 -= value.unwrap_or_default();
+        }
+
+        let default_method = default_costing_method(conn).await?;
+        let methods: HashMap<Uuid, Option<CostingMethod>> = products::table
+            .filter(products::id.eq_any(totals.keys().copied().collect::<Vec<_>>()))
+            .select((products::id, products::costing_method))
+            .load(conn)
+            .await
+            .map_err(map_diesel_error)?
+            .into_iter()
+            .collect();
+
+        Ok(totals
+            .into_iter()
+            .map(|(product_id, (quantity, value))| domain::ProductValuation {
+                product_id,
+                costing_method: methods
+                    .get(&product_id)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(default_method),
+                quantity,
+                value,
+                unit_cost: (!quantity.is_zero()).then(|| (value / quantity).round_dp(6)),
+            })
+            .collect())
+    }
+}
+
+async fn default_costing_method(conn: &mut AsyncPgConnection) -> Result<CostingMethod> {
+    valuation_settings::table
+        .select(valuation_settings::costing_method)
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+/// Costs a movement before it is posted. Inbound stock is valued at the given unit cost or
+/// the product's current cost, outbound stock at the cost of the layers it consumes. Moves
+/// between locations are not valued.
+pub(super) async fn valuate(
+    conn: &mut AsyncPgConnection,
+    mut movement: domain::StockMovement,
+) -> Result<domain::StockMovement> {
+    match (movement.from_location_id, movement.to_location_id) {
+        (None, Some(_)) => {
+            let unit_cost = match movement.unit_cost {
+                Some(unit_cost) => unit_cost,
+                None => current_cost(conn, movement.product_id).await?,
+            };
+            movement.unit_cost = Some(unit_cost);
+            movement.total_cost = Some((unit_cost * movement.quantity).round_dp(6));
+        }
+        (Some(_), None) => {
+            let total_cost = consume_layers(conn, movement.product_id, movement.quantity).await?;
+            movement.unit_cost = Some((total_cost / movement.quantity).round_dp(6));
+            movement.total_cost = Some(total_cost);
+        }
+        _ => {}
+    }
+
+    Ok(movement)
+}
+
+/// Adds the cost layer of a posted inbound movement.
+pub(super) async fn add_cost_layer(
+    conn: &mut AsyncPgConnection,
+    movement: &domain::StockMovement,
+) -> Result<()> {
+    if movement.from_location_id.is_some() || movement.to_location_id.is_none() {
+        return Ok(());
+    }
+
+    diesel::insert_into(cost_layers::table)
+        .values(domain::CostLayer {
+            id: Uuid::new_v4(),
+            product_id: movement.product_id,
+            movement_id: Some(movement.id),
+            created_at: movement.created_at,
+            remaining_quantity: movement.quantity,
+            remaining_value: movement.total_cost.unwrap_or_default(),
+        })
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    Ok(())
+}
+
+/// Locks the product, serializing the valuation of its movements, and returns the costing
+/// method in effect for it.
+async fn lock_costing_method(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+) -> Result<CostingMethod> {
+    let costing_method: Option<CostingMethod> = products::table
+        .find(product_id)
+        .select(products::costing_method)
+        .for_no_key_update()
+        .first(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+    match costing_method {
+        Some(costing_method) => Ok(costing_method),
+        None => default_costing_method(conn).await,
+    }
+}
+
+/// Layers with stock left, oldest first.
+async fn load_layers(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+) -> Result<Vec<domain::CostLayer>> {
+    cost_layers::table
+        .filter(cost_layers::product_id.eq(product_id))
+        .filter(cost_layers::remaining_quantity.gt(Decimal::ZERO))
+        .order((cost_layers::created_at, cost_layers::id))
+        .select(domain::CostLayer::as_select())
+        .load(conn)
+        .await
+        .map_err(map_diesel_error)
+}
+
+/// Average cost of the stock on hand, or the cost of the last receipt when nothing is left.
+pub(super) async fn current_cost(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+) -> Result<Decimal> {
+    lock_costing_method(conn, product_id).await?;
+
+    let layers = load_layers(conn, product_id).await?;
+    let quantity: Decimal = layers.iter().map(|layer| layer.remaining_quantity).sum();
+    if !quantity.is_zero() {
+        let value: Decimal = layers.iter().map(|layer| layer.remaining_value).sum();
+        return Ok((value / quantity).round_dp(6));
+    }
+
+    let last_cost: Option<Option<Decimal>> = stock_movements::table
+        .filter(stock_movements::product_id.eq(product_id))
+        .filter(stock_movements::from_location_id.is_null())
+        .filter(stock_movements::unit_cost.is_not_null())
+        .order(stock_movements::created_at.desc())
+        .select(stock_movements::unit_cost)
+        .first(conn)
+        .await
+        .optional()
+        .map_err(map_diesel_error)?;
+
+    Ok(last_cost.flatten().unwrap_or_default())
+}
+
+/// Takes `quantity` off the product's layers and returns its cost. At weighted average the
+/// layers are first folded into one. Stock not covered by any layer costs nothing.
+async fn consume_layers(
+    conn: &mut AsyncPgConnection,
+    product_id: Uuid,
+    quantity: Decimal,
+) -> Result<Decimal> {
+    let costing_method = lock_costing_method(conn, product_id).await?;
+    let mut layers = load_layers(conn, product_id).await?;
+
+    if costing_method == CostingMethod::WeightedAverage && layers.len() > 1 {
+        let mut merged = layers.remove(0);
+        merged.remaining_quantity += layers.iter().map(|l| l.remaining_quantity).sum::<Decimal>();
+        merged.remaining_value += layers.iter().map(|l| l.remaining_value).sum::<Decimal>();
+
+        diesel::delete(
+            cost_layers::table
+                .filter(cost_layers::id.eq_any(layers.iter().map(|l| l.id).collect::<Vec<_>>())),
+        )
+        .execute(conn)
+        .await
+        .map_err(map_diesel_error)?;
+
+        layers = vec![merged];
+    }
+
+    let mut remaining = quantity;
+    let mut cost = Decimal::ZERO;
+    for layer in layers {
+        if remaining.is_zero() {
+            break;
+        }
+
+        let taken = remaining.min(layer.remaining_quantity);
+        let value = layer.value_of(taken);
+
+        diesel::update(cost_layers::table.find(layer.id))
+            .set((
+                cost_layers::remaining_quantity.eq(layer.remaining_quantity - taken),
+                cost_layers::remaining_value.eq(layer.remaining_value - value),
+            ))
+            .execute(conn)
+            .await
+            .map_err(map_diesel_error)?;
+
+        remaining -= taken;
+        cost += value;
+    }
+
+    Ok(cost)
+}
@@ -0,0 +1,194 @@
+use crate::contract::repository::{LocationRepository, Repository, WarehouseRepository};
+use crate::domain::{LocationFilter, LocationSort};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::schema::{locations, warehouses};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use uuid::Uuid;
+
+pub struct PostgresWarehouseRepository {
+    pool: db::Pool,
+}
+
+impl PostgresWarehouseRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Warehouse> for PostgresWarehouseRepository {
+    #[tracing::instrument(skip(self, val))]
+    async fn create(&self, val: domain::Warehouse) -> Result<domain::Warehouse> {
+        diesel::insert_into(warehouses::table)
+            .values(val)
+            .returning(domain::Warehouse::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Warehouse> {
+        warehouses::table
+            .find(id)
+            .select(domain::Warehouse::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl WarehouseRepository for PostgresWarehouseRepository {}
+
+pub struct PostgresLocationRepository {
+    pool: db::Pool,
+}
+
+impl PostgresLocationRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Location> for PostgresLocationRepository {
+    #[tracing::instrument(skip(self, val))]
+    async fn create(&self, val: domain::Location) -> Result<domain::Location> {
+        diesel::insert_into(locations::table)
+            .values(val)
+            .returning(domain::Location::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Location> {
+        locations::table
+            .find(id)
+            .select(domain::Location::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl LocationRepository for PostgresLocationRepository {
+    #[tracing::instrument(skip(self))]
+    async fn find_by_code(&self, warehouse_id: Uuid, code: &str) -> Result<domain::Location> {
+        locations::table
+            .filter(locations::warehouse_id.eq(warehouse_id))
+            .filter(locations::code.eq(code))
+            .select(domain::Location::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list(&self, query: domain::LocationQuery) -> Result<Vec<domain::Location>> {
+        let mut locations = locations::table
+            .filter(locations::in_transit.eq(false))
+            .select(domain::Location::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            locations = locations.filter(locations::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(zone) = query.zone {
+            locations = locations.filter(locations::zone.eq(zone));
+        }
+        if !query.attributes.is_empty() {
+            locations = locations.filter(
+                locations::attributes.contains(serde_json::Value::Object(query.attributes)),
+            );
+        }
+
+        locations
+            .order((locations::warehouse_id, locations::code))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn page(
+        &self,
+        query: domain::LocationListQuery,
+    ) -> Result<domain::Page<domain::Location>> {
+        let mut conn = self.get_connection().await?;
+        let mut locations = locations::table
+            .filter(locations::in_transit.eq(false))
+            .select(domain::Location::as_select())
+            .into_boxed();
+
+        for filter in query.filters.iter().cloned() {
+            locations = match filter {
+                LocationFilter::WarehouseId(comparison) => {
+                    compare!(locations, locations::warehouse_id, comparison)
+                }
+                LocationFilter::Code(comparison) => {
+                    compare!(locations, locations::code, comparison)
+                }
+                LocationFilter::Zone(comparison) => {
+                    compare!(locations, locations::zone, comparison)
+                }
+                LocationFilter::Quarantine(comparison) => {
+                    compare!(locations, locations::quarantine, comparison)
+                }
+                LocationFilter::PickSequence(comparison) => {
+                    compare!(locations, locations::pick_sequence, comparison)
+                }
+                LocationFilter::Attribute(key, value) => {
+                    locations.filter(locations::attributes.contains(serde_json::Value::Object(
+                        serde_json::Map::from_iter([(key, value)]),
+                    )))
+                }
+            };
+        }
+
+        let locations = match query.sort.field {
+            LocationSort::Code => paginate!(
+                locations,
+                locations::table,
+                locations::code,
+                String,
+                locations::id,
+                query,
+                &mut conn
+            ),
+        };
+
+        let rows = locations.load(&mut conn).await.map_err(map_diesel_error)?;
+        Ok(domain::Page::from_rows(rows, query.limit, |location| {
+            location.id
+        }))
+    }
+
+    #[tracing::instrument(skip(self, attributes))]
+    async fn set_attributes(
+        &self,
+        id: Uuid,
+        attributes: serde_json::Value,
+    ) -> Result<domain::Location> {
+        diesel::update(locations::table.find(id))
+            .set(locations::attributes.eq(attributes))
+            .returning(domain::Location::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
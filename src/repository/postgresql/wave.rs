@@ -0,0 +1,333 @@
+use crate::contract::repository::{Repository, WaveRepository};
+use crate::domain::{PickOrderStatus, PickTaskStatus, WaveError, WaveStatus};
+use crate::repository::postgresql::map_diesel_error;
+use crate::repository::postgresql::reservation::consume_reservation;
+use crate::repository::postgresql::schema::{pick_orders, pick_tasks, waves};
+use crate::{db, domain};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use diesel::dsl::{exists, not};
+use diesel::prelude::*;
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct PostgresWaveRepository {
+    pool: db::Pool,
+}
+
+impl PostgresWaveRepository {
+    pub fn new(pool: db::Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_connection(&self) -> Result<db::Connection> {
+        self.pool.get().await.context("get connection")
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<domain::Wave> for PostgresWaveRepository {
+    #[tracing::instrument(skip(self, val), fields(id = %val.id))]
+    async fn create(&self, val: domain::Wave) -> Result<domain::Wave> {
+        diesel::insert_into(waves::table)
+            .values(val)
+            .returning(domain::Wave::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_id(&self, id: Uuid) -> Result<domain::Wave> {
+        waves::table
+            .find(id)
+            .select(domain::Wave::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl WaveRepository for PostgresWaveRepository {
+    #[tracing::instrument(skip(self, order), fields(id = %order.id))]
+    async fn create_order(&self, order: domain::PickOrder) -> Result<domain::PickOrder> {
+        diesel::insert_into(pick_orders::table)
+            .values(order)
+            .returning(domain::PickOrder::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_order(&self, id: Uuid) -> Result<domain::PickOrder> {
+        pick_orders::table
+            .find(id)
+            .select(domain::PickOrder::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    async fn list_orders(&self, query: domain::PickOrderQuery) -> Result<Vec<domain::PickOrder>> {
+        let mut orders = pick_orders::table
+            .select(domain::PickOrder::as_select())
+            .into_boxed();
+
+        if let Some(warehouse_id) = query.warehouse_id {
+            orders = orders.filter(pick_orders::warehouse_id.eq(warehouse_id));
+        }
+        if let Some(status) = query.status {
+            orders = orders.filter(pick_orders::status.eq(status));
+        }
+
+        orders
+            .order((pick_orders::created_at, pick_orders::id))
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, order), fields(id = %order.id))]
+    async fn update_order(&self, order: domain::PickOrder) -> Result<domain::PickOrder> {
+        diesel::update(pick_orders::table.find(order.id))
+            .set((
+                pick_orders::status.eq(order.status),
+                pick_orders::wave_id.eq(order.wave_id),
+                pick_orders::tote.eq(order.tote),
+            ))
+            .returning(domain::PickOrder::as_returning())
+            .get_result(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, wave, orders, tasks), fields(id = %wave.id))]
+    async fn create_with_tasks(
+        &self,
+        wave: domain::Wave,
+        orders: Vec<domain::PickOrder>,
+        tasks: Vec<domain::PickTask>,
+    ) -> Result<domain::Wave> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let wave = diesel::insert_into(waves::table)
+                    .values(wave)
+                    .returning(domain::Wave::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                for order in orders {
+                    let updated = diesel::update(
+                        pick_orders::table
+                            .find(order.id)
+                            .filter(pick_orders::status.eq(PickOrderStatus::Released)),
+                    )
+                    .set((
+                        pick_orders::status.eq(PickOrderStatus::Waved),
+                        pick_orders::wave_id.eq(wave.id),
+                        pick_orders::tote.eq(order.tote),
+                    ))
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                    if updated == 0 {
+                        return Err(WaveError::OrderNotReleased.into());
+                    }
+                }
+
+                diesel::insert_into(pick_tasks::table)
+                    .values(tasks)
+                    .execute(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                Ok(wave)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_wave_orders(&self, wave_id: Uuid) -> Result<Vec<domain::PickOrder>> {
+        pick_orders::table
+            .filter(pick_orders::wave_id.eq(wave_id))
+            .order((pick_orders::tote, pick_orders::created_at, pick_orders::id))
+            .select(domain::PickOrder::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn list_tasks(&self, wave_id: Uuid) -> Result<Vec<domain::PickTask>> {
+        pick_tasks::table
+            .filter(pick_tasks::wave_id.eq(wave_id))
+            .order(pick_tasks::sequence)
+            .select(domain::PickTask::as_select())
+            .load(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_task(&self, id: Uuid) -> Result<domain::PickTask> {
+        pick_tasks::table
+            .find(id)
+            .select(domain::PickTask::as_select())
+            .first(&mut self.get_connection().await?)
+            .await
+            .map_err(map_diesel_error)
+    }
+
+    #[tracing::instrument(skip(self, serial_numbers))]
+    async fn pick(
+        &self,
+        task_id: Uuid,
+        quantity: Decimal,
+        lot_id: Option<Uuid>,
+        serial_numbers: Vec<String>,
+        picked_by: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<(domain::PickTask, Vec<domain::StockMovement>)> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let task = pick_tasks::table
+                    .find(task_id)
+                    .select(domain::PickTask::as_select())
+                    .for_update()
+                    .first(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                if task.status != PickTaskStatus::Open {
+                    return Err(WaveError::TaskNotOpen.into());
+                }
+
+                let movements = if quantity.is_zero() {
+                    Vec::new()
+                } else {
+                    consume_reservation(
+                        conn,
+                        task.reservation_id,
+                        quantity,
+                        lot_id,
+                        &serial_numbers,
+                        Some(picked_by),
+                    )
+                    .await?
+                };
+
+                let task = diesel::update(pick_tasks::table.find(task.id))
+                    .set((
+                        pick_tasks::picked_quantity.eq(quantity),
+                        pick_tasks::status.eq(PickTaskStatus::Picked),
+                        pick_tasks::picked_by.eq(picked_by),
+                        pick_tasks::picked_at.eq(now),
+                    ))
+                    .returning(domain::PickTask::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+
+                diesel::update(
+                    pick_orders::table
+                        .find(task.pick_order_id)
+                        .filter(not(exists(
+                            pick_tasks::table
+                                .filter(pick_tasks::pick_order_id.eq(task.pick_order_id))
+                                .filter(pick_tasks::status.eq(PickTaskStatus::Open)),
+                        ))),
+                )
+                .set(pick_orders::status.eq(PickOrderStatus::Picked))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                diesel::update(
+                    waves::table.find(task.wave_id).filter(not(exists(
+                        pick_tasks::table
+                            .filter(pick_tasks::wave_id.eq(task.wave_id))
+                            .filter(pick_tasks::status.eq(PickTaskStatus::Open)),
+                    ))),
+                )
+                .set((
+                    waves::status.eq(WaveStatus::Completed),
+                    waves::completed_at.eq(now),
+                ))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                Ok((task, movements))
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn cancel(&self, wave_id: Uuid) -> Result<domain::Wave> {
+        let mut conn = self.get_connection().await?;
+        let conn: &mut AsyncPgConnection = &mut conn;
+
+        conn.transaction::<_, anyhow::Error, _>(|conn| {
+            async move {
+                let wave = waves::table
+                    .find(wave_id)
+                    .select(domain::Wave::as_select())
+                    .for_update()
+                    .first(conn)
+                    .await
+                    .map_err(map_diesel_error)?;
+                if wave.status != WaveStatus::Open {
+                    return Err(WaveError::NotOpen.into());
+                }
+
+                diesel::update(
+                    pick_tasks::table
+                        .filter(pick_tasks::wave_id.eq(wave_id))
+                        .filter(pick_tasks::status.eq(PickTaskStatus::Open)),
+                )
+                .set(pick_tasks::status.eq(PickTaskStatus::Cancelled))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                diesel::update(
+                    pick_orders::table
+                        .filter(pick_orders::wave_id.eq(wave_id))
+                        .filter(pick_orders::status.eq(PickOrderStatus::Waved)),
+                )
+                .set((
+                    pick_orders::status.eq(PickOrderStatus::Released),
+                    pick_orders::wave_id.eq(None::<Uuid>),
+                    pick_orders::tote.eq(None::<i32>),
+                ))
+                .execute(conn)
+                .await
+                .map_err(map_diesel_error)?;
+
+                diesel::update(waves::table.find(wave_id))
+                    .set(waves::status.eq(WaveStatus::Cancelled))
+                    .returning(domain::Wave::as_returning())
+                    .get_result(conn)
+                    .await
+                    .map_err(map_diesel_error)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+}
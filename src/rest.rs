@@ -1,13 +1,87 @@
 use crate::state::AppState;
+use axum::middleware::{from_fn, from_fn_with_state};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
+mod abc;
+mod access;
+mod attachment;
+mod attribute;
 mod auth;
+mod business_partner;
+mod cross_dock;
 mod error;
+mod extract;
 mod health_check;
+mod kit;
+mod label;
+mod list;
+mod lot;
+mod organization;
+mod pallet;
+mod product;
+mod putaway;
+mod replenishment;
+mod reservation;
+mod returns;
+mod scan;
+mod serial;
+mod shipment;
+mod snapshot;
+mod stock;
+mod stock_count;
+mod stock_hold;
+mod transfer_order;
+mod valuation;
+mod warehouse;
+mod wave;
 
-pub fn v1_handler() -> OpenApiRouter<AppState> {
+pub fn v1_handler(state: &AppState) -> OpenApiRouter<AppState> {
     OpenApiRouter::new()
         .routes(routes!(health_check::health_check))
         .nest("/auth", auth::router())
+        .merge(organization::router())
+        .merge(warehouse::router())
+        .merge(product::router())
+        .merge(attribute::router())
+        .merge(stock::router())
+        .merge(stock_hold::router())
+        .merge(snapshot::router())
+        .merge(putaway::router())
+        .merge(wave::router())
+        .merge(reservation::router())
+        .merge(returns::router())
+        .merge(stock_count::router())
+        .merge(transfer_order::router())
+        .merge(replenishment::router())
+        .merge(abc::router())
+        .merge(cross_dock::router())
+        .merge(shipment::router())
+        .merge(business_partner::router())
+        .merge(kit::router())
+        .merge(valuation::router())
+        .merge(lot::router())
+        .merge(serial::router())
+        .merge(pallet::router())
+        .merge(label::router())
+        .merge(scan::router())
+        .merge(attachment::router())
+        .layer(from_fn_with_state(
+            state.clone(),
+            access::scope_organization,
+        ))
+        .layer(from_fn(error::negotiate_problem))
+}
+
+/// Endpoints whose `/api/v1` counterparts keep their original shape for existing clients.
+/// Lists here take `ListParams` and return a `PageResponse`.
+pub fn v2_handler(state: &AppState) -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .merge(warehouse::v2_router())
+        .merge(product::v2_router())
+        .layer(from_fn_with_state(
+            state.clone(),
+            access::scope_organization,
+        ))
+        .layer(from_fn(error::negotiate_problem))
 }
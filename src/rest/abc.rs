@@ -0,0 +1,150 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AbcAnalysesParams, AbcAnalysisDetailResponse, AbcAnalysisResponse, AppError,
+    RunAbcAnalysisRequest, SlottingRecommendationResponse, SlottingRecommendationsParams,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Classifies the products of the warehouse and recommends slotting moves for class A.
+#[utoipa::path(post, path = "/abc-analyses", request_body = RunAbcAnalysisRequest, responses((status = CREATED, body = AbcAnalysisDetailResponse)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn run_analysis(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<RunAbcAnalysisRequest>,
+) -> Result<(StatusCode, Json<AbcAnalysisDetailResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::AbcAnalysis)
+        .await?;
+
+    let (analysis, lines, recommendations) = state
+        .dependencies
+        .abc_service()
+        .await
+        .run(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(AbcAnalysisDetailResponse::new(
+            analysis,
+            lines,
+            recommendations,
+        )),
+    ))
+}
+
+#[utoipa::path(get, path = "/abc-analyses", params(AbcAnalysesParams), responses((status = OK, body = Vec<AbcAnalysisResponse>)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_analyses(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<AbcAnalysesParams>,
+) -> Result<Json<Vec<AbcAnalysisResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::AbcAnalysis)
+        .await?;
+
+    let analyses = state
+        .dependencies
+        .abc_service()
+        .await
+        .list(params.into())
+        .await?;
+    Ok(Json(analyses.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/abc-analyses/{id}", responses((status = OK, body = AbcAnalysisDetailResponse)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_analysis(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AbcAnalysisDetailResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::AbcAnalysis)
+        .await?;
+
+    let (analysis, lines, recommendations) = state.dependencies.abc_service().await.get(id).await?;
+    Ok(Json(AbcAnalysisDetailResponse::new(
+        analysis,
+        lines,
+        recommendations,
+    )))
+}
+
+/// Sets the ABC class of the products to the one of the analysis, for cycle counts
+/// scoped by class.
+#[utoipa::path(post, path = "/abc-analyses/{id}/apply", responses((status = OK, body = AbcAnalysisResponse)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn apply_analysis(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AbcAnalysisResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::AbcAnalysis)
+        .await?;
+
+    let analysis = state.dependencies.abc_service().await.apply(id).await?;
+    Ok(Json(analysis.into()))
+}
+
+#[utoipa::path(get, path = "/slotting-recommendations", params(SlottingRecommendationsParams), responses((status = OK, body = Vec<SlottingRecommendationResponse>)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_recommendations(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<SlottingRecommendationsParams>,
+) -> Result<Json<Vec<SlottingRecommendationResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::AbcAnalysis)
+        .await?;
+
+    let recommendations = state
+        .dependencies
+        .abc_service()
+        .await
+        .list_recommendations(params.into())
+        .await?;
+    Ok(Json(recommendations.into_iter().map(Into::into).collect()))
+}
+
+/// Moves the stock of the product, and of the product it displaces, as recommended.
+#[utoipa::path(post, path = "/slotting-recommendations/{id}/accept", responses((status = OK, body = SlottingRecommendationResponse)), tag = crate::apidoc::ABC_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn accept_recommendation(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SlottingRecommendationResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::AbcAnalysis)
+        .await?;
+
+    let recommendation = state
+        .dependencies
+        .abc_service()
+        .await
+        .accept(id, token.0.id)
+        .await?;
+    Ok(Json(recommendation.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(run_analysis))
+        .routes(routes!(list_analyses))
+        .routes(routes!(get_analysis))
+        .routes(routes!(apply_analysis))
+        .routes(routes!(list_recommendations))
+        .routes(routes!(accept_recommendation))
+}
@@ -0,0 +1,93 @@
+use crate::contract::http::{AUTHORIZATION_HEADER, AUTHORIZATION_SCHEME};
+use crate::domain::{AuthError, ResourceAction, ResourceType};
+use crate::dto::{AccessTokenClaims, AppError};
+use crate::state::AppState;
+use anyhow::anyhow;
+use axum::extract::{FromRequestParts, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::HeaderMap;
+use http::request::Parts;
+
+/// Claims of a valid bearer token sent in the `Authorization` header.
+pub struct AccessToken(pub AccessTokenClaims);
+
+impl FromRequestParts<AppState> for AccessToken {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, AppError> {
+        Ok(Self(decode_access_token(&parts.headers, state).await?))
+    }
+}
+
+async fn decode_access_token(
+    headers: &HeaderMap,
+    state: &AppState,
+) -> Result<AccessTokenClaims, AppError> {
+    let Some(header) = headers.get(AUTHORIZATION_HEADER) else {
+        return Err(AuthError::InvalidCredentials(anyhow!(
+            "Missing {AUTHORIZATION_HEADER} header"
+        ))
+        .into());
+    };
+
+    let token = header
+        .to_str()
+        .ok()
+        .and_then(|header| header.strip_prefix(&format!("{AUTHORIZATION_SCHEME} ")))
+        .ok_or_else(|| {
+            AuthError::InvalidCredentials(anyhow!(
+                "{AUTHORIZATION_HEADER} contains an unsupported authorization scheme"
+            ))
+        })?;
+
+    let token = state
+        .dependencies
+        .auth_service()
+        .await
+        .decode_access_jwt(token)
+        .map_err(AuthError::InvalidCredentials)?;
+
+    Ok(token.claims)
+}
+
+/// Runs the request as the organization of the bearer token, so every query it makes is
+/// restricted to that organization. Requests without a valid token run as none, which only
+/// endpoints not requiring an [`AccessToken`] get to handle. Tokens of users no longer
+/// members of their organization are refused.
+pub async fn scope_organization(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Ok(claims) = decode_access_token(request.headers(), &state).await else {
+        return next.run(request).await;
+    };
+
+    state
+        .dependencies
+        .auth_service()
+        .await
+        .as_member(claims.organization_id, claims.id, next.run(request))
+        .await
+        .unwrap_or_else(|err| AppError::from(err).into_response())
+}
+
+impl AccessToken {
+    /// Checks the token owner's rules for the action on the resource type.
+    pub async fn authorize(
+        &self,
+        state: &AppState,
+        action: ResourceAction,
+        resource_type: ResourceType,
+    ) -> Result<(), AppError> {
+        state
+            .dependencies
+            .authorization_service()
+            .await
+            .authorize(self.0.id, action, resource_type)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::domain::{
+    AttachmentData, AttachmentError, ResourceAction, content_disposition, media_type,
+};
+use crate::dto::{AppError, AttachmentResponse, AttachmentsParams, UploadAttachmentParams};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Attaches the request body to the record. Its `Content-Type` must be one of the allowed
+/// photo and document types, and its size within the configured limit.
+#[utoipa::path(
+    post,
+    path = "/attachments",
+    params(UploadAttachmentParams),
+    request_body(content = Vec<u8>, content_type = "application/octet-stream"),
+    responses((status = CREATED, body = AttachmentResponse)),
+    tag = crate::apidoc::ATTACHMENT_TAG
+)]
+#[tracing::instrument(skip(state, token, headers, body))]
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<UploadAttachmentParams>,
+    headers: HeaderMap,
+    body: Body,
+) -> Result<(StatusCode, Json<AttachmentResponse>), AppError> {
+    params.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Update,
+            params.owner_type.resource_type(),
+        )
+        .await?;
+
+    let service = state.dependencies.attachment_service().await;
+    let content = axum::body::to_bytes(body, service.max_bytes())
+        .await
+        .map_err(|_| AttachmentError::TooLarge(service.max_bytes()))?;
+    let content_type = headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(media_type)
+        .unwrap_or_default();
+
+    let attachment = service
+        .create(
+            AttachmentData {
+                owner_type: params.owner_type,
+                owner_id: params.owner_id,
+                file_name: params.file_name,
+                content_type,
+                content: content.to_vec(),
+            },
+            token.0.id,
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(attachment.into())))
+}
+
+#[utoipa::path(get, path = "/attachments", params(AttachmentsParams), responses((status = OK, body = Vec<AttachmentResponse>)), tag = crate::apidoc::ATTACHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<AttachmentsParams>,
+) -> Result<Json<Vec<AttachmentResponse>>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Read,
+            params.owner_type.resource_type(),
+        )
+        .await?;
+
+    let attachments = state
+        .dependencies
+        .attachment_service()
+        .await
+        .list(params.owner_type, params.owner_id)
+        .await?;
+    Ok(Json(attachments.into_iter().map(Into::into).collect()))
+}
+
+/// The content of the attachment, served as a download with the type it was uploaded with.
+#[utoipa::path(
+    get,
+    path = "/attachments/{id}/content",
+    responses((status = OK, content((Vec<u8> = "application/octet-stream")))),
+    tag = crate::apidoc::ATTACHMENT_TAG
+)]
+#[tracing::instrument(skip(state, token))]
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let service = state.dependencies.attachment_service().await;
+    let attachment = service.get(id).await?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Read,
+            attachment.owner_type.resource_type(),
+        )
+        .await?;
+
+    let content = service.content(&attachment).await?;
+    Ok((
+        [
+            (CONTENT_TYPE, attachment.content_type),
+            (
+                CONTENT_DISPOSITION,
+                content_disposition(&attachment.file_name),
+            ),
+            (X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        content,
+    )
+        .into_response())
+}
+
+#[utoipa::path(delete, path = "/attachments/{id}", responses((status = NO_CONTENT)), tag = crate::apidoc::ATTACHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let service = state.dependencies.attachment_service().await;
+    let attachment = service.get(id).await?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Update,
+            attachment.owner_type.resource_type(),
+        )
+        .await?;
+
+    service.remove(id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(upload_attachment))
+        .routes(routes!(list_attachments))
+        .routes(routes!(download_attachment))
+        .routes(routes!(delete_attachment))
+}
@@ -0,0 +1,96 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, AttributeDefinitionResponse, AttributeDefinitionsParams,
+    CreateAttributeDefinitionRequest,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/attributes", request_body = CreateAttributeDefinitionRequest, responses((status = CREATED, body = AttributeDefinitionResponse)), tag = crate::apidoc::ATTRIBUTE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_attribute_definition(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateAttributeDefinitionRequest>,
+) -> Result<(StatusCode, Json<AttributeDefinitionResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Create,
+            ResourceType::AttributeDefinition,
+        )
+        .await?;
+
+    let definition = state
+        .dependencies
+        .attribute_service()
+        .await
+        .create(req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(definition.into())))
+}
+
+/// Definitions of the entity type in the order forms show them.
+#[utoipa::path(get, path = "/attributes", params(AttributeDefinitionsParams), responses((status = OK, body = Vec<AttributeDefinitionResponse>)), tag = crate::apidoc::ATTRIBUTE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_attribute_definitions(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<AttributeDefinitionsParams>,
+) -> Result<Json<Vec<AttributeDefinitionResponse>>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::List,
+            ResourceType::AttributeDefinition,
+        )
+        .await?;
+
+    let definitions = state
+        .dependencies
+        .attribute_service()
+        .await
+        .list(params.entity)
+        .await?;
+    Ok(Json(definitions.into_iter().map(Into::into).collect()))
+}
+
+/// Removes the definition together with the values entities have for it.
+#[utoipa::path(delete, path = "/attributes/{id}", responses((status = NO_CONTENT)), tag = crate::apidoc::ATTRIBUTE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn remove_attribute_definition(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Delete,
+            ResourceType::AttributeDefinition,
+        )
+        .await?;
+
+    state
+        .dependencies
+        .attribute_service()
+        .await
+        .remove(id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_attribute_definition))
+        .routes(routes!(list_attribute_definitions))
+        .routes(routes!(remove_attribute_definition))
+}
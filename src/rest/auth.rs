@@ -1,8 +1,9 @@
 use crate::dto::AppError;
 use crate::dto::{AuthTokens, SignInRequest, SignUpRequest};
+use crate::rest::extract::Json;
 use crate::state::AppState;
 use anyhow::Result;
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{extract::State, http::StatusCode};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 use validator::Validate;
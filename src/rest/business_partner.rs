@@ -0,0 +1,187 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AddPartnerAddressRequest, AddPartnerContactRequest, AppError, BusinessPartnerDetailResponse,
+    BusinessPartnerResponse, BusinessPartnersParams, CreateBusinessPartnerRequest,
+    PartnerAddressResponse, PartnerContactResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Creates the partner with its addresses and contacts. Fails with a conflict if another
+/// partner has the same tax id, or a similar name unless `allow_similar_name` is set.
+#[utoipa::path(post, path = "/business-partners", request_body = CreateBusinessPartnerRequest, responses((status = CREATED, body = BusinessPartnerResponse)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_business_partner(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateBusinessPartnerRequest>,
+) -> Result<(StatusCode, Json<BusinessPartnerResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Create,
+            ResourceType::BusinessPartner,
+        )
+        .await?;
+
+    let partner = state
+        .dependencies
+        .business_partner_service()
+        .await
+        .create(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(partner.into())))
+}
+
+#[utoipa::path(get, path = "/business-partners", params(BusinessPartnersParams), responses((status = OK, body = Vec<BusinessPartnerResponse>)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_business_partners(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<BusinessPartnersParams>,
+) -> Result<Json<Vec<BusinessPartnerResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::BusinessPartner)
+        .await?;
+
+    let partners = state
+        .dependencies
+        .business_partner_service()
+        .await
+        .search(params.into())
+        .await?;
+    Ok(Json(partners.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/business-partners/{id}", responses((status = OK, body = BusinessPartnerDetailResponse)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_business_partner(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BusinessPartnerDetailResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::BusinessPartner)
+        .await?;
+
+    let (partner, addresses, contacts) = state
+        .dependencies
+        .business_partner_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(BusinessPartnerDetailResponse::new(
+        partner, addresses, contacts,
+    )))
+}
+
+#[utoipa::path(post, path = "/business-partners/{id}/addresses", request_body = AddPartnerAddressRequest, responses((status = CREATED, body = PartnerAddressResponse)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_partner_address(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddPartnerAddressRequest>,
+) -> Result<(StatusCode, Json<PartnerAddressResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Update,
+            ResourceType::BusinessPartner,
+        )
+        .await?;
+
+    let address = state
+        .dependencies
+        .business_partner_service()
+        .await
+        .add_address(id, req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(address.into())))
+}
+
+#[utoipa::path(delete, path = "/business-partners/{id}/addresses/{address_id}", responses((status = NO_CONTENT)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn remove_partner_address(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, address_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Update,
+            ResourceType::BusinessPartner,
+        )
+        .await?;
+
+    state
+        .dependencies
+        .business_partner_service()
+        .await
+        .remove_address(id, address_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(post, path = "/business-partners/{id}/contacts", request_body = AddPartnerContactRequest, responses((status = CREATED, body = PartnerContactResponse)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_partner_contact(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddPartnerContactRequest>,
+) -> Result<(StatusCode, Json<PartnerContactResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::PartnerContact)
+        .await?;
+
+    let contact = state
+        .dependencies
+        .business_partner_service()
+        .await
+        .add_contact(id, req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(contact.into())))
+}
+
+#[utoipa::path(delete, path = "/business-partners/{id}/contacts/{contact_id}", responses((status = NO_CONTENT)), tag = crate::apidoc::BUSINESS_PARTNER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn remove_partner_contact(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, contact_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    token
+        .authorize(&state, ResourceAction::Delete, ResourceType::PartnerContact)
+        .await?;
+
+    state
+        .dependencies
+        .business_partner_service()
+        .await
+        .remove_contact(id, contact_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_business_partner))
+        .routes(routes!(list_business_partners))
+        .routes(routes!(get_business_partner))
+        .routes(routes!(add_partner_address))
+        .routes(routes!(remove_partner_address))
+        .routes(routes!(add_partner_contact))
+        .routes(routes!(remove_partner_contact))
+}
@@ -0,0 +1,152 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, CreateCrossDockRequest, CreateOutboundDemandRequest, CrossDockOpportunitiesParams,
+    CrossDockOpportunityResponse, CrossDockResponse, CrossDocksParams, OutboundDemandResponse,
+    OutboundDemandsParams,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Registers quantity an outbound document still needs, for received stock to be
+/// cross-docked to.
+#[utoipa::path(post, path = "/outbound-demands", request_body = CreateOutboundDemandRequest, responses((status = CREATED, body = OutboundDemandResponse)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_demand(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateOutboundDemandRequest>,
+) -> Result<(StatusCode, Json<OutboundDemandResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::CrossDock)
+        .await?;
+
+    let demand = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .create_demand(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(demand.into())))
+}
+
+#[utoipa::path(get, path = "/outbound-demands", params(OutboundDemandsParams), responses((status = OK, body = Vec<OutboundDemandResponse>)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_demands(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<OutboundDemandsParams>,
+) -> Result<Json<Vec<OutboundDemandResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::CrossDock)
+        .await?;
+
+    let demands = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .list_demands(params.into())
+        .await?;
+    Ok(Json(demands.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(post, path = "/outbound-demands/{id}/cancel", responses((status = OK, body = OutboundDemandResponse)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_demand(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<OutboundDemandResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::CrossDock)
+        .await?;
+
+    let demand = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .cancel_demand(id)
+        .await?;
+    Ok(Json(demand.into()))
+}
+
+/// Open demands the stock received into the location can be routed to, most urgent first.
+#[utoipa::path(get, path = "/cross-docks/opportunities", params(CrossDockOpportunitiesParams), responses((status = OK, body = Vec<CrossDockOpportunityResponse>)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_opportunities(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<CrossDockOpportunitiesParams>,
+) -> Result<Json<Vec<CrossDockOpportunityResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::CrossDock)
+        .await?;
+
+    let opportunities = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .opportunities(params.location_id, params.product_id)
+        .await?;
+    Ok(Json(opportunities.into_iter().map(Into::into).collect()))
+}
+
+/// Moves received stock to the staging location, reserves it there for the demand and
+/// skips its putaway.
+#[utoipa::path(post, path = "/cross-docks", request_body = CreateCrossDockRequest, responses((status = CREATED, body = CrossDockResponse)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_cross_dock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateCrossDockRequest>,
+) -> Result<(StatusCode, Json<CrossDockResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::CrossDock)
+        .await?;
+
+    let cross_dock = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .cross_dock(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(cross_dock.into())))
+}
+
+#[utoipa::path(get, path = "/cross-docks", params(CrossDocksParams), responses((status = OK, body = Vec<CrossDockResponse>)), tag = crate::apidoc::CROSS_DOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_cross_docks(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<CrossDocksParams>,
+) -> Result<Json<Vec<CrossDockResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::CrossDock)
+        .await?;
+
+    let cross_docks = state
+        .dependencies
+        .cross_dock_service()
+        .await
+        .list(params.into())
+        .await?;
+    Ok(Json(cross_docks.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_demand))
+        .routes(routes!(list_demands))
+        .routes(routes!(cancel_demand))
+        .routes(routes!(list_opportunities))
+        .routes(routes!(create_cross_dock))
+        .routes(routes!(list_cross_docks))
+}
@@ -1,13 +1,76 @@
-use crate::dto::AppError;
+use crate::contract::http::PROBLEM_JSON_CONTENT_TYPE;
+use crate::dto::{AppError, ProblemDetails};
 use axum::Json;
+use axum::extract::Request;
+use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
+use http::header::{ACCEPT, CONTENT_TYPE};
+use http::{HeaderMap, HeaderValue};
+use trace_id::TraceId;
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = self.code.status_code();
-        let mut response = Json::from(self).into_response();
+        let mut response = Json::from(self.clone()).into_response();
         *response.status_mut() = status;
+        // Kept for `negotiate_problem` to answer with problem details instead.
+        response.extensions_mut().insert(self);
 
         response
     }
 }
+
+/// Answers errors as RFC 7807 problem details to clients preferring
+/// `application/problem+json` over `application/json` in `Accept`. Other clients keep
+/// getting the plain [`AppError`].
+pub async fn negotiate_problem(request: Request, next: Next) -> Response {
+    let problem = prefers_problem(request.headers());
+    let trace_id = request
+        .extensions()
+        .get::<TraceId>()
+        .map(ToString::to_string);
+
+    let mut response = next.run(request).await;
+    if !problem {
+        return response;
+    }
+    let Some(error) = response.extensions_mut().remove::<AppError>() else {
+        return response;
+    };
+
+    let status = response.status();
+    let mut response = Json(ProblemDetails::new(error, trace_id)).into_response();
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+    );
+
+    response
+}
+
+fn prefers_problem(headers: &HeaderMap) -> bool {
+    let mut problem = 0.0;
+    let mut json = 0.0;
+
+    for range in headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+    {
+        let mut parts = range.split(';').map(str::trim);
+        let media_type = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let quality = parts
+            .filter_map(|param| param.strip_prefix("q="))
+            .find_map(|quality| quality.parse::<f32>().ok())
+            .unwrap_or(1.0);
+        match media_type.as_str() {
+            PROBLEM_JSON_CONTENT_TYPE => problem = quality,
+            "application/json" => json = quality,
+            _ => {}
+        }
+    }
+
+    problem > 0.0 && problem >= json
+}
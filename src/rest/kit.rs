@@ -0,0 +1,195 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, AssemblyOrderResponse, BomResponse, CompleteAssemblyOrderResponse,
+    CreateAssemblyOrderRequest, KitAvailabilityParams, KitAvailabilityResponse, SetBomRequest,
+    StockMovementResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(get, path = "/products/{id}/bom", responses((status = OK, body = BomResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_bom(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<BomResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Kit)
+        .await?;
+
+    let components = state
+        .dependencies
+        .kit_service()
+        .await
+        .components(id)
+        .await?;
+    Ok(Json(BomResponse::new(id, components)))
+}
+
+/// Replaces the bill of materials, making the product a kit.
+#[utoipa::path(post, path = "/products/{id}/bom", responses((status = OK, body = BomResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn set_bom(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetBomRequest>,
+) -> Result<Json<BomResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Kit)
+        .await?;
+
+    let components = state
+        .dependencies
+        .kit_service()
+        .await
+        .set_components(id, req.components.into_iter().map(Into::into).collect())
+        .await?;
+    Ok(Json(BomResponse::new(id, components)))
+}
+
+/// How many kits can be built from the components available.
+#[utoipa::path(get, path = "/products/{id}/kit-availability", params(KitAvailabilityParams), responses((status = OK, body = KitAvailabilityResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_kit_availability(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Query(params): Query<KitAvailabilityParams>,
+) -> Result<Json<KitAvailabilityResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Kit)
+        .await?;
+
+    let availability = state
+        .dependencies
+        .kit_service()
+        .await
+        .availability(id, params.warehouse_id)
+        .await?;
+    Ok(Json(availability.into()))
+}
+
+#[utoipa::path(post, path = "/assembly-orders", responses((status = CREATED, body = AssemblyOrderResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_assembly_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateAssemblyOrderRequest>,
+) -> Result<(StatusCode, Json<AssemblyOrderResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::AssemblyOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .kit_service()
+        .await
+        .create_order(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(AssemblyOrderResponse::new(order, lines)),
+    ))
+}
+
+#[utoipa::path(get, path = "/assembly-orders/{id}", responses((status = OK, body = AssemblyOrderResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_assembly_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AssemblyOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::AssemblyOrder)
+        .await?;
+
+    let (order, lines) = state.dependencies.kit_service().await.get_order(id).await?;
+    Ok(Json(AssemblyOrderResponse::new(order, lines)))
+}
+
+/// Posts the order: consumes its inputs and receives its outputs.
+#[utoipa::path(post, path = "/assembly-orders/{id}/complete", responses((status = OK, body = CompleteAssemblyOrderResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn complete_assembly_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CompleteAssemblyOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::AssemblyOrder)
+        .await?;
+
+    let (order, lines, movements) = state
+        .dependencies
+        .kit_service()
+        .await
+        .complete_order(id, token.0.id)
+        .await?;
+    Ok(Json(CompleteAssemblyOrderResponse {
+        order: AssemblyOrderResponse::new(order, lines),
+        movements: movements.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(post, path = "/assembly-orders/{id}/cancel", responses((status = OK, body = AssemblyOrderResponse)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_assembly_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AssemblyOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::AssemblyOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .kit_service()
+        .await
+        .cancel_order(id)
+        .await?;
+    Ok(Json(AssemblyOrderResponse::new(order, lines)))
+}
+
+#[utoipa::path(get, path = "/assembly-orders/{id}/movements", responses((status = OK, body = Vec<StockMovementResponse>)), tag = crate::apidoc::KIT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_assembly_order_movements(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<StockMovementResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::AssemblyOrder)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .kit_service()
+        .await
+        .list_movements(id)
+        .await?;
+    Ok(Json(movements.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(get_bom))
+        .routes(routes!(set_bom))
+        .routes(routes!(get_kit_availability))
+        .routes(routes!(create_assembly_order))
+        .routes(routes!(get_assembly_order))
+        .routes(routes!(complete_assembly_order))
+        .routes(routes!(cancel_assembly_order))
+        .routes(routes!(list_assembly_order_movements))
+}
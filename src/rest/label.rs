@@ -0,0 +1,55 @@
+use crate::domain::{LabelKind, ResourceAction, ResourceType};
+use crate::dto::{AppError, LabelParams};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+/// Renders the label of a location, product or pallet as SVG, PNG or ZPL.
+#[utoipa::path(
+    get,
+    path = "/labels/{kind}/{id}",
+    params(("kind" = LabelKind, Path), ("id" = Uuid, Path), LabelParams),
+    responses((status = OK, content(
+        (String = "image/svg+xml"),
+        (Vec<u8> = "image/png"),
+        (String = "application/zpl"),
+    ))),
+    tag = crate::apidoc::LABEL_TAG
+)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_label(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((kind, id)): Path<(LabelKind, Uuid)>,
+    Query(params): Query<LabelParams>,
+) -> Result<Response, AppError> {
+    let resource_type = match kind {
+        LabelKind::Location => ResourceType::Location,
+        LabelKind::Product => ResourceType::Product,
+        LabelKind::Pallet => ResourceType::Pallet,
+    };
+    token
+        .authorize(&state, ResourceAction::Read, resource_type)
+        .await?;
+
+    let label = state
+        .dependencies
+        .label_service()
+        .await
+        .label(kind, id)
+        .await?;
+    let body = label.render(params.format)?;
+
+    Ok(([(CONTENT_TYPE, params.format.content_type())], body).into_response())
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(get_label))
+}
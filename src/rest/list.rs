@@ -0,0 +1,14 @@
+use crate::dto::{AppError, ListParams};
+use axum::extract::FromRequestParts;
+use http::request::Parts;
+use validator::Validate;
+
+impl<S: Send + Sync> FromRequestParts<S> for ListParams {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, AppError> {
+        let params = ListParams::from_query(parts.uri.query().unwrap_or_default())?;
+        params.validate()?;
+        Ok(params)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, BlockExpiredLotsResponse, ExpiringStockParams, ExpiringStockResponse, LotResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+#[utoipa::path(get, path = "/lots/{id}", responses((status = OK, body = LotResponse)), tag = crate::apidoc::LOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_lot(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LotResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Lot)
+        .await?;
+
+    let lot = state.dependencies.lot_service().await.get(id).await?;
+    Ok(Json(lot.into()))
+}
+
+/// Excludes the lot from allocation and issue.
+#[utoipa::path(post, path = "/lots/{id}/block", responses((status = OK, body = LotResponse)), tag = crate::apidoc::LOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn block_lot(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LotResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Lot)
+        .await?;
+
+    let lot = state
+        .dependencies
+        .lot_service()
+        .await
+        .set_blocked(id, true)
+        .await?;
+    Ok(Json(lot.into()))
+}
+
+#[utoipa::path(post, path = "/lots/{id}/unblock", responses((status = OK, body = LotResponse)), tag = crate::apidoc::LOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn unblock_lot(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LotResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Lot)
+        .await?;
+
+    let lot = state
+        .dependencies
+        .lot_service()
+        .await
+        .set_blocked(id, false)
+        .await?;
+    Ok(Json(lot.into()))
+}
+
+/// Blocks every lot whose expiry date has passed.
+#[utoipa::path(post, path = "/lots/block-expired", responses((status = OK, body = BlockExpiredLotsResponse)), tag = crate::apidoc::LOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn block_expired_lots(
+    State(state): State<AppState>,
+    token: AccessToken,
+) -> Result<Json<BlockExpiredLotsResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Lot)
+        .await?;
+
+    let blocked = state
+        .dependencies
+        .lot_service()
+        .await
+        .block_expired()
+        .await?;
+    Ok(Json(BlockExpiredLotsResponse { blocked }))
+}
+
+/// Stock that expires within the next `days` days, earliest expiry first.
+#[utoipa::path(get, path = "/lots/expiring", params(ExpiringStockParams), responses((status = OK, body = Vec<ExpiringStockResponse>)), tag = crate::apidoc::LOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn expiring_stock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ExpiringStockParams>,
+) -> Result<Json<Vec<ExpiringStockResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Lot)
+        .await?;
+
+    let stock = state
+        .dependencies
+        .lot_service()
+        .await
+        .expiring(params.days, params.product_id, params.warehouse_id)
+        .await?;
+    Ok(Json(stock.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(get_lot))
+        .routes(routes!(block_lot))
+        .routes(routes!(unblock_lot))
+        .routes(routes!(block_expired_lots))
+        .routes(routes!(expiring_stock))
+}
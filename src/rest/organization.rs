@@ -0,0 +1,152 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AddMemberRequest, AppError, AuthTokens, CreateOrganizationRequest, OrganizationMemberResponse,
+    OrganizationResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Creates an organization the caller is a member of, with the rules they have in the
+/// organization they act as.
+#[utoipa::path(post, path = "/organizations", request_body = CreateOrganizationRequest, responses((status = CREATED, body = OrganizationResponse)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_organization(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateOrganizationRequest>,
+) -> Result<(StatusCode, Json<OrganizationResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Organization)
+        .await?;
+
+    let organization = state
+        .dependencies
+        .organization_service()
+        .await
+        .create(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(organization.into())))
+}
+
+/// Organizations the caller is a member of and can switch to.
+#[utoipa::path(get, path = "/organizations", responses((status = OK, body = Vec<OrganizationResponse>)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_organizations(
+    State(state): State<AppState>,
+    token: AccessToken,
+) -> Result<Json<Vec<OrganizationResponse>>, AppError> {
+    let organizations = state
+        .dependencies
+        .organization_service()
+        .await
+        .list_by_user(token.0.id)
+        .await?;
+    Ok(Json(organizations.into_iter().map(Into::into).collect()))
+}
+
+/// Tokens acting as another organization the caller is a member of.
+#[utoipa::path(post, path = "/organizations/{id}/switch", responses((status = OK, body = AuthTokens)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn switch_organization(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<AuthTokens>, AppError> {
+    let tokens = state
+        .dependencies
+        .auth_service()
+        .await
+        .switch_organization(token.0.id, id)
+        .await?;
+    Ok(Json(tokens))
+}
+
+#[utoipa::path(get, path = "/organizations/current/members", responses((status = OK, body = Vec<OrganizationMemberResponse>)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_members(
+    State(state): State<AppState>,
+    token: AccessToken,
+) -> Result<Json<Vec<OrganizationMemberResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Organization)
+        .await?;
+
+    let members = state
+        .dependencies
+        .organization_service()
+        .await
+        .list_members(token.0.organization_id)
+        .await?;
+    Ok(Json(
+        members
+            .into_iter()
+            .map(|(member, email)| OrganizationMemberResponse::new(member, email))
+            .collect(),
+    ))
+}
+
+/// Adds a user to the organization the caller acts as. They are granted nothing until
+/// assigned roles of the organization.
+#[utoipa::path(post, path = "/organizations/current/members", request_body = AddMemberRequest, responses((status = CREATED, body = OrganizationMemberResponse)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_member(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<AddMemberRequest>,
+) -> Result<(StatusCode, Json<OrganizationMemberResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Organization)
+        .await?;
+
+    let member = state
+        .dependencies
+        .organization_service()
+        .await
+        .add_member(token.0.organization_id, &req.email, token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(OrganizationMemberResponse::new(member, req.email)),
+    ))
+}
+
+/// Removes a user from the organization the caller acts as, with the roles they were
+/// assigned in it.
+#[utoipa::path(delete, path = "/organizations/current/members/{user_id}", responses((status = NO_CONTENT)), tag = crate::apidoc::ORGANIZATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn remove_member(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(user_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Organization)
+        .await?;
+
+    state
+        .dependencies
+        .organization_service()
+        .await
+        .remove_member(token.0.organization_id, user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_organization))
+        .routes(routes!(list_organizations))
+        .routes(routes!(switch_organization))
+        .routes(routes!(list_members))
+        .routes(routes!(add_member))
+        .routes(routes!(remove_member))
+}
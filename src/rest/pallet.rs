@@ -0,0 +1,54 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{AppError, CreatePalletRequest, PalletResponse};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Registers a pallet and allocates its SSCC.
+#[utoipa::path(post, path = "/pallets", responses((status = CREATED, body = PalletResponse)), tag = crate::apidoc::LABEL_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_pallet(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreatePalletRequest>,
+) -> Result<(StatusCode, Json<PalletResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Pallet)
+        .await?;
+
+    let pallet = state
+        .dependencies
+        .pallet_service()
+        .await
+        .create(req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(pallet.into())))
+}
+
+#[utoipa::path(get, path = "/pallets/{id}", responses((status = OK, body = PalletResponse)), tag = crate::apidoc::LABEL_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_pallet(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PalletResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Pallet)
+        .await?;
+
+    let pallet = state.dependencies.pallet_service().await.get(id).await?;
+    Ok(Json(pallet.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_pallet))
+        .routes(routes!(get_pallet))
+}
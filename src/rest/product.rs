@@ -0,0 +1,195 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, CreateProductRequest, CreateProductUomRequest, ListParams, PageResponse,
+    ProductResponse, ProductUomResponse, ProductsParams, SetAttributesRequest,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use serde_json::Value;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/products", responses((status = CREATED, body = ProductResponse)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_product(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateProductRequest>,
+) -> Result<(StatusCode, Json<ProductResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Product)
+        .await?;
+
+    let product = state
+        .dependencies
+        .product_service()
+        .await
+        .create(req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(product.into())))
+}
+
+#[utoipa::path(get, path = "/products/{id}", responses((status = OK, body = ProductResponse)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_product(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ProductResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Product)
+        .await?;
+
+    let product = state.dependencies.product_service().await.get(id).await?;
+    Ok(Json(product.into()))
+}
+
+#[utoipa::path(get, path = "/products", params(ProductsParams), responses((status = OK, body = Vec<ProductResponse>)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_products(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ProductsParams>,
+) -> Result<Json<Vec<ProductResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Product)
+        .await?;
+
+    let products = state
+        .dependencies
+        .product_service()
+        .await
+        .list(params.try_into()?)
+        .await?;
+    Ok(Json(products.into_iter().map(Into::into).collect()))
+}
+
+/// Products by SKU unless sorted by `name`. Filters: `sku`, `name`, `category`,
+/// `abc_class`, `lot_tracked`, `serial_tracked` and `attributes.<key>` for custom attributes.
+#[utoipa::path(get, path = "/products", params(ListParams), responses((status = OK, body = PageResponse<ProductResponse>)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn page_products(
+    State(state): State<AppState>,
+    token: AccessToken,
+    params: ListParams,
+) -> Result<Json<PageResponse<Value>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Product)
+        .await?;
+
+    let products = state
+        .dependencies
+        .product_service()
+        .await
+        .page(params.query()?)
+        .await?;
+    Ok(Json(params.page::<_, ProductResponse>(products)?))
+}
+
+/// Replaces the custom attribute values of the product, validated against the attribute
+/// definitions of products.
+#[utoipa::path(post, path = "/products/{id}/attributes", request_body = SetAttributesRequest, responses((status = OK, body = ProductResponse)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn set_product_attributes(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetAttributesRequest>,
+) -> Result<Json<ProductResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Product)
+        .await?;
+
+    let product = state
+        .dependencies
+        .product_service()
+        .await
+        .set_attributes(id, req.attributes)
+        .await?;
+    Ok(Json(product.into()))
+}
+
+#[utoipa::path(post, path = "/products/{id}/uoms", responses((status = CREATED, body = ProductUomResponse)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_product_uom(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateProductUomRequest>,
+) -> Result<(StatusCode, Json<ProductUomResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Product)
+        .await?;
+
+    let uom = state
+        .dependencies
+        .product_service()
+        .await
+        .add_uom(id, req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(uom.into())))
+}
+
+#[utoipa::path(get, path = "/products/{id}/uoms", responses((status = OK, body = Vec<ProductUomResponse>)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_product_uoms(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<ProductUomResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Product)
+        .await?;
+
+    let uoms = state
+        .dependencies
+        .product_service()
+        .await
+        .list_uoms(id)
+        .await?;
+    Ok(Json(uoms.into_iter().map(Into::into).collect()))
+}
+
+/// Resolves a scanned barcode to the product and unit of measure it identifies.
+#[utoipa::path(get, path = "/products/barcodes/{barcode}", responses((status = OK, body = ProductUomResponse)), tag = crate::apidoc::PRODUCT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn find_product_uom_by_barcode(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(barcode): Path<String>,
+) -> Result<Json<ProductUomResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Product)
+        .await?;
+
+    let uom = state
+        .dependencies
+        .product_service()
+        .await
+        .find_uom_by_barcode(&barcode)
+        .await?;
+    Ok(Json(uom.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_product))
+        .routes(routes!(get_product))
+        .routes(routes!(list_products))
+        .routes(routes!(set_product_attributes))
+        .routes(routes!(add_product_uom))
+        .routes(routes!(list_product_uoms))
+        .routes(routes!(find_product_uom_by_barcode))
+}
+
+pub fn v2_router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(page_products))
+}
@@ -0,0 +1,174 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, ConfirmPutawayRequest, ConfirmPutawayResponse, GeneratePutawayTasksRequest,
+    PutawayRuleResponse, PutawayTaskResponse, PutawayTasksParams, SetPutawayRulesRequest,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(get, path = "/warehouses/{id}/putaway-rules", responses((status = OK, body = Vec<PutawayRuleResponse>)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_putaway_rules(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<PutawayRuleResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Putaway)
+        .await?;
+
+    let rules = state.dependencies.putaway_service().await.rules(id).await?;
+    Ok(Json(rules.into_iter().map(Into::into).collect()))
+}
+
+/// Replaces the putaway rules of the warehouse.
+#[utoipa::path(post, path = "/warehouses/{id}/putaway-rules", responses((status = OK, body = Vec<PutawayRuleResponse>)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn set_putaway_rules(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetPutawayRulesRequest>,
+) -> Result<Json<Vec<PutawayRuleResponse>>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Putaway)
+        .await?;
+
+    let rules = state
+        .dependencies
+        .putaway_service()
+        .await
+        .set_rules(id, req.rules.into_iter().map(Into::into).collect())
+        .await?;
+    Ok(Json(rules.into_iter().map(Into::into).collect()))
+}
+
+/// Creates putaway tasks for the stock at a receiving location, with suggested destinations.
+#[utoipa::path(post, path = "/putaway-tasks/generate", responses((status = CREATED, body = Vec<PutawayTaskResponse>)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn generate_putaway_tasks(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<GeneratePutawayTasksRequest>,
+) -> Result<(StatusCode, Json<Vec<PutawayTaskResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Putaway)
+        .await?;
+
+    let tasks = state
+        .dependencies
+        .putaway_service()
+        .await
+        .generate(req.location_id, token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(tasks.into_iter().map(Into::into).collect()),
+    ))
+}
+
+#[utoipa::path(get, path = "/putaway-tasks", params(PutawayTasksParams), responses((status = OK, body = Vec<PutawayTaskResponse>)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_putaway_tasks(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<PutawayTasksParams>,
+) -> Result<Json<Vec<PutawayTaskResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Putaway)
+        .await?;
+
+    let tasks = state
+        .dependencies
+        .putaway_service()
+        .await
+        .list_tasks(params.into())
+        .await?;
+    Ok(Json(tasks.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/putaway-tasks/{id}", responses((status = OK, body = PutawayTaskResponse)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_putaway_task(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PutawayTaskResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Putaway)
+        .await?;
+
+    let task = state
+        .dependencies
+        .putaway_service()
+        .await
+        .get_task(id)
+        .await?;
+    Ok(Json(task.into()))
+}
+
+/// Confirms the task with the scanned destination and moves the stock there.
+#[utoipa::path(post, path = "/putaway-tasks/{id}/confirm", responses((status = OK, body = ConfirmPutawayResponse)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn confirm_putaway_task(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ConfirmPutawayRequest>,
+) -> Result<Json<ConfirmPutawayResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Putaway)
+        .await?;
+
+    let (task, movements) = state
+        .dependencies
+        .putaway_service()
+        .await
+        .confirm(id, req.into(), token.0.id)
+        .await?;
+    Ok(Json(ConfirmPutawayResponse {
+        task: task.into(),
+        movements: movements.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[utoipa::path(post, path = "/putaway-tasks/{id}/cancel", responses((status = OK, body = PutawayTaskResponse)), tag = crate::apidoc::PUTAWAY_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_putaway_task(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PutawayTaskResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Putaway)
+        .await?;
+
+    let task = state
+        .dependencies
+        .putaway_service()
+        .await
+        .cancel(id)
+        .await?;
+    Ok(Json(task.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(list_putaway_rules))
+        .routes(routes!(set_putaway_rules))
+        .routes(routes!(generate_putaway_tasks))
+        .routes(routes!(list_putaway_tasks))
+        .routes(routes!(get_putaway_task))
+        .routes(routes!(confirm_putaway_task))
+        .routes(routes!(cancel_putaway_task))
+}
@@ -0,0 +1,149 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, ReplenishmentRuleResponse, ReplenishmentRulesParams, ReplenishmentSuggestionResponse,
+    ReplenishmentSuggestionsParams, RunReplenishmentRequest, SaveReplenishmentRuleRequest,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Creates the rule of the product, warehouse and location or replaces the existing one.
+#[utoipa::path(post, path = "/replenishment/rules", responses((status = OK, body = ReplenishmentRuleResponse)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn save_rule(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<SaveReplenishmentRuleRequest>,
+) -> Result<Json<ReplenishmentRuleResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Replenishment)
+        .await?;
+
+    let rule = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .save_rule(req.into())
+        .await?;
+    Ok(Json(rule.into()))
+}
+
+#[utoipa::path(get, path = "/replenishment/rules", params(ReplenishmentRulesParams), responses((status = OK, body = Vec<ReplenishmentRuleResponse>)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_rules(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ReplenishmentRulesParams>,
+) -> Result<Json<Vec<ReplenishmentRuleResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Replenishment)
+        .await?;
+
+    let rules = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .list_rules(params.into())
+        .await?;
+    Ok(Json(rules.into_iter().map(Into::into).collect()))
+}
+
+/// Evaluates the rules now and replaces their pending suggestions.
+#[utoipa::path(post, path = "/replenishment/run", responses((status = OK, body = Vec<ReplenishmentSuggestionResponse>)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn run_replenishment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<RunReplenishmentRequest>,
+) -> Result<Json<Vec<ReplenishmentSuggestionResponse>>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Replenishment)
+        .await?;
+
+    let suggestions = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .run(req.warehouse_id, Some(token.0.id))
+        .await?;
+    Ok(Json(suggestions.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/replenishment/suggestions", params(ReplenishmentSuggestionsParams), responses((status = OK, body = Vec<ReplenishmentSuggestionResponse>)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_suggestions(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ReplenishmentSuggestionsParams>,
+) -> Result<Json<Vec<ReplenishmentSuggestionResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Replenishment)
+        .await?;
+
+    let suggestions = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .list_suggestions(params.into())
+        .await?;
+    Ok(Json(suggestions.into_iter().map(Into::into).collect()))
+}
+
+/// Creates the purchase order, or posts the pick-face replenishment, the suggestion proposes.
+#[utoipa::path(post, path = "/replenishment/suggestions/{id}/accept", responses((status = OK, body = ReplenishmentSuggestionResponse)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn accept_suggestion(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReplenishmentSuggestionResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::Replenishment)
+        .await?;
+
+    let suggestion = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .accept(id, token.0.id)
+        .await?;
+    Ok(Json(suggestion.into()))
+}
+
+#[utoipa::path(post, path = "/replenishment/suggestions/{id}/reject", responses((status = OK, body = ReplenishmentSuggestionResponse)), tag = crate::apidoc::REPLENISHMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn reject_suggestion(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReplenishmentSuggestionResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::Replenishment)
+        .await?;
+
+    let suggestion = state
+        .dependencies
+        .replenishment_service()
+        .await
+        .reject(id, token.0.id)
+        .await?;
+    Ok(Json(suggestion.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(save_rule))
+        .routes(routes!(list_rules))
+        .routes(routes!(run_replenishment))
+        .routes(routes!(list_suggestions))
+        .routes(routes!(accept_suggestion))
+        .routes(routes!(reject_suggestion))
+}
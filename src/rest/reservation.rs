@@ -0,0 +1,107 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, ConsumeReservationRequest, ReservationResponse, ReserveRequest, StockMovementResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/reservations", responses((status = CREATED, body = ReservationResponse)), tag = crate::apidoc::RESERVATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn reserve(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<ReserveRequest>,
+) -> Result<(StatusCode, Json<ReservationResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Reservation)
+        .await?;
+
+    let reservation = state
+        .dependencies
+        .reservation_service()
+        .await
+        .reserve(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(reservation.into())))
+}
+
+#[utoipa::path(get, path = "/reservations/{id}", responses((status = OK, body = ReservationResponse)), tag = crate::apidoc::RESERVATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_reservation(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReservationResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Reservation)
+        .await?;
+
+    let reservation = state
+        .dependencies
+        .reservation_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(reservation.into()))
+}
+
+#[utoipa::path(post, path = "/reservations/{id}/release", responses((status = OK, body = ReservationResponse)), tag = crate::apidoc::RESERVATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn release_reservation(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReservationResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Reservation)
+        .await?;
+
+    let reservation = state
+        .dependencies
+        .reservation_service()
+        .await
+        .release(id)
+        .await?;
+    Ok(Json(reservation.into()))
+}
+
+#[utoipa::path(post, path = "/reservations/{id}/consume", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::RESERVATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn consume_reservation(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ConsumeReservationRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Reservation)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .reservation_service()
+        .await
+        .consume(id, req.quantity, req.uom, req.serial_numbers, token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(reserve))
+        .routes(routes!(get_reservation))
+        .routes(routes!(release_reservation))
+        .routes(routes!(consume_reservation))
+}
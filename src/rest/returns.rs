@@ -0,0 +1,234 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, CreateReturnAuthorizationRequest, InspectReturnRequest, ReceiveReturnRequest,
+    ReceiveReturnResponse, ReturnAuthorizationResponse, ReturnSummaryParams, ReturnSummaryResponse,
+    StockMovementResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Authorizes the return of goods shipped on a sales order.
+#[utoipa::path(post, path = "/returns", responses((status = CREATED, body = ReturnAuthorizationResponse)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_return_authorization(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateReturnAuthorizationRequest>,
+) -> Result<(StatusCode, Json<ReturnAuthorizationResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Create,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let (authorization, lines) = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .create(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ReturnAuthorizationResponse::new(
+            authorization,
+            lines,
+            Vec::new(),
+        )),
+    ))
+}
+
+#[utoipa::path(get, path = "/returns/{id}", responses((status = OK, body = ReturnAuthorizationResponse)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_return_authorization(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReturnAuthorizationResponse>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Read,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let (authorization, lines, inspections) = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(ReturnAuthorizationResponse::new(
+        authorization,
+        lines,
+        inspections,
+    )))
+}
+
+/// Receives returned units into quarantine locations.
+#[utoipa::path(post, path = "/returns/{id}/receive", responses((status = OK, body = ReceiveReturnResponse)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn receive_return(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ReceiveReturnRequest>,
+) -> Result<Json<ReceiveReturnResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Update,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let (authorization, lines, inspections, movements) = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .receive(
+            id,
+            req.lines.into_iter().map(Into::into).collect(),
+            token.0.id,
+        )
+        .await?;
+    Ok(Json(ReceiveReturnResponse {
+        authorization: ReturnAuthorizationResponse::new(authorization, lines, inspections),
+        movements: movements.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// Records the disposition of received units and moves them out of quarantine.
+#[utoipa::path(post, path = "/returns/{id}/inspect", responses((status = OK, body = ReturnAuthorizationResponse)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn inspect_return(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<InspectReturnRequest>,
+) -> Result<Json<ReturnAuthorizationResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(
+            &state,
+            ResourceAction::Approve,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let (authorization, lines, inspections) = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .inspect(
+            id,
+            req.lines.into_iter().map(Into::into).collect(),
+            token.0.id,
+        )
+        .await?;
+    Ok(Json(ReturnAuthorizationResponse::new(
+        authorization,
+        lines,
+        inspections,
+    )))
+}
+
+/// Closes the authorization, units not received yet are no longer expected.
+#[utoipa::path(post, path = "/returns/{id}/close", responses((status = OK, body = ReturnAuthorizationResponse)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn close_return_authorization(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ReturnAuthorizationResponse>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Approve,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let (authorization, lines, inspections) = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .close(id)
+        .await?;
+    Ok(Json(ReturnAuthorizationResponse::new(
+        authorization,
+        lines,
+        inspections,
+    )))
+}
+
+#[utoipa::path(get, path = "/returns/{id}/movements", responses((status = OK, body = Vec<StockMovementResponse>)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_return_movements(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<StockMovementResponse>>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::Read,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let movements = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .list_movements(id)
+        .await?;
+    Ok(Json(movements.into_iter().map(Into::into).collect()))
+}
+
+/// Returned quantities by product, reason and disposition.
+#[utoipa::path(get, path = "/returns/summary", params(ReturnSummaryParams), responses((status = OK, body = Vec<ReturnSummaryResponse>)), tag = crate::apidoc::RETURN_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn summarize_returns(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ReturnSummaryParams>,
+) -> Result<Json<Vec<ReturnSummaryResponse>>, AppError> {
+    token
+        .authorize(
+            &state,
+            ResourceAction::List,
+            ResourceType::ReturnAuthorization,
+        )
+        .await?;
+
+    let summaries = state
+        .dependencies
+        .return_authorization_service()
+        .await
+        .summarize(params.into())
+        .await?;
+    Ok(Json(summaries.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_return_authorization))
+        .routes(routes!(get_return_authorization))
+        .routes(routes!(receive_return))
+        .routes(routes!(inspect_return))
+        .routes(routes!(close_return_authorization))
+        .routes(routes!(list_return_movements))
+        .routes(routes!(summarize_returns))
+}
@@ -0,0 +1,36 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{AppError, Gs1ScanRequest, Gs1ScanResponse};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::Json;
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use validator::Validate;
+
+/// Splits a scanned GS1-128, DataMatrix or QR code into its fields and resolves the product.
+#[utoipa::path(post, path = "/scans/gs1", responses((status = OK, body = Gs1ScanResponse)), tag = crate::apidoc::SCAN_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn parse_gs1_scan(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<Gs1ScanRequest>,
+) -> Result<Json<Gs1ScanResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Product)
+        .await?;
+
+    let scan = state
+        .dependencies
+        .scan_service()
+        .await
+        .gs1(&req.data)
+        .await?;
+    Ok(Json(scan.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(parse_gs1_scan))
+}
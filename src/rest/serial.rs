@@ -0,0 +1,79 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{AppError, SerialNumberResponse, StockMovementResponse};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+#[utoipa::path(get, path = "/serial-numbers/{id}", responses((status = OK, body = SerialNumberResponse)), tag = crate::apidoc::SERIAL_NUMBER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_serial_number(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SerialNumberResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::SerialNumber)
+        .await?;
+
+    let serial = state
+        .dependencies
+        .serial_number_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(serial.into()))
+}
+
+/// Looks a unit up by the serial number printed on it.
+#[utoipa::path(get, path = "/products/{id}/serial-numbers/{serial_number}", responses((status = OK, body = SerialNumberResponse)), tag = crate::apidoc::SERIAL_NUMBER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn find_serial_number(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((product_id, serial_number)): Path<(Uuid, String)>,
+) -> Result<Json<SerialNumberResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::SerialNumber)
+        .await?;
+
+    let serial = state
+        .dependencies
+        .serial_number_service()
+        .await
+        .find(product_id, &serial_number)
+        .await?;
+    Ok(Json(serial.into()))
+}
+
+/// Movements the unit took part in, oldest first.
+#[utoipa::path(get, path = "/serial-numbers/{id}/history", responses((status = OK, body = Vec<StockMovementResponse>)), tag = crate::apidoc::SERIAL_NUMBER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn serial_number_history(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<StockMovementResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::SerialNumber)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .serial_number_service()
+        .await
+        .history(id)
+        .await?;
+    Ok(Json(movements.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(get_serial_number))
+        .routes(routes!(find_serial_number))
+        .routes(routes!(serial_number_history))
+}
@@ -0,0 +1,222 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AddParcelRequest, AppError, CreateShipmentRequest, ParcelResponse, RateResponse, RatesParams,
+    ShipRequest, ShipmentDetailResponse, ShipmentResponse, ShipmentsParams,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/shipments", request_body = CreateShipmentRequest, responses((status = CREATED, body = ShipmentResponse)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_shipment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateShipmentRequest>,
+) -> Result<(StatusCode, Json<ShipmentResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Shipment)
+        .await?;
+
+    let shipment = state
+        .dependencies
+        .shipment_service()
+        .await
+        .create(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(shipment.into())))
+}
+
+#[utoipa::path(get, path = "/shipments", params(ShipmentsParams), responses((status = OK, body = Vec<ShipmentResponse>)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_shipments(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ShipmentsParams>,
+) -> Result<Json<Vec<ShipmentResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Shipment)
+        .await?;
+
+    let shipments = state
+        .dependencies
+        .shipment_service()
+        .await
+        .list(params.into())
+        .await?;
+    Ok(Json(shipments.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/shipments/{id}", responses((status = OK, body = ShipmentDetailResponse)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_shipment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ShipmentDetailResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Shipment)
+        .await?;
+
+    let (shipment, parcels) = state.dependencies.shipment_service().await.get(id).await?;
+    Ok(Json(ShipmentDetailResponse::new(shipment, parcels)))
+}
+
+#[utoipa::path(post, path = "/shipments/{id}/parcels", request_body = AddParcelRequest, responses((status = CREATED, body = ParcelResponse)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_parcel(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddParcelRequest>,
+) -> Result<(StatusCode, Json<ParcelResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Shipment)
+        .await?;
+
+    let parcel = state
+        .dependencies
+        .shipment_service()
+        .await
+        .add_parcel(id, req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(parcel.into())))
+}
+
+#[utoipa::path(delete, path = "/shipments/{id}/parcels/{parcel_id}", responses((status = NO_CONTENT)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn remove_parcel(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, parcel_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Shipment)
+        .await?;
+
+    state
+        .dependencies
+        .shipment_service()
+        .await
+        .remove_parcel(id, parcel_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Rate-shops the carriers for the parcels of the shipment, cheapest first.
+#[utoipa::path(get, path = "/shipments/{id}/rates", params(RatesParams), responses((status = OK, body = Vec<RateResponse>)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_rates(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Query(params): Query<RatesParams>,
+) -> Result<Json<Vec<RateResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Shipment)
+        .await?;
+
+    let rates = state
+        .dependencies
+        .shipment_service()
+        .await
+        .rates(id, params.carrier.as_deref())
+        .await?;
+    Ok(Json(rates.into_iter().map(Into::into).collect()))
+}
+
+/// Books the carrier service, assigning tracking numbers and labels to the parcels.
+#[utoipa::path(post, path = "/shipments/{id}/ship", request_body = ShipRequest, responses((status = OK, body = ShipmentDetailResponse)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn ship_shipment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ShipRequest>,
+) -> Result<Json<ShipmentDetailResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::Shipment)
+        .await?;
+
+    let (shipment, parcels) = state
+        .dependencies
+        .shipment_service()
+        .await
+        .ship(id, req.into(), token.0.id)
+        .await?;
+    Ok(Json(ShipmentDetailResponse::new(shipment, parcels)))
+}
+
+#[utoipa::path(post, path = "/shipments/{id}/cancel", responses((status = OK, body = ShipmentResponse)), tag = crate::apidoc::SHIPMENT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_shipment(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ShipmentResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Shipment)
+        .await?;
+
+    let shipment = state
+        .dependencies
+        .shipment_service()
+        .await
+        .cancel(id)
+        .await?;
+    Ok(Json(shipment.into()))
+}
+
+/// The label the carrier printed for the parcel, in the format requested when shipping.
+#[utoipa::path(
+    get,
+    path = "/parcels/{id}/label",
+    responses((status = OK, content(
+        (String = "image/svg+xml"),
+        (Vec<u8> = "image/png"),
+        (String = "application/zpl"),
+    ))),
+    tag = crate::apidoc::SHIPMENT_TAG
+)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_parcel_label(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Shipment)
+        .await?;
+
+    let (content_type, label) = state
+        .dependencies
+        .shipment_service()
+        .await
+        .parcel_label(id)
+        .await?;
+    Ok(([(CONTENT_TYPE, content_type)], label).into_response())
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_shipment))
+        .routes(routes!(list_shipments))
+        .routes(routes!(get_shipment))
+        .routes(routes!(add_parcel))
+        .routes(routes!(remove_parcel))
+        .routes(routes!(list_rates))
+        .routes(routes!(ship_shipment))
+        .routes(routes!(cancel_shipment))
+        .routes(routes!(get_parcel_label))
+}
@@ -0,0 +1,128 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, StockAsOfParams, StockPositionResponse, StockPositionsParams,
+    StockSnapshotDetailResponse, StockSnapshotDiffParams, StockSnapshotDiffResponse,
+    StockSnapshotResponse, StockSnapshotsParams,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+/// Stock on hand at a past moment, by product, location, lot and status.
+#[utoipa::path(get, path = "/stock/as-of", params(StockAsOfParams), responses((status = OK, body = Vec<StockPositionResponse>)), tag = crate::apidoc::SNAPSHOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn stock_as_of(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<StockAsOfParams>,
+) -> Result<Json<Vec<StockPositionResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Stock)
+        .await?;
+
+    let as_of = params.as_of;
+    let positions = state
+        .dependencies
+        .snapshot_service()
+        .await
+        .positions_as_of(as_of, params.into())
+        .await?;
+    Ok(Json(positions.into_iter().map(Into::into).collect()))
+}
+
+/// Snapshots stock on hand right now.
+#[utoipa::path(post, path = "/stock/snapshots", responses((status = CREATED, body = StockSnapshotResponse)), tag = crate::apidoc::SNAPSHOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn take_snapshot(
+    State(state): State<AppState>,
+    token: AccessToken,
+) -> Result<(StatusCode, Json<StockSnapshotResponse>), AppError> {
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::StockSnapshot)
+        .await?;
+
+    let snapshot = state
+        .dependencies
+        .snapshot_service()
+        .await
+        .take(token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(snapshot.into())))
+}
+
+#[utoipa::path(get, path = "/stock/snapshots", params(StockSnapshotsParams), responses((status = OK, body = Vec<StockSnapshotResponse>)), tag = crate::apidoc::SNAPSHOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_snapshots(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<StockSnapshotsParams>,
+) -> Result<Json<Vec<StockSnapshotResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::StockSnapshot)
+        .await?;
+
+    let snapshots = state
+        .dependencies
+        .snapshot_service()
+        .await
+        .list(params.into())
+        .await?;
+    Ok(Json(snapshots.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/stock/snapshots/{id}", params(StockPositionsParams), responses((status = OK, body = StockSnapshotDetailResponse)), tag = crate::apidoc::SNAPSHOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_snapshot(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Query(params): Query<StockPositionsParams>,
+) -> Result<Json<StockSnapshotDetailResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::StockSnapshot)
+        .await?;
+
+    let (snapshot, lines) = state
+        .dependencies
+        .snapshot_service()
+        .await
+        .get(id, params.into())
+        .await?;
+    Ok(Json(StockSnapshotDetailResponse::new(snapshot, lines)))
+}
+
+/// Products and locations whose stock changed between two snapshots.
+#[utoipa::path(get, path = "/stock/snapshots/diff", params(StockSnapshotDiffParams), responses((status = OK, body = Vec<StockSnapshotDiffResponse>)), tag = crate::apidoc::SNAPSHOT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn diff_snapshots(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<StockSnapshotDiffParams>,
+) -> Result<Json<Vec<StockSnapshotDiffResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::StockSnapshot)
+        .await?;
+
+    let (from, to) = (params.from, params.to);
+    let diff = state
+        .dependencies
+        .snapshot_service()
+        .await
+        .diff(from, to, params.into())
+        .await?;
+    Ok(Json(diff.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(stock_as_of))
+        .routes(routes!(take_snapshot))
+        .routes(routes!(list_snapshots))
+        .routes(routes!(get_snapshot))
+        .routes(routes!(diff_snapshots))
+}
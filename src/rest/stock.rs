@@ -0,0 +1,111 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, AvailableToPromiseParams, AvailableToPromiseResponse, CreateMovementRequest,
+    StockLevelResponse, StockLevelsParams, StockMovementResponse, StockMovementsParams,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Posts a movement. Outbound stock without a lot is allocated first-expired-first-out,
+/// one movement is returned per lot.
+#[utoipa::path(post, path = "/stock/movements", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn post_movement(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateMovementRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Stock)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_service()
+        .await
+        .post_movement(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+/// Movement history, oldest first.
+#[utoipa::path(get, path = "/stock/movements", params(StockMovementsParams), responses((status = OK, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_movements(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<StockMovementsParams>,
+) -> Result<Json<Vec<StockMovementResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Stock)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_service()
+        .await
+        .movements(params.into())
+        .await?;
+    Ok(Json(movements.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/stock/levels", params(StockLevelsParams), responses((status = OK, body = Vec<StockLevelResponse>)), tag = crate::apidoc::STOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_levels(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<StockLevelsParams>,
+) -> Result<Json<Vec<StockLevelResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Stock)
+        .await?;
+
+    let levels = state
+        .dependencies
+        .stock_service()
+        .await
+        .levels(params.into())
+        .await?;
+    Ok(Json(levels.into_iter().map(Into::into).collect()))
+}
+
+/// Quantity of a product that can still be promised to new orders.
+#[utoipa::path(get, path = "/stock/available-to-promise/{product_id}", params(AvailableToPromiseParams), responses((status = OK, body = AvailableToPromiseResponse)), tag = crate::apidoc::STOCK_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn available_to_promise(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(product_id): Path<Uuid>,
+    Query(params): Query<AvailableToPromiseParams>,
+) -> Result<Json<AvailableToPromiseResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Stock)
+        .await?;
+
+    let atp = state
+        .dependencies
+        .stock_service()
+        .await
+        .available_to_promise(product_id, params.warehouse_id)
+        .await?;
+    Ok(Json(atp.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(post_movement))
+        .routes(routes!(list_movements))
+        .routes(routes!(get_levels))
+        .routes(routes!(available_to_promise))
+}
@@ -0,0 +1,174 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AddCountTaskRequest, AppError, CountSessionResponse, CountTaskResponse,
+    CreateCountSessionRequest, RecordCountRequest,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Plans a count session and generates its count tasks.
+#[utoipa::path(post, path = "/stock-counts", responses((status = CREATED, body = CountSessionResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn plan_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateCountSessionRequest>,
+) -> Result<(StatusCode, Json<CountSessionResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::StockCount)
+        .await?;
+
+    let service = state.dependencies.stock_count_service().await;
+    let session = service.plan(req.into(), token.0.id).await?;
+    let (session, tasks) = service.get(session.id).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CountSessionResponse::new(session, tasks)),
+    ))
+}
+
+#[utoipa::path(get, path = "/stock-counts/{id}", responses((status = OK, body = CountSessionResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CountSessionResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::StockCount)
+        .await?;
+
+    let (session, tasks) = state
+        .dependencies
+        .stock_count_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(CountSessionResponse::new(session, tasks)))
+}
+
+/// Adds a count task for stock found where the session has none.
+#[utoipa::path(post, path = "/stock-counts/{id}/tasks", responses((status = CREATED, body = CountTaskResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn add_count_task(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddCountTaskRequest>,
+) -> Result<(StatusCode, Json<CountTaskResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::StockCount)
+        .await?;
+
+    let (session, task) = state
+        .dependencies
+        .stock_count_service()
+        .await
+        .add_task(id, req.into())
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CountTaskResponse::new(task, session.blind)),
+    ))
+}
+
+#[utoipa::path(post, path = "/stock-counts/{id}/tasks/{task_id}/count", responses((status = OK, body = CountTaskResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn record_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+    Json(req): Json<RecordCountRequest>,
+) -> Result<Json<CountTaskResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::StockCount)
+        .await?;
+
+    let (session, task) = state
+        .dependencies
+        .stock_count_service()
+        .await
+        .record_count(id, task_id, req.counted_quantity, req.uom, token.0.id)
+        .await?;
+    Ok(Json(CountTaskResponse::new(task, session.blind)))
+}
+
+/// Approves a variance outside the tolerance and posts the adjustment.
+#[utoipa::path(post, path = "/stock-counts/{id}/tasks/{task_id}/approve", responses((status = OK, body = CountTaskResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn approve_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<CountTaskResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::StockCount)
+        .await?;
+
+    let (session, task) = state
+        .dependencies
+        .stock_count_service()
+        .await
+        .approve(id, task_id, token.0.id)
+        .await?;
+    Ok(Json(CountTaskResponse::new(task, session.blind)))
+}
+
+/// Rejects a variance and sends the task back to recount.
+#[utoipa::path(post, path = "/stock-counts/{id}/tasks/{task_id}/reject", responses((status = OK, body = CountTaskResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn reject_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<CountTaskResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::StockCount)
+        .await?;
+
+    let (session, task) = state
+        .dependencies
+        .stock_count_service()
+        .await
+        .reject(id, task_id, token.0.id)
+        .await?;
+    Ok(Json(CountTaskResponse::new(task, session.blind)))
+}
+
+#[utoipa::path(post, path = "/stock-counts/{id}/complete", responses((status = OK, body = CountSessionResponse)), tag = crate::apidoc::STOCK_COUNT_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn complete_count(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<CountSessionResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::StockCount)
+        .await?;
+
+    let service = state.dependencies.stock_count_service().await;
+    service.complete(id).await?;
+    let (session, tasks) = service.get(id).await?;
+    Ok(Json(CountSessionResponse::new(session, tasks)))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(plan_count))
+        .routes(routes!(get_count))
+        .routes(routes!(add_count_task))
+        .routes(routes!(record_count))
+        .routes(routes!(approve_count))
+        .routes(routes!(reject_count))
+        .routes(routes!(complete_count))
+}
@@ -0,0 +1,145 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, HeldStockParams, HoldLotRequest, StockBalanceResponse, StockHoldRequest,
+    StockMovementResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Puts unreserved available stock on hold, excluding it from allocation.
+#[utoipa::path(post, path = "/stock/holds", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_HOLD_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn hold_stock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<StockHoldRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::StockHold)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_hold_service()
+        .await
+        .hold(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+#[utoipa::path(get, path = "/stock/holds", params(HeldStockParams), responses((status = OK, body = Vec<StockBalanceResponse>)), tag = crate::apidoc::STOCK_HOLD_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_held_stock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<HeldStockParams>,
+) -> Result<Json<Vec<StockBalanceResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::StockHold)
+        .await?;
+
+    let balances = state
+        .dependencies
+        .stock_hold_service()
+        .await
+        .held(params.into())
+        .await?;
+    Ok(Json(balances.into_iter().map(Into::into).collect()))
+}
+
+/// Makes held stock available again.
+#[utoipa::path(post, path = "/stock/holds/release", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_HOLD_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn release_stock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<StockHoldRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::StockHold)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_hold_service()
+        .await
+        .release(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+/// Writes held stock off with an adjustment.
+#[utoipa::path(post, path = "/stock/holds/scrap", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_HOLD_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn scrap_stock(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<StockHoldRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Delete, ResourceType::StockHold)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_hold_service()
+        .await
+        .scrap(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+/// Puts all available stock of the lot on hold at every location, reserved stock included.
+/// Reservations the remaining stock no longer covers are released, newest first.
+#[utoipa::path(post, path = "/lots/{id}/hold", responses((status = CREATED, body = Vec<StockMovementResponse>)), tag = crate::apidoc::STOCK_HOLD_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn hold_lot(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<HoldLotRequest>,
+) -> Result<(StatusCode, Json<Vec<StockMovementResponse>>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::StockHold)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .stock_hold_service()
+        .await
+        .hold_lot(id, req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(movements.into_iter().map(Into::into).collect()),
+    ))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(hold_stock))
+        .routes(routes!(list_held_stock))
+        .routes(routes!(release_stock))
+        .routes(routes!(scrap_stock))
+        .routes(routes!(hold_lot))
+}
@@ -0,0 +1,212 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, CreateTransferOrderRequest, InTransitParams, InTransitStockResponse,
+    ReceiveTransferOrderRequest, ReceiveTransferOrderResponse, ShipTransferOrderRequest,
+    StockMovementResponse, TransferDiscrepanciesParams, TransferDiscrepancyResponse,
+    TransferOrderResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/transfer-orders", responses((status = CREATED, body = TransferOrderResponse)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_transfer_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateTransferOrderRequest>,
+) -> Result<(StatusCode, Json<TransferOrderResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::TransferOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .create(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(TransferOrderResponse::new(order, lines)),
+    ))
+}
+
+#[utoipa::path(get, path = "/transfer-orders/{id}", responses((status = OK, body = TransferOrderResponse)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_transfer_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TransferOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::TransferOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .get(id)
+        .await?;
+    Ok(Json(TransferOrderResponse::new(order, lines)))
+}
+
+/// Moves all lines out of the source warehouse into transit.
+#[utoipa::path(post, path = "/transfer-orders/{id}/ship", responses((status = OK, body = TransferOrderResponse)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn ship_transfer_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ShipTransferOrderRequest>,
+) -> Result<Json<TransferOrderResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::TransferOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .ship(
+            id,
+            req.lines.into_iter().map(Into::into).collect(),
+            token.0.id,
+        )
+        .await?;
+    Ok(Json(TransferOrderResponse::new(order, lines)))
+}
+
+/// Receives stock in transit into the destination warehouse, fully or partially.
+#[utoipa::path(post, path = "/transfer-orders/{id}/receive", responses((status = OK, body = ReceiveTransferOrderResponse)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn receive_transfer_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ReceiveTransferOrderRequest>,
+) -> Result<Json<ReceiveTransferOrderResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::TransferOrder)
+        .await?;
+
+    let (order, lines, movements) = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .receive(
+            id,
+            req.lines.into_iter().map(Into::into).collect(),
+            token.0.id,
+        )
+        .await?;
+    Ok(Json(ReceiveTransferOrderResponse {
+        order: TransferOrderResponse::new(order, lines),
+        movements: movements.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// Closes the order, writing off whatever is still in transit as a discrepancy.
+#[utoipa::path(post, path = "/transfer-orders/{id}/close", responses((status = OK, body = TransferOrderResponse)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn close_transfer_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<TransferOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Approve, ResourceType::TransferOrder)
+        .await?;
+
+    let (order, lines) = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .close(id, token.0.id)
+        .await?;
+    Ok(Json(TransferOrderResponse::new(order, lines)))
+}
+
+#[utoipa::path(get, path = "/transfer-orders/{id}/movements", responses((status = OK, body = Vec<StockMovementResponse>)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_transfer_order_movements(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<StockMovementResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::TransferOrder)
+        .await?;
+
+    let movements = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .list_movements(id)
+        .await?;
+    Ok(Json(movements.into_iter().map(Into::into).collect()))
+}
+
+/// Stock shipped by open transfer orders and not yet received.
+#[utoipa::path(get, path = "/transfer-orders/in-transit", params(InTransitParams), responses((status = OK, body = Vec<InTransitStockResponse>)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_in_transit(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<InTransitParams>,
+) -> Result<Json<Vec<InTransitStockResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::TransferOrder)
+        .await?;
+
+    let stock = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .in_transit(params.into())
+        .await?;
+    Ok(Json(stock.into_iter().map(Into::into).collect()))
+}
+
+/// Quantities of closed transfer orders that never arrived.
+#[utoipa::path(get, path = "/transfer-orders/discrepancies", params(TransferDiscrepanciesParams), responses((status = OK, body = Vec<TransferDiscrepancyResponse>)), tag = crate::apidoc::TRANSFER_ORDER_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_discrepancies(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<TransferDiscrepanciesParams>,
+) -> Result<Json<Vec<TransferDiscrepancyResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::TransferOrder)
+        .await?;
+
+    let discrepancies = state
+        .dependencies
+        .transfer_order_service()
+        .await
+        .discrepancies(params.warehouse_id)
+        .await?;
+    Ok(Json(discrepancies.into_iter().map(Into::into).collect()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_transfer_order))
+        .routes(routes!(list_in_transit))
+        .routes(routes!(list_discrepancies))
+        .routes(routes!(get_transfer_order))
+        .routes(routes!(ship_transfer_order))
+        .routes(routes!(receive_transfer_order))
+        .routes(routes!(close_transfer_order))
+        .routes(routes!(list_transfer_order_movements))
+}
@@ -0,0 +1,103 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, ProductResponse, ProductValuationResponse, SetCostingMethodRequest, ValuationParams,
+    ValuationSettingsRequest, ValuationSettingsResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::extract::State;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+/// Inventory value per product, as of now or an earlier point in time.
+#[utoipa::path(get, path = "/valuation", params(ValuationParams), responses((status = OK, body = Vec<ProductValuationResponse>)), tag = crate::apidoc::VALUATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_valuations(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<ValuationParams>,
+) -> Result<Json<Vec<ProductValuationResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Valuation)
+        .await?;
+
+    let valuations = state
+        .dependencies
+        .valuation_service()
+        .await
+        .list_valuations(params.into())
+        .await?;
+    Ok(Json(valuations.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/valuation/settings", responses((status = OK, body = ValuationSettingsResponse)), tag = crate::apidoc::VALUATION_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_valuation_settings(
+    State(state): State<AppState>,
+    token: AccessToken,
+) -> Result<Json<ValuationSettingsResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Valuation)
+        .await?;
+
+    let settings = state
+        .dependencies
+        .valuation_service()
+        .await
+        .get_settings()
+        .await?;
+    Ok(Json(settings.into()))
+}
+
+#[utoipa::path(post, path = "/valuation/settings", responses((status = OK, body = ValuationSettingsResponse)), tag = crate::apidoc::VALUATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn update_valuation_settings(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<ValuationSettingsRequest>,
+) -> Result<Json<ValuationSettingsResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Valuation)
+        .await?;
+
+    let settings = state
+        .dependencies
+        .valuation_service()
+        .await
+        .update_settings(req.into())
+        .await?;
+    Ok(Json(settings.into()))
+}
+
+/// Overrides the company costing method for the product.
+#[utoipa::path(post, path = "/products/{id}/costing-method", responses((status = OK, body = ProductResponse)), tag = crate::apidoc::VALUATION_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn set_costing_method(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetCostingMethodRequest>,
+) -> Result<Json<ProductResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Valuation)
+        .await?;
+
+    let product = state
+        .dependencies
+        .valuation_service()
+        .await
+        .set_costing_method(id, req.costing_method)
+        .await?;
+    Ok(Json(product.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(list_valuations))
+        .routes(routes!(get_valuation_settings))
+        .routes(routes!(update_valuation_settings))
+        .routes(routes!(set_costing_method))
+}
@@ -0,0 +1,178 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, CreateLocationRequest, CreateWarehouseRequest, ListParams, LocationResponse,
+    LocationsParams, PageResponse, SetAttributesRequest, WarehouseResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use serde_json::Value;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(post, path = "/warehouses", responses((status = CREATED, body = WarehouseResponse)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_warehouse(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateWarehouseRequest>,
+) -> Result<(StatusCode, Json<WarehouseResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Warehouse)
+        .await?;
+
+    let warehouse = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .create_warehouse(req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(warehouse.into())))
+}
+
+#[utoipa::path(get, path = "/warehouses/{id}", responses((status = OK, body = WarehouseResponse)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_warehouse(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WarehouseResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Warehouse)
+        .await?;
+
+    let warehouse = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .get_warehouse(id)
+        .await?;
+    Ok(Json(warehouse.into()))
+}
+
+#[utoipa::path(post, path = "/locations", responses((status = CREATED, body = LocationResponse)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_location(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateLocationRequest>,
+) -> Result<(StatusCode, Json<LocationResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Location)
+        .await?;
+
+    let location = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .create_location(req.into())
+        .await?;
+    Ok((StatusCode::CREATED, Json(location.into())))
+}
+
+#[utoipa::path(get, path = "/locations/{id}", responses((status = OK, body = LocationResponse)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_location(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<LocationResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Location)
+        .await?;
+
+    let location = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .get_location(id)
+        .await?;
+    Ok(Json(location.into()))
+}
+
+#[utoipa::path(get, path = "/locations", params(LocationsParams), responses((status = OK, body = Vec<LocationResponse>)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_locations(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<LocationsParams>,
+) -> Result<Json<Vec<LocationResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Location)
+        .await?;
+
+    let locations = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .list_locations(params.try_into()?)
+        .await?;
+    Ok(Json(locations.into_iter().map(Into::into).collect()))
+}
+
+/// Storage locations by code, leaving out the virtual in-transit locations. Filters:
+/// `warehouse_id`, `code`, `zone`, `quarantine`, `pick_sequence` and `attributes.<key>` for
+/// custom attributes.
+#[utoipa::path(get, path = "/locations", params(ListParams), responses((status = OK, body = PageResponse<LocationResponse>)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn page_locations(
+    State(state): State<AppState>,
+    token: AccessToken,
+    params: ListParams,
+) -> Result<Json<PageResponse<Value>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::Location)
+        .await?;
+
+    let locations = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .page_locations(params.query()?)
+        .await?;
+    Ok(Json(params.page::<_, LocationResponse>(locations)?))
+}
+
+/// Replaces the custom attribute values of the location, validated against the attribute
+/// definitions of locations.
+#[utoipa::path(post, path = "/locations/{id}/attributes", request_body = SetAttributesRequest, responses((status = OK, body = LocationResponse)), tag = crate::apidoc::WAREHOUSE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn set_location_attributes(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetAttributesRequest>,
+) -> Result<Json<LocationResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Location)
+        .await?;
+
+    let location = state
+        .dependencies
+        .warehouse_service()
+        .await
+        .set_location_attributes(id, req.attributes)
+        .await?;
+    Ok(Json(location.into()))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(create_warehouse))
+        .routes(routes!(get_warehouse))
+        .routes(routes!(create_location))
+        .routes(routes!(get_location))
+        .routes(routes!(list_locations))
+        .routes(routes!(set_location_attributes))
+}
+
+pub fn v2_router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new().routes(routes!(page_locations))
+}
@@ -0,0 +1,194 @@
+use crate::domain::{ResourceAction, ResourceType};
+use crate::dto::{
+    AppError, ConfirmPickRequest, ConfirmPickResponse, CreateWaveRequest, PickOrderResponse,
+    PickOrdersParams, ReleasePickOrderRequest, WaveResponse,
+};
+use crate::rest::access::AccessToken;
+use crate::rest::extract::{Json, Path, Query};
+use crate::state::AppState;
+use anyhow::Result;
+use axum::{extract::State, http::StatusCode};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Releases a sales order with reserved stock to the warehouse for picking.
+#[utoipa::path(post, path = "/pick-orders", responses((status = CREATED, body = PickOrderResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn release_pick_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<ReleasePickOrderRequest>,
+) -> Result<(StatusCode, Json<PickOrderResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::PickOrder)
+        .await?;
+
+    let order = state
+        .dependencies
+        .wave_service()
+        .await
+        .release_order(req.into(), token.0.id)
+        .await?;
+    Ok((StatusCode::CREATED, Json(order.into())))
+}
+
+#[utoipa::path(get, path = "/pick-orders", params(PickOrdersParams), responses((status = OK, body = Vec<PickOrderResponse>)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn list_pick_orders(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Query(params): Query<PickOrdersParams>,
+) -> Result<Json<Vec<PickOrderResponse>>, AppError> {
+    token
+        .authorize(&state, ResourceAction::List, ResourceType::PickOrder)
+        .await?;
+
+    let orders = state
+        .dependencies
+        .wave_service()
+        .await
+        .list_orders(params.into())
+        .await?;
+    Ok(Json(orders.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(get, path = "/pick-orders/{id}", responses((status = OK, body = PickOrderResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_pick_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PickOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::PickOrder)
+        .await?;
+
+    let order = state
+        .dependencies
+        .wave_service()
+        .await
+        .get_order(id)
+        .await?;
+    Ok(Json(order.into()))
+}
+
+#[utoipa::path(post, path = "/pick-orders/{id}/cancel", responses((status = OK, body = PickOrderResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_pick_order(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<PickOrderResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::PickOrder)
+        .await?;
+
+    let order = state
+        .dependencies
+        .wave_service()
+        .await
+        .cancel_order(id)
+        .await?;
+    Ok(Json(order.into()))
+}
+
+/// Groups released orders matching the criteria into a wave with sequenced pick tasks.
+#[utoipa::path(post, path = "/waves", responses((status = CREATED, body = WaveResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn create_wave(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Json(req): Json<CreateWaveRequest>,
+) -> Result<(StatusCode, Json<WaveResponse>), AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Create, ResourceType::Wave)
+        .await?;
+
+    let (wave, orders, tasks) = state
+        .dependencies
+        .wave_service()
+        .await
+        .create_wave(req.into(), token.0.id)
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(WaveResponse::new(wave, orders, tasks)),
+    ))
+}
+
+#[utoipa::path(get, path = "/waves/{id}", responses((status = OK, body = WaveResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn get_wave(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WaveResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Read, ResourceType::Wave)
+        .await?;
+
+    let (wave, orders, tasks) = state.dependencies.wave_service().await.get_wave(id).await?;
+    Ok(Json(WaveResponse::new(wave, orders, tasks)))
+}
+
+#[utoipa::path(post, path = "/waves/{id}/cancel", responses((status = OK, body = WaveResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token))]
+pub async fn cancel_wave(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WaveResponse>, AppError> {
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Wave)
+        .await?;
+
+    let (wave, orders, tasks) = state
+        .dependencies
+        .wave_service()
+        .await
+        .cancel_wave(id)
+        .await?;
+    Ok(Json(WaveResponse::new(wave, orders, tasks)))
+}
+
+/// Confirms a pick, issuing the stock against the reservation of the task.
+#[utoipa::path(post, path = "/pick-tasks/{id}/confirm", responses((status = OK, body = ConfirmPickResponse)), tag = crate::apidoc::WAVE_TAG)]
+#[tracing::instrument(skip(state, token, req))]
+pub async fn confirm_pick_task(
+    State(state): State<AppState>,
+    token: AccessToken,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ConfirmPickRequest>,
+) -> Result<Json<ConfirmPickResponse>, AppError> {
+    req.validate()?;
+    token
+        .authorize(&state, ResourceAction::Update, ResourceType::Wave)
+        .await?;
+
+    let (task, movements) = state
+        .dependencies
+        .wave_service()
+        .await
+        .confirm_task(id, req.into(), token.0.id)
+        .await?;
+    Ok(Json(ConfirmPickResponse {
+        task: task.into(),
+        movements: movements.into_iter().map(Into::into).collect(),
+    }))
+}
+
+pub fn router() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        .routes(routes!(release_pick_order))
+        .routes(routes!(list_pick_orders))
+        .routes(routes!(get_pick_order))
+        .routes(routes!(cancel_pick_order))
+        .routes(routes!(create_wave))
+        .routes(routes!(get_wave))
+        .routes(routes!(cancel_wave))
+        .routes(routes!(confirm_pick_task))
+}
@@ -20,13 +20,47 @@ pub async fn run(
 ) {
     let routes = generate_route_list(App);
 
+    tokio::spawn({
+        let dependencies = dependencies.clone();
+        async move {
+            dependencies
+                .reservation_service()
+                .await
+                .run_on_schedule()
+                .await
+        }
+    });
+
+    tokio::spawn({
+        let dependencies = dependencies.clone();
+        async move {
+            dependencies
+                .replenishment_service()
+                .await
+                .run_on_schedule()
+                .await
+        }
+    });
+
+    tokio::spawn({
+        let dependencies = dependencies.clone();
+        async move {
+            dependencies
+                .snapshot_service()
+                .await
+                .run_on_schedule()
+                .await
+        }
+    });
+
     let app_state = AppState {
         dependencies,
         leptos_options,
     };
 
     let (router, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
-        .nest("/api/v1", rest::v1_handler())
+        .nest("/api/v1", rest::v1_handler(&app_state))
+        .nest("/api/v2", rest::v2_handler(&app_state))
         .fallback(file_and_error_handler_with_context::<AppState, _>(
             {
                 let app_state = app_state.clone();
@@ -1 +1,28 @@
+pub mod abc;
+pub mod attachment;
+pub mod attribute;
 pub mod auth;
+pub mod authorization;
+pub mod business_partner;
+pub mod cross_dock;
+pub mod kit;
+pub mod label;
+pub mod lot;
+pub mod organization;
+pub mod pallet;
+pub mod product;
+pub mod putaway;
+pub mod replenishment;
+pub mod reservation;
+pub mod returns;
+pub mod scan;
+pub mod serial;
+pub mod shipment;
+pub mod snapshot;
+pub mod stock;
+pub mod stock_count;
+pub mod stock_hold;
+pub mod transfer;
+pub mod valuation;
+pub mod warehouse;
+pub mod wave;
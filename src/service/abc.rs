@@ -0,0 +1,248 @@
+use crate::contract::repository::{
+    AbcRepository, PutawayRepository, StockRepository, WarehouseRepository,
+};
+use crate::domain::{
+    AbcAnalysis, AbcAnalysisData, AbcAnalysisLine, AbcAnalysisQuery, AbcError, MovementKind,
+    ProductScore, SlottingRecommendation, SlottingRecommendationQuery, SlottingStatus,
+    StockLevelQuery, StockMovement, StockStatus, classify, slotting,
+};
+use anyhow::{Context, Result};
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+pub struct AbcService {
+    abc_repository: Box<dyn AbcRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    putaway_repository: Box<dyn PutawayRepository>,
+    stock_repository: Box<dyn StockRepository>,
+}
+
+impl AbcService {
+    pub fn new(
+        abc_repository: Box<dyn AbcRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        putaway_repository: Box<dyn PutawayRepository>,
+        stock_repository: Box<dyn StockRepository>,
+    ) -> Self {
+        Self {
+            abc_repository,
+            warehouse_repository,
+            putaway_repository,
+            stock_repository,
+        }
+    }
+
+    /// Classifies the products stored in or issued from the warehouse over the window
+    /// ending now, and recommends moving class A products into the most accessible
+    /// locations. Recommendations of earlier analyses of the warehouse still pending are
+    /// superseded.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn run(
+        &self,
+        args: AbcAnalysisData,
+        user_id: Uuid,
+    ) -> Result<(
+        AbcAnalysis,
+        Vec<AbcAnalysisLine>,
+        Vec<SlottingRecommendation>,
+    )> {
+        if args.b_share < args.a_share {
+            return Err(AbcError::SharesOutOfOrder.into());
+        }
+
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await?;
+
+        let now = Utc::now();
+        let analysis = AbcAnalysis {
+            id: Uuid::new_v4(),
+            warehouse_id: args.warehouse_id,
+            criterion: args.criterion,
+            window_days: args.window_days,
+            period_start: now - Duration::days(args.window_days.into()),
+            period_end: now,
+            a_share: args.a_share,
+            b_share: args.b_share,
+            applied_at: None,
+            created_by: Some(user_id),
+            created_at: now,
+        };
+
+        let mut scores = self
+            .abc_repository
+            .product_scores(
+                analysis.warehouse_id,
+                analysis.criterion,
+                analysis.period_start,
+                analysis.period_end,
+            )
+            .await
+            .context("Failed to score products")?;
+        let storage = self
+            .putaway_repository
+            .list_occupancy(analysis.warehouse_id)
+            .await
+            .context("Failed to load storage locations")?;
+
+        // Products in stock that were not issued in the window rank last.
+        let scored: HashSet<Uuid> = scores.iter().map(|score| score.product_id).collect();
+        let idle: HashSet<Uuid> = storage
+            .iter()
+            .flat_map(|occupancy| occupancy.product_ids.iter().copied())
+            .filter(|product_id| !scored.contains(product_id))
+            .collect();
+        scores.extend(idle.into_iter().map(|product_id| ProductScore {
+            product_id,
+            score: Decimal::ZERO,
+        }));
+
+        let lines = classify(&analysis, scores);
+        let recommendations = slotting(&analysis, &lines, &storage);
+
+        let analysis = self
+            .abc_repository
+            .save(analysis, lines.clone(), recommendations.clone())
+            .await
+            .context("Failed to save ABC analysis")?;
+        Ok((analysis, lines, recommendations))
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list(&self, query: AbcAnalysisQuery) -> Result<Vec<AbcAnalysis>> {
+        self.abc_repository
+            .list(query)
+            .await
+            .context("Failed to load ABC analyses")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(
+        &self,
+        id: Uuid,
+    ) -> Result<(
+        AbcAnalysis,
+        Vec<AbcAnalysisLine>,
+        Vec<SlottingRecommendation>,
+    )> {
+        let analysis = self.abc_repository.get_by_id(id).await?;
+        let lines = self
+            .abc_repository
+            .list_lines(id)
+            .await
+            .context("Failed to load ABC analysis lines")?;
+        let recommendations = self
+            .list_recommendations(SlottingRecommendationQuery {
+                analysis_id: Some(id),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok((analysis, lines, recommendations))
+    }
+
+    /// Sets the ABC class of every product of the analysis, which cycle-count sessions
+    /// scoped by class then count by. Products classified by another warehouse keep
+    /// their class until that one is applied again.
+    #[tracing::instrument(skip(self))]
+    pub async fn apply(&self, id: Uuid) -> Result<AbcAnalysis> {
+        let mut analysis = self.abc_repository.get_by_id(id).await?;
+        analysis.applied_at = Some(Utc::now());
+
+        self.abc_repository
+            .apply(analysis)
+            .await
+            .context("Failed to apply ABC analysis")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_recommendations(
+        &self,
+        query: SlottingRecommendationQuery,
+    ) -> Result<Vec<SlottingRecommendation>> {
+        self.abc_repository
+            .list_recommendations(query)
+            .await
+            .context("Failed to load slotting recommendations")
+    }
+
+    /// Transfers the available stock of the product into the recommended location, and
+    /// the available stock of the displaced product into the location the product leaves.
+    #[tracing::instrument(skip(self))]
+    pub async fn accept(&self, id: Uuid, user_id: Uuid) -> Result<SlottingRecommendation> {
+        let mut recommendation = self.abc_repository.get_recommendation(id).await?;
+        if recommendation.status != SlottingStatus::Pending {
+            return Err(AbcError::NotPending.into());
+        }
+
+        let now = Utc::now();
+        let mut movements = Vec::new();
+        let moves = [
+            Some((
+                recommendation.product_id,
+                recommendation.from_location_id,
+                recommendation.to_location_id,
+            )),
+            recommendation.displaced_product_id.map(|product_id| {
+                (
+                    product_id,
+                    recommendation.to_location_id,
+                    recommendation.from_location_id,
+                )
+            }),
+        ];
+        for (product_id, from, to) in moves.into_iter().flatten() {
+            let available: Decimal = self
+                .stock_repository
+                .get_levels(StockLevelQuery {
+                    product_id: Some(product_id),
+                    location_id: Some(from),
+                    ..Default::default()
+                })
+                .await
+                .context("Failed to load stock levels")?
+                .iter()
+                .map(|level| level.available)
+                .sum();
+            if available <= Decimal::ZERO {
+                continue;
+            }
+
+            movements.push(StockMovement {
+                id: Uuid::new_v4(),
+                kind: MovementKind::Transfer,
+                product_id,
+                from_location_id: Some(from),
+                to_location_id: Some(to),
+                quantity: available,
+                document_type: None,
+                document_id: None,
+                created_by: Some(user_id),
+                created_at: now,
+                lot_id: None,
+                unit_cost: None,
+                total_cost: None,
+                status: StockStatus::Available,
+                to_status: None,
+                reason: None,
+            });
+        }
+        if movements
+            .first()
+            .is_none_or(|movement| movement.product_id != recommendation.product_id)
+        {
+            return Err(AbcError::NothingToMove.into());
+        }
+
+        recommendation.status = SlottingStatus::Accepted;
+        recommendation.reviewed_by = Some(user_id);
+        recommendation.reviewed_at = Some(now);
+
+        self.abc_repository
+            .accept_recommendation(recommendation, movements)
+            .await
+            .context("Failed to move stock into the recommended location")
+    }
+}
@@ -0,0 +1,123 @@
+use crate::config::StorageConfig;
+use crate::contract::repository::AttachmentRepository;
+use crate::contract::storage::Storage;
+use crate::domain::{
+    Attachment, AttachmentData, AttachmentError, AttachmentOwner, RepositoryError, check_content,
+};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct AttachmentService {
+    config: StorageConfig,
+    attachment_repository: Box<dyn AttachmentRepository>,
+    storage: Arc<dyn Storage>,
+}
+
+impl AttachmentService {
+    pub fn new(
+        config: StorageConfig,
+        attachment_repository: Box<dyn AttachmentRepository>,
+        storage: Arc<dyn Storage>,
+    ) -> Self {
+        Self {
+            config,
+            attachment_repository,
+            storage,
+        }
+    }
+
+    /// Largest content accepted, in bytes.
+    pub fn max_bytes(&self) -> usize {
+        self.config.maxbytes
+    }
+
+    /// Stores the content and then its metadata, removing the content again when the
+    /// metadata cannot be saved.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: AttachmentData, user_id: Uuid) -> Result<Attachment> {
+        if args.content.len() > self.config.maxbytes {
+            return Err(AttachmentError::TooLarge(self.config.maxbytes).into());
+        }
+        check_content(&args.content_type, &args.content)?;
+
+        let owner_exists = self
+            .attachment_repository
+            .owner_exists(args.owner_type, args.owner_id)
+            .await
+            .context("Failed to find attachment owner")?;
+        if !owner_exists {
+            return Err(RepositoryError::NotFound.into());
+        }
+
+        let id = Uuid::new_v4();
+        let attachment = Attachment {
+            id,
+            owner_type: args.owner_type,
+            owner_id: args.owner_id,
+            file_name: args.file_name,
+            content_type: args.content_type,
+            size: args.content.len() as i64,
+            storage_key: id.to_string(),
+            created_by: Some(user_id),
+            created_at: Utc::now(),
+        };
+
+        self.storage
+            .put(
+                &attachment.storage_key,
+                args.content,
+                &attachment.content_type,
+            )
+            .await
+            .context("Failed to store attachment content")?;
+
+        match self.attachment_repository.create(attachment.clone()).await {
+            Ok(attachment) => Ok(attachment),
+            Err(err) => {
+                if let Err(err) = self.storage.delete(&attachment.storage_key).await {
+                    tracing::error!(error = ?err, "Failed to remove orphaned attachment content");
+                }
+                Err(err.context("Failed to create attachment"))
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list(
+        &self,
+        owner_type: AttachmentOwner,
+        owner_id: Uuid,
+    ) -> Result<Vec<Attachment>> {
+        self.attachment_repository
+            .list(owner_type, owner_id)
+            .await
+            .context("Failed to load attachments")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<Attachment> {
+        self.attachment_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self, attachment), fields(id = %attachment.id))]
+    pub async fn content(&self, attachment: &Attachment) -> Result<Vec<u8>> {
+        self.storage
+            .get(&attachment.storage_key)
+            .await
+            .context("Failed to load attachment content")
+    }
+
+    /// Removes the metadata and then the content, so that an attachment is never listed
+    /// without its content.
+    #[tracing::instrument(skip(self))]
+    pub async fn remove(&self, id: Uuid) -> Result<()> {
+        let attachment = self.attachment_repository.remove(id).await?;
+
+        self.storage
+            .delete(&attachment.storage_key)
+            .await
+            .context("Failed to remove attachment content")
+    }
+}
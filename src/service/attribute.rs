@@ -0,0 +1,73 @@
+use crate::contract::repository::AttributeRepository;
+use crate::domain::{
+    AttributeDefinition, AttributeDefinitionData, AttributeEntity, AttributeError, AttributeKind,
+    validate_attributes,
+};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+pub struct AttributeService {
+    attribute_repository: Box<dyn AttributeRepository>,
+}
+
+impl AttributeService {
+    pub fn new(attribute_repository: Box<dyn AttributeRepository>) -> Self {
+        Self {
+            attribute_repository,
+        }
+    }
+
+    /// Required attributes are enforced whenever attributes of an entity are written,
+    /// existing entities keep their values until then.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: AttributeDefinitionData) -> Result<AttributeDefinition> {
+        if (args.kind == AttributeKind::Enum) == args.options.is_empty() {
+            return Err(AttributeError::InvalidOptions.into());
+        }
+
+        self.attribute_repository
+            .create(AttributeDefinition {
+                id: Uuid::new_v4(),
+                entity: args.entity,
+                key: args.key,
+                label: args.label,
+                kind: args.kind,
+                required: args.required,
+                options: args.options,
+                position: args.position,
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to create attribute definition")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list(&self, entity: AttributeEntity) -> Result<Vec<AttributeDefinition>> {
+        self.attribute_repository
+            .list(entity)
+            .await
+            .context("Failed to load attribute definitions")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove(&self, id: Uuid) -> Result<()> {
+        self.attribute_repository.remove(id).await
+    }
+}
+
+/// Validates custom attribute values of an entity against the definitions of its type,
+/// returning the JSON object to store.
+pub(crate) async fn attribute_values(
+    attribute_repository: &dyn AttributeRepository,
+    entity: AttributeEntity,
+    values: Map<String, Value>,
+) -> Result<Value> {
+    let definitions = attribute_repository
+        .list(entity)
+        .await
+        .context("Failed to load attribute definitions")?;
+
+    Ok(validate_attributes(&definitions, values)?)
+}
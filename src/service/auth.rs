@@ -1,6 +1,7 @@
-use crate::contract::repository::UserRepository;
+use crate::contract::repository::{OrganizationRepository, UserRepository};
+use crate::db;
 use crate::domain::{AuthError, RepositoryError};
-use crate::domain::{SignInData, SignUpData, User};
+use crate::domain::{Organization, OrganizationMember, Role, SignInData, SignUpData, User};
 use crate::dto::{AccessTokenClaims, AuthTokens};
 use crate::telemetry::spawn_blocking_with_tracing;
 use anyhow::{Context, Result};
@@ -15,16 +16,24 @@ use uuid::Uuid;
 pub struct AuthService {
     jwt_secret: SecretString,
     user_repository: Box<dyn UserRepository>,
+    organization_repository: Box<dyn OrganizationRepository>,
 }
 
 impl AuthService {
-    pub fn new(jwt_secret: SecretString, user_repository: Box<dyn UserRepository>) -> Self {
+    pub fn new(
+        jwt_secret: SecretString,
+        user_repository: Box<dyn UserRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> Self {
         Self {
             jwt_secret,
             user_repository,
+            organization_repository,
         }
     }
 
+    /// Signs the user up with an organization of their own, which they can be granted rules
+    /// in through its owner role.
     #[tracing::instrument(skip(self, args))]
     pub async fn sign_up(&self, args: SignUpData) -> Result<AuthTokens> {
         let user = User {
@@ -45,18 +54,103 @@ impl AuthService {
             .await
             .context("Failed to create user")?;
 
-        let access_token = self.encode_access_jwt(&user)?;
+        let now = Utc::now();
+        let organization_id = Uuid::new_v4();
+        self.organization_repository
+            .create_with_owner(
+                Organization {
+                    id: organization_id,
+                    name: format!("{} {}", user.first_name, user.last_name),
+                    created_at: now,
+                },
+                OrganizationMember {
+                    user_id: user.id,
+                    organization_id,
+                    added_by: None,
+                    created_at: now,
+                },
+                Role {
+                    id: Uuid::new_v4(),
+                    name: "Owner".to_string(),
+                    description: None,
+                },
+                Vec::new(),
+            )
+            .await
+            .context("Failed to create user's organization")?;
+
+        let access_token = self.encode_access_jwt(&user, organization_id)?;
         Ok(AuthTokens { access_token })
     }
 
+    /// Signs the user in to the organization they joined first.
     #[tracing::instrument(skip(self, args))]
     pub async fn sign_in(&self, args: SignInData) -> Result<AuthTokens> {
         let user = self.validate_credentials(args).await?;
 
-        let access_token = self.encode_access_jwt(&user)?;
+        let organization = self
+            .organization_repository
+            .list_by_user(user.id)
+            .await
+            .context("Failed to load user's organizations")?
+            .into_iter()
+            .next()
+            .ok_or(AuthError::PermissionDenied)?;
+
+        let access_token = self.encode_access_jwt(&user, organization.id)?;
         Ok(AuthTokens { access_token })
     }
 
+    /// New tokens of the user, acting as another organization they are a member of.
+    #[tracing::instrument(skip(self))]
+    pub async fn switch_organization(
+        &self,
+        user_id: Uuid,
+        organization_id: Uuid,
+    ) -> Result<AuthTokens> {
+        self.check_member(organization_id, user_id).await?;
+
+        let user = self
+            .user_repository
+            .get_by_id(user_id)
+            .await
+            .context("Failed to find user")?;
+
+        let access_token = self.encode_access_jwt(&user, organization_id)?;
+        Ok(AuthTokens { access_token })
+    }
+
+    /// Fails with [`AuthError::PermissionDenied`] unless the user is a member of the
+    /// organization. Tokens keep naming an organization the user was removed from.
+    #[tracing::instrument(skip(self))]
+    pub async fn check_member(&self, organization_id: Uuid, user_id: Uuid) -> Result<()> {
+        match self
+            .organization_repository
+            .get_member(organization_id, user_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => match err.downcast_ref::<RepositoryError>() {
+                Some(RepositoryError::NotFound) => Err(AuthError::PermissionDenied.into()),
+                _ => Err(err.context("Failed to find membership")),
+            },
+        }
+    }
+
+    /// Runs the future as the organization of the token once its user is checked to still be
+    /// a member, see [`Self::check_member`]. Everything acting on behalf of a token, REST
+    /// requests and server functions alike, goes through here.
+    #[tracing::instrument(skip(self, f))]
+    pub async fn as_member<F: Future>(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        f: F,
+    ) -> Result<F::Output> {
+        self.check_member(organization_id, user_id).await?;
+        Ok(db::with_organization(organization_id, f).await)
+    }
+
     #[tracing::instrument(skip(self, credentials))]
     async fn validate_credentials(&self, credentials: SignInData) -> Result<User, AuthError> {
         let mut user = None;
@@ -108,7 +202,7 @@ impl AuthService {
     }
 
     #[tracing::instrument(skip(self, user), fields(id = %user.id))]
-    pub fn encode_access_jwt(&self, user: &User) -> Result<String> {
+    pub fn encode_access_jwt(&self, user: &User, organization_id: Uuid) -> Result<String> {
         let now = Utc::now();
         let expire = Duration::hours(24);
         let exp = (now + expire).timestamp();
@@ -118,6 +212,7 @@ impl AuthService {
             exp,
             id: user.id,
             email: user.email.to_owned(),
+            organization_id,
         };
 
         jsonwebtoken::encode(
@@ -0,0 +1,34 @@
+use crate::contract::repository::RuleRepository;
+use crate::domain::{AuthError, ResourceAction, ResourceType, is_allowed};
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub struct AuthorizationService {
+    rule_repository: Box<dyn RuleRepository>,
+}
+
+impl AuthorizationService {
+    pub fn new(rule_repository: Box<dyn RuleRepository>) -> Self {
+        Self { rule_repository }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn authorize(
+        &self,
+        user_id: Uuid,
+        action: ResourceAction,
+        resource_type: ResourceType,
+    ) -> Result<(), AuthError> {
+        let rules = self
+            .rule_repository
+            .list_by_user(user_id)
+            .await
+            .context("Failed to load user rules")?;
+
+        if !is_allowed(&rules, action, resource_type) {
+            return Err(AuthError::PermissionDenied);
+        }
+
+        Ok(())
+    }
+}
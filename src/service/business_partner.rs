@@ -0,0 +1,189 @@
+use crate::contract::repository::BusinessPartnerRepository;
+use crate::domain::{
+    BusinessPartner, BusinessPartnerData, BusinessPartnerQuery, PartnerAddress, PartnerAddressData,
+    PartnerContact, PartnerContactData, PartnerError, PartnerRole, normalize_name,
+    normalize_tax_id,
+};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+pub struct BusinessPartnerService {
+    business_partner_repository: Box<dyn BusinessPartnerRepository>,
+}
+
+impl BusinessPartnerService {
+    pub fn new(business_partner_repository: Box<dyn BusinessPartnerRepository>) -> Self {
+        Self {
+            business_partner_repository,
+        }
+    }
+
+    /// Refuses partners sharing the tax id of another partner, and partners with a name
+    /// similar to another one unless `allow_similar_name` is set.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(
+        &self,
+        args: BusinessPartnerData,
+        user_id: Uuid,
+    ) -> Result<BusinessPartner> {
+        if args.roles.is_empty() {
+            return Err(PartnerError::NoRole.into());
+        }
+
+        let normalized_name = normalize_name(&args.name);
+        let tax_id = args
+            .tax_id
+            .as_deref()
+            .map(normalize_tax_id)
+            .filter(|tax_id| !tax_id.is_empty());
+
+        let duplicates = self
+            .business_partner_repository
+            .find_duplicates(tax_id.as_deref(), &normalized_name)
+            .await
+            .context("Failed to look for duplicate business partners")?;
+        if let Some(duplicate) = duplicates
+            .iter()
+            .find(|partner| tax_id.is_some() && partner.tax_id == tax_id)
+        {
+            return Err(PartnerError::DuplicateTaxId(duplicate.code.clone()).into());
+        }
+        if let (Some(duplicate), false) = (duplicates.first(), args.allow_similar_name) {
+            return Err(PartnerError::SimilarName(duplicate.code.clone()).into());
+        }
+
+        let id = Uuid::new_v4();
+        let partner = BusinessPartner {
+            id,
+            code: args.code,
+            name: args.name,
+            normalized_name,
+            tax_id,
+            is_supplier: args.roles.contains(&PartnerRole::Supplier),
+            is_customer: args.roles.contains(&PartnerRole::Customer),
+            is_carrier: args.roles.contains(&PartnerRole::Carrier),
+            email: args.email,
+            phone: args.phone,
+            created_by: Some(user_id),
+            created_at: Utc::now(),
+        };
+        let addresses = args
+            .addresses
+            .into_iter()
+            .map(|address| new_address(id, address))
+            .collect();
+        let contacts = args
+            .contacts
+            .into_iter()
+            .map(|contact| new_contact(id, contact))
+            .collect();
+
+        self.business_partner_repository
+            .create_with_details(partner, addresses, contacts)
+            .await
+            .context("Failed to create business partner")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn search(&self, query: BusinessPartnerQuery) -> Result<Vec<BusinessPartner>> {
+        self.business_partner_repository
+            .search(query)
+            .await
+            .context("Failed to search business partners")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(
+        &self,
+        id: Uuid,
+    ) -> Result<(BusinessPartner, Vec<PartnerAddress>, Vec<PartnerContact>)> {
+        let partner = self.business_partner_repository.get_by_id(id).await?;
+        let addresses = self
+            .business_partner_repository
+            .list_addresses(id)
+            .await
+            .context("Failed to load partner addresses")?;
+        let contacts = self
+            .business_partner_repository
+            .list_contacts(id)
+            .await
+            .context("Failed to load partner contacts")?;
+
+        Ok((partner, addresses, contacts))
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn add_address(
+        &self,
+        partner_id: Uuid,
+        args: PartnerAddressData,
+    ) -> Result<PartnerAddress> {
+        self.business_partner_repository
+            .get_by_id(partner_id)
+            .await
+            .context("Failed to find business partner")?;
+
+        self.business_partner_repository
+            .add_address(new_address(partner_id, args))
+            .await
+            .context("Failed to add partner address")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_address(&self, partner_id: Uuid, address_id: Uuid) -> Result<()> {
+        self.business_partner_repository
+            .remove_address(partner_id, address_id)
+            .await
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn add_contact(
+        &self,
+        partner_id: Uuid,
+        args: PartnerContactData,
+    ) -> Result<PartnerContact> {
+        self.business_partner_repository
+            .get_by_id(partner_id)
+            .await
+            .context("Failed to find business partner")?;
+
+        self.business_partner_repository
+            .add_contact(new_contact(partner_id, args))
+            .await
+            .context("Failed to add partner contact")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_contact(&self, partner_id: Uuid, contact_id: Uuid) -> Result<()> {
+        self.business_partner_repository
+            .remove_contact(partner_id, contact_id)
+            .await
+    }
+}
+
+fn new_address(partner_id: Uuid, args: PartnerAddressData) -> PartnerAddress {
+    PartnerAddress {
+        id: Uuid::new_v4(),
+        partner_id,
+        kind: args.kind,
+        street: args.street,
+        city: args.city,
+        postal_code: args.postal_code,
+        country: args.country.to_uppercase(),
+        is_default: args.is_default,
+        created_at: Utc::now(),
+    }
+}
+
+fn new_contact(partner_id: Uuid, args: PartnerContactData) -> PartnerContact {
+    PartnerContact {
+        id: Uuid::new_v4(),
+        partner_id,
+        name: args.name,
+        email: args.email,
+        phone: args.phone,
+        position: args.position,
+        created_at: Utc::now(),
+    }
+}
@@ -0,0 +1,244 @@
+use crate::contract::repository::{
+    CrossDockRepository, LocationRepository, ProductRepository, StockRepository,
+    WarehouseRepository,
+};
+use crate::domain::{
+    CrossDock, CrossDockData, CrossDockError, CrossDockOpportunity, CrossDockQuery, DemandStatus,
+    MovementKind, OutboundDemand, OutboundDemandData, OutboundDemandQuery, StockLevelQuery,
+    StockMovement, StockStatus, check_serial_numbers, match_demands,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct CrossDockService {
+    cross_dock_repository: Box<dyn CrossDockRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    stock_repository: Box<dyn StockRepository>,
+}
+
+impl CrossDockService {
+    pub fn new(
+        cross_dock_repository: Box<dyn CrossDockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        stock_repository: Box<dyn StockRepository>,
+    ) -> Self {
+        Self {
+            cross_dock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            stock_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create_demand(
+        &self,
+        args: OutboundDemandData,
+        user_id: Uuid,
+    ) -> Result<OutboundDemand> {
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &product,
+            args.quantity,
+            args.uom.as_deref(),
+        )
+        .await?;
+
+        self.cross_dock_repository
+            .create(OutboundDemand {
+                id: Uuid::new_v4(),
+                warehouse_id: args.warehouse_id,
+                document_type: args.document_type,
+                document_id: args.document_id,
+                product_id: product.id,
+                quantity,
+                allocated_quantity: Decimal::ZERO,
+                due_at: args.due_at,
+                status: DemandStatus::Open,
+                created_by: Some(user_id),
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to register outbound demand")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_demands(&self, query: OutboundDemandQuery) -> Result<Vec<OutboundDemand>> {
+        self.cross_dock_repository
+            .list_demands(query)
+            .await
+            .context("Failed to load outbound demands")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel_demand(&self, id: Uuid) -> Result<OutboundDemand> {
+        let demand = self.cross_dock_repository.get_by_id(id).await?;
+        if demand.status != DemandStatus::Open {
+            return Err(CrossDockError::DemandNotOpen.into());
+        }
+
+        self.cross_dock_repository.cancel_demand(id).await
+    }
+
+    /// Open demands of the location's warehouse the stock available at the location, a
+    /// receiving location typically, can be cross-docked to.
+    #[tracing::instrument(skip(self))]
+    pub async fn opportunities(
+        &self,
+        location_id: Uuid,
+        product_id: Option<Uuid>,
+    ) -> Result<Vec<CrossDockOpportunity>> {
+        let location = self.location_repository.get_by_id(location_id).await?;
+
+        let mut available: HashMap<Uuid, Decimal> = HashMap::new();
+        for level in self
+            .stock_repository
+            .get_levels(StockLevelQuery {
+                product_id,
+                location_id: Some(location.id),
+                ..Default::default()
+            })
+            .await
+            .context("Failed to load stock levels")?
+        {
+            *available.entry(level.product_id).or_default() += level.available;
+        }
+        if available.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let demands = self
+            .list_demands(OutboundDemandQuery {
+                warehouse_id: Some(location.warehouse_id),
+                product_id,
+                status: Some(DemandStatus::Open),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(match_demands(location.id, available, demands))
+    }
+
+    /// Moves received stock to the staging location instead of putting it away, and
+    /// reserves it there for the document of the demand.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn cross_dock(&self, args: CrossDockData, user_id: Uuid) -> Result<CrossDock> {
+        let demand = self.cross_dock_repository.get_by_id(args.demand_id).await?;
+        if demand.status != DemandStatus::Open {
+            return Err(CrossDockError::DemandNotOpen.into());
+        }
+
+        let from_location = self
+            .location_repository
+            .get_by_id(args.from_location_id)
+            .await?;
+        let staging_location = self
+            .location_repository
+            .get_by_id(args.staging_location_id)
+            .await?;
+        if from_location.warehouse_id != demand.warehouse_id
+            || staging_location.warehouse_id != demand.warehouse_id
+            || staging_location.in_transit
+            || staging_location.quarantine
+        {
+            return Err(CrossDockError::LocationNotInWarehouse.into());
+        }
+
+        let product = self.product_repository.get_by_id(demand.product_id).await?;
+        let quantity = match args.quantity {
+            Some(quantity) => {
+                let quantity = to_base_quantity(
+                    self.product_repository.as_ref(),
+                    &product,
+                    quantity,
+                    args.uom.as_deref(),
+                )
+                .await?;
+                if quantity > demand.open_quantity() {
+                    return Err(CrossDockError::ExceedsDemand.into());
+                }
+                quantity
+            }
+            None => {
+                let available: Decimal = self
+                    .stock_repository
+                    .get_levels(StockLevelQuery {
+                        product_id: Some(product.id),
+                        location_id: Some(from_location.id),
+                        ..Default::default()
+                    })
+                    .await
+                    .context("Failed to load stock levels")?
+                    .iter()
+                    .map(|level| level.available)
+                    .sum();
+                demand.open_quantity().min(available)
+            }
+        };
+        if quantity <= Decimal::ZERO {
+            return Err(CrossDockError::NothingToCrossDock.into());
+        }
+        check_serial_numbers(&product, quantity, &args.serial_numbers)?;
+
+        let now = Utc::now();
+        let movement = StockMovement {
+            id: Uuid::new_v4(),
+            kind: MovementKind::Transfer,
+            product_id: product.id,
+            from_location_id: Some(from_location.id),
+            to_location_id: Some(staging_location.id),
+            quantity,
+            document_type: Some(demand.document_type),
+            document_id: Some(demand.document_id),
+            created_by: Some(user_id),
+            created_at: now,
+            lot_id: None,
+            unit_cost: None,
+            total_cost: None,
+            status: StockStatus::Available,
+            to_status: None,
+            reason: None,
+        };
+        let cross_dock = CrossDock {
+            id: Uuid::new_v4(),
+            demand_id: demand.id,
+            warehouse_id: demand.warehouse_id,
+            product_id: product.id,
+            from_location_id: from_location.id,
+            staging_location_id: staging_location.id,
+            quantity,
+            inbound_document_type: args.inbound_document_type,
+            inbound_document_id: args.inbound_document_id,
+            reservation_id: Uuid::new_v4(),
+            created_by: Some(user_id),
+            created_at: now,
+        };
+
+        self.cross_dock_repository
+            .cross_dock(cross_dock, movement, args.serial_numbers)
+            .await
+            .context("Failed to cross-dock stock")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list(&self, query: CrossDockQuery) -> Result<Vec<CrossDock>> {
+        self.cross_dock_repository
+            .list(query)
+            .await
+            .context("Failed to load cross-docks")
+    }
+}
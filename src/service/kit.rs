@@ -0,0 +1,379 @@
+use crate::contract::repository::{
+    KitRepository, LocationRepository, ProductRepository, StockRepository,
+};
+use crate::domain::{
+    AssemblyKind, AssemblyOrder, AssemblyOrderData, AssemblyOrderLine, AssemblyOrderStatus,
+    BomComponent, BomComponentData, ComponentAvailability, DocumentType, KitAvailability, KitError,
+    Location, MovementKind, Product, RepositoryError, StockLevelQuery, StockMovement, StockStatus,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+pub struct KitService {
+    kit_repository: Box<dyn KitRepository>,
+    stock_repository: Box<dyn StockRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+}
+
+impl KitService {
+    pub fn new(
+        kit_repository: Box<dyn KitRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            kit_repository,
+            stock_repository,
+            location_repository,
+            product_repository,
+        }
+    }
+
+    /// Replaces the bill of materials of the kit. Quantities are stored in base units of
+    /// the component, a component listed twice counts with both quantities.
+    #[tracing::instrument(skip(self, components))]
+    pub async fn set_components(
+        &self,
+        kit_product_id: Uuid,
+        components: Vec<BomComponentData>,
+    ) -> Result<Vec<BomComponent>> {
+        if components.is_empty() {
+            return Err(KitError::NoComponents.into());
+        }
+        let kit = self.product_repository.get_by_id(kit_product_id).await?;
+        check_tracking(&kit, true)?;
+
+        let mut quantities = BTreeMap::<Uuid, Decimal>::new();
+        for component in components {
+            if component.component_product_id == kit.id {
+                return Err(KitError::SelfComponent.into());
+            }
+            let product = self
+                .product_repository
+                .get_by_id(component.component_product_id)
+                .await?;
+            check_tracking(&product, false)?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                component.quantity,
+                component.uom.as_deref(),
+            )
+            .await?;
+
+            *quantities.entry(product.id).or_default() += quantity;
+        }
+
+        self.kit_repository
+            .replace_components(
+                kit.id,
+                quantities
+                    .into_iter()
+                    .map(|(component_product_id, quantity)| BomComponent {
+                        kit_product_id: kit.id,
+                        component_product_id,
+                        quantity,
+                    })
+                    .collect(),
+            )
+            .await
+            .context("Failed to save bill of materials")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn components(&self, kit_product_id: Uuid) -> Result<Vec<BomComponent>> {
+        let components = self
+            .kit_repository
+            .list_components(kit_product_id)
+            .await
+            .context("Failed to load bill of materials")?;
+        if components.is_empty() {
+            return Err(RepositoryError::NotFound.into());
+        }
+
+        Ok(components)
+    }
+
+    /// Whole kits that can be built from the components available in the warehouse, or in
+    /// every warehouse. Open assembly orders do not hold their components back.
+    #[tracing::instrument(skip(self))]
+    pub async fn availability(
+        &self,
+        kit_product_id: Uuid,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<KitAvailability> {
+        let bom = self.bill_of_materials(kit_product_id).await?;
+
+        let mut components = Vec::with_capacity(bom.len());
+        for component in bom {
+            let available: Decimal = self
+                .stock_repository
+                .get_levels(StockLevelQuery {
+                    product_id: Some(component.component_product_id),
+                    warehouse_id,
+                    ..Default::default()
+                })
+                .await
+                .context("Failed to load stock levels")?
+                .into_iter()
+                .map(|level| level.available)
+                .sum();
+
+            components.push(ComponentAvailability {
+                component_product_id: component.component_product_id,
+                quantity: component.quantity,
+                available,
+                buildable: (available / component.quantity).floor(),
+            });
+        }
+
+        Ok(KitAvailability {
+            kit_product_id,
+            warehouse_id,
+            buildable: components
+                .iter()
+                .map(|component| component.buildable)
+                .min()
+                .unwrap_or_default(),
+            components,
+        })
+    }
+
+    /// Creates an open order, copying the components from the current bill of materials.
+    /// Both locations must belong to the same warehouse.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create_order(
+        &self,
+        args: AssemblyOrderData,
+        user_id: Uuid,
+    ) -> Result<(AssemblyOrder, Vec<AssemblyOrderLine>)> {
+        let kit = self
+            .product_repository
+            .get_by_id(args.kit_product_id)
+            .await?;
+        let quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &kit,
+            args.quantity,
+            args.uom.as_deref(),
+        )
+        .await?;
+        let bom = self.bill_of_materials(kit.id).await?;
+
+        let from_location = self.storage_location(args.from_location_id).await?;
+        let to_location = self.storage_location(args.to_location_id).await?;
+        if from_location.warehouse_id != to_location.warehouse_id {
+            return Err(KitError::LocationNotInWarehouse.into());
+        }
+
+        if args.kind == AssemblyKind::Disassembly {
+            for component in &bom {
+                let product = self
+                    .product_repository
+                    .get_by_id(component.component_product_id)
+                    .await?;
+                if product.lot_tracked || product.expiry_tracked {
+                    return Err(KitError::TrackedComponent.into());
+                }
+            }
+        }
+
+        let order = AssemblyOrder {
+            id: Uuid::new_v4(),
+            kind: args.kind,
+            kit_product_id: kit.id,
+            warehouse_id: from_location.warehouse_id,
+            from_location_id: from_location.id,
+            to_location_id: to_location.id,
+            quantity,
+            status: AssemblyOrderStatus::Open,
+            created_by: Some(user_id),
+            created_at: Utc::now(),
+            completed_at: None,
+        };
+        let lines: Vec<AssemblyOrderLine> = bom
+            .into_iter()
+            .map(|component| AssemblyOrderLine {
+                id: Uuid::new_v4(),
+                assembly_order_id: order.id,
+                product_id: component.component_product_id,
+                quantity: component.quantity * quantity,
+            })
+            .collect();
+
+        let order = self
+            .kit_repository
+            .create_with_lines(order, lines.clone())
+            .await
+            .context("Failed to create assembly order")?;
+
+        Ok((order, lines))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_order(&self, id: Uuid) -> Result<(AssemblyOrder, Vec<AssemblyOrderLine>)> {
+        let order = self.kit_repository.get_by_id(id).await?;
+        let lines = self
+            .kit_repository
+            .list_lines(id)
+            .await
+            .context("Failed to load assembly order lines")?;
+
+        Ok((order, lines))
+    }
+
+    /// Issues the stock the order consumes and receives what it produces, as movements
+    /// linked to the order. Assembled kits are valued at the cost of their components.
+    #[tracing::instrument(skip(self))]
+    pub async fn complete_order(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(AssemblyOrder, Vec<AssemblyOrderLine>, Vec<StockMovement>)> {
+        let (mut order, lines) = self.get_order(id).await?;
+        if order.status != AssemblyOrderStatus::Open {
+            return Err(KitError::NotOpen.into());
+        }
+
+        let now = Utc::now();
+        let kit = (order.kit_product_id, order.quantity);
+        let components = lines.iter().map(|line| (line.product_id, line.quantity));
+        let (consumed, produced): (Vec<_>, Vec<_>) = match order.kind {
+            AssemblyKind::Assembly => (components.collect(), vec![kit]),
+            AssemblyKind::Disassembly => (vec![kit], components.collect()),
+        };
+
+        let mut movements = Vec::with_capacity(consumed.len() + produced.len());
+        for (product_id, quantity) in consumed {
+            movements.push(movement(
+                &order,
+                product_id,
+                Some(order.from_location_id),
+                None,
+                quantity,
+                user_id,
+                now,
+            ));
+        }
+        for (product_id, quantity) in produced {
+            movements.push(movement(
+                &order,
+                product_id,
+                None,
+                Some(order.to_location_id),
+                quantity,
+                user_id,
+                now,
+            ));
+        }
+
+        order.status = AssemblyOrderStatus::Completed;
+        order.completed_at = Some(now);
+
+        let (order, movements) = self
+            .kit_repository
+            .update_with_movements(order, movements)
+            .await
+            .context("Failed to complete assembly order")?;
+
+        Ok((order, lines, movements))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel_order(&self, id: Uuid) -> Result<(AssemblyOrder, Vec<AssemblyOrderLine>)> {
+        let (mut order, lines) = self.get_order(id).await?;
+        if order.status != AssemblyOrderStatus::Open {
+            return Err(KitError::NotOpen.into());
+        }
+
+        order.status = AssemblyOrderStatus::Cancelled;
+
+        let (order, _) = self
+            .kit_repository
+            .update_with_movements(order, Vec::new())
+            .await
+            .context("Failed to cancel assembly order")?;
+
+        Ok((order, lines))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_movements(&self, id: Uuid) -> Result<Vec<StockMovement>> {
+        self.kit_repository.get_by_id(id).await?;
+        self.kit_repository
+            .list_movements(id)
+            .await
+            .context("Failed to load assembly order movements")
+    }
+
+    async fn bill_of_materials(&self, kit_product_id: Uuid) -> Result<Vec<BomComponent>> {
+        let components = self
+            .kit_repository
+            .list_components(kit_product_id)
+            .await
+            .context("Failed to load bill of materials")?;
+        if components.is_empty() {
+            return Err(KitError::NoBillOfMaterials.into());
+        }
+
+        Ok(components)
+    }
+
+    /// Kits are built in regular locations, not in transit or quarantine.
+    async fn storage_location(&self, location_id: Uuid) -> Result<Location> {
+        let location = self.location_repository.get_by_id(location_id).await?;
+        if location.in_transit || location.quarantine {
+            return Err(KitError::LocationNotInWarehouse.into());
+        }
+
+        Ok(location)
+    }
+}
+
+/// Units of serial-tracked products are not tracked through assembly, and assembled kits
+/// are received without a lot.
+fn check_tracking(product: &Product, kit: bool) -> Result<()> {
+    if product.serial_tracked || (kit && (product.lot_tracked || product.expiry_tracked)) {
+        return Err(KitError::TrackedProduct.into());
+    }
+
+    Ok(())
+}
+
+fn movement(
+    order: &AssemblyOrder,
+    product_id: Uuid,
+    from_location_id: Option<Uuid>,
+    to_location_id: Option<Uuid>,
+    quantity: Decimal,
+    user_id: Uuid,
+    now: DateTime<Utc>,
+) -> StockMovement {
+    StockMovement {
+        id: Uuid::new_v4(),
+        kind: match from_location_id {
+            Some(_) => MovementKind::Issue,
+            None => MovementKind::Receipt,
+        },
+        product_id,
+        from_location_id,
+        to_location_id,
+        quantity,
+        document_type: Some(DocumentType::AssemblyOrder),
+        document_id: Some(order.id),
+        created_by: Some(user_id),
+        created_at: now,
+        lot_id: None,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    }
+}
@@ -0,0 +1,61 @@
+use crate::contract::repository::{
+    LocationRepository, PalletRepository, ProductRepository, WarehouseRepository,
+};
+use crate::domain::LabelKind;
+use crate::label::{Label, location_label, pallet_label, product_label};
+use anyhow::Result;
+use uuid::Uuid;
+
+pub struct LabelService {
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    pallet_repository: Box<dyn PalletRepository>,
+}
+
+impl LabelService {
+    pub fn new(
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        pallet_repository: Box<dyn PalletRepository>,
+    ) -> Self {
+        Self {
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            pallet_repository,
+        }
+    }
+
+    /// Lays out the label of the object `id` with the template of its kind.
+    #[tracing::instrument(skip(self))]
+    pub async fn label(&self, kind: LabelKind, id: Uuid) -> Result<Label> {
+        let label = match kind {
+            LabelKind::Location => {
+                let location = self.location_repository.get_by_id(id).await?;
+                let warehouse = self
+                    .warehouse_repository
+                    .get_by_id(location.warehouse_id)
+                    .await?;
+                location_label(&warehouse, &location)?
+            }
+            LabelKind::Product => {
+                let product = self.product_repository.get_by_id(id).await?;
+                product_label(&product)?
+            }
+            LabelKind::Pallet => {
+                let pallet = self.pallet_repository.get_by_id(id).await?;
+                let location = match pallet.location_id {
+                    Some(location_id) => {
+                        Some(self.location_repository.get_by_id(location_id).await?)
+                    }
+                    None => None,
+                };
+                pallet_label(&pallet, location.as_ref())?
+            }
+        };
+
+        Ok(label)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::contract::repository::LotRepository;
+use crate::domain::{ExpiringStock, ExpiringStockQuery, Lot};
+use anyhow::{Context, Result};
+use chrono::{Days, Utc};
+use uuid::Uuid;
+
+pub struct LotService {
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl LotService {
+    pub fn new(lot_repository: Box<dyn LotRepository>) -> Self {
+        Self { lot_repository }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<Lot> {
+        self.lot_repository.get_by_id(id).await
+    }
+
+    /// Blocked lots are excluded from allocation and cannot be issued.
+    #[tracing::instrument(skip(self))]
+    pub async fn set_blocked(&self, id: Uuid, blocked: bool) -> Result<Lot> {
+        self.lot_repository
+            .set_blocked(id, blocked)
+            .await
+            .context("Failed to update lot")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn block_expired(&self) -> Result<usize> {
+        self.lot_repository
+            .block_expired(Utc::now().date_naive())
+            .await
+            .context("Failed to block expired lots")
+    }
+
+    /// Stock of lots that expire within the next `days` days, already expired lots included.
+    #[tracing::instrument(skip(self))]
+    pub async fn expiring(
+        &self,
+        days: u32,
+        product_id: Option<Uuid>,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<ExpiringStock>> {
+        let until = Utc::now().date_naive() + Days::new(days.into());
+
+        self.lot_repository
+            .list_expiring(ExpiringStockQuery {
+                until,
+                product_id,
+                warehouse_id,
+            })
+            .await
+            .context("Failed to load expiring stock")
+    }
+}
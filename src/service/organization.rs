@@ -0,0 +1,113 @@
+use crate::contract::repository::{OrganizationRepository, RuleRepository, UserRepository};
+use crate::domain::{Organization, OrganizationData, OrganizationMember, Role, Rule};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+pub struct OrganizationService {
+    organization_repository: Box<dyn OrganizationRepository>,
+    user_repository: Box<dyn UserRepository>,
+    rule_repository: Box<dyn RuleRepository>,
+}
+
+impl OrganizationService {
+    pub fn new(
+        organization_repository: Box<dyn OrganizationRepository>,
+        user_repository: Box<dyn UserRepository>,
+        rule_repository: Box<dyn RuleRepository>,
+    ) -> Self {
+        Self {
+            organization_repository,
+            user_repository,
+            rule_repository,
+        }
+    }
+
+    /// Creates an organization the user is the first member of, granted the rules they have
+    /// in the organization they act as through an owner role.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: OrganizationData, user_id: Uuid) -> Result<Organization> {
+        let rules = self
+            .rule_repository
+            .list_by_user(user_id)
+            .await
+            .context("Failed to load user rules")?
+            .into_iter()
+            .map(|rule| Rule {
+                id: Uuid::new_v4(),
+                ..rule
+            })
+            .collect();
+
+        let now = Utc::now();
+        let organization = Organization {
+            id: Uuid::new_v4(),
+            name: args.name,
+            created_at: now,
+        };
+        let owner = OrganizationMember {
+            user_id,
+            organization_id: organization.id,
+            added_by: Some(user_id),
+            created_at: now,
+        };
+        let role = Role {
+            id: Uuid::new_v4(),
+            name: "Owner".to_string(),
+            description: None,
+        };
+
+        self.organization_repository
+            .create_with_owner(organization, owner, role, rules)
+            .await
+            .context("Failed to create organization")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<Organization>> {
+        self.organization_repository
+            .list_by_user(user_id)
+            .await
+            .context("Failed to load organizations")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<(OrganizationMember, String)>> {
+        self.organization_repository
+            .list_members(organization_id)
+            .await
+            .context("Failed to load organization members")
+    }
+
+    /// Adds the user with the email to the organization. They are granted nothing until
+    /// assigned roles of the organization.
+    #[tracing::instrument(skip(self))]
+    pub async fn add_member(
+        &self,
+        organization_id: Uuid,
+        email: &str,
+        added_by: Uuid,
+    ) -> Result<OrganizationMember> {
+        let user = self.user_repository.get_by_email(email).await?;
+
+        self.organization_repository
+            .add_member(OrganizationMember {
+                user_id: user.id,
+                organization_id,
+                added_by: Some(added_by),
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to add organization member")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> Result<()> {
+        self.organization_repository
+            .remove_member(organization_id, user_id)
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use crate::config::Gs1Config;
+use crate::contract::repository::{LocationRepository, PalletRepository};
+use crate::domain::{Pallet, PalletData, build_sscc};
+use anyhow::{Context, Result};
+use chrono::Utc;
+use uuid::Uuid;
+
+pub struct PalletService {
+    gs1: Gs1Config,
+    pallet_repository: Box<dyn PalletRepository>,
+    location_repository: Box<dyn LocationRepository>,
+}
+
+impl PalletService {
+    pub fn new(
+        gs1: Gs1Config,
+        pallet_repository: Box<dyn PalletRepository>,
+        location_repository: Box<dyn LocationRepository>,
+    ) -> Self {
+        Self {
+            gs1,
+            pallet_repository,
+            location_repository,
+        }
+    }
+
+    /// Registers a pallet under a newly allocated SSCC.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: PalletData) -> Result<Pallet> {
+        if let Some(location_id) = args.location_id {
+            self.location_repository.get_by_id(location_id).await?;
+        }
+
+        let serial_reference = self.pallet_repository.next_serial_reference().await?;
+        let sscc = build_sscc(
+            self.gs1.extensiondigit,
+            &self.gs1.companyprefix,
+            serial_reference as u64,
+        )?;
+
+        self.pallet_repository
+            .create(Pallet {
+                id: Uuid::new_v4(),
+                sscc,
+                location_id: args.location_id,
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to create pallet")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<Pallet> {
+        self.pallet_repository.get_by_id(id).await
+    }
+}
@@ -0,0 +1,155 @@
+use crate::contract::repository::{AttributeRepository, ProductRepository};
+use crate::domain::{
+    AttributeEntity, Page, Product, ProductData, ProductListQuery, ProductQuery, ProductUom,
+    ProductUomData, UomError,
+};
+use crate::service::attribute::attribute_values;
+use anyhow::{Context, Result};
+use rust_decimal::Decimal;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+pub struct ProductService {
+    product_repository: Box<dyn ProductRepository>,
+    attribute_repository: Box<dyn AttributeRepository>,
+}
+
+impl ProductService {
+    pub fn new(
+        product_repository: Box<dyn ProductRepository>,
+        attribute_repository: Box<dyn AttributeRepository>,
+    ) -> Self {
+        Self {
+            product_repository,
+            attribute_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: ProductData) -> Result<Product> {
+        let attributes = attribute_values(
+            self.attribute_repository.as_ref(),
+            AttributeEntity::Product,
+            args.attributes,
+        )
+        .await?;
+
+        self.product_repository
+            .create(Product {
+                id: Uuid::new_v4(),
+                sku: args.sku,
+                name: args.name,
+                description: args.description,
+                abc_class: args.abc_class,
+                lot_tracked: args.lot_tracked,
+                expiry_tracked: args.expiry_tracked,
+                serial_tracked: args.serial_tracked,
+                base_uom: args.base_uom,
+                fractional_quantities: args.fractional_quantities,
+                costing_method: args.costing_method,
+                category: args.category,
+                unit_volume: args.unit_volume,
+                unit_weight: args.unit_weight,
+                attributes,
+            })
+            .await
+            .context("Failed to create product")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<Product> {
+        self.product_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list(&self, query: ProductQuery) -> Result<Vec<Product>> {
+        self.product_repository
+            .list(query)
+            .await
+            .context("Failed to load products")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn page(&self, query: ProductListQuery) -> Result<Page<Product>> {
+        self.product_repository
+            .page(query)
+            .await
+            .context("Failed to load products")
+    }
+
+    /// Replaces the custom attribute values of the product.
+    #[tracing::instrument(skip(self, values))]
+    pub async fn set_attributes(&self, id: Uuid, values: Map<String, Value>) -> Result<Product> {
+        self.product_repository.get_by_id(id).await?;
+        let attributes = attribute_values(
+            self.attribute_repository.as_ref(),
+            AttributeEntity::Product,
+            values,
+        )
+        .await?;
+
+        self.product_repository
+            .set_attributes(id, attributes)
+            .await
+            .context("Failed to save product attributes")
+    }
+
+    /// The base UoM may be listed too, e.g. to give it a barcode, but only with a factor of 1.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn add_uom(&self, product_id: Uuid, args: ProductUomData) -> Result<ProductUom> {
+        let product = self.product_repository.get_by_id(product_id).await?;
+        if args.code == product.base_uom && args.factor != Decimal::ONE {
+            return Err(UomError::InvalidBaseFactor.into());
+        }
+
+        self.product_repository
+            .add_uom(ProductUom {
+                id: Uuid::new_v4(),
+                product_id,
+                code: args.code,
+                factor: args.factor,
+                barcode: args.barcode,
+            })
+            .await
+            .context("Failed to add unit of measure")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_uoms(&self, product_id: Uuid) -> Result<Vec<ProductUom>> {
+        self.product_repository.get_by_id(product_id).await?;
+
+        self.product_repository
+            .list_uoms(product_id)
+            .await
+            .context("Failed to load units of measure")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn find_uom_by_barcode(&self, barcode: &str) -> Result<ProductUom> {
+        self.product_repository.find_uom_by_barcode(barcode).await
+    }
+}
+
+/// Converts a quantity given in `uom` to base units of the product. A missing UoM
+/// means the base UoM.
+pub(crate) async fn to_base_quantity(
+    product_repository: &dyn ProductRepository,
+    product: &Product,
+    quantity: Decimal,
+    uom: Option<&str>,
+) -> Result<Decimal> {
+    let uom = match uom {
+        Some(code) if code != product.base_uom => Some(
+            product_repository
+                .list_uoms(product.id)
+                .await
+                .context("Failed to load units of measure")?
+                .into_iter()
+                .find(|uom| uom.code == code)
+                .ok_or_else(|| UomError::Unknown(code.to_string()))?,
+        ),
+        _ => None,
+    };
+
+    Ok(product.to_base_quantity(quantity, uom.as_ref())?)
+}
@@ -0,0 +1,299 @@
+use crate::contract::repository::{
+    LocationRepository, ProductRepository, PutawayRepository, StockRepository, WarehouseRepository,
+};
+use crate::domain::{
+    Location, MovementKind, PutawayConfirmation, PutawayError, PutawayRule, PutawayRuleData,
+    PutawayStrategy, PutawayTask, PutawayTaskQuery, PutawayTaskStatus, StockBalanceQuery,
+    StockMovement, StockStatus, check_serial_numbers, suggest_location,
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+pub struct PutawayService {
+    putaway_repository: Box<dyn PutawayRepository>,
+    stock_repository: Box<dyn StockRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+}
+
+impl PutawayService {
+    pub fn new(
+        putaway_repository: Box<dyn PutawayRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            putaway_repository,
+            stock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+        }
+    }
+
+    /// Replaces the putaway rules of the warehouse. Rules are tried in the order given.
+    #[tracing::instrument(skip(self, rules))]
+    pub async fn set_rules(
+        &self,
+        warehouse_id: Uuid,
+        rules: Vec<PutawayRuleData>,
+    ) -> Result<Vec<PutawayRule>> {
+        self.warehouse_repository
+            .get_by_id(warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+
+        let mut saved = Vec::with_capacity(rules.len());
+        for (sequence, rule) in (1..).zip(rules) {
+            match (rule.strategy, rule.location_id) {
+                (PutawayStrategy::FixedLocation, None) => {
+                    return Err(PutawayError::LocationRequired.into());
+                }
+                (PutawayStrategy::CategoryZone, _)
+                    if rule.category.is_none() || rule.zone.is_none() =>
+                {
+                    return Err(PutawayError::CategoryZoneRequired.into());
+                }
+                _ => {}
+            }
+            if let Some(location_id) = rule.location_id {
+                let location = self.location_repository.get_by_id(location_id).await?;
+                if location.warehouse_id != warehouse_id {
+                    return Err(PutawayError::LocationNotInWarehouse.into());
+                }
+            }
+            if let Some(product_id) = rule.product_id {
+                self.product_repository.get_by_id(product_id).await?;
+            }
+
+            saved.push(PutawayRule {
+                id: Uuid::new_v4(),
+                warehouse_id,
+                sequence,
+                strategy: rule.strategy,
+                product_id: rule.product_id,
+                category: rule.category,
+                location_id: rule.location_id,
+                zone: rule.zone,
+            });
+        }
+
+        self.putaway_repository
+            .replace_rules(warehouse_id, saved)
+            .await
+            .context("Failed to save putaway rules")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn rules(&self, warehouse_id: Uuid) -> Result<Vec<PutawayRule>> {
+        self.putaway_repository
+            .list_rules(warehouse_id)
+            .await
+            .context("Failed to load putaway rules")
+    }
+
+    /// Creates a task for every product at the receiving location that no open task moves
+    /// yet, each with the destination the rules of the warehouse propose. Tasks no rule
+    /// finds a location for are created without a suggestion.
+    #[tracing::instrument(skip(self))]
+    pub async fn generate(&self, location_id: Uuid, user_id: Uuid) -> Result<Vec<PutawayTask>> {
+        let from = self.location_repository.get_by_id(location_id).await?;
+
+        // Held stock stays where it is until it is released.
+        let mut pending = BTreeMap::<Uuid, Decimal>::new();
+        for balance in self
+            .stock_repository
+            .list_balances(StockBalanceQuery {
+                location_id: Some(from.id),
+                statuses: vec![StockStatus::Available],
+                ..Default::default()
+            })
+            .await
+            .context("Failed to load stock balances")?
+        {
+            *pending.entry(balance.product_id).or_default() += balance.on_hand;
+        }
+        for task in self
+            .putaway_repository
+            .list_tasks(PutawayTaskQuery {
+                from_location_id: Some(from.id),
+                status: Some(PutawayTaskStatus::Open),
+                ..Default::default()
+            })
+            .await
+            .context("Failed to load putaway tasks")?
+        {
+            *pending.entry(task.product_id).or_default() -= task.quantity;
+        }
+        pending.retain(|_, quantity| *quantity > Decimal::ZERO);
+        if pending.is_empty() {
+            return Err(PutawayError::NothingToPutAway.into());
+        }
+
+        let rules = self.rules(from.warehouse_id).await?;
+        let mut candidates = self
+            .putaway_repository
+            .list_occupancy(from.warehouse_id)
+            .await
+            .context("Failed to load location occupancy")?;
+
+        let now = Utc::now();
+        let mut tasks = Vec::with_capacity(pending.len());
+        for (product_id, quantity) in pending {
+            let product = self.product_repository.get_by_id(product_id).await?;
+            let suggestion = suggest_location(&rules, &product, quantity, &from, &candidates);
+
+            // Later tasks of the run must see the space this one takes.
+            if let Some(candidate) = suggestion.and_then(|(location_id, _)| {
+                candidates
+                    .iter_mut()
+                    .find(|candidate| candidate.location.id == location_id)
+            }) {
+                candidate.volume += product.unit_volume.unwrap_or_default() * quantity;
+                candidate.weight += product.unit_weight.unwrap_or_default() * quantity;
+                if !candidate.product_ids.contains(&product.id) {
+                    candidate.product_ids.push(product.id);
+                }
+            }
+
+            tasks.push(PutawayTask {
+                id: Uuid::new_v4(),
+                warehouse_id: from.warehouse_id,
+                product_id,
+                from_location_id: from.id,
+                quantity,
+                suggested_location_id: suggestion.map(|(location_id, _)| location_id),
+                strategy: suggestion.map(|(_, strategy)| strategy),
+                to_location_id: None,
+                status: PutawayTaskStatus::Open,
+                movement_id: None,
+                created_by: Some(user_id),
+                created_at: now,
+                completed_by: None,
+                completed_at: None,
+            });
+        }
+
+        self.putaway_repository
+            .create_tasks(tasks)
+            .await
+            .context("Failed to create putaway tasks")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_task(&self, id: Uuid) -> Result<PutawayTask> {
+        self.putaway_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_tasks(&self, query: PutawayTaskQuery) -> Result<Vec<PutawayTask>> {
+        self.putaway_repository
+            .list_tasks(query)
+            .await
+            .context("Failed to load putaway tasks")
+    }
+
+    /// Moves the stock to the scanned location. It must be the suggested destination;
+    /// without a suggestion, any storage location with room for the stock will do.
+    #[tracing::instrument(skip(self, confirmation))]
+    pub async fn confirm(
+        &self,
+        id: Uuid,
+        confirmation: PutawayConfirmation,
+        user_id: Uuid,
+    ) -> Result<(PutawayTask, Vec<StockMovement>)> {
+        let mut task = self.get_task(id).await?;
+        if task.status != PutawayTaskStatus::Open {
+            return Err(PutawayError::NotOpen.into());
+        }
+
+        let location = self
+            .location_repository
+            .find_by_code(task.warehouse_id, &confirmation.location_code)
+            .await
+            .with_context(|| format!("Failed to find location {}", confirmation.location_code))?;
+        let product = self.product_repository.get_by_id(task.product_id).await?;
+        match task.suggested_location_id {
+            Some(suggested) if suggested != location.id => {
+                return Err(PutawayError::WrongLocation(location.code).into());
+            }
+            Some(_) => {}
+            None => {
+                let occupancy = self
+                    .putaway_repository
+                    .list_occupancy(task.warehouse_id)
+                    .await
+                    .context("Failed to load location occupancy")?;
+                let candidate = occupancy
+                    .iter()
+                    .find(|candidate| candidate.location.id == location.id)
+                    .filter(|_| location.id != task.from_location_id)
+                    .ok_or(PutawayError::LocationNotInWarehouse)?;
+                if !candidate.fits(&product, task.quantity) {
+                    return Err(PutawayError::ExceedsCapacity.into());
+                }
+            }
+        }
+        check_serial_numbers(&product, task.quantity, &confirmation.serial_numbers)?;
+
+        let now = Utc::now();
+        let movement = transfer(&task, &location, user_id, now);
+        task.to_location_id = Some(location.id);
+        task.status = PutawayTaskStatus::Completed;
+        task.movement_id = Some(movement.id);
+        task.completed_by = Some(user_id);
+        task.completed_at = Some(now);
+
+        self.putaway_repository
+            .complete(task, movement, confirmation.serial_numbers)
+            .await
+            .context("Failed to complete putaway task")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel(&self, id: Uuid) -> Result<PutawayTask> {
+        let mut task = self.get_task(id).await?;
+        if task.status != PutawayTaskStatus::Open {
+            return Err(PutawayError::NotOpen.into());
+        }
+
+        task.status = PutawayTaskStatus::Cancelled;
+
+        self.putaway_repository
+            .update(task)
+            .await
+            .context("Failed to cancel putaway task")
+    }
+}
+
+fn transfer(
+    task: &PutawayTask,
+    location: &Location,
+    user_id: Uuid,
+    now: DateTime<Utc>,
+) -> StockMovement {
+    StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::Transfer,
+        product_id: task.product_id,
+        from_location_id: Some(task.from_location_id),
+        to_location_id: Some(location.id),
+        quantity: task.quantity,
+        document_type: None,
+        document_id: None,
+        created_by: Some(user_id),
+        created_at: now,
+        lot_id: None,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    }
+}
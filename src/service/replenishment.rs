@@ -0,0 +1,340 @@
+use crate::config::ReplenishmentConfig;
+use crate::contract::repository::{
+    LocationRepository, OrganizationRepository, ProductRepository, ReplenishmentRepository,
+    StockRepository, WarehouseRepository,
+};
+use crate::db;
+use crate::domain::{
+    DocumentType, MovementKind, PurchaseOrder, ReplenishmentError, ReplenishmentKind,
+    ReplenishmentRule, ReplenishmentRuleData, ReplenishmentRuleQuery, ReplenishmentSuggestion,
+    ReplenishmentSuggestionQuery, StockLevelQuery, StockMovement, StockStatus, SuggestionStatus,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use std::time::Duration;
+use uuid::Uuid;
+
+pub struct ReplenishmentService {
+    config: ReplenishmentConfig,
+    replenishment_repository: Box<dyn ReplenishmentRepository>,
+    stock_repository: Box<dyn StockRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    organization_repository: Box<dyn OrganizationRepository>,
+}
+
+impl ReplenishmentService {
+    pub fn new(
+        config: ReplenishmentConfig,
+        replenishment_repository: Box<dyn ReplenishmentRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            config,
+            replenishment_repository,
+            stock_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            organization_repository,
+        }
+    }
+
+    /// Saves the rule of the product, warehouse and location, replacing any previous one.
+    /// Quantities are converted to base units.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn save_rule(&self, args: ReplenishmentRuleData) -> Result<ReplenishmentRule> {
+        if args.max_quantity.is_none() && args.reorder_quantity.is_none() {
+            return Err(ReplenishmentError::PolicyRequired.into());
+        }
+        if args
+            .max_quantity
+            .is_some_and(|max_quantity| max_quantity <= args.reorder_point)
+        {
+            return Err(ReplenishmentError::MaxBelowReorderPoint.into());
+        }
+
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let uom = args.uom.as_deref();
+        let repository = self.product_repository.as_ref();
+        let reorder_point = to_base_quantity(repository, &product, args.reorder_point, uom).await?;
+        let max_quantity = match args.max_quantity {
+            Some(quantity) => Some(to_base_quantity(repository, &product, quantity, uom).await?),
+            None => None,
+        };
+        let reorder_quantity = match args.reorder_quantity {
+            Some(quantity) => Some(to_base_quantity(repository, &product, quantity, uom).await?),
+            None => None,
+        };
+
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+        if let Some(location_id) = args.location_id {
+            let location = self.location_repository.get_by_id(location_id).await?;
+            if location.warehouse_id != args.warehouse_id || location.in_transit {
+                return Err(ReplenishmentError::LocationNotInWarehouse.into());
+            }
+        }
+
+        self.replenishment_repository
+            .create(ReplenishmentRule {
+                id: Uuid::new_v4(),
+                product_id: args.product_id,
+                warehouse_id: args.warehouse_id,
+                location_id: args.location_id,
+                reorder_point,
+                max_quantity,
+                reorder_quantity,
+                updated_at: Utc::now(),
+            })
+            .await
+            .context("Failed to save replenishment rule")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_rules(
+        &self,
+        query: ReplenishmentRuleQuery,
+    ) -> Result<Vec<ReplenishmentRule>> {
+        self.replenishment_repository
+            .list_rules(query)
+            .await
+            .context("Failed to load replenishment rules")
+    }
+
+    /// Evaluates the rules of the warehouse, or of every warehouse, and replaces their pending
+    /// suggestions. Purchase rules compare available stock plus open inbound orders with the
+    /// reorder point, pick-face rules the available stock at the location, which is then
+    /// restocked from the location of the warehouse with the most available stock.
+    #[tracing::instrument(skip(self))]
+    pub async fn run(
+        &self,
+        warehouse_id: Option<Uuid>,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<ReplenishmentSuggestion>> {
+        let rules = self
+            .list_rules(ReplenishmentRuleQuery {
+                warehouse_id,
+                ..Default::default()
+            })
+            .await?;
+        let pick_faces: HashSet<(Uuid, Uuid)> = rules
+            .iter()
+            .filter_map(|rule| Some((rule.product_id, rule.location_id?)))
+            .collect();
+
+        let now = Utc::now();
+        let mut suggestions = Vec::new();
+        for rule in &rules {
+            let levels = self
+                .stock_repository
+                .get_levels(StockLevelQuery {
+                    product_id: Some(rule.product_id),
+                    warehouse_id: Some(rule.warehouse_id),
+                    ..Default::default()
+                })
+                .await
+                .context("Failed to load stock levels")?;
+
+            let (available, inbound) = match rule.location_id {
+                Some(location_id) => (
+                    levels
+                        .iter()
+                        .filter(|level| level.location_id == location_id)
+                        .map(|level| level.available)
+                        .sum(),
+                    Decimal::ZERO,
+                ),
+                None => (
+                    levels.iter().map(|level| level.available).sum(),
+                    self.replenishment_repository
+                        .inbound_quantity(rule.product_id, rule.warehouse_id)
+                        .await
+                        .context("Failed to load inbound quantity")?,
+                ),
+            };
+
+            let Some(mut quantity) = rule.shortfall(available + inbound) else {
+                continue;
+            };
+
+            let mut from_location_id = None;
+            if let Some(location_id) = rule.location_id {
+                // Other pick faces of the product are not drained to fill this one.
+                let Some(source) = levels
+                    .iter()
+                    .filter(|level| level.location_id != location_id)
+                    .filter(|level| !pick_faces.contains(&(rule.product_id, level.location_id)))
+                    .filter(|level| level.available > Decimal::ZERO)
+                    .max_by_key(|level| level.available)
+                else {
+                    continue;
+                };
+                quantity = quantity.min(source.available);
+                from_location_id = Some(source.location_id);
+            }
+
+            suggestions.push(ReplenishmentSuggestion {
+                id: Uuid::new_v4(),
+                rule_id: rule.id,
+                kind: rule.kind(),
+                product_id: rule.product_id,
+                warehouse_id: rule.warehouse_id,
+                location_id: rule.location_id,
+                from_location_id,
+                quantity,
+                available,
+                inbound,
+                status: SuggestionStatus::Pending,
+                created_by: user_id,
+                created_at: now,
+                reviewed_by: None,
+                reviewed_at: None,
+                document_type: None,
+                document_id: None,
+            });
+        }
+
+        self.replenishment_repository
+            .replace_suggestions(rules.iter().map(|rule| rule.id).collect(), suggestions)
+            .await
+            .context("Failed to save replenishment suggestions")
+    }
+
+    /// Runs the engine for every warehouse of every organization at the configured interval,
+    /// starting one interval after startup. Returns right away when no interval is
+    /// configured.
+    pub async fn run_on_schedule(&self) {
+        if self.config.intervalsecs == 0 {
+            return;
+        }
+
+        let period = Duration::from_secs(self.config.intervalsecs);
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let organizations = match self.organization_repository.list().await {
+                Ok(organizations) => organizations,
+                Err(err) => {
+                    tracing::error!(error = ?err, "Failed to load organizations");
+                    continue;
+                }
+            };
+
+            for organization in organizations {
+                match db::with_organization(organization.id, self.run(None, None)).await {
+                    Ok(suggestions) => tracing::info!(
+                        organization_id = %organization.id,
+                        count = suggestions.len(),
+                        "Replenishment run completed"
+                    ),
+                    Err(err) => tracing::error!(
+                        organization_id = %organization.id,
+                        error = ?err,
+                        "Replenishment run failed"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_suggestions(
+        &self,
+        query: ReplenishmentSuggestionQuery,
+    ) -> Result<Vec<ReplenishmentSuggestion>> {
+        self.replenishment_repository
+            .list_suggestions(query)
+            .await
+            .context("Failed to load replenishment suggestions")
+    }
+
+    /// Turns the suggestion into a purchase order, or posts the pick-face replenishment.
+    #[tracing::instrument(skip(self))]
+    pub async fn accept(&self, id: Uuid, user_id: Uuid) -> Result<ReplenishmentSuggestion> {
+        let mut suggestion = self.pending_suggestion(id).await?;
+        let now = Utc::now();
+        suggestion.status = SuggestionStatus::Accepted;
+        suggestion.reviewed_by = Some(user_id);
+        suggestion.reviewed_at = Some(now);
+
+        match suggestion.kind {
+            ReplenishmentKind::Purchase => {
+                let order = PurchaseOrder {
+                    id: Uuid::new_v4(),
+                    warehouse_id: suggestion.warehouse_id,
+                    product_id: suggestion.product_id,
+                    quantity: suggestion.quantity,
+                    created_by: Some(user_id),
+                    created_at: now,
+                };
+                suggestion.document_type = Some(DocumentType::PurchaseOrder);
+                suggestion.document_id = Some(order.id);
+
+                self.replenishment_repository
+                    .accept_as_purchase_order(suggestion, order)
+                    .await
+                    .context("Failed to create purchase order")
+            }
+            ReplenishmentKind::PickFace => {
+                let movement = StockMovement {
+                    id: Uuid::new_v4(),
+                    kind: MovementKind::Transfer,
+                    product_id: suggestion.product_id,
+                    from_location_id: suggestion.from_location_id,
+                    to_location_id: suggestion.location_id,
+                    quantity: suggestion.quantity,
+                    document_type: None,
+                    document_id: None,
+                    created_by: Some(user_id),
+                    created_at: now,
+                    lot_id: None,
+                    unit_cost: None,
+                    total_cost: None,
+                    status: StockStatus::Available,
+                    to_status: None,
+                    reason: None,
+                };
+                suggestion.document_id = Some(movement.id);
+
+                self.replenishment_repository
+                    .accept_as_movement(suggestion, movement)
+                    .await
+                    .context("Failed to replenish pick face")
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn reject(&self, id: Uuid, user_id: Uuid) -> Result<ReplenishmentSuggestion> {
+        let mut suggestion = self.pending_suggestion(id).await?;
+        suggestion.status = SuggestionStatus::Rejected;
+        suggestion.reviewed_by = Some(user_id);
+        suggestion.reviewed_at = Some(Utc::now());
+
+        self.replenishment_repository
+            .update_suggestion(suggestion)
+            .await
+            .context("Failed to reject replenishment suggestion")
+    }
+
+    async fn pending_suggestion(&self, id: Uuid) -> Result<ReplenishmentSuggestion> {
+        let suggestion = self.replenishment_repository.get_suggestion(id).await?;
+        if suggestion.status != SuggestionStatus::Pending {
+            return Err(ReplenishmentError::NotPending.into());
+        }
+
+        Ok(suggestion)
+    }
+}
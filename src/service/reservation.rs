@@ -0,0 +1,167 @@
+use crate::config::ReservationConfig;
+use crate::contract::repository::{
+    OrganizationRepository, ProductRepository, ReservationRepository,
+};
+use crate::db;
+use crate::domain::{
+    Reservation, ReservationStatus, ReserveData, StockMovement, check_serial_numbers,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::time::Duration;
+use uuid::Uuid;
+
+pub struct ReservationService {
+    config: ReservationConfig,
+    reservation_repository: Box<dyn ReservationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    organization_repository: Box<dyn OrganizationRepository>,
+}
+
+impl ReservationService {
+    pub fn new(
+        config: ReservationConfig,
+        reservation_repository: Box<dyn ReservationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            config,
+            reservation_repository,
+            product_repository,
+            organization_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn reserve(&self, args: ReserveData, user_id: Uuid) -> Result<Reservation> {
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &product,
+            args.quantity,
+            args.uom.as_deref(),
+        )
+        .await?;
+
+        self.reservation_repository
+            .create(Reservation {
+                id: Uuid::new_v4(),
+                product_id: args.product_id,
+                location_id: args.location_id,
+                quantity,
+                status: ReservationStatus::Active,
+                document_type: args.document_type,
+                document_id: args.document_id,
+                expires_at: args.expires_at,
+                created_by: Some(user_id),
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to reserve stock")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<Reservation> {
+        self.reservation_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn release(&self, id: Uuid) -> Result<Reservation> {
+        self.reservation_repository
+            .release(id)
+            .await
+            .context("Failed to release reservation")
+    }
+
+    /// Consumes `quantity` of the reservation, given in `uom`, or everything still reserved
+    /// when omitted. Serial-tracked products must name every unit picked.
+    #[tracing::instrument(skip(self, serial_numbers))]
+    pub async fn consume(
+        &self,
+        id: Uuid,
+        quantity: Option<Decimal>,
+        uom: Option<String>,
+        serial_numbers: Vec<String>,
+        user_id: Uuid,
+    ) -> Result<Vec<StockMovement>> {
+        let reservation = self.reservation_repository.get_by_id(id).await?;
+        let product = self
+            .product_repository
+            .get_by_id(reservation.product_id)
+            .await?;
+
+        let quantity = match quantity {
+            Some(quantity) => Some(
+                to_base_quantity(
+                    self.product_repository.as_ref(),
+                    &product,
+                    quantity,
+                    uom.as_deref(),
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        // Without a quantity the repository consumes what is still reserved at the time and
+        // checks the serial numbers against that again.
+        check_serial_numbers(
+            &product,
+            quantity.unwrap_or(reservation.quantity),
+            &serial_numbers,
+        )?;
+
+        self.reservation_repository
+            .consume(id, quantity, serial_numbers, Some(user_id))
+            .await
+            .context("Failed to consume reservation")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn expire_overdue(&self) -> Result<usize> {
+        self.reservation_repository
+            .expire_overdue(Utc::now())
+            .await
+            .context("Failed to expire reservations")
+    }
+
+    /// Expires overdue reservations of every organization at the configured interval,
+    /// starting right after startup so reservations that ran out while the service was down
+    /// stop holding stock. Returns right away when no interval is configured.
+    pub async fn run_on_schedule(&self) {
+        if self.config.intervalsecs == 0 {
+            return;
+        }
+
+        let mut interval = tokio::time::interval(Duration::from_secs(self.config.intervalsecs));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let organizations = match self.organization_repository.list().await {
+                Ok(organizations) => organizations,
+                Err(err) => {
+                    tracing::error!(error = ?err, "Failed to load organizations");
+                    continue;
+                }
+            };
+
+            for organization in organizations {
+                match db::with_organization(organization.id, self.expire_overdue()).await {
+                    Ok(0) => {}
+                    Ok(count) => tracing::info!(
+                        organization_id = %organization.id,
+                        count,
+                        "Overdue reservations expired"
+                    ),
+                    Err(err) => tracing::error!(
+                        organization_id = %organization.id,
+                        error = ?err,
+                        "Expiring reservations failed"
+                    ),
+                }
+            }
+        }
+    }
+}
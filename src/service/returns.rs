@@ -0,0 +1,490 @@
+use crate::contract::repository::{
+    LocationRepository, LotRepository, ProductRepository, ReturnAuthorizationRepository,
+    WarehouseRepository,
+};
+use crate::domain::{
+    DocumentType, Location, LotError, MovementKind, Product, ReturnAuthorization,
+    ReturnAuthorizationData, ReturnAuthorizationLine, ReturnDisposition, ReturnError,
+    ReturnInspection, ReturnInspectionData, ReturnReceipt, ReturnStatus, ReturnSummary,
+    ReturnSummaryQuery, StockMovement, StockStatus, check_serial_numbers,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Authorization, lines and inspections of a return after a receipt, with the movements
+/// of the receipt.
+pub type ReceivedReturn = (
+    ReturnAuthorization,
+    Vec<ReturnAuthorizationLine>,
+    Vec<ReturnInspection>,
+    Vec<StockMovement>,
+);
+
+pub struct ReturnAuthorizationService {
+    return_authorization_repository: Box<dyn ReturnAuthorizationRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl ReturnAuthorizationService {
+    pub fn new(
+        return_authorization_repository: Box<dyn ReturnAuthorizationRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> Self {
+        Self {
+            return_authorization_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            lot_repository,
+        }
+    }
+
+    /// Authorizes the return of goods issued on the sales order. A product cannot be
+    /// authorized for more than was shipped on the order and not yet authorized before.
+    /// Quantities are stored in base units.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(
+        &self,
+        args: ReturnAuthorizationData,
+        user_id: Uuid,
+    ) -> Result<(ReturnAuthorization, Vec<ReturnAuthorizationLine>)> {
+        if args.lines.is_empty() {
+            return Err(ReturnError::NoLines.into());
+        }
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+
+        let authorization = ReturnAuthorization {
+            id: Uuid::new_v4(),
+            sales_order_id: args.sales_order_id,
+            warehouse_id: args.warehouse_id,
+            status: ReturnStatus::Open,
+            created_by: Some(user_id),
+            created_at: Utc::now(),
+            closed_at: None,
+        };
+
+        let mut lines: Vec<ReturnAuthorizationLine> = Vec::with_capacity(args.lines.len());
+        for line in args.lines {
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                line.quantity,
+                line.uom.as_deref(),
+            )
+            .await?;
+
+            let shipped = self
+                .return_authorization_repository
+                .shipped_quantity(args.sales_order_id, line.product_id)
+                .await?
+                .ok_or(ReturnError::NotShipped)?;
+            let authorized = self
+                .return_authorization_repository
+                .authorized_quantity(args.sales_order_id, line.product_id)
+                .await?;
+            let requested: Decimal = lines
+                .iter()
+                .filter(|other| other.product_id == line.product_id)
+                .map(|other| other.quantity)
+                .sum();
+            if authorized + requested + quantity > shipped {
+                return Err(ReturnError::ExceedsShipped.into());
+            }
+
+            lines.push(ReturnAuthorizationLine {
+                id: Uuid::new_v4(),
+                return_authorization_id: authorization.id,
+                product_id: line.product_id,
+                reason: line.reason,
+                note: line.note,
+                quantity,
+                received_quantity: Decimal::ZERO,
+                inspected_quantity: Decimal::ZERO,
+            });
+        }
+
+        let authorization = self
+            .return_authorization_repository
+            .create_with_lines(authorization, lines.clone())
+            .await
+            .context("Failed to create return authorization")?;
+
+        Ok((authorization, lines))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(
+        &self,
+        id: Uuid,
+    ) -> Result<(
+        ReturnAuthorization,
+        Vec<ReturnAuthorizationLine>,
+        Vec<ReturnInspection>,
+    )> {
+        let authorization = self.return_authorization_repository.get_by_id(id).await?;
+        let lines = self
+            .return_authorization_repository
+            .list_lines(id)
+            .await
+            .context("Failed to load return authorization lines")?;
+        let inspections = self
+            .return_authorization_repository
+            .list_inspections(id)
+            .await
+            .context("Failed to load return inspections")?;
+
+        Ok((authorization, lines, inspections))
+    }
+
+    /// Receives returned units into quarantine locations of the warehouse, where they wait
+    /// for inspection. A line cannot receive more than was authorized.
+    #[tracing::instrument(skip(self, receipts))]
+    pub async fn receive(
+        &self,
+        id: Uuid,
+        receipts: Vec<ReturnReceipt>,
+        user_id: Uuid,
+    ) -> Result<ReceivedReturn> {
+        let (authorization, read_lines, inspections) = self.get(id).await?;
+        if authorization.status != ReturnStatus::Open {
+            return Err(ReturnError::NotOpen.into());
+        }
+        let mut lines = read_lines.clone();
+
+        let now = Utc::now();
+        let mut movements = Vec::with_capacity(receipts.len());
+        for receipt in receipts {
+            let line = lines
+                .iter_mut()
+                .find(|line| line.id == receipt.line_id)
+                .ok_or(ReturnError::UnknownLine)?;
+            let location = self
+                .warehouse_location(receipt.location_id, authorization.warehouse_id)
+                .await?;
+            if !location.quarantine {
+                return Err(ReturnError::NotQuarantine.into());
+            }
+
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                receipt.quantity,
+                receipt.uom.as_deref(),
+            )
+            .await?;
+            check_serial_numbers(&product, quantity, &receipt.serial_numbers)?;
+            if quantity > line.awaiting_receipt() {
+                return Err(ReturnError::ExceedsAuthorized.into());
+            }
+            let lot_id = self
+                .receipt_lot(&product, receipt.lot_number, receipt.expiry_date)
+                .await?;
+            line.received_quantity += quantity;
+
+            let mut movement = movement(
+                &authorization,
+                line,
+                None,
+                Some(location.id),
+                quantity,
+                user_id,
+                now,
+            );
+            movement.kind = MovementKind::Receipt;
+            movement.lot_id = lot_id;
+            movements.push((movement, receipt.serial_numbers));
+        }
+
+        let (authorization, movements) = self
+            .return_authorization_repository
+            .update_with_movements(
+                authorization,
+                lines.clone(),
+                read_lines,
+                Vec::new(),
+                movements,
+            )
+            .await
+            .context("Failed to receive returned stock")?;
+
+        Ok((authorization, lines, inspections, movements))
+    }
+
+    /// Records the disposition of received units and moves them out of the quarantine
+    /// location given, or the one the product was last received into: to a location of the
+    /// warehouse when restocked or refurbished, out of stock otherwise. The authorization
+    /// completes once every authorized unit was received and inspected.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn inspect(
+        &self,
+        id: Uuid,
+        args: Vec<ReturnInspectionData>,
+        user_id: Uuid,
+    ) -> Result<(
+        ReturnAuthorization,
+        Vec<ReturnAuthorizationLine>,
+        Vec<ReturnInspection>,
+    )> {
+        let (mut authorization, read_lines, mut inspections) = self.get(id).await?;
+        if authorization.status != ReturnStatus::Open {
+            return Err(ReturnError::NotOpen.into());
+        }
+        let mut lines = read_lines.clone();
+
+        let now = Utc::now();
+        let received = self
+            .return_authorization_repository
+            .list_movements(id)
+            .await
+            .context("Failed to load return movements")?;
+
+        let mut new_inspections = Vec::with_capacity(args.len());
+        let mut movements = Vec::with_capacity(args.len());
+        for inspection in args {
+            let line = lines
+                .iter_mut()
+                .find(|line| line.id == inspection.line_id)
+                .ok_or(ReturnError::UnknownLine)?;
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                inspection.quantity,
+                inspection.uom.as_deref(),
+            )
+            .await?;
+            check_serial_numbers(&product, quantity, &inspection.serial_numbers)?;
+            if quantity > line.awaiting_inspection() {
+                return Err(ReturnError::ExceedsReceived.into());
+            }
+
+            let to_location_id = match (
+                inspection.disposition.keeps_stock(),
+                inspection.to_location_id,
+            ) {
+                (true, Some(location_id)) => {
+                    let location = self
+                        .warehouse_location(location_id, authorization.warehouse_id)
+                        .await?;
+                    Some(location.id)
+                }
+                (true, None) => return Err(ReturnError::LocationRequired.into()),
+                (false, _) => None,
+            };
+            let mut receipt_locations = received
+                .iter()
+                .rev()
+                .filter(|movement| {
+                    movement.kind == MovementKind::Receipt && movement.product_id == line.product_id
+                })
+                .filter_map(|movement| movement.to_location_id);
+            let quarantine_location_id = match inspection.from_location_id {
+                Some(location_id) => receipt_locations
+                    .find(|received_into| *received_into == location_id)
+                    .ok_or(ReturnError::NotReceivedAtLocation)?,
+                None => receipt_locations
+                    .next()
+                    .ok_or(ReturnError::ExceedsReceived)?,
+            };
+            let lot_id = match (inspection.lot_number, inspection.expiry_date) {
+                (None, None) => None,
+                (lot_number, expiry_date) => Some(
+                    self.lot_repository
+                        .find(line.product_id, lot_number, expiry_date)
+                        .await?
+                        .id,
+                ),
+            };
+            line.inspected_quantity += quantity;
+
+            let mut movement = movement(
+                &authorization,
+                line,
+                Some(quarantine_location_id),
+                to_location_id,
+                quantity,
+                user_id,
+                now,
+            );
+            movement.kind = match inspection.disposition {
+                ReturnDisposition::Restock | ReturnDisposition::Refurbish => MovementKind::Transfer,
+                ReturnDisposition::Scrap => MovementKind::Adjustment,
+                ReturnDisposition::ReturnToVendor => MovementKind::Issue,
+            };
+            movement.lot_id = lot_id;
+            movements.push((movement, inspection.serial_numbers));
+
+            new_inspections.push(ReturnInspection {
+                id: Uuid::new_v4(),
+                line_id: line.id,
+                quantity,
+                disposition: inspection.disposition,
+                to_location_id,
+                note: inspection.note,
+                inspected_by: Some(user_id),
+                inspected_at: now,
+            });
+        }
+
+        if lines
+            .iter()
+            .all(|line| line.awaiting_receipt().is_zero() && line.awaiting_inspection().is_zero())
+        {
+            authorization.status = ReturnStatus::Completed;
+            authorization.closed_at = Some(now);
+        }
+
+        let (authorization, _) = self
+            .return_authorization_repository
+            .update_with_movements(
+                authorization,
+                lines.clone(),
+                read_lines,
+                new_inspections.clone(),
+                movements,
+            )
+            .await
+            .context("Failed to inspect returned stock")?;
+        inspections.extend(new_inspections);
+
+        Ok((authorization, lines, inspections))
+    }
+
+    /// Closes the authorization, units not received by now are no longer expected. Received
+    /// units must be inspected first.
+    #[tracing::instrument(skip(self))]
+    pub async fn close(
+        &self,
+        id: Uuid,
+    ) -> Result<(
+        ReturnAuthorization,
+        Vec<ReturnAuthorizationLine>,
+        Vec<ReturnInspection>,
+    )> {
+        let (mut authorization, lines, inspections) = self.get(id).await?;
+        if authorization.status != ReturnStatus::Open {
+            return Err(ReturnError::NotOpen.into());
+        }
+        if lines
+            .iter()
+            .any(|line| !line.awaiting_inspection().is_zero())
+        {
+            return Err(ReturnError::AwaitingInspection.into());
+        }
+
+        authorization.status = ReturnStatus::Closed;
+        authorization.closed_at = Some(Utc::now());
+
+        let (authorization, _) = self
+            .return_authorization_repository
+            .update_with_movements(
+                authorization,
+                lines.clone(),
+                lines.clone(),
+                Vec::new(),
+                Vec::new(),
+            )
+            .await
+            .context("Failed to close return authorization")?;
+
+        Ok((authorization, lines, inspections))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_movements(&self, id: Uuid) -> Result<Vec<StockMovement>> {
+        self.return_authorization_repository.get_by_id(id).await?;
+        self.return_authorization_repository
+            .list_movements(id)
+            .await
+            .context("Failed to load return movements")
+    }
+
+    /// Returned quantity by product, reason and disposition.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn summarize(&self, query: ReturnSummaryQuery) -> Result<Vec<ReturnSummary>> {
+        self.return_authorization_repository
+            .summarize(query)
+            .await
+            .context("Failed to summarize returns")
+    }
+
+    /// Returned units of tracked products come back into the lot they were shipped from.
+    async fn receipt_lot(
+        &self,
+        product: &Product,
+        lot_number: Option<String>,
+        expiry_date: Option<NaiveDate>,
+    ) -> Result<Option<Uuid>> {
+        if !product.lot_tracked && !product.expiry_tracked {
+            if lot_number.is_some() || expiry_date.is_some() {
+                return Err(LotError::NotTracked.into());
+            }
+            return Ok(None);
+        }
+        if product.lot_tracked && lot_number.is_none() {
+            return Err(LotError::LotNumberRequired.into());
+        }
+        if product.expiry_tracked && expiry_date.is_none() {
+            return Err(LotError::ExpiryDateRequired.into());
+        }
+
+        let lot = self
+            .lot_repository
+            .find(product.id, lot_number, expiry_date)
+            .await?;
+
+        Ok(Some(lot.id))
+    }
+
+    async fn warehouse_location(&self, location_id: Uuid, warehouse_id: Uuid) -> Result<Location> {
+        let location = self.location_repository.get_by_id(location_id).await?;
+        if location.warehouse_id != warehouse_id || location.in_transit {
+            return Err(ReturnError::LocationNotInWarehouse.into());
+        }
+
+        Ok(location)
+    }
+}
+
+fn movement(
+    authorization: &ReturnAuthorization,
+    line: &ReturnAuthorizationLine,
+    from_location_id: Option<Uuid>,
+    to_location_id: Option<Uuid>,
+    quantity: Decimal,
+    user_id: Uuid,
+    now: DateTime<Utc>,
+) -> StockMovement {
+    StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::Transfer,
+        product_id: line.product_id,
+        from_location_id,
+        to_location_id,
+        quantity,
+        document_type: Some(DocumentType::ReturnAuthorization),
+        document_id: Some(authorization.id),
+        created_by: Some(user_id),
+        created_at: now,
+        lot_id: None,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    }
+}
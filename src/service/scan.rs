@@ -0,0 +1,61 @@
+use crate::contract::repository::ProductRepository;
+use crate::domain::{Gs1Scan, RepositoryError, parse_gs1};
+use anyhow::Result;
+use chrono::Utc;
+
+pub struct ScanService {
+    product_repository: Box<dyn ProductRepository>,
+}
+
+impl ScanService {
+    pub fn new(product_repository: Box<dyn ProductRepository>) -> Self {
+        Self { product_repository }
+    }
+
+    /// Parses a scanned GS1 element string and looks its GTIN up among the barcodes of the
+    /// products' units of measure, so a single scan names product, unit, lot and expiry.
+    #[tracing::instrument(skip(self))]
+    pub async fn gs1(&self, data: &str) -> Result<Gs1Scan> {
+        scan_gs1(self.product_repository.as_ref(), data).await
+    }
+}
+
+/// Parses a GS1 element string and resolves the product unit its GTIN is the barcode of.
+/// The GTIN is left unresolved when no unit has it as barcode.
+pub(crate) async fn scan_gs1(
+    product_repository: &dyn ProductRepository,
+    data: &str,
+) -> Result<Gs1Scan> {
+    let mut scan = parse_gs1(data, Utc::now().date_naive())?;
+
+    let Some(gtin) = scan.gtin.clone().or_else(|| scan.content_gtin.clone()) else {
+        return Ok(scan);
+    };
+
+    // GTIN-14 is padded with leading zeros, barcodes may be stored as EAN-13 or UPC-A.
+    for barcode in [&gtin[..], &gtin[1..], &gtin[2..]] {
+        match product_repository.find_uom_by_barcode(barcode).await {
+            Ok(uom) => {
+                scan.product_id = Some(uom.product_id);
+                scan.uom = Some(uom.code);
+                break;
+            }
+            Err(err) if is_not_found(&err) => {}
+            Err(err) => return Err(err),
+        }
+        if !barcode.starts_with('0') {
+            break;
+        }
+    }
+
+    Ok(scan)
+}
+
+fn is_not_found(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<RepositoryError>(),
+            Some(RepositoryError::NotFound)
+        )
+    })
+}
@@ -0,0 +1,39 @@
+use crate::contract::repository::SerialNumberRepository;
+use crate::domain::{SerialNumber, StockMovement};
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+pub struct SerialNumberService {
+    serial_number_repository: Box<dyn SerialNumberRepository>,
+}
+
+impl SerialNumberService {
+    pub fn new(serial_number_repository: Box<dyn SerialNumberRepository>) -> Self {
+        Self {
+            serial_number_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<SerialNumber> {
+        self.serial_number_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn find(&self, product_id: Uuid, serial_number: &str) -> Result<SerialNumber> {
+        self.serial_number_repository
+            .find(product_id, serial_number)
+            .await
+    }
+
+    /// Every movement the unit took part in, oldest first.
+    #[tracing::instrument(skip(self))]
+    pub async fn history(&self, id: Uuid) -> Result<Vec<StockMovement>> {
+        self.serial_number_repository.get_by_id(id).await?;
+
+        self.serial_number_repository
+            .list_movements(id)
+            .await
+            .context("Failed to load serial number history")
+    }
+}
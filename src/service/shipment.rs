@@ -0,0 +1,239 @@
+use crate::contract::carrier::Carrier;
+use crate::contract::repository::{ShipmentRepository, WarehouseRepository, WaveRepository};
+use crate::domain::{
+    Parcel, ParcelData, PickOrderStatus, Rate, RepositoryError, ShipData, Shipment, ShipmentData,
+    ShipmentError, ShipmentQuery, ShipmentStatus, rank_rates,
+};
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use uuid::Uuid;
+
+pub struct ShipmentService {
+    shipment_repository: Box<dyn ShipmentRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    wave_repository: Box<dyn WaveRepository>,
+    carriers: Vec<Box<dyn Carrier>>,
+}
+
+impl ShipmentService {
+    pub fn new(
+        shipment_repository: Box<dyn ShipmentRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        wave_repository: Box<dyn WaveRepository>,
+        carriers: Vec<Box<dyn Carrier>>,
+    ) -> Self {
+        Self {
+            shipment_repository,
+            warehouse_repository,
+            wave_repository,
+            carriers,
+        }
+    }
+
+    /// Shipments packed from a pick order ship to the order's document, once the order is
+    /// picked.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(&self, args: ShipmentData, user_id: Uuid) -> Result<Shipment> {
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+
+        let mut document_id = args.document_id;
+        if let Some(pick_order_id) = args.pick_order_id {
+            let order = self.wave_repository.get_order(pick_order_id).await?;
+            if order.status != PickOrderStatus::Picked || order.warehouse_id != args.warehouse_id {
+                return Err(ShipmentError::PickOrderNotPicked.into());
+            }
+            document_id = document_id.or(Some(order.document_id));
+        }
+
+        self.shipment_repository
+            .create(Shipment {
+                id: Uuid::new_v4(),
+                warehouse_id: args.warehouse_id,
+                pick_order_id: args.pick_order_id,
+                document_id,
+                ship_to_name: args.ship_to_name,
+                ship_to_address: args.ship_to_address,
+                ship_to_postal_code: args.ship_to_postal_code,
+                ship_to_country: args.ship_to_country.to_uppercase(),
+                carrier: None,
+                service: None,
+                shipping_cost: None,
+                currency: None,
+                status: ShipmentStatus::Open,
+                shipped_by: None,
+                shipped_at: None,
+                created_by: Some(user_id),
+                created_at: Utc::now(),
+            })
+            .await
+            .context("Failed to create shipment")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list(&self, query: ShipmentQuery) -> Result<Vec<Shipment>> {
+        self.shipment_repository
+            .list(query)
+            .await
+            .context("Failed to load shipments")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<(Shipment, Vec<Parcel>)> {
+        let shipment = self.shipment_repository.get_by_id(id).await?;
+        let parcels = self
+            .shipment_repository
+            .list_parcels(id)
+            .await
+            .context("Failed to load parcels")?;
+
+        Ok((shipment, parcels))
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn add_parcel(&self, shipment_id: Uuid, args: ParcelData) -> Result<Parcel> {
+        self.shipment_repository
+            .add_parcel(Parcel {
+                id: Uuid::new_v4(),
+                shipment_id,
+                length_cm: args.length_cm,
+                width_cm: args.width_cm,
+                height_cm: args.height_cm,
+                weight_kg: args.weight_kg,
+                tracking_number: None,
+                label: None,
+                label_content_type: None,
+                created_at: Utc::now(),
+            })
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn remove_parcel(&self, shipment_id: Uuid, parcel_id: Uuid) -> Result<()> {
+        self.shipment_repository
+            .remove_parcel(shipment_id, parcel_id)
+            .await
+    }
+
+    /// Rates of every carrier, or of the given one, for the parcels of the shipment,
+    /// cheapest first.
+    #[tracing::instrument(skip(self))]
+    pub async fn rates(&self, id: Uuid, carrier: Option<&str>) -> Result<Vec<Rate>> {
+        let (shipment, parcels) = self.shippable(id).await?;
+
+        let carriers: Vec<&dyn Carrier> = match carrier {
+            Some(code) => vec![self.carrier(code)?],
+            None => self.carriers.iter().map(AsRef::as_ref).collect(),
+        };
+
+        let mut rates = Vec::new();
+        for carrier in carriers {
+            rates.extend(
+                carrier
+                    .rates(&shipment, &parcels)
+                    .await
+                    .with_context(|| format!("Failed to get rates of {}", carrier.code()))?,
+            );
+        }
+        rank_rates(&mut rates);
+
+        Ok(rates)
+    }
+
+    /// Books the service with the carrier, storing the cost it quotes and the tracking
+    /// number and label of every parcel.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn ship(
+        &self,
+        id: Uuid,
+        args: ShipData,
+        user_id: Uuid,
+    ) -> Result<(Shipment, Vec<Parcel>)> {
+        let (mut shipment, mut parcels) = self.shippable(id).await?;
+        let carrier = self.carrier(&args.carrier)?;
+
+        let rates = carrier
+            .rates(&shipment, &parcels)
+            .await
+            .context("Failed to get carrier rates")?;
+        let rate = rates
+            .iter()
+            .find(|rate| rate.service == args.service)
+            .cloned();
+        if !rates.is_empty() && rate.is_none() {
+            return Err(ShipmentError::UnknownService(args.service).into());
+        }
+
+        let labels = carrier
+            .ship(&shipment, &parcels, &args)
+            .await
+            .context("Failed to book carrier service")?;
+        if labels.len() != parcels.len() {
+            bail!(
+                "Carrier {} returned {} labels for {} parcels",
+                args.carrier,
+                labels.len(),
+                parcels.len()
+            );
+        }
+        for (parcel, label) in parcels.iter_mut().zip(labels) {
+            parcel.tracking_number = label.tracking_number;
+            parcel.label = label.label;
+            parcel.label_content_type = label.content_type;
+        }
+
+        shipment.carrier = Some(args.carrier);
+        shipment.service = Some(args.service);
+        shipment.shipping_cost = rate.as_ref().map(|rate| rate.amount);
+        shipment.currency = rate.map(|rate| rate.currency);
+        shipment.status = ShipmentStatus::Shipped;
+        shipment.shipped_by = Some(user_id);
+        shipment.shipped_at = Some(Utc::now());
+
+        let shipment = self
+            .shipment_repository
+            .ship(shipment, parcels.clone())
+            .await
+            .context("Failed to save shipment")?;
+        Ok((shipment, parcels))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel(&self, id: Uuid) -> Result<Shipment> {
+        self.shipment_repository.cancel(id).await
+    }
+
+    /// The label the carrier printed for the parcel and its content type.
+    #[tracing::instrument(skip(self))]
+    pub async fn parcel_label(&self, parcel_id: Uuid) -> Result<(String, Vec<u8>)> {
+        let parcel = self.shipment_repository.get_parcel(parcel_id).await?;
+
+        match (parcel.label_content_type, parcel.label) {
+            (Some(content_type), Some(label)) => Ok((content_type, label)),
+            _ => Err(RepositoryError::NotFound.into()),
+        }
+    }
+
+    fn carrier(&self, code: &str) -> Result<&dyn Carrier> {
+        self.carriers
+            .iter()
+            .find(|carrier| carrier.code() == code)
+            .map(AsRef::as_ref)
+            .ok_or_else(|| ShipmentError::UnknownCarrier(code.to_string()).into())
+    }
+
+    /// An open shipment with its parcels, which it must have some of.
+    async fn shippable(&self, id: Uuid) -> Result<(Shipment, Vec<Parcel>)> {
+        let (shipment, parcels) = self.get(id).await?;
+        if shipment.status != ShipmentStatus::Open {
+            return Err(ShipmentError::NotOpen.into());
+        }
+        if parcels.is_empty() {
+            return Err(ShipmentError::NoParcels.into());
+        }
+
+        Ok((shipment, parcels))
+    }
+}
@@ -0,0 +1,159 @@
+use crate::config::SnapshotConfig;
+use crate::contract::repository::{OrganizationRepository, SnapshotRepository};
+use crate::db;
+use crate::domain::{
+    SnapshotKind, StockPosition, StockPositionQuery, StockSnapshot, StockSnapshotDiff,
+    StockSnapshotQuery, diff_positions,
+};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use uuid::Uuid;
+
+pub struct SnapshotService {
+    config: SnapshotConfig,
+    snapshot_repository: Box<dyn SnapshotRepository>,
+    organization_repository: Box<dyn OrganizationRepository>,
+}
+
+impl SnapshotService {
+    pub fn new(
+        config: SnapshotConfig,
+        snapshot_repository: Box<dyn SnapshotRepository>,
+        organization_repository: Box<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            config,
+            snapshot_repository,
+            organization_repository,
+        }
+    }
+
+    /// Stock on hand at the moment, by product, location, lot and status.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn positions_as_of(
+        &self,
+        as_of: DateTime<Utc>,
+        query: StockPositionQuery,
+    ) -> Result<Vec<StockPosition>> {
+        self.snapshot_repository
+            .positions_as_of(as_of, query)
+            .await
+            .context("Failed to load stock as of the date")
+    }
+
+    /// Snapshots stock on hand right now.
+    #[tracing::instrument(skip(self))]
+    pub async fn take(&self, user_id: Uuid) -> Result<StockSnapshot> {
+        let now = Utc::now();
+        self.snapshot_repository
+            .take(StockSnapshot {
+                id: Uuid::new_v4(),
+                kind: SnapshotKind::Manual,
+                as_of: now,
+                created_by: Some(user_id),
+                created_at: now,
+            })
+            .await
+            .context("Failed to take stock snapshot")
+    }
+
+    /// Takes the daily or month-end snapshot due at `now` unless it was taken already.
+    #[tracing::instrument(skip(self))]
+    pub async fn take_scheduled(&self, now: DateTime<Utc>) -> Result<Option<StockSnapshot>> {
+        let snapshot = StockSnapshot::scheduled(now);
+        if self
+            .snapshot_repository
+            .find_scheduled(snapshot.as_of)
+            .await?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        self.snapshot_repository
+            .take(snapshot)
+            .await
+            .map(Some)
+            .context("Failed to take scheduled stock snapshot")
+    }
+
+    /// Takes due snapshots of every organization at the configured interval, starting right
+    /// after startup so a snapshot missed while the service was down is caught up. Returns
+    /// right away when no interval is configured.
+    pub async fn run_on_schedule(&self) {
+        if self.config.intervalsecs == 0 {
+            return;
+        }
+
+        let mut interval = tokio::time::interval(Duration::from_secs(self.config.intervalsecs));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let organizations = match self.organization_repository.list().await {
+                Ok(organizations) => organizations,
+                Err(err) => {
+                    tracing::error!(error = ?err, "Failed to load organizations");
+                    continue;
+                }
+            };
+
+            let now = Utc::now();
+            for organization in organizations {
+                match db::with_organization(organization.id, self.take_scheduled(now)).await {
+                    Ok(Some(snapshot)) => {
+                        tracing::info!(
+                            organization_id = %organization.id,
+                            as_of = %snapshot.as_of,
+                            "Stock snapshot taken"
+                        );
+                    }
+                    Ok(None) => {}
+                    Err(err) => tracing::error!(
+                        organization_id = %organization.id,
+                        error = ?err,
+                        "Stock snapshot failed"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list(&self, query: StockSnapshotQuery) -> Result<Vec<StockSnapshot>> {
+        self.snapshot_repository
+            .list(query)
+            .await
+            .context("Failed to load stock snapshots")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn get(
+        &self,
+        id: Uuid,
+        query: StockPositionQuery,
+    ) -> Result<(StockSnapshot, Vec<StockPosition>)> {
+        let snapshot = self.snapshot_repository.get_by_id(id).await?;
+        let lines = self
+            .snapshot_repository
+            .list_lines(id, query)
+            .await
+            .context("Failed to load snapshot lines")?;
+
+        Ok((snapshot, lines))
+    }
+
+    /// Products and locations whose stock changed from one snapshot to the other.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn diff(
+        &self,
+        from_id: Uuid,
+        to_id: Uuid,
+        query: StockPositionQuery,
+    ) -> Result<Vec<StockSnapshotDiff>> {
+        let (_, from) = self.get(from_id, query.clone()).await?;
+        let (_, to) = self.get(to_id, query).await?;
+
+        Ok(diff_positions(from, to))
+    }
+}
@@ -0,0 +1,196 @@
+use crate::contract::repository::{LotRepository, ProductRepository, StockRepository};
+use crate::domain::{
+    AvailableToPromise, Lot, LotError, MovementData, MovementKind, Product, StockLevel,
+    StockLevelQuery, StockMovement, StockMovementQuery, StockStatus, check_serial_numbers,
+};
+use crate::service::product::to_base_quantity;
+use crate::service::scan::scan_gs1;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub struct StockService {
+    stock_repository: Box<dyn StockRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl StockService {
+    pub fn new(
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> Self {
+        Self {
+            stock_repository,
+            product_repository,
+            lot_repository,
+        }
+    }
+
+    /// Posts the movement. Stock leaving a location without a lot is allocated
+    /// first-expired-first-out, so a single request may result in several movements.
+    /// Serial-tracked products must name every unit moved. Quantities are stored in base units.
+    /// Receipts and issues are valued at the product's costing method. A scan of the goods
+    /// fills in what is not given.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn post_movement(
+        &self,
+        mut args: MovementData,
+        user_id: Uuid,
+    ) -> Result<Vec<StockMovement>> {
+        if let Some(data) = args.scan.take() {
+            let scan = scan_gs1(self.product_repository.as_ref(), &data).await?;
+            args.apply_scan(&scan)?;
+        }
+
+        let now = Utc::now();
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &product,
+            args.quantity,
+            args.uom.as_deref(),
+        )
+        .await?;
+        check_serial_numbers(&product, quantity, &args.serial_numbers)?;
+        let lot_id = self.resolve_lot(&product, &args, now).await?;
+        // Costs are kept per base unit, whatever unit the quantity was given in.
+        let unit_cost = args
+            .unit_cost
+            .map(|cost| (cost * args.quantity / quantity).round_dp(6));
+
+        self.stock_repository
+            .post_movement(
+                StockMovement {
+                    id: Uuid::new_v4(),
+                    kind: args.kind,
+                    product_id: args.product_id,
+                    from_location_id: args.from_location_id,
+                    to_location_id: args.to_location_id,
+                    quantity,
+                    document_type: args.document_type,
+                    document_id: args.document_id,
+                    created_by: Some(user_id),
+                    created_at: now,
+                    lot_id,
+                    unit_cost,
+                    total_cost: None,
+                    status: StockStatus::Available,
+                    to_status: None,
+                    reason: None,
+                },
+                args.serial_numbers,
+            )
+            .await
+            .context("Failed to post stock movement")
+    }
+
+    /// Inbound stock of a tracked product must name its lot, which is registered on first
+    /// receipt. Outbound stock may name an existing lot; expired or blocked lots cannot be issued.
+    async fn resolve_lot(
+        &self,
+        product: &Product,
+        args: &MovementData,
+        now: DateTime<Utc>,
+    ) -> Result<Option<Uuid>> {
+        let lot_given = args.lot_number.is_some() || args.expiry_date.is_some();
+
+        if args.from_location_id.is_some() {
+            if !lot_given {
+                return Ok(None);
+            }
+
+            let lot = self
+                .lot_repository
+                .find(args.product_id, args.lot_number.clone(), args.expiry_date)
+                .await?;
+            if args.kind == MovementKind::Issue && !lot.is_usable_on(now.date_naive()) {
+                return Err(LotError::Blocked.into());
+            }
+
+            return Ok(Some(lot.id));
+        }
+
+        if !product.lot_tracked && !product.expiry_tracked {
+            if lot_given {
+                return Err(LotError::NotTracked.into());
+            }
+            return Ok(None);
+        }
+        if product.lot_tracked && args.lot_number.is_none() {
+            return Err(LotError::LotNumberRequired.into());
+        }
+        if product.expiry_tracked && args.expiry_date.is_none() {
+            return Err(LotError::ExpiryDateRequired.into());
+        }
+
+        let lot = self
+            .lot_repository
+            .get_or_create(Lot {
+                id: Uuid::new_v4(),
+                product_id: product.id,
+                lot_number: args.lot_number.clone(),
+                expiry_date: args.expiry_date,
+                blocked: false,
+                created_at: now,
+            })
+            .await
+            .context("Failed to register lot")?;
+
+        Ok(Some(lot.id))
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn levels(&self, query: StockLevelQuery) -> Result<Vec<StockLevel>> {
+        self.stock_repository
+            .get_levels(query)
+            .await
+            .context("Failed to load stock levels")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn movements(&self, query: StockMovementQuery) -> Result<Vec<StockMovement>> {
+        self.stock_repository
+            .list_movements(query)
+            .await
+            .context("Failed to load stock movements")
+    }
+
+    /// Quantity of the product that can still be promised to new demand,
+    /// summed over all locations of the warehouse (or of every warehouse).
+    #[tracing::instrument(skip(self))]
+    pub async fn available_to_promise(
+        &self,
+        product_id: Uuid,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<AvailableToPromise> {
+        let levels = self
+            .levels(StockLevelQuery {
+                product_id: Some(product_id),
+                warehouse_id,
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(levels.into_iter().fold(
+            AvailableToPromise {
+                product_id,
+                warehouse_id,
+                on_hand: Default::default(),
+                blocked: Default::default(),
+                in_transit: Default::default(),
+                reserved: Default::default(),
+                available: Default::default(),
+            },
+            |mut atp, level| {
+                atp.on_hand += level.on_hand;
+                atp.blocked += level.blocked;
+                atp.in_transit += level.in_transit;
+                atp.reserved += level.reserved;
+                atp.available += level.available;
+                atp
+            },
+        ))
+    }
+}
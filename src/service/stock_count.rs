@@ -0,0 +1,361 @@
+use crate::contract::repository::{
+    LocationRepository, LotRepository, ProductRepository, StockCountRepository, StockRepository,
+};
+use crate::domain::{
+    AuthError, CountSession, CountSessionData, CountSessionStatus, CountTask, CountTaskData,
+    CountTaskStatus, DocumentType, Lot, LotError, MovementKind, RepositoryError, StockBalanceQuery,
+    StockCountError, StockMovement, StockStatus,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+pub struct StockCountService {
+    stock_count_repository: Box<dyn StockCountRepository>,
+    stock_repository: Box<dyn StockRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl StockCountService {
+    pub fn new(
+        stock_count_repository: Box<dyn StockCountRepository>,
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> Self {
+        Self {
+            stock_count_repository,
+            stock_repository,
+            product_repository,
+            location_repository,
+            lot_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn plan(&self, args: CountSessionData, user_id: Uuid) -> Result<CountSession> {
+        self.stock_count_repository
+            .create(CountSession {
+                id: Uuid::new_v4(),
+                warehouse_id: args.warehouse_id,
+                scope: args.scope,
+                zone: args.zone,
+                abc_class: args.abc_class,
+                blind: args.blind,
+                tolerance_percent: args.tolerance_percent,
+                status: CountSessionStatus::Open,
+                created_by: Some(user_id),
+                created_at: Utc::now(),
+                completed_at: None,
+            })
+            .await
+            .context("Failed to plan count session")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<(CountSession, Vec<CountTask>)> {
+        let session = self.stock_count_repository.get_by_id(id).await?;
+        let tasks = self
+            .stock_count_repository
+            .list_tasks(id)
+            .await
+            .context("Failed to load count tasks")?;
+
+        Ok((session, tasks))
+    }
+
+    /// Adds a task for stock found where the session has none, e.g. in a bin that was empty
+    /// when the session was planned. Nothing is expected there until the count says otherwise.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn add_task(
+        &self,
+        session_id: Uuid,
+        args: CountTaskData,
+    ) -> Result<(CountSession, CountTask)> {
+        let session = self.stock_count_repository.get_by_id(session_id).await?;
+        if session.status != CountSessionStatus::Open {
+            return Err(StockCountError::SessionNotOpen.into());
+        }
+
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let location = self.location_repository.get_by_id(args.location_id).await?;
+        if !session.covers(&product, &location) {
+            return Err(StockCountError::OutOfScope.into());
+        }
+
+        let now = Utc::now();
+        let lot_given = args.lot_number.is_some() || args.expiry_date.is_some();
+        let lot_id = if !product.lot_tracked && !product.expiry_tracked {
+            if lot_given {
+                return Err(LotError::NotTracked.into());
+            }
+            None
+        } else {
+            if product.lot_tracked && args.lot_number.is_none() {
+                return Err(LotError::LotNumberRequired.into());
+            }
+            if product.expiry_tracked && args.expiry_date.is_none() {
+                return Err(LotError::ExpiryDateRequired.into());
+            }
+            // Found stock may well be of a lot the warehouse has not seen yet.
+            let lot = self
+                .lot_repository
+                .get_or_create(Lot {
+                    id: Uuid::new_v4(),
+                    product_id: product.id,
+                    lot_number: args.lot_number,
+                    expiry_date: args.expiry_date,
+                    blocked: false,
+                    created_at: now,
+                })
+                .await
+                .context("Failed to register lot")?;
+            Some(lot.id)
+        };
+
+        let task = self
+            .stock_count_repository
+            .create_task(CountTask {
+                id: Uuid::new_v4(),
+                session_id: session.id,
+                product_id: product.id,
+                location_id: location.id,
+                expected_quantity: Decimal::ZERO,
+                counted_quantity: None,
+                variance: None,
+                attempts: 0,
+                status: CountTaskStatus::Pending,
+                counted_by: None,
+                counted_at: None,
+                approved_by: None,
+                movement_id: None,
+                lot_id,
+                counters: Vec::new(),
+            })
+            .await
+            .context("Failed to add count task")?;
+
+        Ok((session, task))
+    }
+
+    /// Records a counted quantity. Variances within the session tolerance are adjusted
+    /// right away, larger ones are sent to recount once and then wait for approval.
+    #[tracing::instrument(skip(self))]
+    pub async fn record_count(
+        &self,
+        session_id: Uuid,
+        task_id: Uuid,
+        counted_quantity: Decimal,
+        uom: Option<String>,
+        user_id: Uuid,
+    ) -> Result<(CountSession, CountTask)> {
+        let (session, mut task) = self.open_task(session_id, task_id).await?;
+        if !matches!(
+            task.status,
+            CountTaskStatus::Pending | CountTaskStatus::Recount
+        ) {
+            return Err(StockCountError::TaskNotCountable.into());
+        }
+
+        let product = self.product_repository.get_by_id(task.product_id).await?;
+        let counted_quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &product,
+            counted_quantity,
+            uom.as_deref(),
+        )
+        .await?;
+
+        // Held stock is left out of count tasks, so only available stock is expected.
+        let on_hand = self
+            .stock_repository
+            .list_balances(StockBalanceQuery {
+                product_id: Some(task.product_id),
+                location_id: Some(task.location_id),
+                lot_id: task.lot_id,
+                statuses: vec![StockStatus::Available],
+                ..Default::default()
+            })
+            .await
+            .context("Failed to load stock level")?
+            .into_iter()
+            .map(|balance| balance.on_hand)
+            .sum::<Decimal>();
+        let variance = counted_quantity - on_hand;
+
+        let first_attempt = task.status == CountTaskStatus::Pending;
+        task.expected_quantity = on_hand;
+        task.counted_quantity = Some(counted_quantity);
+        task.variance = Some(variance);
+        task.attempts += 1;
+        task.counted_by = Some(user_id);
+        task.counted_at = Some(Utc::now());
+        if !task.counters.contains(&user_id) {
+            task.counters.push(user_id);
+        }
+
+        let mut adjustment = None;
+        if session.within_tolerance(on_hand, variance) {
+            task.status = CountTaskStatus::Approved;
+            adjustment = adjustment_for(&session, &task, user_id);
+            task.movement_id = adjustment.as_ref().map(|m| m.id);
+        } else if first_attempt {
+            task.status = CountTaskStatus::Recount;
+        } else {
+            task.status = CountTaskStatus::Counted;
+        }
+
+        let task = self
+            .stock_count_repository
+            .update_task(
+                task,
+                &[CountTaskStatus::Pending, CountTaskStatus::Recount],
+                adjustment,
+            )
+            .await
+            .context("Failed to record count")?;
+
+        Ok((session, task))
+    }
+
+    /// Approves the variance of a counted task and posts the adjustment.
+    /// No user who counted the task, on any attempt, can approve it, and a task whose stock
+    /// moved since the count has to be rejected and recounted.
+    #[tracing::instrument(skip(self))]
+    pub async fn approve(
+        &self,
+        session_id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(CountSession, CountTask)> {
+        let (session, mut task) = self.reviewable_task(session_id, task_id, user_id).await?;
+
+        task.status = CountTaskStatus::Approved;
+        task.approved_by = Some(user_id);
+        let adjustment = adjustment_for(&session, &task, user_id);
+        task.movement_id = adjustment.as_ref().map(|m| m.id);
+
+        let task = self
+            .stock_count_repository
+            .update_task(task, &[CountTaskStatus::Counted], adjustment)
+            .await
+            .context("Failed to approve count")?;
+
+        Ok((session, task))
+    }
+
+    /// Rejects the variance of a counted task and sends it back to recount.
+    #[tracing::instrument(skip(self))]
+    pub async fn reject(
+        &self,
+        session_id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(CountSession, CountTask)> {
+        let (session, mut task) = self.reviewable_task(session_id, task_id, user_id).await?;
+
+        task.status = CountTaskStatus::Recount;
+
+        let task = self
+            .stock_count_repository
+            .update_task(task, &[CountTaskStatus::Counted], None)
+            .await
+            .context("Failed to reject count")?;
+
+        Ok((session, task))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn complete(&self, session_id: Uuid) -> Result<CountSession> {
+        let (mut session, tasks) = self.get(session_id).await?;
+        if session.status != CountSessionStatus::Open {
+            return Err(StockCountError::SessionNotOpen.into());
+        }
+        if tasks
+            .iter()
+            .any(|task| task.status != CountTaskStatus::Approved)
+        {
+            return Err(StockCountError::TasksOutstanding.into());
+        }
+
+        session.status = CountSessionStatus::Completed;
+        session.completed_at = Some(Utc::now());
+
+        self.stock_count_repository
+            .update_session(session)
+            .await
+            .context("Failed to complete count session")
+    }
+
+    async fn open_task(
+        &self,
+        session_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<(CountSession, CountTask)> {
+        let session = self.stock_count_repository.get_by_id(session_id).await?;
+        if session.status != CountSessionStatus::Open {
+            return Err(StockCountError::SessionNotOpen.into());
+        }
+
+        let task = self.stock_count_repository.get_task(task_id).await?;
+        if task.session_id != session.id {
+            return Err(RepositoryError::NotFound.into());
+        }
+
+        Ok((session, task))
+    }
+
+    async fn reviewable_task(
+        &self,
+        session_id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(CountSession, CountTask)> {
+        let (session, task) = self.open_task(session_id, task_id).await?;
+        if task.status != CountTaskStatus::Counted {
+            return Err(StockCountError::TaskNotAwaitingApproval.into());
+        }
+        if task.counters.contains(&user_id) {
+            return Err(AuthError::PermissionDenied.into());
+        }
+
+        Ok((session, task))
+    }
+}
+
+fn adjustment_for(
+    session: &CountSession,
+    task: &CountTask,
+    user_id: Uuid,
+) -> Option<StockMovement> {
+    let variance = task.variance.filter(|variance| !variance.is_zero())?;
+    let (from_location_id, to_location_id) = if variance.is_sign_positive() {
+        (None, Some(task.location_id))
+    } else {
+        (Some(task.location_id), None)
+    };
+
+    Some(StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::Adjustment,
+        product_id: task.product_id,
+        from_location_id,
+        to_location_id,
+        quantity: variance.abs(),
+        document_type: Some(DocumentType::StockCount),
+        document_id: Some(session.id),
+        created_by: Some(user_id),
+        created_at: Utc::now(),
+        lot_id: task.lot_id,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    })
+}
@@ -0,0 +1,227 @@
+use crate::contract::repository::{LotRepository, ProductRepository, StockRepository};
+use crate::domain::{
+    LotHoldData, MovementKind, StockBalance, StockBalanceQuery, StockHoldData, StockHoldError,
+    StockMovement, StockStatus, check_serial_numbers,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+const HOLD_STATUSES: [StockStatus; 3] = [
+    StockStatus::Quarantine,
+    StockStatus::Damaged,
+    StockStatus::OnHold,
+];
+
+pub struct StockHoldService {
+    stock_repository: Box<dyn StockRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl StockHoldService {
+    pub fn new(
+        stock_repository: Box<dyn StockRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> Self {
+        Self {
+            stock_repository,
+            product_repository,
+            lot_repository,
+        }
+    }
+
+    /// Moves unreserved available stock into the hold status, out of reach of allocation.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn hold(&self, args: StockHoldData, user_id: Uuid) -> Result<Vec<StockMovement>> {
+        if args.reason.is_none() {
+            return Err(StockHoldError::ReasonRequired.into());
+        }
+        let to_status = args.status;
+        self.post(
+            MovementKind::StatusChange,
+            StockStatus::Available,
+            Some(to_status),
+            args,
+            user_id,
+        )
+        .await
+        .context("Failed to put stock on hold")
+    }
+
+    /// Makes held stock available again.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn release(&self, args: StockHoldData, user_id: Uuid) -> Result<Vec<StockMovement>> {
+        let from_status = args.status;
+        self.post(
+            MovementKind::StatusChange,
+            from_status,
+            Some(StockStatus::Available),
+            args,
+            user_id,
+        )
+        .await
+        .context("Failed to release held stock")
+    }
+
+    /// Writes held stock off.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn scrap(&self, args: StockHoldData, user_id: Uuid) -> Result<Vec<StockMovement>> {
+        let from_status = args.status;
+        self.post(MovementKind::Adjustment, from_status, None, args, user_id)
+            .await
+            .context("Failed to scrap held stock")
+    }
+
+    /// Puts all available stock of the lot on hold at every location, reserved stock
+    /// included. Reservations the remaining stock no longer covers are released, newest
+    /// first, rather than failing later when they are picked.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn hold_lot(
+        &self,
+        lot_id: Uuid,
+        args: LotHoldData,
+        user_id: Uuid,
+    ) -> Result<Vec<StockMovement>> {
+        check_hold_status(args.status)?;
+        let lot = self.lot_repository.get_by_id(lot_id).await?;
+
+        let balances = self
+            .stock_repository
+            .list_balances(StockBalanceQuery {
+                lot_id: Some(lot.id),
+                statuses: vec![StockStatus::Available],
+                ..Default::default()
+            })
+            .await?;
+        if balances.is_empty() {
+            return Err(StockHoldError::NothingToHold.into());
+        }
+
+        let now = Utc::now();
+        let movements = balances
+            .into_iter()
+            .map(|balance| {
+                status_change(
+                    &balance,
+                    args.status,
+                    args.reason.clone(),
+                    Some(user_id),
+                    now,
+                )
+            })
+            .collect();
+
+        let (movements, released) = self
+            .stock_repository
+            .post_movements_releasing_reservations(movements)
+            .await
+            .context("Failed to put lot on hold")?;
+        if !released.is_empty() {
+            tracing::warn!(
+                lot_id = %lot.id,
+                reservation_ids = ?released.iter().map(|r| r.id).collect::<Vec<_>>(),
+                "Released reservations no longer covered by stock"
+            );
+        }
+
+        Ok(movements)
+    }
+
+    /// Held stock, of the given status or of any hold status.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn held(&self, mut query: StockBalanceQuery) -> Result<Vec<StockBalance>> {
+        query
+            .statuses
+            .retain(|status| *status != StockStatus::Available);
+        if query.statuses.is_empty() {
+            query.statuses = HOLD_STATUSES.to_vec();
+        }
+
+        self.stock_repository
+            .list_balances(query)
+            .await
+            .context("Failed to load held stock")
+    }
+
+    async fn post(
+        &self,
+        kind: MovementKind,
+        status: StockStatus,
+        to_status: Option<StockStatus>,
+        args: StockHoldData,
+        user_id: Uuid,
+    ) -> Result<Vec<StockMovement>> {
+        check_hold_status(args.status)?;
+        let product = self.product_repository.get_by_id(args.product_id).await?;
+        let quantity = to_base_quantity(
+            self.product_repository.as_ref(),
+            &product,
+            args.quantity,
+            args.uom.as_deref(),
+        )
+        .await?;
+        check_serial_numbers(&product, quantity, &args.serial_numbers)?;
+
+        self.stock_repository
+            .post_movement(
+                StockMovement {
+                    id: Uuid::new_v4(),
+                    kind,
+                    product_id: args.product_id,
+                    from_location_id: Some(args.location_id),
+                    to_location_id: to_status.map(|_| args.location_id),
+                    quantity,
+                    document_type: None,
+                    document_id: None,
+                    created_by: Some(user_id),
+                    created_at: Utc::now(),
+                    lot_id: args.lot_id,
+                    unit_cost: None,
+                    total_cost: None,
+                    status,
+                    to_status,
+                    reason: args.reason,
+                },
+                args.serial_numbers,
+            )
+            .await
+    }
+}
+
+fn check_hold_status(status: StockStatus) -> Result<()> {
+    if status == StockStatus::Available {
+        return Err(StockHoldError::NotAHoldStatus.into());
+    }
+
+    Ok(())
+}
+
+fn status_change(
+    balance: &StockBalance,
+    to_status: StockStatus,
+    reason: String,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+) -> StockMovement {
+    StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::StatusChange,
+        product_id: balance.product_id,
+        from_location_id: Some(balance.location_id),
+        to_location_id: Some(balance.location_id),
+        quantity: balance.on_hand,
+        document_type: None,
+        document_id: None,
+        created_by,
+        created_at,
+        lot_id: balance.lot_id,
+        unit_cost: None,
+        total_cost: None,
+        status: balance.status,
+        to_status: Some(to_status),
+        reason: Some(reason),
+    }
+}
@@ -0,0 +1,404 @@
+use crate::contract::repository::{
+    LocationRepository, ProductRepository, TransferOrderRepository, WarehouseRepository,
+};
+use crate::domain::{
+    DocumentType, InTransitQuery, InTransitStock, Location, MovementKind, StockMovement,
+    StockStatus, TransferDiscrepancy, TransferOrder, TransferOrderData, TransferOrderError,
+    TransferOrderLine, TransferOrderStatus, TransferReceipt, TransferShipment,
+    check_serial_numbers,
+};
+use crate::service::product::to_base_quantity;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use uuid::Uuid;
+
+pub struct TransferOrderService {
+    transfer_order_repository: Box<dyn TransferOrderRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+}
+
+impl TransferOrderService {
+    pub fn new(
+        transfer_order_repository: Box<dyn TransferOrderRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            transfer_order_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+        }
+    }
+
+    /// Lines are picked from locations of the source warehouse. Quantities are stored in
+    /// base units.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create(
+        &self,
+        args: TransferOrderData,
+        user_id: Uuid,
+    ) -> Result<(TransferOrder, Vec<TransferOrderLine>)> {
+        if args.source_warehouse_id == args.destination_warehouse_id {
+            return Err(TransferOrderError::SameWarehouse.into());
+        }
+        if args.lines.is_empty() {
+            return Err(TransferOrderError::NoLines.into());
+        }
+        for warehouse_id in [args.source_warehouse_id, args.destination_warehouse_id] {
+            self.warehouse_repository
+                .get_by_id(warehouse_id)
+                .await
+                .context("Failed to find warehouse")?;
+        }
+
+        let order = TransferOrder {
+            id: Uuid::new_v4(),
+            source_warehouse_id: args.source_warehouse_id,
+            destination_warehouse_id: args.destination_warehouse_id,
+            transit_location_id: None,
+            status: TransferOrderStatus::Open,
+            created_by: Some(user_id),
+            created_at: Utc::now(),
+            shipped_at: None,
+            closed_at: None,
+        };
+
+        let mut lines = Vec::with_capacity(args.lines.len());
+        for line in args.lines {
+            self.warehouse_location(line.from_location_id, order.source_warehouse_id)
+                .await?;
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                line.quantity,
+                line.uom.as_deref(),
+            )
+            .await?;
+
+            lines.push(TransferOrderLine {
+                id: Uuid::new_v4(),
+                transfer_order_id: order.id,
+                product_id: line.product_id,
+                from_location_id: line.from_location_id,
+                quantity,
+                received_quantity: Decimal::ZERO,
+                written_off_quantity: Decimal::ZERO,
+            });
+        }
+
+        let order = self
+            .transfer_order_repository
+            .create_with_lines(order, lines.clone())
+            .await
+            .context("Failed to create transfer order")?;
+
+        Ok((order, lines))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get(&self, id: Uuid) -> Result<(TransferOrder, Vec<TransferOrderLine>)> {
+        let order = self.transfer_order_repository.get_by_id(id).await?;
+        let lines = self
+            .transfer_order_repository
+            .list_lines(id)
+            .await
+            .context("Failed to load transfer order lines")?;
+
+        Ok((order, lines))
+    }
+
+    /// Moves every line out of the source warehouse into a transit location of the
+    /// destination warehouse. Serial-tracked lines must name every unit shipped.
+    #[tracing::instrument(skip(self, shipments))]
+    pub async fn ship(
+        &self,
+        id: Uuid,
+        shipments: Vec<TransferShipment>,
+        user_id: Uuid,
+    ) -> Result<(TransferOrder, Vec<TransferOrderLine>)> {
+        let (mut order, lines) = self.get(id).await?;
+        if order.status != TransferOrderStatus::Open {
+            return Err(TransferOrderError::NotOpen.into());
+        }
+        if let Some(shipment) = shipments
+            .iter()
+            .find(|shipment| !lines.iter().any(|line| line.id == shipment.line_id))
+        {
+            return Err(TransferOrderError::UnknownLine)
+                .with_context(|| format!("Line {} is not on the order", shipment.line_id));
+        }
+
+        let now = Utc::now();
+        let transit_location = Location {
+            id: Uuid::new_v4(),
+            warehouse_id: order.destination_warehouse_id,
+            code: format!("TRANSIT-{}", order.id.simple()),
+            zone: None,
+            in_transit: true,
+            quarantine: false,
+            max_volume: None,
+            max_weight: None,
+            position_x: None,
+            position_y: None,
+            pick_sequence: None,
+            attributes: serde_json::json!({}),
+        };
+
+        let mut movements = Vec::with_capacity(lines.len());
+        for line in &lines {
+            let serial_numbers: Vec<String> = shipments
+                .iter()
+                .filter(|shipment| shipment.line_id == line.id)
+                .flat_map(|shipment| shipment.serial_numbers.clone())
+                .collect();
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            check_serial_numbers(&product, line.quantity, &serial_numbers)?;
+
+            movements.push((
+                movement(
+                    &order,
+                    line,
+                    Some(line.from_location_id),
+                    Some(transit_location.id),
+                    line.quantity,
+                    user_id,
+                    now,
+                ),
+                serial_numbers,
+            ));
+        }
+
+        order.status = TransferOrderStatus::Shipped;
+        order.transit_location_id = Some(transit_location.id);
+        order.shipped_at = Some(now);
+
+        let order = self
+            .transfer_order_repository
+            .ship(order, transit_location, movements)
+            .await
+            .context("Failed to ship transfer order")?;
+
+        Ok((order, lines))
+    }
+
+    /// Receives shipped stock into locations of the destination warehouse. A line cannot
+    /// receive more than is still in transit for it.
+    #[tracing::instrument(skip(self, receipts))]
+    pub async fn receive(
+        &self,
+        id: Uuid,
+        receipts: Vec<TransferReceipt>,
+        user_id: Uuid,
+    ) -> Result<(TransferOrder, Vec<TransferOrderLine>, Vec<StockMovement>)> {
+        let (mut order, read_lines) = self.get(id).await?;
+        let read_status = order.status;
+        let transit_location_id = in_transit(&order)?;
+        let mut lines = read_lines.clone();
+
+        let now = Utc::now();
+        let mut movements = Vec::with_capacity(receipts.len());
+        for receipt in receipts {
+            let line = lines
+                .iter_mut()
+                .find(|line| line.id == receipt.line_id)
+                .ok_or(TransferOrderError::UnknownLine)?;
+            self.warehouse_location(receipt.to_location_id, order.destination_warehouse_id)
+                .await?;
+            let product = self.product_repository.get_by_id(line.product_id).await?;
+            let quantity = to_base_quantity(
+                self.product_repository.as_ref(),
+                &product,
+                receipt.quantity,
+                receipt.uom.as_deref(),
+            )
+            .await?;
+            check_serial_numbers(&product, quantity, &receipt.serial_numbers)?;
+
+            if quantity > line.outstanding() {
+                return Err(TransferOrderError::ExceedsInTransit.into());
+            }
+            line.received_quantity += quantity;
+
+            movements.push((
+                movement(
+                    &order,
+                    line,
+                    Some(transit_location_id),
+                    Some(receipt.to_location_id),
+                    quantity,
+                    user_id,
+                    now,
+                ),
+                receipt.serial_numbers,
+            ));
+        }
+
+        order.status = if lines.iter().all(|line| line.outstanding().is_zero()) {
+            TransferOrderStatus::Received
+        } else {
+            TransferOrderStatus::PartiallyReceived
+        };
+
+        let (order, movements) = self
+            .transfer_order_repository
+            .update_with_movements(order, lines.clone(), read_status, read_lines, movements)
+            .await
+            .context("Failed to receive transfer order")?;
+
+        Ok((order, lines, movements))
+    }
+
+    /// Closes the order. Stock still in transit is written off as lost on the way and
+    /// reported as a discrepancy; an order that was never shipped is simply cancelled.
+    #[tracing::instrument(skip(self))]
+    pub async fn close(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(TransferOrder, Vec<TransferOrderLine>)> {
+        let (mut order, read_lines) = self.get(id).await?;
+        let read_status = order.status;
+        let mut lines = read_lines.clone();
+
+        let now = Utc::now();
+        let mut movements: Vec<(StockMovement, Vec<String>)> = Vec::new();
+        if order.status != TransferOrderStatus::Open {
+            let transit_location_id = in_transit(&order)?;
+            for line in lines.iter_mut() {
+                let missing = line.outstanding();
+                if missing.is_zero() {
+                    continue;
+                }
+                line.written_off_quantity += missing;
+
+                // Units of serial-tracked products are written off with the stock, in serial
+                // number order, so none stay in stock at the emptied transit location.
+                let product = self.product_repository.get_by_id(line.product_id).await?;
+                let mut serial_numbers = Vec::new();
+                if product.serial_tracked {
+                    serial_numbers = self
+                        .transfer_order_repository
+                        .list_in_transit_serials(transit_location_id, line.product_id)
+                        .await
+                        .context("Failed to load serial numbers in transit")?;
+                    serial_numbers.retain(|serial_number| {
+                        !movements
+                            .iter()
+                            .any(|(_, taken)| taken.contains(serial_number))
+                    });
+                    serial_numbers.truncate(missing.to_usize().unwrap_or_default());
+                }
+                check_serial_numbers(&product, missing, &serial_numbers)?;
+
+                let mut write_off = movement(
+                    &order,
+                    line,
+                    Some(transit_location_id),
+                    None,
+                    missing,
+                    user_id,
+                    now,
+                );
+                write_off.kind = MovementKind::Adjustment;
+                movements.push((write_off, serial_numbers));
+            }
+        }
+
+        order.status = TransferOrderStatus::Closed;
+        order.closed_at = Some(now);
+
+        let (order, _) = self
+            .transfer_order_repository
+            .update_with_movements(order, lines.clone(), read_status, read_lines, movements)
+            .await
+            .context("Failed to close transfer order")?;
+
+        Ok((order, lines))
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn list_movements(&self, id: Uuid) -> Result<Vec<StockMovement>> {
+        self.transfer_order_repository.get_by_id(id).await?;
+        self.transfer_order_repository
+            .list_movements(id)
+            .await
+            .context("Failed to load transfer order movements")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn in_transit(&self, query: InTransitQuery) -> Result<Vec<InTransitStock>> {
+        self.transfer_order_repository
+            .list_in_transit(query)
+            .await
+            .context("Failed to load stock in transit")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn discrepancies(
+        &self,
+        warehouse_id: Option<Uuid>,
+    ) -> Result<Vec<TransferDiscrepancy>> {
+        self.transfer_order_repository
+            .list_discrepancies(warehouse_id)
+            .await
+            .context("Failed to load transfer discrepancies")
+    }
+
+    /// Stock moves between regular locations only, transit locations are managed by the order.
+    async fn warehouse_location(&self, location_id: Uuid, warehouse_id: Uuid) -> Result<Location> {
+        let location = self.location_repository.get_by_id(location_id).await?;
+        if location.warehouse_id != warehouse_id || location.in_transit {
+            return Err(TransferOrderError::LocationNotInWarehouse.into());
+        }
+
+        Ok(location)
+    }
+}
+
+/// Transit location of an order that still has stock on the way.
+fn in_transit(order: &TransferOrder) -> Result<Uuid> {
+    match (order.status, order.transit_location_id) {
+        (
+            TransferOrderStatus::Shipped | TransferOrderStatus::PartiallyReceived,
+            Some(location_id),
+        ) => Ok(location_id),
+        (TransferOrderStatus::Open, _) => Err(TransferOrderError::NotInTransit.into()),
+        _ => Err(TransferOrderError::Completed.into()),
+    }
+}
+
+fn movement(
+    order: &TransferOrder,
+    line: &TransferOrderLine,
+    from_location_id: Option<Uuid>,
+    to_location_id: Option<Uuid>,
+    quantity: Decimal,
+    user_id: Uuid,
+    now: DateTime<Utc>,
+) -> StockMovement {
+    StockMovement {
+        id: Uuid::new_v4(),
+        kind: MovementKind::Transfer,
+        product_id: line.product_id,
+        from_location_id,
+        to_location_id,
+        quantity,
+        document_type: Some(DocumentType::TransferOrder),
+        document_id: Some(order.id),
+        created_by: Some(user_id),
+        created_at: now,
+        lot_id: None,
+        unit_cost: None,
+        total_cost: None,
+        status: StockStatus::Available,
+        to_status: None,
+        reason: None,
+    }
+}
@@ -0,0 +1,45 @@
+use crate::contract::repository::ValuationRepository;
+use crate::domain::{CostingMethod, Product, ProductValuation, ValuationQuery, ValuationSettings};
+use anyhow::Result;
+use uuid::Uuid;
+
+pub struct ValuationService {
+    valuation_repository: Box<dyn ValuationRepository>,
+}
+
+impl ValuationService {
+    pub fn new(valuation_repository: Box<dyn ValuationRepository>) -> Self {
+        Self {
+            valuation_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_settings(&self) -> Result<ValuationSettings> {
+        self.valuation_repository.get_settings().await
+    }
+
+    /// Changing the costing method affects issues posted from now on. Products valued at
+    /// weighted average start from the average of their remaining FIFO layers.
+    #[tracing::instrument(skip(self, settings))]
+    pub async fn update_settings(&self, settings: ValuationSettings) -> Result<ValuationSettings> {
+        self.valuation_repository.update_settings(settings).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn set_costing_method(
+        &self,
+        product_id: Uuid,
+        costing_method: Option<CostingMethod>,
+    ) -> Result<Product> {
+        self.valuation_repository
+            .set_costing_method(product_id, costing_method)
+            .await
+    }
+
+    /// Quantity and value per product from the movements posted up to the query's point in time.
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_valuations(&self, query: ValuationQuery) -> Result<Vec<ProductValuation>> {
+        self.valuation_repository.list_valuations(query).await
+    }
+}
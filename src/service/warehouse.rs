@@ -0,0 +1,120 @@
+use crate::contract::repository::{AttributeRepository, LocationRepository, WarehouseRepository};
+use crate::domain::{
+    AttributeEntity, Location, LocationData, LocationListQuery, LocationQuery, Page, Warehouse,
+    WarehouseData,
+};
+use crate::service::attribute::attribute_values;
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+pub struct WarehouseService {
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    attribute_repository: Box<dyn AttributeRepository>,
+}
+
+impl WarehouseService {
+    pub fn new(
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        attribute_repository: Box<dyn AttributeRepository>,
+    ) -> Self {
+        Self {
+            warehouse_repository,
+            location_repository,
+            attribute_repository,
+        }
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create_warehouse(&self, args: WarehouseData) -> Result<Warehouse> {
+        self.warehouse_repository
+            .create(Warehouse {
+                id: Uuid::new_v4(),
+                code: args.code,
+                name: args.name,
+            })
+            .await
+            .context("Failed to create warehouse")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_warehouse(&self, id: Uuid) -> Result<Warehouse> {
+        self.warehouse_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self, args))]
+    pub async fn create_location(&self, args: LocationData) -> Result<Location> {
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+        let attributes = attribute_values(
+            self.attribute_repository.as_ref(),
+            AttributeEntity::Location,
+            args.attributes,
+        )
+        .await?;
+
+        self.location_repository
+            .create(Location {
+                id: Uuid::new_v4(),
+                warehouse_id: args.warehouse_id,
+                code: args.code,
+                zone: args.zone,
+                in_transit: false,
+                quarantine: args.quarantine,
+                max_volume: args.max_volume,
+                max_weight: args.max_weight,
+                position_x: args.position_x,
+                position_y: args.position_y,
+                pick_sequence: args.pick_sequence,
+                attributes,
+            })
+            .await
+            .context("Failed to create location")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_location(&self, id: Uuid) -> Result<Location> {
+        self.location_repository.get_by_id(id).await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_locations(&self, query: LocationQuery) -> Result<Vec<Location>> {
+        self.location_repository
+            .list(query)
+            .await
+            .context("Failed to load locations")
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn page_locations(&self, query: LocationListQuery) -> Result<Page<Location>> {
+        self.location_repository
+            .page(query)
+            .await
+            .context("Failed to load locations")
+    }
+
+    /// Replaces the custom attribute values of the location.
+    #[tracing::instrument(skip(self, values))]
+    pub async fn set_location_attributes(
+        &self,
+        id: Uuid,
+        values: Map<String, Value>,
+    ) -> Result<Location> {
+        self.location_repository.get_by_id(id).await?;
+        let attributes = attribute_values(
+            self.attribute_repository.as_ref(),
+            AttributeEntity::Location,
+            values,
+        )
+        .await?;
+
+        self.location_repository
+            .set_attributes(id, attributes)
+            .await
+            .context("Failed to save location attributes")
+    }
+}
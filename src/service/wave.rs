@@ -0,0 +1,349 @@
+use crate::contract::repository::{
+    LocationRepository, LotRepository, ProductRepository, ReservationRepository,
+    WarehouseRepository, WaveRepository,
+};
+use crate::domain::{
+    DocumentType, Location, LotError, PickConfirmation, PickOrder, PickOrderData, PickOrderQuery,
+    PickOrderStatus, PickTask, PickTaskStatus, Reservation, StockMovement, Wave, WaveCriteria,
+    WaveError, WaveStatus, check_serial_numbers, pick_path,
+};
+use crate::service::product::to_base_quantity;
+use crate::service::scan::scan_gs1;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct WaveService {
+    wave_repository: Box<dyn WaveRepository>,
+    reservation_repository: Box<dyn ReservationRepository>,
+    warehouse_repository: Box<dyn WarehouseRepository>,
+    location_repository: Box<dyn LocationRepository>,
+    product_repository: Box<dyn ProductRepository>,
+    lot_repository: Box<dyn LotRepository>,
+}
+
+impl WaveService {
+    pub fn new(
+        wave_repository: Box<dyn WaveRepository>,
+        reservation_repository: Box<dyn ReservationRepository>,
+        warehouse_repository: Box<dyn WarehouseRepository>,
+        location_repository: Box<dyn LocationRepository>,
+        product_repository: Box<dyn ProductRepository>,
+        lot_repository: Box<dyn LotRepository>,
+    ) -> Self {
+        Self {
+            wave_repository,
+            reservation_repository,
+            warehouse_repository,
+            location_repository,
+            product_repository,
+            lot_repository,
+        }
+    }
+
+    /// Releases a sales order to the warehouse for picking. The order must have stock
+    /// reserved at locations of the warehouse.
+    #[tracing::instrument(skip(self, args))]
+    pub async fn release_order(&self, args: PickOrderData, user_id: Uuid) -> Result<PickOrder> {
+        self.warehouse_repository
+            .get_by_id(args.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+
+        let now = Utc::now();
+        let mut locations = HashMap::new();
+        let lines = self
+            .order_lines(args.warehouse_id, args.document_id, now, &mut locations)
+            .await?;
+        if lines.is_empty() {
+            return Err(WaveError::NothingToPick.into());
+        }
+
+        self.wave_repository
+            .create_order(PickOrder {
+                id: Uuid::new_v4(),
+                warehouse_id: args.warehouse_id,
+                document_id: args.document_id,
+                priority: args.priority,
+                carrier: args.carrier,
+                cutoff_at: args.cutoff_at,
+                status: PickOrderStatus::Released,
+                wave_id: None,
+                tote: None,
+                created_by: Some(user_id),
+                created_at: now,
+            })
+            .await
+            .context("Failed to release order")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_order(&self, id: Uuid) -> Result<PickOrder> {
+        self.wave_repository.get_order(id).await
+    }
+
+    #[tracing::instrument(skip(self, query))]
+    pub async fn list_orders(&self, query: PickOrderQuery) -> Result<Vec<PickOrder>> {
+        self.wave_repository
+            .list_orders(query)
+            .await
+            .context("Failed to load pick orders")
+    }
+
+    /// Withdraws an order not yet waved. Its reservations are left as they are.
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel_order(&self, id: Uuid) -> Result<PickOrder> {
+        let mut order = self.wave_repository.get_order(id).await?;
+        if order.status != PickOrderStatus::Released {
+            return Err(WaveError::OrderNotReleased.into());
+        }
+
+        order.status = PickOrderStatus::Cancelled;
+        self.wave_repository
+            .update_order(order)
+            .await
+            .context("Failed to cancel pick order")
+    }
+
+    /// Groups the most urgent released orders matching the criteria into a wave, with one
+    /// task per reserved line numbered along the pick path. Cluster-picked waves give
+    /// every order its own tote.
+    #[tracing::instrument(skip(self, criteria))]
+    pub async fn create_wave(
+        &self,
+        criteria: WaveCriteria,
+        user_id: Uuid,
+    ) -> Result<(Wave, Vec<PickOrder>, Vec<PickTask>)> {
+        self.warehouse_repository
+            .get_by_id(criteria.warehouse_id)
+            .await
+            .context("Failed to find warehouse")?;
+
+        let mut candidates = self
+            .list_orders(PickOrderQuery {
+                warehouse_id: Some(criteria.warehouse_id),
+                status: Some(PickOrderStatus::Released),
+            })
+            .await?;
+        candidates.retain(|order| criteria.matches(order));
+        candidates.sort_by_key(PickOrder::urgency_key);
+
+        let now = Utc::now();
+        let limit = criteria.limit().unwrap_or(usize::MAX);
+        let mut locations = HashMap::new();
+        let mut selected = Vec::new();
+        for order in candidates {
+            if selected.len() >= limit {
+                break;
+            }
+
+            let lines = self
+                .order_lines(order.warehouse_id, order.document_id, now, &mut locations)
+                .await?;
+            let in_zone = |line: &Reservation| {
+                criteria
+                    .zone
+                    .as_ref()
+                    .is_none_or(|zone| locations[&line.location_id].zone.as_ref() == Some(zone))
+            };
+            if lines.is_empty() || !lines.iter().all(in_zone) {
+                continue;
+            }
+
+            selected.push((order, lines));
+        }
+        if selected.is_empty() {
+            return Err(WaveError::NoMatchingOrders.into());
+        }
+
+        let wave = Wave {
+            id: Uuid::new_v4(),
+            warehouse_id: criteria.warehouse_id,
+            status: WaveStatus::Open,
+            totes: criteria.totes,
+            created_by: Some(user_id),
+            created_at: now,
+            completed_at: None,
+        };
+
+        let mut stops = selected
+            .iter()
+            .flat_map(|(_, lines)| lines.iter().map(|line| line.location_id))
+            .collect::<Vec<_>>();
+        stops.sort();
+        stops.dedup();
+        let stop_index = pick_path(stops.iter().map(|id| locations[id].clone()).collect())
+            .into_iter()
+            .enumerate()
+            .map(|(index, location)| (location.id, index))
+            .collect::<HashMap<_, _>>();
+
+        let mut orders = Vec::with_capacity(selected.len());
+        let mut tasks = Vec::new();
+        for (tote, (mut order, lines)) in (1..).zip(selected) {
+            order.tote = wave.totes.map(|_| tote);
+            for line in lines {
+                tasks.push(PickTask {
+                    id: Uuid::new_v4(),
+                    wave_id: wave.id,
+                    pick_order_id: order.id,
+                    reservation_id: line.id,
+                    product_id: line.product_id,
+                    location_id: line.location_id,
+                    quantity: line.quantity,
+                    picked_quantity: None,
+                    sequence: 0,
+                    tote: order.tote,
+                    status: PickTaskStatus::Open,
+                    picked_by: None,
+                    picked_at: None,
+                });
+            }
+            orders.push(order);
+        }
+        // Stable, so tasks at the same stop keep the urgency order of their orders.
+        tasks.sort_by_key(|task| stop_index[&task.location_id]);
+        for (sequence, task) in (1..).zip(tasks.iter_mut()) {
+            task.sequence = sequence;
+        }
+
+        let wave = self
+            .wave_repository
+            .create_with_tasks(wave, orders, tasks)
+            .await
+            .context("Failed to create wave")?;
+        self.get_wave(wave.id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn get_wave(&self, id: Uuid) -> Result<(Wave, Vec<PickOrder>, Vec<PickTask>)> {
+        let wave = self.wave_repository.get_by_id(id).await?;
+        let orders = self
+            .wave_repository
+            .list_wave_orders(id)
+            .await
+            .context("Failed to load wave orders")?;
+        let tasks = self
+            .wave_repository
+            .list_tasks(id)
+            .await
+            .context("Failed to load pick tasks")?;
+
+        Ok((wave, orders, tasks))
+    }
+
+    /// Picks the task, issuing the stock out of its location against the reservation.
+    /// Picking less than the task asks for leaves the rest reserved for a later wave.
+    /// A scan of the goods picked must be of the task's product and fills in what is not given.
+    #[tracing::instrument(skip(self, confirmation))]
+    pub async fn confirm_task(
+        &self,
+        id: Uuid,
+        mut confirmation: PickConfirmation,
+        user_id: Uuid,
+    ) -> Result<(PickTask, Vec<StockMovement>)> {
+        let task = self.wave_repository.get_task(id).await?;
+        if task.status != PickTaskStatus::Open {
+            return Err(WaveError::TaskNotOpen.into());
+        }
+        if let Some(data) = confirmation.scan.take() {
+            let scan = scan_gs1(self.product_repository.as_ref(), &data).await?;
+            confirmation.apply_scan(&scan, task.product_id)?;
+        }
+
+        let product = self.product_repository.get_by_id(task.product_id).await?;
+        let quantity = match confirmation.quantity {
+            Some(quantity) => {
+                to_base_quantity(
+                    self.product_repository.as_ref(),
+                    &product,
+                    quantity,
+                    confirmation.uom.as_deref(),
+                )
+                .await?
+            }
+            None => task.quantity,
+        };
+        if quantity > task.quantity {
+            return Err(WaveError::ExceedsTask.into());
+        }
+        check_serial_numbers(&product, quantity, &confirmation.serial_numbers)?;
+
+        let now = Utc::now();
+        let lot_id = if confirmation.lot_number.is_some() || confirmation.expiry_date.is_some() {
+            let lot = self
+                .lot_repository
+                .find(
+                    task.product_id,
+                    confirmation.lot_number,
+                    confirmation.expiry_date,
+                )
+                .await?;
+            if !lot.is_usable_on(now.date_naive()) {
+                return Err(LotError::Blocked.into());
+            }
+            Some(lot.id)
+        } else {
+            None
+        };
+
+        self.wave_repository
+            .pick(
+                id,
+                quantity,
+                lot_id,
+                confirmation.serial_numbers,
+                user_id,
+                now,
+            )
+            .await
+            .context("Failed to confirm pick task")
+    }
+
+    /// Cancels the open tasks of the wave. Orders not picked in full are released again
+    /// and keep what is still reserved for them.
+    #[tracing::instrument(skip(self))]
+    pub async fn cancel_wave(&self, id: Uuid) -> Result<(Wave, Vec<PickOrder>, Vec<PickTask>)> {
+        self.wave_repository
+            .cancel(id)
+            .await
+            .context("Failed to cancel wave")?;
+        self.get_wave(id).await
+    }
+
+    /// Active reservations of the sales order at locations of the warehouse, with their
+    /// locations loaded into `locations`.
+    async fn order_lines(
+        &self,
+        warehouse_id: Uuid,
+        document_id: Uuid,
+        now: DateTime<Utc>,
+        locations: &mut HashMap<Uuid, Location>,
+    ) -> Result<Vec<Reservation>> {
+        let reservations = self
+            .reservation_repository
+            .list_by_document(DocumentType::SalesOrder, document_id)
+            .await
+            .context("Failed to load order reservations")?;
+
+        let mut lines = Vec::new();
+        for reservation in reservations {
+            if !reservation.is_active_at(now) || reservation.quantity.is_zero() {
+                continue;
+            }
+            if !locations.contains_key(&reservation.location_id) {
+                let location = self
+                    .location_repository
+                    .get_by_id(reservation.location_id)
+                    .await?;
+                locations.insert(location.id, location);
+            }
+            if locations[&reservation.location_id].warehouse_id == warehouse_id {
+                lines.push(reservation);
+            }
+        }
+
+        Ok(lines)
+    }
+}
@@ -0,0 +1,24 @@
+//! Backends attachment contents are kept in: a directory of the local filesystem, or a
+//! bucket of an S3-compatible service such as AWS S3 or MinIO.
+
+use crate::config::{StorageBackend, StorageConfig};
+use crate::contract::storage::Storage;
+use anyhow::{Context, Result};
+use std::sync::Arc;
+
+mod local;
+mod s3;
+
+pub use local::LocalStorage;
+pub use s3::S3Storage;
+
+/// Built once at startup, so that a backend that cannot be configured stops the server.
+pub fn storage(config: &StorageConfig) -> Result<Arc<dyn Storage>> {
+    Ok(match config.backend {
+        StorageBackend::Local => Arc::new(LocalStorage::new(&config.path)),
+        StorageBackend::S3 => Arc::new(
+            S3Storage::new(&config.bucket, config.endpoint.as_deref())
+                .context("configure S3 storage")?,
+        ),
+    })
+}
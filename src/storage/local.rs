@@ -0,0 +1,51 @@
+use crate::contract::storage::Storage;
+use anyhow::{Context, Result};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+/// Keeps every content in a file named by its key under the root directory. Keys are
+/// generated by the system, never taken from uploads, so they cannot escape the root.
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for LocalStorage {
+    async fn put(&self, key: &str, content: Vec<u8>, _content_type: &str) -> Result<()> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("create storage directory")?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .with_context(|| format!("write {}", path.display()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        let path = self.root.join(key);
+
+        tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("read {}", path.display()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.root.join(key);
+
+        match tokio::fs::remove_file(&path).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("remove {}", path.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
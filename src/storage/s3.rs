@@ -0,0 +1,68 @@
+use crate::contract::storage::Storage;
+use anyhow::{Context, Result};
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::path::Path;
+use object_store::{Attribute, Attributes, ObjectStore, PutOptions, PutPayload};
+
+/// Keeps contents as objects of a bucket. Objects are addressed by path, so any
+/// S3-compatible service works when its endpoint is given.
+pub struct S3Storage {
+    store: AmazonS3,
+}
+
+impl S3Storage {
+    pub fn new(bucket: &str, endpoint: Option<&str>) -> Result<Self> {
+        let mut builder = AmazonS3Builder::from_env().with_bucket_name(bucket);
+        if let Some(endpoint) = endpoint {
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_allow_http(endpoint.starts_with("http://"));
+        }
+
+        Ok(Self {
+            store: builder.build().context("build S3 client")?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for S3Storage {
+    async fn put(&self, key: &str, content: Vec<u8>, content_type: &str) -> Result<()> {
+        let mut attributes = Attributes::new();
+        attributes.insert(Attribute::ContentType, content_type.to_string().into());
+
+        self.store
+            .put_opts(
+                &Path::from(key),
+                PutPayload::from(content),
+                PutOptions {
+                    attributes,
+                    ..Default::default()
+                },
+            )
+            .await
+            .with_context(|| format!("put object {key}"))?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>> {
+        let content = self
+            .store
+            .get(&Path::from(key))
+            .await
+            .with_context(|| format!("get object {key}"))?
+            .bytes()
+            .await
+            .with_context(|| format!("read object {key}"))?;
+
+        Ok(content.to_vec())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match self.store.delete(&Path::from(key)).await {
+            Err(object_store::Error::NotFound { .. }) | Ok(()) => Ok(()),
+            Err(err) => Err(err).with_context(|| format!("delete object {key}")),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::web::component::{SideBar, TopBar};
-use crate::web::page::{HomePage, NotFound, SignIn, SignUp};
+use crate::web::page::{HomePage, NewProduct, NotFound, SignIn, SignUp};
 use leptos::prelude::*;
 use leptos_meta::{MetaTags, Stylesheet, Title, provide_meta_context};
 use leptos_router::{
@@ -42,6 +42,10 @@ pub fn App() -> impl IntoView {
                         <Route path=StaticSegment("") view=HomePage />
                         <Route path=StaticSegment("/sign-in") view=SignIn />
                         <Route path=StaticSegment("/sign-up") view=SignUp />
+                        <Route
+                            path=(StaticSegment("products"), StaticSegment("new"))
+                            view=NewProduct
+                        />
                     </Routes>
                 </main>
             </div>
@@ -1,9 +1,11 @@
+mod attributes;
 mod auth;
 mod error;
 mod side_bar;
 mod toast;
 mod top_bar;
 
+pub use attributes::*;
 pub use auth::*;
 pub use error::*;
 pub use side_bar::*;
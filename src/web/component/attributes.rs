@@ -0,0 +1,182 @@
+use crate::domain::{AttributeEntity, AttributeKind};
+use crate::dto::{AppError, AttributeDefinitionResponse};
+use crate::web::client::CustomClient;
+use crate::web::component::{ErrorToast, WebError};
+use leptos::prelude::*;
+use serde_json::{Map, Number, Value};
+
+/// Inputs for the custom attributes the organization defined for the entity type, in
+/// their defined order. Values are kept in `values` by key; clearing an input removes it.
+#[component]
+pub fn AttributeFields(
+    entity: AttributeEntity,
+    values: RwSignal<Map<String, Value>>,
+) -> impl IntoView {
+    let definitions = LocalResource::new(move || list_attribute_definitions(entity));
+
+    view! {
+        <ErrorToast>
+            {move || {
+                definitions
+                    .get()
+                    .map(|definitions| {
+                        definitions
+                            .map(|definitions| {
+                                definitions
+                                    .into_iter()
+                                    .map(|definition| {
+                                        view! { <AttributeField definition values /> }
+                                    })
+                                    .collect_view()
+                            })
+                            .map_err(WebError::from)
+                    })
+            }}
+        </ErrorToast>
+    }
+}
+
+#[component]
+fn AttributeField(
+    definition: AttributeDefinitionResponse,
+    values: RwSignal<Map<String, Value>>,
+) -> impl IntoView {
+    let AttributeDefinitionResponse {
+        key,
+        label,
+        kind,
+        required,
+        options,
+        ..
+    } = definition;
+    let value = {
+        let key = key.clone();
+        move || values.with(|values| values.get(&key).cloned())
+    };
+    let set = move |value: Option<Value>| {
+        values.update(|values| match value {
+            Some(value) => {
+                values.insert(key.clone(), value);
+            }
+            None => {
+                values.remove(&key);
+            }
+        })
+    };
+
+    let input = match kind {
+        AttributeKind::String | AttributeKind::Date => view! {
+            <input
+                type=if kind == AttributeKind::Date { "date" } else { "text" }
+                required=required
+                prop:value=move || display(value())
+                on:input:target=move |ev| set(non_empty(ev.target().value()).map(Value::String))
+            />
+        }
+        .into_any(),
+        AttributeKind::Number => view! {
+            <input
+                type="number"
+                step="any"
+                required=required
+                prop:value=move || display(value())
+                on:input:target=move |ev| {
+                    set(
+                        ev
+                            .target()
+                            .value()
+                            .parse::<f64>()
+                            .ok()
+                            .and_then(Number::from_f64)
+                            .map(Value::Number),
+                    )
+                }
+            />
+        }
+        .into_any(),
+        AttributeKind::Boolean => view! {
+            <input
+                type="checkbox"
+                prop:checked=move || value() == Some(Value::Bool(true))
+                on:change:target=move |ev| set(Some(Value::Bool(ev.target().checked())))
+            />
+        }
+        .into_any(),
+        AttributeKind::Enum => view! {
+            <select
+                required=required
+                prop:value=move || display(value())
+                on:change:target=move |ev| set(non_empty(ev.target().value()).map(Value::String))
+            >
+                <option value="">"-"</option>
+                {options
+                    .into_iter()
+                    .map(|option| {
+                        let label = option.clone();
+                        view! { <option value=option>{label}</option> }
+                    })
+                    .collect_view()}
+            </select>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <label class="attribute-field">
+            {label}
+            {required.then_some(" *")}
+            {input}
+        </label>
+    }
+}
+
+fn display(value: Option<Value>) -> String {
+    match value {
+        Some(Value::String(value)) => value,
+        Some(Value::Number(value)) => value.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    Some(value).filter(|value| !value.is_empty())
+}
+
+#[tracing::instrument]
+#[server(client=CustomClient)]
+#[middleware(crate::web::middleware::AuthorizationLayer)]
+async fn list_attribute_definitions(
+    entity: AttributeEntity,
+) -> Result<Vec<AttributeDefinitionResponse>, AppError> {
+    use crate::domain::{ResourceAction, ResourceType};
+    use crate::web::utils::{expect_access_token, expect_app_state};
+
+    let claims = expect_access_token().claims;
+    let state = expect_app_state();
+
+    state
+        .dependencies
+        .auth_service()
+        .await
+        .as_member(claims.organization_id, claims.id, async {
+            state
+                .dependencies
+                .authorization_service()
+                .await
+                .authorize(
+                    claims.id,
+                    ResourceAction::List,
+                    ResourceType::AttributeDefinition,
+                )
+                .await?;
+
+            let definitions = state
+                .dependencies
+                .attribute_service()
+                .await
+                .list(entity)
+                .await?;
+            Ok(definitions.into_iter().map(Into::into).collect())
+        })
+        .await?
+}
@@ -1,4 +1,4 @@
-use crate::dto::AppError;
+use crate::dto::{AppError, field_errors};
 use leptos::attr::any_attribute::AnyAttribute;
 use leptos::prelude::*;
 use leptos::tachys;
@@ -7,6 +7,7 @@ use leptos::tachys::no_attrs;
 use leptos::tachys::renderer::CastFrom;
 use leptos::tachys::renderer::Rndr;
 use leptos::tachys::view::{Position, PositionState};
+use std::collections::BTreeMap;
 use validator::ValidationErrors;
 
 no_attrs!(WebError);
@@ -16,17 +17,28 @@ pub struct WebError(pub String);
 
 impl From<ValidationErrors> for WebError {
     fn from(err: ValidationErrors) -> Self {
-        //TODO: error message
-        Self(err.to_string())
+        Self(fields_message(field_errors(&err)))
     }
 }
 
 impl From<AppError> for WebError {
     fn from(value: AppError) -> Self {
-        Self(value.message)
+        match value.errors.is_empty() {
+            true => Self(value.detail.unwrap_or(value.message)),
+            false => Self(fields_message(value.errors)),
+        }
     }
 }
 
+/// One sentence per invalid field, e.g. `email must be an email address`.
+fn fields_message(errors: BTreeMap<String, Vec<String>>) -> String {
+    errors
+        .into_iter()
+        .map(|(field, messages)| format!("{field} {}", messages.join(", ")))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl From<WebError> for Error {
     fn from(val: WebError) -> Self {
         Error::from(val.0)
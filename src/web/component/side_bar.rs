@@ -13,6 +13,7 @@ pub fn SideBar() -> impl IntoView {
             <aside class="side-bar">
                 <nav class="sidebar-nav">
                     <A href="">"Home"</A>
+                    <A href="/products/new">"New product"</A>
                 </nav>
             </aside>
         </Show>
@@ -8,8 +8,8 @@ impl FromServerFnError for AppError {
 
     fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
         Self {
-            code: ErrorCode::UnexpectedError,
             message: value.to_string(),
+            ..Self::from(ErrorCode::UnexpectedError)
         }
     }
 }
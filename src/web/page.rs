@@ -1,9 +1,11 @@
 mod home;
+mod new_product;
 mod not_found;
 mod sign_in;
 mod sign_up;
 
 pub use home::HomePage;
+pub use new_product::NewProduct;
 pub use not_found::NotFound;
 pub use sign_in::SignIn;
 pub use sign_up::SignUp;
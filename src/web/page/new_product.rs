@@ -0,0 +1,107 @@
+use crate::domain::AttributeEntity;
+use crate::dto::{AppError, CreateProductRequest, ProductResponse};
+use crate::web::client::CustomClient;
+use crate::web::component::{AttributeFields, Authorized, ErrorToast, Toast, WebError};
+use leptos::prelude::*;
+use serde_json::Map;
+use validator::Validate;
+
+#[component]
+pub fn NewProduct() -> impl IntoView {
+    let (sku, set_sku) = signal(String::new());
+    let (name, set_name) = signal(String::new());
+    let attributes = RwSignal::new(Map::new());
+
+    let req = move || CreateProductRequest {
+        sku: sku.get(),
+        name: name.get(),
+        description: None,
+        abc_class: None,
+        lot_tracked: false,
+        expiry_tracked: false,
+        serial_tracked: false,
+        base_uom: "ea".to_string(),
+        fractional_quantities: false,
+        costing_method: None,
+        category: None,
+        unit_volume: None,
+        unit_weight: None,
+        attributes: attributes.get(),
+    };
+
+    let create_action =
+        Action::<CreateProductRequest, Result<ProductResponse, WebError>>::new(move |input| {
+            let input = input.to_owned();
+            async move {
+                input.validate()?;
+                Ok(create_product(input).await?)
+            }
+        });
+
+    view! {
+        <Authorized>
+            <form on:submit=move |ev| {
+                ev.prevent_default();
+                create_action.dispatch(req());
+            }>
+                <input
+                    type="text"
+                    placeholder="SKU"
+                    on:input:target=move |ev| set_sku.set(ev.target().value())
+                    prop:value=sku
+                />
+                <input
+                    type="text"
+                    placeholder="Name"
+                    on:input:target=move |ev| set_name.set(ev.target().value())
+                    prop:value=name
+                />
+                <AttributeFields entity=AttributeEntity::Product values=attributes />
+                <button type="submit">Create product</button>
+            </form>
+            <Toast when=move || create_action.pending().get()>
+                <p>"Creating product..."</p>
+            </Toast>
+            <Toast when=move || matches!(create_action.value().get(), Some(Ok(_)))>
+                <p>"Product created"</p>
+            </Toast>
+            <ErrorToast>
+                {move || create_action.value().get().map(|result| result.map(|_| ()))}
+            </ErrorToast>
+        </Authorized>
+    }
+}
+
+#[tracing::instrument(skip(req))]
+#[server(client=CustomClient)]
+#[middleware(crate::web::middleware::AuthorizationLayer)]
+async fn create_product(req: CreateProductRequest) -> Result<ProductResponse, AppError> {
+    use crate::domain::{ResourceAction, ResourceType};
+    use crate::web::utils::{expect_access_token, expect_app_state};
+
+    req.validate()?;
+    let claims = expect_access_token().claims;
+    let state = expect_app_state();
+
+    state
+        .dependencies
+        .auth_service()
+        .await
+        .as_member(claims.organization_id, claims.id, async {
+            state
+                .dependencies
+                .authorization_service()
+                .await
+                .authorize(claims.id, ResourceAction::Create, ResourceType::Product)
+                .await?;
+
+            let product = state
+                .dependencies
+                .product_service()
+                .await
+                .create(req.into())
+                .await?;
+            Ok(product.into())
+        })
+        .await?
+}
@@ -0,0 +1,265 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{AbcClass, SlottingStatus};
+use warehouse::dto::{
+    AbcAnalysisDetailResponse, AbcAnalysisResponse, AppError, LocationResponse, ProductResponse,
+    SlottingRecommendationResponse, StockLevelResponse,
+};
+
+async fn create_product(app: &TestApp<'_>, name: &str) -> Uuid {
+    let sku = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+    app.post("/products", serde_json::json!({ "sku": sku, "name": name }))
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id
+}
+
+async fn create_location(
+    app: &TestApp<'_>,
+    warehouse_id: Uuid,
+    mut body: serde_json::Value,
+) -> Uuid {
+    body["warehouse_id"] = serde_json::json!(warehouse_id);
+    app.post("/locations", body)
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id
+}
+
+async fn issue(app: &TestApp<'_>, fixture: &StockFixture, times: u32) {
+    for _ in 0..times {
+        let response = app
+            .post(
+                "/stock/movements",
+                serde_json::json!({
+                    "kind": "issue",
+                    "product_id": fixture.product_id,
+                    "from_location_id": fixture.location_id,
+                    "quantity": 1,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 201);
+    }
+}
+
+/// A slow mover sits in the first location of the pick path while a fast mover sits in
+/// a far bin. Returns the slow and the fast mover.
+async fn stock_warehouse(app: &TestApp<'_>) -> (StockFixture, StockFixture) {
+    let fixture = app.create_stock_fixture().await;
+    let slow = StockFixture {
+        location_id: create_location(
+            app,
+            fixture.warehouse_id,
+            serde_json::json!({ "code": "PICK-01", "pick_sequence": 1 }),
+        )
+        .await,
+        ..fixture
+    };
+    let fast = StockFixture {
+        product_id: create_product(app, "Fast mover").await,
+        location_id: create_location(
+            app,
+            fixture.warehouse_id,
+            serde_json::json!({ "code": "Z-99", "position_x": 50, "position_y": 0 }),
+        )
+        .await,
+        ..fixture
+    };
+    app.receive(&slow, 10).await;
+    app.receive(&fast, 10).await;
+    issue(app, &slow, 1).await;
+    issue(app, &fast, 3).await;
+
+    (slow, fast)
+}
+
+async fn run(app: &TestApp<'_>, warehouse_id: Uuid) -> AbcAnalysisDetailResponse {
+    let response = app
+        .post(
+            "/abc-analyses",
+            serde_json::json!({
+                "warehouse_id": warehouse_id,
+                "criterion": "pick_frequency",
+                "a_share": 0.5,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<AbcAnalysisDetailResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn analysis_classifies_by_pick_frequency_and_recommends_swap() {
+    // Arrange
+    let app = spawn_app().await;
+    let (slow, fast) = stock_warehouse(&app).await;
+
+    // Act
+    let analysis = run(&app, slow.warehouse_id).await;
+
+    // Assert
+    assert_eq!(
+        analysis
+            .lines
+            .iter()
+            .map(|line| (line.product_id, line.score, line.abc_class))
+            .collect::<Vec<_>>(),
+        vec![
+            (fast.product_id, Decimal::from(3), AbcClass::A),
+            (slow.product_id, Decimal::from(1), AbcClass::B),
+        ]
+    );
+    assert_eq!(analysis.lines[0].share, Decimal::new(75, 2));
+    assert_eq!(
+        analysis
+            .recommendations
+            .iter()
+            .map(|recommendation| (
+                recommendation.product_id,
+                recommendation.from_location_id,
+                recommendation.to_location_id,
+                recommendation.displaced_product_id,
+            ))
+            .collect::<Vec<_>>(),
+        vec![(
+            fast.product_id,
+            fast.location_id,
+            slow.location_id,
+            Some(slow.product_id)
+        )]
+    );
+
+    let analyses = app
+        .get(&format!("/abc-analyses?warehouse_id={}", slow.warehouse_id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<AbcAnalysisResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        analyses
+            .iter()
+            .map(|analysis| analysis.id)
+            .collect::<Vec<_>>(),
+        vec![analysis.analysis.id]
+    );
+}
+
+#[tokio::test]
+async fn accepting_recommendation_swaps_stock_of_both_products() {
+    // Arrange
+    let app = spawn_app().await;
+    let (slow, fast) = stock_warehouse(&app).await;
+    let analysis = run(&app, slow.warehouse_id).await;
+
+    // Act
+    let response = app
+        .post(
+            &format!(
+                "/slotting-recommendations/{}/accept",
+                analysis.recommendations[0].id
+            ),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let recommendation = response
+        .json::<SlottingRecommendationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(recommendation.status, SlottingStatus::Accepted);
+
+    let levels = app
+        .get(&format!("/stock/levels?warehouse_id={}", slow.warehouse_id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    let mut positions = levels
+        .iter()
+        .filter(|level| !level.on_hand.is_zero())
+        .map(|level| (level.product_id, level.location_id, level.on_hand))
+        .collect::<Vec<_>>();
+    positions.sort();
+    let mut expected = vec![
+        (fast.product_id, slow.location_id, Decimal::from(7)),
+        (slow.product_id, fast.location_id, Decimal::from(9)),
+    ];
+    expected.sort();
+    assert_eq!(positions, expected);
+
+    let response = app
+        .post(
+            &format!(
+                "/slotting-recommendations/{}/accept",
+                analysis.recommendations[0].id
+            ),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
+
+#[tokio::test]
+async fn applying_analysis_sets_product_classes() {
+    // Arrange
+    let app = spawn_app().await;
+    let (slow, fast) = stock_warehouse(&app).await;
+    let analysis = run(&app, slow.warehouse_id).await;
+
+    // Act
+    let response = app
+        .post(
+            &format!("/abc-analyses/{}/apply", analysis.analysis.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let applied = response
+        .json::<AbcAnalysisResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert!(applied.applied_at.is_some());
+
+    for (product_id, abc_class) in [
+        (fast.product_id, AbcClass::A),
+        (slow.product_id, AbcClass::B),
+    ] {
+        let product = app
+            .get(&format!("/products/{product_id}"))
+            .await
+            .expect("Failed to execute request.")
+            .json::<ProductResponse>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(product.abc_class, Some(abc_class));
+    }
+}
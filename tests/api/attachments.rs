@@ -0,0 +1,242 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use reqwest::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{AttachmentOwner, ResourceAction, ResourceType};
+use warehouse::dto::{AppError, AttachmentResponse};
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+async fn upload(
+    app: &TestApp<'_>,
+    access_token: &str,
+    query: &str,
+    content_type: &str,
+    content: Vec<u8>,
+) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/v1/attachments?{}", &app.address, query))
+        .bearer_auth(access_token)
+        .header(CONTENT_TYPE, content_type)
+        .body(content)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+fn product_query(product_id: Uuid, file_name: &str) -> String {
+    format!("owner_type=product&owner_id={product_id}&file_name={file_name}")
+}
+
+#[tokio::test]
+async fn attachments_are_uploaded_listed_and_downloaded() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let access_token = app.access_token().await;
+
+    // Act
+    let response = upload(
+        &app,
+        &access_token,
+        &product_query(fixture.product_id, "front.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let attachment = response
+        .json::<AttachmentResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(attachment.owner_type, AttachmentOwner::Product);
+    assert_eq!(attachment.file_name, "front.png");
+    assert_eq!(attachment.size, PNG.len() as i64);
+
+    let attachments = app
+        .get(&format!(
+            "/attachments?owner_type=product&owner_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<AttachmentResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(attachments, vec![attachment.clone()]);
+
+    let download = app
+        .get(&format!("/attachments/{}/content", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(download.status(), 200);
+    assert_eq!(download.headers()[CONTENT_TYPE], "image/png");
+    assert_eq!(
+        download.headers()[CONTENT_DISPOSITION],
+        "attachment; filename=\"front.png\""
+    );
+    assert_eq!(
+        download.bytes().await.expect("Failed to read response."),
+        PNG
+    );
+}
+
+#[tokio::test]
+async fn non_ascii_file_name_is_downloaded_with_an_ascii_fallback() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let access_token = app.access_token().await;
+    let attachment = upload(
+        &app,
+        &access_token,
+        // Étiquette été.png
+        &product_query(fixture.product_id, "%C3%89tiquette%20%C3%A9t%C3%A9.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await
+    .json::<AttachmentResponse>()
+    .await
+    .expect("Failed to parse response.");
+
+    // Act
+    let download = app
+        .get(&format!("/attachments/{}/content", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(attachment.file_name, "Étiquette été.png");
+    assert_eq!(download.status(), 200);
+    assert_eq!(
+        download.headers()[CONTENT_DISPOSITION],
+        "attachment; filename=\"_tiquette _t_.png\"; \
+         filename*=UTF-8''%C3%89tiquette%20%C3%A9t%C3%A9.png"
+    );
+}
+
+#[tokio::test]
+async fn uploads_outside_the_limits_are_refused() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let access_token = app.access_token().await;
+    let query = product_query(fixture.product_id, "note.png");
+
+    // Act
+    let unsupported_type = upload(
+        &app,
+        &access_token,
+        &query,
+        "text/html",
+        b"<script></script>".to_vec(),
+    )
+    .await;
+    let mismatched_content = upload(
+        &app,
+        &access_token,
+        &query,
+        "image/png",
+        b"<script></script>".to_vec(),
+    )
+    .await;
+    let too_large = upload(
+        &app,
+        &access_token,
+        &query,
+        "text/plain",
+        vec![b'a'; 1024 * 1024 + 1],
+    )
+    .await;
+    let invalid_file_name = upload(
+        &app,
+        &access_token,
+        &product_query(fixture.product_id, "..%2Fnote.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await;
+    let unknown_owner = upload(
+        &app,
+        &access_token,
+        &product_query(Uuid::new_v4(), "note.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await;
+
+    // Assert
+    for response in [
+        unsupported_type,
+        mismatched_content,
+        too_large,
+        invalid_file_name,
+    ] {
+        assert_eq!(response.status(), 400);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+    }
+    assert_eq!(unknown_owner.status(), 404);
+}
+
+#[tokio::test]
+async fn attachments_need_access_to_their_owner() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let attachment = upload(
+        &app,
+        &app.access_token().await,
+        &product_query(fixture.product_id, "front.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await
+    .json::<AttachmentResponse>()
+    .await
+    .expect("Failed to parse response.");
+    let viewer = app
+        .create_user(&[(ResourceAction::Read, ResourceType::Product)])
+        .await;
+
+    // Act
+    let upload_response = upload(
+        &app,
+        &viewer,
+        &product_query(fixture.product_id, "back.png"),
+        "image/png",
+        PNG.to_vec(),
+    )
+    .await;
+    let download_response = app
+        .get_as(&viewer, &format!("/attachments/{}/content", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+    let delete_response = app
+        .delete_as(&viewer, &format!("/attachments/{}", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(upload_response.status(), 403);
+    assert_eq!(download_response.status(), 200);
+    assert_eq!(delete_response.status(), 403);
+
+    let response = app
+        .delete(&format!("/attachments/{}", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+    let response = app
+        .get(&format!("/attachments/{}/content", attachment.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,196 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{AppError, AttributeDefinitionResponse, LocationResponse, ProductResponse};
+
+async fn define(app: &TestApp<'_>, body: serde_json::Value) -> AttributeDefinitionResponse {
+    let response = app
+        .post("/attributes", body)
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<AttributeDefinitionResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn create_product(app: &TestApp<'_>, attributes: serde_json::Value) -> reqwest::Response {
+    let sku = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+    app.post(
+        "/products",
+        serde_json::json!({ "sku": sku, "name": "Solvent", "attributes": attributes }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn product_attributes_are_validated_against_their_definitions() {
+    // Arrange
+    let app = spawn_app().await;
+    define(
+        &app,
+        serde_json::json!({
+            "entity": "product",
+            "key": "hazard_class",
+            "label": "Hazard class",
+            "kind": "enum",
+            "required": true,
+            "options": ["flammable", "corrosive"],
+        }),
+    )
+    .await;
+    define(
+        &app,
+        serde_json::json!({
+            "entity": "product",
+            "key": "shelf_life_days",
+            "label": "Shelf life (days)",
+            "kind": "number",
+        }),
+    )
+    .await;
+
+    // Act
+    let missing = create_product(&app, serde_json::json!({ "shelf_life_days": 30 })).await;
+    let invalid = create_product(&app, serde_json::json!({ "hazard_class": "toxic" })).await;
+    let unknown = create_product(
+        &app,
+        serde_json::json!({ "hazard_class": "flammable", "colour": "red" }),
+    )
+    .await;
+    let valid = create_product(
+        &app,
+        serde_json::json!({ "hazard_class": "flammable", "shelf_life_days": 30 }),
+    )
+    .await;
+
+    // Assert
+    for response in [missing, invalid, unknown] {
+        assert_eq!(response.status(), 400);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+    }
+    assert_eq!(valid.status(), 201);
+    let product = valid
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        product.attributes,
+        serde_json::json!({ "hazard_class": "flammable", "shelf_life_days": 30 })
+    );
+}
+
+#[tokio::test]
+async fn products_are_filtered_by_attributes() {
+    // Arrange
+    let app = spawn_app().await;
+    define(
+        &app,
+        serde_json::json!({
+            "entity": "product",
+            "key": "hazard_class",
+            "label": "Hazard class",
+            "kind": "enum",
+            "options": ["flammable", "corrosive"],
+        }),
+    )
+    .await;
+    let flammable = create_product(&app, serde_json::json!({ "hazard_class": "flammable" }))
+        .await
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.");
+    create_product(&app, serde_json::json!({ "hazard_class": "corrosive" })).await;
+    create_product(&app, serde_json::json!({})).await;
+
+    // Act
+    // ?attributes={"hazard_class":"flammable"}
+    let response = app
+        .get("/products?attributes=%7B%22hazard_class%22%3A%22flammable%22%7D")
+        .await
+        .expect("Failed to execute request.");
+    let invalid = app
+        .get("/products?attributes=flammable")
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let products = response
+        .json::<Vec<ProductResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        products
+            .iter()
+            .map(|product| product.id)
+            .collect::<Vec<_>>(),
+        vec![flammable.id]
+    );
+    assert_eq!(invalid.status(), 400);
+}
+
+#[tokio::test]
+async fn removing_a_definition_strips_its_values() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let definition = define(
+        &app,
+        serde_json::json!({
+            "entity": "location",
+            "key": "max_temperature",
+            "label": "Max temperature",
+            "kind": "number",
+        }),
+    )
+    .await;
+    define(
+        &app,
+        serde_json::json!({
+            "entity": "location",
+            "key": "inspected_on",
+            "label": "Inspected on",
+            "kind": "date",
+        }),
+    )
+    .await;
+    let response = app
+        .post(
+            &format!("/locations/{}/attributes", fixture.location_id),
+            serde_json::json!({
+                "attributes": { "max_temperature": -18, "inspected_on": "2026-01-30" },
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    // Act
+    let response = app
+        .delete(&format!("/attributes/{}", definition.id))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 204);
+    let locations = app
+        .get(&format!("/locations?warehouse_id={}", fixture.warehouse_id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<LocationResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(locations.len(), 1);
+    assert_eq!(
+        locations[0].attributes,
+        serde_json::json!({ "inspected_on": "2026-01-30" })
+    );
+}
@@ -63,7 +63,9 @@ async fn sign_in_with_invalid_email_fails() {
         response.json::<AppError>().await.unwrap(),
         AppError {
             code: ErrorCode::AuthenticationFailed,
-            message: "Invalid login or password".to_string()
+            message: "Invalid login or password".to_string(),
+            detail: None,
+            errors: Default::default(),
         }
     );
 }
@@ -92,7 +94,9 @@ async fn sign_in_with_invalid_password_fails() {
         response.json::<AppError>().await.unwrap(),
         AppError {
             code: ErrorCode::AuthenticationFailed,
-            message: "Invalid login or password".to_string()
+            message: "Invalid login or password".to_string(),
+            detail: None,
+            errors: Default::default(),
         }
     );
 }
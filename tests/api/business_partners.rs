@@ -0,0 +1,233 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{AddressKind, PartnerRole};
+use warehouse::dto::{
+    AppError, BusinessPartnerDetailResponse, BusinessPartnerResponse, PartnerAddressResponse,
+};
+
+/// A code and name unique to the test, so that partners of other tests are not matched.
+fn unique(prefix: &str) -> String {
+    format!(
+        "{prefix}{}",
+        &uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string()[..12]
+    )
+}
+
+async fn create_partner(app: &TestApp<'_>, body: serde_json::Value) -> reqwest::Response {
+    app.post("/business-partners", body)
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn partners_are_searched_by_name_and_role() {
+    // Arrange
+    let app = spawn_app().await;
+    let name = unique("Acme ");
+    let response = create_partner(
+        &app,
+        serde_json::json!({
+            "code": unique("P"),
+            "name": &name,
+            "tax_id": unique("DE "),
+            "roles": ["supplier", "carrier"],
+            "addresses": [{
+                "kind": "billing",
+                "street": "1 Main Street",
+                "city": "Springfield",
+                "postal_code": "12345",
+                "country": "de",
+                "is_default": true,
+            }],
+            "contacts": [{ "name": "Jane Doe", "email": "jane@example.com" }],
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+    let partner = response
+        .json::<BusinessPartnerResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        partner.roles,
+        vec![PartnerRole::Supplier, PartnerRole::Carrier]
+    );
+
+    // Act
+    let suppliers = app
+        .get(&format!(
+            "/business-partners?q={}&role=supplier",
+            name.to_uppercase().replace(' ', "%20")
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<BusinessPartnerResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    let customers = app
+        .get(&format!(
+            "/business-partners?q={}&role=customer",
+            &partner.code
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<BusinessPartnerResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(suppliers, vec![partner.clone()]);
+    assert!(customers.is_empty());
+
+    let detail = app
+        .get(&format!("/business-partners/{}", partner.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<BusinessPartnerDetailResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(detail.partner, partner);
+    assert_eq!(detail.addresses.len(), 1);
+    assert_eq!(detail.addresses[0].country, "DE");
+    assert_eq!(detail.contacts.len(), 1);
+    assert_eq!(detail.contacts[0].name, "Jane Doe");
+}
+
+#[tokio::test]
+async fn duplicate_partners_are_refused() {
+    // Arrange
+    let app = spawn_app().await;
+    let name = unique("Globex ");
+    let tax_id = unique("FR");
+    let response = create_partner(
+        &app,
+        serde_json::json!({
+            "code": unique("P"),
+            "name": &name,
+            "tax_id": &tax_id,
+            "roles": ["customer"],
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+
+    // Act
+    let response = create_partner(
+        &app,
+        serde_json::json!({
+            "code": unique("P"),
+            "name": unique("Initech "),
+            "tax_id": format!("{}-{}", &tax_id[..4], tax_id[4..].to_lowercase()),
+            "roles": ["customer"],
+            "allow_similar_name": true,
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ObjectAlreadyExists);
+
+    let similar = serde_json::json!({
+        "code": unique("P"),
+        "name": format!("{}.", name.to_lowercase()),
+        "roles": ["supplier"],
+    });
+    let response = create_partner(&app, similar.clone()).await;
+    assert_eq!(response.status(), 409);
+
+    let mut similar = similar;
+    similar["allow_similar_name"] = serde_json::json!(true);
+    let response = create_partner(&app, similar).await;
+    assert_eq!(response.status(), 201);
+}
+
+#[tokio::test]
+async fn a_new_default_address_replaces_the_previous_one() {
+    // Arrange
+    let app = spawn_app().await;
+    let partner = create_partner(
+        &app,
+        serde_json::json!({
+            "code": unique("P"),
+            "name": unique("Umbrella "),
+            "roles": ["customer"],
+            "addresses": [{
+                "kind": "shipping",
+                "street": "1 Dock Road",
+                "city": "Raccoon City",
+                "postal_code": "54321",
+                "country": "US",
+                "is_default": true,
+            }],
+        }),
+    )
+    .await
+    .json::<BusinessPartnerResponse>()
+    .await
+    .expect("Failed to parse response.");
+
+    // Act
+    let response = app
+        .post(
+            &format!("/business-partners/{}/addresses", partner.id),
+            serde_json::json!({
+                "kind": "shipping",
+                "street": "2 Dock Road",
+                "city": "Raccoon City",
+                "postal_code": "54321",
+                "country": "US",
+                "is_default": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let address = response
+        .json::<PartnerAddressResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(address.kind, AddressKind::Shipping);
+
+    let detail = app
+        .get(&format!("/business-partners/{}", partner.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<BusinessPartnerDetailResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        detail
+            .addresses
+            .iter()
+            .map(|address| (address.street.as_str(), address.is_default))
+            .collect::<Vec<_>>(),
+        vec![("2 Dock Road", true), ("1 Dock Road", false)]
+    );
+
+    let response = app
+        .delete(&format!(
+            "/business-partners/{}/addresses/{}",
+            partner.id, address.id
+        ))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+
+    let response = app
+        .delete(&format!(
+            "/business-partners/{}/addresses/{}",
+            partner.id, address.id
+        ))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 404);
+}
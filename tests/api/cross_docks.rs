@@ -0,0 +1,202 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{DemandStatus, PutawayTaskStatus, ReservationStatus};
+use warehouse::dto::{
+    AppError, CrossDockOpportunityResponse, CrossDockResponse, LocationResponse,
+    OutboundDemandResponse, PutawayTaskResponse, ReservationResponse, StockLevelResponse,
+};
+
+async fn create_location(app: &TestApp<'_>, warehouse_id: Uuid, code: &str) -> Uuid {
+    app.post(
+        "/locations",
+        serde_json::json!({ "warehouse_id": warehouse_id, "code": code }),
+    )
+    .await
+    .expect("Failed to execute request.")
+    .json::<LocationResponse>()
+    .await
+    .expect("Failed to parse response.")
+    .id
+}
+
+async fn create_demand(app: &TestApp<'_>, fixture: &StockFixture, quantity: u32) -> Uuid {
+    let response = app
+        .post(
+            "/outbound-demands",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "document_id": Uuid::new_v4(),
+                "product_id": fixture.product_id,
+                "quantity": quantity,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<OutboundDemandResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id
+}
+
+/// Receives ten units at the fixture location and registers a demand for four.
+async fn receive_with_demand(app: &TestApp<'_>) -> (StockFixture, Uuid, Uuid) {
+    let fixture = app.create_stock_fixture().await;
+    let staging_id = create_location(app, fixture.warehouse_id, "STAGE-01").await;
+    app.receive(&fixture, 10).await;
+    let demand_id = create_demand(app, &fixture, 4).await;
+
+    (fixture, staging_id, demand_id)
+}
+
+async fn cross_dock(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    staging_id: Uuid,
+    demand_id: Uuid,
+) -> reqwest::Response {
+    app.post(
+        "/cross-docks",
+        serde_json::json!({
+            "demand_id": demand_id,
+            "from_location_id": fixture.location_id,
+            "staging_location_id": staging_id,
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn opportunities_offer_received_stock_to_open_demand() {
+    // Arrange
+    let app = spawn_app().await;
+    let (fixture, _, demand_id) = receive_with_demand(&app).await;
+
+    // Act
+    let opportunities = app
+        .get(&format!(
+            "/cross-docks/opportunities?location_id={}",
+            fixture.location_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<CrossDockOpportunityResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(
+        opportunities
+            .iter()
+            .map(|opportunity| (opportunity.demand_id, opportunity.quantity))
+            .collect::<Vec<_>>(),
+        vec![(demand_id, Decimal::from(4))]
+    );
+}
+
+#[tokio::test]
+async fn cross_dock_moves_stock_to_staging_and_fulfills_demand() {
+    // Arrange
+    let app = spawn_app().await;
+    let (fixture, staging_id, demand_id) = receive_with_demand(&app).await;
+
+    // Act
+    let response = cross_dock(&app, &fixture, staging_id, demand_id).await;
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let cross_dock_response = response
+        .json::<CrossDockResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(cross_dock_response.quantity, Decimal::from(4));
+
+    let reservation = app
+        .get(&format!(
+            "/reservations/{}",
+            cross_dock_response.reservation_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(reservation.location_id, staging_id);
+    assert_eq!(reservation.quantity, Decimal::from(4));
+    assert_eq!(reservation.status, ReservationStatus::Active);
+
+    let levels = app
+        .get(&format!("/stock/levels?location_id={staging_id}"))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        levels
+            .iter()
+            .map(|level| (level.on_hand, level.available))
+            .collect::<Vec<_>>(),
+        vec![(Decimal::from(4), Decimal::ZERO)]
+    );
+
+    let demands = app
+        .get(&format!(
+            "/outbound-demands?product_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<OutboundDemandResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(demands[0].status, DemandStatus::Fulfilled);
+    assert_eq!(demands[0].allocated_quantity, Decimal::from(4));
+
+    let response = cross_dock(&app, &fixture, staging_id, demand_id).await;
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
+
+#[tokio::test]
+async fn cross_dock_reduces_open_putaway_task() {
+    // Arrange
+    let app = spawn_app().await;
+    let (fixture, staging_id, demand_id) = receive_with_demand(&app).await;
+    let response = app
+        .post(
+            "/putaway-tasks/generate",
+            serde_json::json!({ "location_id": fixture.location_id }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let tasks = response
+        .json::<Vec<PutawayTaskResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = cross_dock(&app, &fixture, staging_id, demand_id).await;
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let task = app
+        .get(&format!("/putaway-tasks/{}", tasks[0].id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<PutawayTaskResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(task.quantity, Decimal::from(6));
+    assert_eq!(task.status, PutawayTaskStatus::Open);
+}
@@ -1,9 +1,11 @@
 use anyhow::{Context, Result};
+use chrono::Utc;
 use diesel::Connection;
 use diesel::pg::PgConnection;
 use diesel::sql_query;
 use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl};
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+use fake::Fake;
 use leptos::config::LeptosOptions;
 use reqwest::Response;
 use reqwest::header::CONTENT_TYPE;
@@ -11,13 +13,18 @@ use secrecy::{ExposeSecret, SecretString};
 use std::sync::LazyLock;
 use tokio::net::TcpListener;
 use uuid::Uuid;
-use warehouse::config::{Config, DatabaseConfig};
-use warehouse::domain::{Role, RoleRule, Rule, User, UserRole};
+use warehouse::config::{Config, DatabaseConfig, StorageBackend};
+use warehouse::domain::{Organization, OrganizationMember, Role, RoleRule, Rule, User, UserRole};
+use warehouse::dto::{
+    AccessTokenClaims, AuthTokens, LocationResponse, ProductResponse, WarehouseResponse,
+};
 use warehouse::service::auth::compute_password_hash;
 use warehouse::{
     config::get_configuration,
+    db,
     dependency::AppContainer,
     domain, server,
+    storage::storage,
     telemetry::{get_subscriber, init_subscriber},
 };
 
@@ -39,9 +46,16 @@ pub struct TestApp<'a> {
     pub data: TestData,
 }
 
+pub struct StockFixture {
+    pub warehouse_id: Uuid,
+    pub location_id: Uuid,
+    pub product_id: Uuid,
+}
+
 pub struct TestData {
     pub admin: domain::SignUpData,
     pub admin_id: Uuid,
+    pub organization_id: Uuid,
 }
 
 impl<'a> TestApp<'a> {
@@ -63,6 +77,243 @@ impl<'a> TestApp<'a> {
             .await
     }
 
+    pub async fn access_token(&self) -> String {
+        let request = serde_json::json!({
+            "email": &self.data.admin.email,
+            "password": &self.data.admin.password.expose_secret(),
+        });
+
+        self.sign_in(request.to_string())
+            .await
+            .expect("Failed to execute request.")
+            .json::<AuthTokens>()
+            .await
+            .expect("Failed to parse response.")
+            .access_token
+    }
+
+    pub async fn post(
+        &self,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<Response, reqwest::Error> {
+        self.post_as(&self.access_token().await, path, body).await
+    }
+
+    pub async fn post_as(
+        &self,
+        access_token: &str,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<Response, reqwest::Error> {
+        reqwest::Client::new()
+            .post(format!("{}/api/v1{}", &self.address, path))
+            .bearer_auth(access_token)
+            .json(&body)
+            .send()
+            .await
+    }
+
+    /// Signs up a new user, adds them to the admin's organization granted only the given
+    /// rules and returns their access token acting as it.
+    pub async fn create_user(
+        &self,
+        rules: &[(domain::ResourceAction, domain::ResourceType)],
+    ) -> String {
+        let request = serde_json::json!({
+            "first_name": "counter",
+            "last_name": "counter",
+            "email": fake::faker::internet::en::SafeEmail().fake::<String>(),
+            "password": uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string(),
+        });
+
+        let tokens = self
+            .sign_up(request.to_string())
+            .await
+            .expect("Failed to execute request.")
+            .json::<AuthTokens>()
+            .await
+            .expect("Failed to parse response.");
+
+        let claims = jsonwebtoken::dangerous::insecure_decode::<AccessTokenClaims>(
+            tokens.access_token.clone(),
+        )
+        .expect("Failed to decode access token.")
+        .claims;
+
+        self.dependency
+            .organization_repository()
+            .await
+            .add_member(OrganizationMember {
+                user_id: claims.id,
+                organization_id: self.data.organization_id,
+                added_by: Some(self.data.admin_id),
+                created_at: Utc::now(),
+            })
+            .await
+            .expect("Failed to add organization member");
+
+        db::with_organization(self.data.organization_id, async {
+            let role = self
+                .dependency
+                .role_repository()
+                .await
+                .create(Role {
+                    id: Uuid::new_v4(),
+                    name: uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string(),
+                    description: None,
+                })
+                .await
+                .expect("Failed to create role");
+
+            for (action, resource_type) in rules.iter().copied() {
+                let rule = self
+                    .dependency
+                    .rule_repository()
+                    .await
+                    .create(Rule {
+                        id: Uuid::new_v4(),
+                        action,
+                        resource_type,
+                        effect: domain::RuleEffect::Allow,
+                    })
+                    .await
+                    .expect("Failed to create rule");
+
+                self.dependency
+                    .role_rule_repository()
+                    .await
+                    .create(RoleRule {
+                        role_id: role.id,
+                        rule_id: rule.id,
+                        assigned_by: None,
+                    })
+                    .await
+                    .expect("Failed to assign rule");
+            }
+
+            self.dependency
+                .user_role_repository()
+                .await
+                .create(UserRole {
+                    user_id: claims.id,
+                    role_id: role.id,
+                    assigned_by: None,
+                })
+                .await
+                .expect("Failed to assign role");
+        })
+        .await;
+
+        self.post_as(
+            &tokens.access_token,
+            &format!("/organizations/{}/switch", self.data.organization_id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<AuthTokens>()
+        .await
+        .expect("Failed to parse response.")
+        .access_token
+    }
+
+    pub async fn get(&self, path: &str) -> Result<Response, reqwest::Error> {
+        self.get_as(&self.access_token().await, path).await
+    }
+
+    pub async fn get_as(&self, access_token: &str, path: &str) -> Result<Response, reqwest::Error> {
+        reqwest::Client::new()
+            .get(format!("{}/api/v1{}", &self.address, path))
+            .bearer_auth(access_token)
+            .send()
+            .await
+    }
+
+    pub async fn get_v2(&self, path: &str) -> Result<Response, reqwest::Error> {
+        reqwest::Client::new()
+            .get(format!("{}/api/v2{}", &self.address, path))
+            .bearer_auth(self.access_token().await)
+            .send()
+            .await
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<Response, reqwest::Error> {
+        self.delete_as(&self.access_token().await, path).await
+    }
+
+    pub async fn delete_as(
+        &self,
+        access_token: &str,
+        path: &str,
+    ) -> Result<Response, reqwest::Error> {
+        reqwest::Client::new()
+            .delete(format!("{}/api/v1{}", &self.address, path))
+            .bearer_auth(access_token)
+            .send()
+            .await
+    }
+
+    /// Creates a warehouse with a single location and a product.
+    pub async fn create_stock_fixture(&self) -> StockFixture {
+        let code = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+
+        let warehouse = self
+            .post(
+                "/warehouses",
+                serde_json::json!({ "code": &code[..16], "name": "Main warehouse" }),
+            )
+            .await
+            .expect("Failed to execute request.")
+            .json::<WarehouseResponse>()
+            .await
+            .expect("Failed to parse response.");
+
+        let location = self
+            .post(
+                "/locations",
+                serde_json::json!({ "warehouse_id": warehouse.id, "code": "A-01-01" }),
+            )
+            .await
+            .expect("Failed to execute request.")
+            .json::<LocationResponse>()
+            .await
+            .expect("Failed to parse response.");
+
+        let product = self
+            .post(
+                "/products",
+                serde_json::json!({ "sku": &code, "name": "Pallet of bricks" }),
+            )
+            .await
+            .expect("Failed to execute request.")
+            .json::<ProductResponse>()
+            .await
+            .expect("Failed to parse response.");
+
+        StockFixture {
+            warehouse_id: warehouse.id,
+            location_id: location.id,
+            product_id: product.id,
+        }
+    }
+
+    pub async fn receive(&self, fixture: &StockFixture, quantity: u32) {
+        let response = self
+            .post(
+                "/stock/movements",
+                serde_json::json!({
+                    "kind": "receipt",
+                    "product_id": fixture.product_id,
+                    "to_location_id": fixture.location_id,
+                    "quantity": quantity,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 201);
+    }
+
     pub async fn health_check(self) -> Result<Response, reqwest::Error> {
         reqwest::Client::new()
             .get(&format!("{}/api/v1/health-check", &self.address))
@@ -101,9 +352,19 @@ pub async fn spawn_app<'a>() -> TestApp<'a> {
 
 async fn setup_test_database<'a>(mut config: Config) -> Result<(AppContainer<'a>, TestData)> {
     config.database.database = format!("test_{}", Uuid::new_v4().to_string());
+    config.shipping.mockcarrier = true;
+    config.reservation.intervalsecs = 1;
+    config.storage.maxbytes = 1024 * 1024;
+    if config.storage.backend == StorageBackend::Local {
+        config.storage.path = std::env::temp_dir()
+            .join(&config.database.database)
+            .to_string_lossy()
+            .into_owned();
+    }
     configure_database(&config.database).await?;
 
-    let dependencies = AppContainer::new(config);
+    let storage = storage(&config.storage)?;
+    let dependencies = AppContainer::new(config, storage);
 
     let data = populate_database(&dependencies).await?;
 
@@ -196,51 +457,88 @@ async fn populate_database<'a>(dependencies: &AppContainer<'a>) -> Result<TestDa
         effect: domain::RuleEffect::Allow,
     };
 
-    let root_rules = vec![
+    let mut root_rules = vec![
         allow_create_role,
         allow_create_user_role,
         allow_create_rule,
         allow_create_role_rule,
     ];
 
-    for rule in root_rules.iter().cloned() {
-        dependencies.rule_repository().await.create(rule).await?;
-    }
-
-    let root_role = dependencies
-        .role_repository()
-        .await
-        .create(Role {
-            id: Uuid::new_v4(),
-            name: "root".to_string(),
-            description: None,
-        })
-        .await?;
-
-    for rule in root_rules {
-        dependencies
-            .role_rule_repository()
-            .await
-            .create(RoleRule {
-                role_id: root_role.id,
-                rule_id: rule.id,
-                assigned_by: None,
-            })
-            .await?;
+    for resource_type in [
+        domain::ResourceType::Warehouse,
+        domain::ResourceType::Location,
+        domain::ResourceType::Product,
+        domain::ResourceType::Stock,
+        domain::ResourceType::Reservation,
+        domain::ResourceType::StockCount,
+        domain::ResourceType::Lot,
+        domain::ResourceType::SerialNumber,
+        domain::ResourceType::Pallet,
+        domain::ResourceType::TransferOrder,
+        domain::ResourceType::Replenishment,
+        domain::ResourceType::Valuation,
+        domain::ResourceType::ReturnAuthorization,
+        domain::ResourceType::Kit,
+        domain::ResourceType::AssemblyOrder,
+        domain::ResourceType::Putaway,
+        domain::ResourceType::PickOrder,
+        domain::ResourceType::Wave,
+        domain::ResourceType::StockHold,
+        domain::ResourceType::StockSnapshot,
+        domain::ResourceType::AbcAnalysis,
+        domain::ResourceType::CrossDock,
+        domain::ResourceType::Shipment,
+        domain::ResourceType::Organization,
+        domain::ResourceType::BusinessPartner,
+        domain::ResourceType::PartnerContact,
+        domain::ResourceType::AttributeDefinition,
+    ] {
+        for action in [
+            domain::ResourceAction::Create,
+            domain::ResourceAction::Read,
+            domain::ResourceAction::List,
+            domain::ResourceAction::Update,
+            domain::ResourceAction::Delete,
+            domain::ResourceAction::Approve,
+        ] {
+            root_rules.push(Rule {
+                id: Uuid::new_v4(),
+                action,
+                resource_type,
+                effect: domain::RuleEffect::Allow,
+            });
+        }
     }
 
+    let organization_id = Uuid::new_v4();
     dependencies
-        .user_role_repository()
+        .organization_repository()
         .await
-        .create(UserRole {
-            user_id: admin.id,
-            role_id: root_role.id,
-            assigned_by: None,
-        })
-        .await?;
+        .create_with_owner(
+            Organization {
+                id: organization_id,
+                name: "Warehouse".to_string(),
+                created_at: Utc::now(),
+            },
+            OrganizationMember {
+                user_id: admin.id,
+                organization_id,
+                added_by: None,
+                created_at: Utc::now(),
+            },
+            Role {
+                id: Uuid::new_v4(),
+                name: "root".to_string(),
+                description: None,
+            },
+            root_rules,
+        )
+        .await
+        .context("Failed to create organization")?;
 
     Ok(TestData {
         admin: admin_sign_up_data,
         admin_id: admin.id,
+        organization_id,
     })
 }
@@ -0,0 +1,355 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{AssemblyOrderStatus, MovementKind};
+use warehouse::dto::{
+    AppError, AssemblyOrderResponse, BomResponse, CompleteAssemblyOrderResponse,
+    KitAvailabilityResponse, ProductResponse, StockLevelResponse,
+};
+
+async fn create_product(app: &TestApp<'_>, name: &str) -> Uuid {
+    let sku = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+    app.post("/products", serde_json::json!({ "sku": sku, "name": name }))
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id
+}
+
+async fn receive_at_cost(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    product_id: Uuid,
+    quantity: u32,
+    unit_cost: &str,
+) {
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": quantity,
+                "unit_cost": unit_cost,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+}
+
+/// Kit of two units of the fixture product and one unit of a second component, with ten
+/// and three units of them received.
+async fn create_kit(app: &TestApp<'_>, fixture: &StockFixture) -> (Uuid, Uuid) {
+    let component_id = create_product(app, "Mortar bag").await;
+    receive_at_cost(app, fixture, fixture.product_id, 10, "1.5").await;
+    receive_at_cost(app, fixture, component_id, 3, "4").await;
+
+    let kit_id = create_product(app, "Bricklaying kit").await;
+    let response = app
+        .post(
+            &format!("/products/{kit_id}/bom"),
+            serde_json::json!({
+                "components": [
+                    { "component_product_id": fixture.product_id, "quantity": 2 },
+                    { "component_product_id": component_id, "quantity": 1 },
+                ],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    (kit_id, component_id)
+}
+
+async fn create_order(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    kind: &str,
+    kit_id: Uuid,
+    quantity: u32,
+) -> AssemblyOrderResponse {
+    let response = app
+        .post(
+            "/assembly-orders",
+            serde_json::json!({
+                "kind": kind,
+                "kit_product_id": kit_id,
+                "from_location_id": fixture.location_id,
+                "to_location_id": fixture.location_id,
+                "quantity": quantity,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    response
+        .json::<AssemblyOrderResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn complete(app: &TestApp<'_>, order_id: Uuid) -> reqwest::Response {
+    app.post(
+        &format!("/assembly-orders/{order_id}/complete"),
+        serde_json::json!({}),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+async fn on_hand(app: &TestApp<'_>, fixture: &StockFixture, product_id: Uuid) -> Decimal {
+    app.get(&format!(
+        "/stock/levels?location_id={}&product_id={product_id}",
+        fixture.location_id
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<Vec<StockLevelResponse>>()
+    .await
+    .expect("Failed to parse response.")
+    .iter()
+    .map(|level| level.on_hand)
+    .sum()
+}
+
+#[tokio::test]
+async fn availability_is_limited_by_the_scarcest_component() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, component_id) = create_kit(&app, &fixture).await;
+
+    // Act
+    let response = app
+        .get(&format!(
+            "/products/{kit_id}/kit-availability?warehouse_id={}",
+            fixture.warehouse_id
+        ))
+        .await
+        .expect("Failed to execute request.");
+    let bom = app
+        .get(&format!("/products/{kit_id}/bom"))
+        .await
+        .expect("Failed to execute request.")
+        .json::<BomResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let availability = response
+        .json::<KitAvailabilityResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(availability.buildable, Decimal::from(3));
+    let component = availability
+        .components
+        .iter()
+        .find(|component| component.component_product_id == fixture.product_id)
+        .expect("Component is missing.");
+    assert_eq!(component.buildable, Decimal::from(5));
+
+    assert_eq!(bom.components.len(), 2);
+    assert!(
+        bom.components
+            .iter()
+            .any(|component| component.component_product_id == component_id)
+    );
+}
+
+#[tokio::test]
+async fn assembly_consumes_components_and_produces_the_kit_at_their_cost() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, component_id) = create_kit(&app, &fixture).await;
+    let order = create_order(&app, &fixture, "assembly", kit_id, 2).await;
+
+    // Act
+    let response = complete(&app, order.id).await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let completed = response
+        .json::<CompleteAssemblyOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(completed.order.status, AssemblyOrderStatus::Completed);
+    assert_eq!(completed.movements.len(), 3);
+    assert!(
+        completed
+            .movements
+            .iter()
+            .all(|movement| movement.document_id == Some(order.id))
+    );
+
+    let receipt = completed
+        .movements
+        .iter()
+        .find(|movement| movement.kind == MovementKind::Receipt)
+        .expect("Kit receipt is missing.");
+    assert_eq!(receipt.product_id, kit_id);
+    assert_eq!(receipt.unit_cost, Some(Decimal::from_str("7").unwrap()));
+
+    assert_eq!(on_hand(&app, &fixture, kit_id).await, Decimal::from(2));
+    assert_eq!(
+        on_hand(&app, &fixture, fixture.product_id).await,
+        Decimal::from(6)
+    );
+    assert_eq!(
+        on_hand(&app, &fixture, component_id).await,
+        Decimal::from(1)
+    );
+}
+
+#[tokio::test]
+async fn disassembly_returns_components_to_stock() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, component_id) = create_kit(&app, &fixture).await;
+    let assembly = create_order(&app, &fixture, "assembly", kit_id, 2).await;
+    assert_eq!(complete(&app, assembly.id).await.status(), 200);
+    let order = create_order(&app, &fixture, "disassembly", kit_id, 1).await;
+
+    // Act
+    let response = complete(&app, order.id).await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    assert_eq!(on_hand(&app, &fixture, kit_id).await, Decimal::from(1));
+    assert_eq!(
+        on_hand(&app, &fixture, fixture.product_id).await,
+        Decimal::from(8)
+    );
+    assert_eq!(
+        on_hand(&app, &fixture, component_id).await,
+        Decimal::from(2)
+    );
+}
+
+#[tokio::test]
+async fn order_quantity_is_converted_from_the_given_unit() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, component_id) = create_kit(&app, &fixture).await;
+    let response = app
+        .post(
+            &format!("/products/{kit_id}/uoms"),
+            serde_json::json!({ "code": "pair", "factor": 2 }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    // Act
+    let response = app
+        .post(
+            "/assembly-orders",
+            serde_json::json!({
+                "kind": "assembly",
+                "kit_product_id": kit_id,
+                "from_location_id": fixture.location_id,
+                "to_location_id": fixture.location_id,
+                "quantity": 1,
+                "uom": "pair",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let order = response
+        .json::<AssemblyOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(order.quantity, Decimal::from(2));
+    let line = order
+        .lines
+        .iter()
+        .find(|line| line.product_id == component_id)
+        .expect("Component line is missing.");
+    assert_eq!(line.quantity, Decimal::from(2));
+}
+
+#[tokio::test]
+async fn completed_order_cannot_be_completed_again() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, _) = create_kit(&app, &fixture).await;
+    let order = create_order(&app, &fixture, "assembly", kit_id, 1).await;
+    assert_eq!(complete(&app, order.id).await.status(), 200);
+
+    // Act
+    let response = complete(&app, order.id).await;
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
+
+#[tokio::test]
+async fn concurrent_completions_assemble_the_order_once() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (kit_id, _) = create_kit(&app, &fixture).await;
+    let order = create_order(&app, &fixture, "assembly", kit_id, 1).await;
+
+    // Act
+    let (first, second) = tokio::join!(complete(&app, order.id), complete(&app, order.id));
+
+    // Assert
+    let statuses = [first.status(), second.status()];
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        1
+    );
+    assert_eq!(on_hand(&app, &fixture, kit_id).await, Decimal::from(1));
+    assert_eq!(
+        on_hand(&app, &fixture, fixture.product_id).await,
+        Decimal::from(8)
+    );
+}
+
+#[tokio::test]
+async fn kit_cannot_contain_itself() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+
+    // Act
+    let response = app
+        .post(
+            &format!("/products/{}/bom", fixture.product_id),
+            serde_json::json!({
+                "components": [{ "component_product_id": fixture.product_id, "quantity": 1 }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
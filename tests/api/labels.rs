@@ -0,0 +1,156 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use reqwest::header::CONTENT_TYPE;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::gs1_check_digit;
+use warehouse::dto::{AppError, LocationResponse, PalletResponse, WarehouseResponse};
+
+async fn create_location(app: &TestApp<'_>) -> LocationResponse {
+    let warehouse = app
+        .post(
+            "/warehouses",
+            serde_json::json!({ "code": "WH01", "name": "Main warehouse" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<WarehouseResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    app.post(
+        "/locations",
+        serde_json::json!({ "warehouse_id": warehouse.id, "code": "A-01-01", "zone": "A" }),
+    )
+    .await
+    .expect("Failed to execute request.")
+    .json::<LocationResponse>()
+    .await
+    .expect("Failed to parse response.")
+}
+
+async fn get_label(app: &TestApp<'_>, path: &str) -> (String, Vec<u8>) {
+    let response = app.get(path).await.expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    let content_type = response.headers()[CONTENT_TYPE]
+        .to_str()
+        .expect("Invalid content type.")
+        .to_string();
+    let body = response.bytes().await.expect("Failed to read response.");
+
+    (content_type, body.to_vec())
+}
+
+#[tokio::test]
+async fn location_label_renders_as_zpl() {
+    // Arrange
+    let app = spawn_app().await;
+    let location = create_location(&app).await;
+
+    // Act
+    let (content_type, body) = get_label(
+        &app,
+        &format!("/labels/location/{}?format=zpl", location.id),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(content_type, "application/zpl");
+    assert_eq!(
+        String::from_utf8(body).expect("Label is not UTF-8."),
+        "^XA\n\
+         ^CI28\n\
+         ^PW812\n\
+         ^LL406\n\
+         ^FO20,20^A0N,30,30^FH^FDWH01 / A^FS\n\
+         ^FO20,70^A0N,90,90^FH^FDA-01-01^FS\n\
+         ^FO40,190^BY4^BCN,180,N,N,N,A^FH^FDA-01-01^FS\n\
+         ^XZ\n"
+    );
+}
+
+#[tokio::test]
+async fn label_rendering_is_deterministic() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let path = format!("/labels/product/{}", fixture.product_id);
+
+    // Act
+    let (content_type, first) = get_label(&app, &path).await;
+    let (_, second) = get_label(&app, &path).await;
+
+    // Assert
+    assert_eq!(content_type, "image/svg+xml");
+    assert!(first.starts_with(b"<svg"));
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn product_label_renders_as_png() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+
+    // Act
+    let (content_type, body) = get_label(
+        &app,
+        &format!("/labels/product/{}?format=png", fixture.product_id),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(content_type, "image/png");
+    assert!(body.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[tokio::test]
+async fn pallet_label_carries_sscc_as_gs1_128() {
+    // Arrange
+    let app = spawn_app().await;
+    let location = create_location(&app).await;
+    let pallet = app
+        .post(
+            "/pallets",
+            serde_json::json!({ "location_id": location.id }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<PalletResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let (_, body) = get_label(&app, &format!("/labels/pallet/{}?format=zpl", pallet.id)).await;
+
+    // Assert
+    assert_eq!(pallet.sscc.len(), 18);
+    assert_eq!(
+        gs1_check_digit(&pallet.sscc[..17]).map(|digit| digit.to_string()),
+        Some(pallet.sscc[17..].to_string())
+    );
+    let zpl = String::from_utf8(body).expect("Label is not UTF-8.");
+    assert!(zpl.contains(&format!("^BCN,300,N,N,N,D^FH^FD(00){}^FS", pallet.sscc)));
+    assert!(zpl.contains("^FDLocation A-01-01^FS"));
+}
+
+#[tokio::test]
+async fn label_of_missing_object_is_not_found() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = app
+        .get(&format!("/labels/location/{}", Uuid::new_v4()))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 404);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ObjectNotFound);
+}
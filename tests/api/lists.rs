@@ -0,0 +1,160 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{AppError, LocationResponse, PageResponse, ProductResponse};
+
+async fn create_product(app: &TestApp<'_>, sku: &str, name: &str) {
+    let response = app
+        .post("/products", serde_json::json!({ "sku": sku, "name": name }))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+}
+
+async fn list_products(app: &TestApp<'_>, query: &str) -> PageResponse<ProductResponse> {
+    let response = app
+        .get_v2(&format!("/products?{query}"))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    response
+        .json::<PageResponse<ProductResponse>>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn lists_are_paged_by_cursor_in_sort_order() {
+    // Arrange
+    let app = spawn_app().await;
+    for (sku, name) in [
+        ("SKU-1", "Bolt"),
+        ("SKU-2", "Nut"),
+        ("SKU-3", "Washer"),
+        ("SKU-4", "Anchor"),
+        ("SKU-5", "Screw"),
+    ] {
+        create_product(&app, sku, name).await;
+    }
+
+    // Act
+    let mut names = Vec::new();
+    let mut pages = 0;
+    let mut cursor: Option<Uuid> = None;
+    loop {
+        let query = match cursor {
+            Some(cursor) => format!("sort=-name&limit=2&cursor={cursor}"),
+            None => "sort=-name&limit=2".to_string(),
+        };
+        let page = list_products(&app, &query).await;
+        pages += 1;
+        names.extend(page.items.into_iter().map(|product| product.name));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    // Assert
+    assert_eq!(pages, 3);
+    assert_eq!(names, vec!["Washer", "Screw", "Nut", "Bolt", "Anchor"]);
+}
+
+#[tokio::test]
+async fn lists_return_only_the_requested_fields() {
+    // Arrange
+    let app = spawn_app().await;
+    create_product(&app, "SKU-1", "Bolt").await;
+
+    // Act
+    let response = app
+        .get_v2("/products?fields=id,sku")
+        .await
+        .expect("Failed to execute request.");
+    let unknown = app
+        .get_v2("/products?fields=id,colour")
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let page = response
+        .json::<PageResponse<serde_json::Value>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(page.items.len(), 1);
+    let keys = page.items[0]
+        .as_object()
+        .expect("Item is not an object.")
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec!["id", "sku"]);
+    assert_eq!(page.items[0]["sku"], "SKU-1");
+    assert_eq!(unknown.status(), 400);
+}
+
+#[tokio::test]
+async fn lists_are_filtered_and_reject_invalid_parameters() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    for (code, pick_sequence) in [("B-01-01", 1), ("B-01-02", 10)] {
+        let response = app
+            .post(
+                "/locations",
+                serde_json::json!({
+                    "warehouse_id": fixture.warehouse_id,
+                    "code": code,
+                    "pick_sequence": pick_sequence,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 201);
+    }
+
+    // Act
+    let response = app
+        .get_v2("/locations?filter[pick_sequence][gt]=5")
+        .await
+        .expect("Failed to execute request.");
+    let mut invalid = Vec::new();
+    for query in [
+        "/locations?limit=500",
+        "/locations?limit=0",
+        "/locations?sort=zone",
+        "/locations?filter[colour]=red",
+        "/locations?filter[pick_sequence][gt]=high",
+        "/locations?filter[pick_sequence][like]=5",
+        &format!("/locations?cursor={}", Uuid::new_v4()),
+        &format!("/locations?warehouse_id={}", fixture.warehouse_id),
+    ] {
+        invalid.push(app.get_v2(query).await.expect("Failed to execute request."));
+    }
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let locations = response
+        .json::<PageResponse<LocationResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        locations
+            .items
+            .iter()
+            .map(|location| location.code.as_str())
+            .collect::<Vec<_>>(),
+        vec!["B-01-02"]
+    );
+    assert_eq!(locations.next_cursor, None);
+    for response in invalid {
+        assert_eq!(response.status(), 400);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+    }
+}
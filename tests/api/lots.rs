@@ -0,0 +1,223 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use chrono::{Days, NaiveDate, Utc};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{
+    AppError, AvailableToPromiseResponse, ExpiringStockResponse, LotResponse, ProductResponse,
+    StockMovementResponse,
+};
+
+/// Replaces the fixture product with one that is lot and expiry tracked.
+async fn create_tracked_fixture(app: &TestApp<'_>) -> StockFixture {
+    let mut fixture = app.create_stock_fixture().await;
+    let sku = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+
+    let product = app
+        .post(
+            "/products",
+            serde_json::json!({
+                "sku": &sku,
+                "name": "Yoghurt",
+                "lot_tracked": true,
+                "expiry_tracked": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.");
+    fixture.product_id = product.id;
+
+    fixture
+}
+
+fn in_days(days: u64) -> NaiveDate {
+    Utc::now().date_naive() + Days::new(days)
+}
+
+async fn receive_lot(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    lot_number: &str,
+    expiry_date: NaiveDate,
+    quantity: u32,
+) -> StockMovementResponse {
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": quantity,
+                "lot_number": lot_number,
+                "expiry_date": expiry_date,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    response
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.")
+        .remove(0)
+}
+
+#[tokio::test]
+async fn receipt_of_lot_tracked_product_requires_lot_number() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_tracked_fixture(&app).await;
+
+    // Act
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": 10,
+                "expiry_date": in_days(30),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response.json::<AppError>().await.unwrap().code,
+        ErrorCode::ValidationFailed
+    );
+}
+
+#[tokio::test]
+async fn issue_allocates_first_expired_first_out() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_tracked_fixture(&app).await;
+    let late = receive_lot(&app, &fixture, "LATE", in_days(60), 10).await;
+    let early = receive_lot(&app, &fixture, "EARLY", in_days(20), 4).await;
+
+    // Act
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "issue",
+                "product_id": fixture.product_id,
+                "from_location_id": fixture.location_id,
+                "quantity": 6,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let movements = response
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    let allocated: Vec<(Option<Uuid>, Decimal)> = movements
+        .iter()
+        .map(|movement| (movement.lot_id, movement.quantity))
+        .collect();
+    assert_eq!(
+        allocated,
+        vec![
+            (early.lot_id, Decimal::from(4)),
+            (late.lot_id, Decimal::from(2)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn expiring_query_returns_lots_within_window() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_tracked_fixture(&app).await;
+    let soon = receive_lot(&app, &fixture, "SOON", in_days(5), 3).await;
+    receive_lot(&app, &fixture, "LATER", in_days(90), 3).await;
+
+    // Act
+    let expiring = app
+        .get(&format!(
+            "/lots/expiring?days=30&product_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<ExpiringStockResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(Some(expiring[0].lot_id), soon.lot_id);
+    assert_eq!(expiring[0].lot_number.as_deref(), Some("SOON"));
+    assert_eq!(expiring[0].on_hand, Decimal::from(3));
+}
+
+#[tokio::test]
+async fn blocked_lot_is_not_available() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_tracked_fixture(&app).await;
+    let blocked = receive_lot(&app, &fixture, "BAD", in_days(10), 5).await;
+    receive_lot(&app, &fixture, "GOOD", in_days(40), 7).await;
+
+    // Act
+    let lot = app
+        .post(
+            &format!("/lots/{}/block", blocked.lot_id.unwrap()),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LotResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert!(lot.blocked);
+
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.on_hand, Decimal::from(12));
+    assert_eq!(atp.blocked, Decimal::from(5));
+    assert_eq!(atp.available, Decimal::from(7));
+
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "issue",
+                "product_id": fixture.product_id,
+                "from_location_id": fixture.location_id,
+                "quantity": 1,
+                "lot_number": "BAD",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 409);
+    assert_eq!(
+        response.json::<AppError>().await.unwrap().code,
+        ErrorCode::InvalidState
+    );
+}
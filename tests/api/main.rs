@@ -1,4 +1,29 @@
+mod abc_analyses;
+mod attachments;
+mod attributes;
 mod auth_sign_in;
 mod auth_sign_up;
+mod business_partners;
+mod cross_docks;
 mod health_check;
 mod helpers;
+mod kits;
+mod labels;
+mod lists;
+mod lots;
+mod organizations;
+mod problems;
+mod putaway;
+mod replenishment;
+mod reservations;
+mod returns;
+mod scans;
+mod serial_numbers;
+mod shipments;
+mod snapshots;
+mod stock_counts;
+mod stock_holds;
+mod transfer_orders;
+mod uoms;
+mod valuation;
+mod waves;
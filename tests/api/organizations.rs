@@ -0,0 +1,277 @@
+use crate::helpers::{TestApp, spawn_app};
+use fake::Fake;
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{ResourceAction, ResourceType};
+use warehouse::dto::{
+    AccessTokenClaims, AppError, AuthTokens, OrganizationMemberResponse, OrganizationResponse,
+    WarehouseResponse,
+};
+
+async fn switch(app: &TestApp<'_>, access_token: &str, organization_id: Uuid) -> reqwest::Response {
+    app.post_as(
+        access_token,
+        &format!("/organizations/{organization_id}/switch"),
+        serde_json::json!({}),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+/// Signs up a user and returns their email and access token.
+async fn sign_up(app: &TestApp<'_>) -> (String, String) {
+    let email = fake::faker::internet::en::SafeEmail().fake::<String>();
+    let request = serde_json::json!({
+        "first_name": "Jane",
+        "last_name": "Doe",
+        "email": &email,
+        "password": uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string(),
+    });
+
+    let tokens = app
+        .sign_up(request.to_string())
+        .await
+        .expect("Failed to execute request.")
+        .json::<AuthTokens>()
+        .await
+        .expect("Failed to parse response.");
+
+    (email, tokens.access_token)
+}
+
+#[tokio::test]
+async fn data_of_other_organizations_is_invisible() {
+    // Arrange
+    let app = spawn_app().await;
+    let code = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string()[..16].to_string();
+    let warehouse = app
+        .post(
+            "/warehouses",
+            serde_json::json!({ "code": &code, "name": "Main warehouse" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<WarehouseResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    let response = app
+        .post("/organizations", serde_json::json!({ "name": "Client B" }))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let organization = response
+        .json::<OrganizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = switch(&app, &app.access_token().await, organization.id).await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let access_token = response
+        .json::<AuthTokens>()
+        .await
+        .expect("Failed to parse response.")
+        .access_token;
+    let claims = jsonwebtoken::dangerous::insecure_decode::<AccessTokenClaims>(&access_token)
+        .expect("Failed to decode access token.")
+        .claims;
+    assert_eq!(claims.organization_id, organization.id);
+
+    let response = app
+        .get_as(&access_token, &format!("/warehouses/{}", warehouse.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 404);
+
+    let response = app
+        .post_as(
+            &access_token,
+            "/warehouses",
+            serde_json::json!({ "code": &code, "name": "Client B warehouse" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    let response = app
+        .get(&format!("/warehouses/{}", warehouse.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn switching_to_an_organization_of_others_is_denied() {
+    // Arrange
+    let app = spawn_app().await;
+    let (_, access_token) = sign_up(&app).await;
+
+    // Act
+    let response = switch(&app, &access_token, app.data.organization_id).await;
+
+    // Assert
+    assert_eq!(response.status(), 403);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[tokio::test]
+async fn members_can_switch_until_removed() {
+    // Arrange
+    let app = spawn_app().await;
+    let (email, access_token) = sign_up(&app).await;
+
+    // Act
+    let response = app
+        .post(
+            "/organizations/current/members",
+            serde_json::json!({ "email": &email }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let member = response
+        .json::<OrganizationMemberResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(member.organization_id, app.data.organization_id);
+
+    let organizations = app
+        .get_as(&access_token, "/organizations")
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<OrganizationResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(organizations.len(), 2);
+    assert_eq!(organizations[1].id, app.data.organization_id);
+
+    let members = app
+        .get("/organizations/current/members")
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<OrganizationMemberResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert!(members.contains(&member));
+
+    let response = switch(&app, &access_token, app.data.organization_id).await;
+    assert_eq!(response.status(), 200);
+
+    let response = app
+        .delete(&format!(
+            "/organizations/current/members/{}",
+            member.user_id
+        ))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+
+    let response = switch(&app, &access_token, app.data.organization_id).await;
+    assert_eq!(response.status(), 403);
+}
+
+#[tokio::test]
+async fn tokens_of_removed_members_are_refused() {
+    // Arrange
+    let app = spawn_app().await;
+    let (email, access_token) = sign_up(&app).await;
+    let member = app
+        .post(
+            "/organizations/current/members",
+            serde_json::json!({ "email": &email }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<OrganizationMemberResponse>()
+        .await
+        .expect("Failed to parse response.");
+    let member_token = switch(&app, &access_token, app.data.organization_id)
+        .await
+        .json::<AuthTokens>()
+        .await
+        .expect("Failed to parse response.")
+        .access_token;
+    let response = app
+        .get_as(&member_token, "/organizations")
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    // Act
+    let response = app
+        .delete(&format!(
+            "/organizations/current/members/{}",
+            member.user_id
+        ))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+    let response = app
+        .get_as(&member_token, "/organizations")
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 403);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
+
+#[tokio::test]
+async fn server_functions_refuse_removed_members() {
+    // Arrange
+    let app = spawn_app().await;
+    let member_token = app
+        .create_user(&[(ResourceAction::List, ResourceType::AttributeDefinition)])
+        .await;
+    let member_id = jsonwebtoken::dangerous::insecure_decode::<AccessTokenClaims>(&member_token)
+        .expect("Failed to decode access token.")
+        .claims
+        .id;
+    let path = leptos::server_fn::axum::server_fn_paths()
+        .map(|(path, _)| path)
+        .find(|path| path.contains("list_attribute_definitions"))
+        .expect("Server function is not registered.");
+    let list_attribute_definitions = || {
+        reqwest::Client::new()
+            .post(format!("{}{}", &app.address, path))
+            .bearer_auth(&member_token)
+            .form(&[("entity", "product")])
+            .send()
+    };
+    let response = list_attribute_definitions()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    // Act
+    let response = app
+        .delete(&format!("/organizations/current/members/{member_id}"))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+    let response = list_attribute_definitions()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert!(!response.status().is_success());
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::PermissionDenied);
+}
@@ -0,0 +1,216 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use reqwest::Response;
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{AppError, ProblemDetails};
+
+async fn create_partner(app: &TestApp<'_>, accept: &str) -> Response {
+    reqwest::Client::new()
+        .post(format!("{}/api/v1/business-partners", &app.address))
+        .bearer_auth(app.access_token().await)
+        .header(ACCEPT, accept)
+        .json(&serde_json::json!({
+            "code": "",
+            "name": "Acme",
+            "roles": ["supplier"],
+            "contacts": [
+                { "name": "Jane Doe", "email": "jane@example.com" },
+                { "name": "John Doe", "email": "not an email" },
+            ],
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn get_product(app: &TestApp<'_>, accept: &str) -> Response {
+    reqwest::Client::new()
+        .get(format!(
+            "{}/api/v1/products/{}",
+            &app.address,
+            Uuid::new_v4()
+        ))
+        .bearer_auth(app.access_token().await)
+        .header(ACCEPT, accept)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn validation_errors_name_the_invalid_fields() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = create_partner(&app, "application/json").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+    assert_eq!(error.message, "Invalid arguments");
+    assert_eq!(
+        error.errors.keys().collect::<Vec<_>>(),
+        vec!["code", "contacts[1].email"]
+    );
+    assert_eq!(
+        error.errors["contacts[1].email"],
+        vec!["must be an email address"]
+    );
+}
+
+#[tokio::test]
+async fn errors_are_problem_details_when_accepted() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = create_partner(&app, "application/problem+json").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+        Some("application/problem+json")
+    );
+    let problem = response
+        .json::<ProblemDetails>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        problem.problem_type,
+        "urn:warehouse:problem:validation-failed"
+    );
+    assert_eq!(problem.title, "Invalid arguments");
+    assert_eq!(problem.status, 400);
+    assert_eq!(problem.code, ErrorCode::ValidationFailed);
+    assert_eq!(
+        problem.errors.keys().collect::<Vec<_>>(),
+        vec!["code", "contacts[1].email"]
+    );
+}
+
+#[tokio::test]
+async fn problem_details_follow_the_accept_preference() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let problem = get_product(&app, "application/json;q=0.5, application/problem+json").await;
+    let plain = get_product(&app, "application/json, application/problem+json;q=0.5").await;
+
+    // Assert
+    assert_eq!(problem.status(), 404);
+    let problem = problem
+        .json::<ProblemDetails>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        problem.problem_type,
+        "urn:warehouse:problem:object-not-found"
+    );
+    assert_eq!(problem.detail, None);
+    assert!(problem.errors.is_empty());
+    assert_eq!(plain.status(), 404);
+    assert_eq!(
+        plain
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+        Some("application/json")
+    );
+    let error = plain
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ObjectNotFound);
+}
+
+#[tokio::test]
+async fn malformed_requests_are_validation_problems() {
+    // Arrange
+    let app = spawn_app().await;
+    let client = reqwest::Client::new();
+    let token = app.access_token().await;
+
+    // Act
+    let body = client
+        .post(format!("{}/api/v1/business-partners", &app.address))
+        .bearer_auth(&token)
+        .header(ACCEPT, "application/problem+json")
+        .header(CONTENT_TYPE, "application/json")
+        .body("{\"name\": ")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let query = client
+        .get(format!(
+            "{}/api/v1/stock/available-to-promise/{}?warehouse_id=nope",
+            &app.address,
+            Uuid::new_v4()
+        ))
+        .bearer_auth(&token)
+        .header(ACCEPT, "application/problem+json")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let path = client
+        .get(format!("{}/api/v1/products/not-a-uuid", &app.address))
+        .bearer_auth(&token)
+        .header(ACCEPT, "application/problem+json")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    for response in [body, query, path] {
+        assert_eq!(response.status(), 400);
+        assert_eq!(
+            response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok()),
+            Some("application/problem+json")
+        );
+        let problem = response
+            .json::<ProblemDetails>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(problem.code, ErrorCode::ValidationFailed);
+        assert!(problem.detail.is_some());
+    }
+}
+
+#[tokio::test]
+async fn database_errors_give_no_detail() {
+    // Arrange
+    let app = spawn_app().await;
+    let product = serde_json::json!({ "sku": "DUP-1", "name": "Duplicate" });
+    app.post("/products", product.clone())
+        .await
+        .expect("Failed to execute request.");
+
+    // Act
+    let response = app
+        .post("/products", product)
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ObjectAlreadyExists);
+    assert_eq!(error.detail, None);
+}
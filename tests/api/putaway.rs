@@ -0,0 +1,244 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{PutawayStrategy, PutawayTaskStatus};
+use warehouse::dto::{
+    AppError, ConfirmPutawayResponse, LocationResponse, PutawayRuleResponse, PutawayTaskResponse,
+    StockLevelResponse,
+};
+
+async fn create_location(app: &TestApp<'_>, warehouse_id: Uuid, code: &str, x: u32) -> Uuid {
+    app.post(
+        "/locations",
+        serde_json::json!({
+            "warehouse_id": warehouse_id,
+            "code": code,
+            "position_x": x,
+            "position_y": 0,
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+    .json::<LocationResponse>()
+    .await
+    .expect("Failed to parse response.")
+    .id
+}
+
+/// Receives five units at a receiving dock next to an empty near and an empty far bin and
+/// generates putaway tasks with consolidation first and empty locations second.
+async fn generate(app: &TestApp<'_>) -> (StockFixture, Uuid, PutawayTaskResponse) {
+    let fixture = app.create_stock_fixture().await;
+    let dock = StockFixture {
+        location_id: create_location(app, fixture.warehouse_id, "DOCK", 0).await,
+        ..fixture
+    };
+    let near = create_location(app, dock.warehouse_id, "B-02", 2).await;
+    create_location(app, dock.warehouse_id, "B-10", 10).await;
+    app.receive(&dock, 5).await;
+
+    let response = app
+        .post(
+            &format!("/warehouses/{}/putaway-rules", dock.warehouse_id),
+            serde_json::json!({
+                "rules": [
+                    { "strategy": "consolidate" },
+                    { "strategy": "empty_location" },
+                ],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    let rules = response
+        .json::<Vec<PutawayRuleResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        rules.iter().map(|rule| rule.sequence).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    let response = app
+        .post(
+            "/putaway-tasks/generate",
+            serde_json::json!({ "location_id": dock.location_id }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let mut tasks = response
+        .json::<Vec<PutawayTaskResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(tasks.len(), 1);
+
+    (dock, near, tasks.remove(0))
+}
+
+#[tokio::test]
+async fn generate_suggests_nearest_empty_location() {
+    let app = spawn_app().await;
+    let (dock, near, task) = generate(&app).await;
+
+    assert_eq!(task.product_id, dock.product_id);
+    assert_eq!(task.quantity, Decimal::from(5));
+    assert_eq!(task.suggested_location_id, Some(near));
+    assert_eq!(task.strategy, Some(PutawayStrategy::EmptyLocation));
+    assert_eq!(task.status, PutawayTaskStatus::Open);
+}
+
+#[tokio::test]
+async fn generate_skips_stock_with_open_tasks() {
+    let app = spawn_app().await;
+    let (dock, _, _) = generate(&app).await;
+
+    let error = app
+        .post(
+            "/putaway-tasks/generate",
+            serde_json::json!({ "location_id": dock.location_id }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
+
+#[tokio::test]
+async fn generate_leaves_out_held_stock() {
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let dock = StockFixture {
+        location_id: create_location(&app, fixture.warehouse_id, "DOCK", 0).await,
+        ..fixture
+    };
+    app.receive(&dock, 5).await;
+    let response = app
+        .post(
+            "/stock/holds",
+            serde_json::json!({
+                "product_id": dock.product_id,
+                "location_id": dock.location_id,
+                "quantity": 2,
+                "status": "quarantine",
+                "reason": "Damaged packaging",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    let tasks = app
+        .post(
+            "/putaway-tasks/generate",
+            serde_json::json!({ "location_id": dock.location_id }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<PutawayTaskResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].quantity, Decimal::from(3));
+}
+
+#[tokio::test]
+async fn confirm_moves_stock_to_scanned_location() {
+    let app = spawn_app().await;
+    let (dock, near, task) = generate(&app).await;
+
+    let response = app
+        .post(
+            &format!("/putaway-tasks/{}/confirm", task.id),
+            serde_json::json!({ "location_code": "B-02" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    let confirmed = response
+        .json::<ConfirmPutawayResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(confirmed.task.status, PutawayTaskStatus::Completed);
+    assert_eq!(confirmed.task.to_location_id, Some(near));
+    assert_eq!(confirmed.movements.len(), 1);
+
+    let levels = app
+        .get(&format!(
+            "/stock/levels?location_id={near}&product_id={}",
+            dock.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(levels[0].on_hand, Decimal::from(5));
+}
+
+#[tokio::test]
+async fn confirm_rejects_wrong_location() {
+    let app = spawn_app().await;
+    let (_, _, task) = generate(&app).await;
+
+    let response = app
+        .post(
+            &format!("/putaway-tasks/{}/confirm", task.id),
+            serde_json::json!({ "location_code": "B-10" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+
+    let task = app
+        .get(&format!("/putaway-tasks/{}", task.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<PutawayTaskResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(task.status, PutawayTaskStatus::Open);
+}
+
+#[tokio::test]
+async fn concurrent_confirmations_move_stock_once() {
+    let app = spawn_app().await;
+    let (dock, near, task) = generate(&app).await;
+    // Enough stock at the dock for both confirmations to move it.
+    app.receive(&dock, 5).await;
+    let path = format!("/putaway-tasks/{}/confirm", task.id);
+    let body = serde_json::json!({ "location_code": "B-02" });
+
+    let (first, second) =
+        tokio::join!(app.post(&path, body.clone()), app.post(&path, body.clone()));
+
+    let statuses = [
+        first.expect("Failed to execute request.").status(),
+        second.expect("Failed to execute request.").status(),
+    ];
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        1
+    );
+    let levels = app
+        .get(&format!(
+            "/stock/levels?location_id={near}&product_id={}",
+            dock.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(levels[0].on_hand, Decimal::from(5));
+}
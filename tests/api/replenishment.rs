@@ -0,0 +1,247 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{DocumentType, ReplenishmentKind, SuggestionStatus};
+use warehouse::dto::{
+    AppError, LocationResponse, ReplenishmentRuleResponse, ReplenishmentSuggestionResponse,
+    StockLevelResponse,
+};
+
+async fn save_rule(app: &TestApp<'_>, rule: serde_json::Value) {
+    let response = app
+        .post("/replenishment/rules", rule)
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+}
+
+async fn run(app: &TestApp<'_>, fixture: &StockFixture) -> Vec<ReplenishmentSuggestionResponse> {
+    let response = app
+        .post(
+            "/replenishment/run",
+            serde_json::json!({ "warehouse_id": fixture.warehouse_id }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<Vec<ReplenishmentSuggestionResponse>>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn review(
+    app: &TestApp<'_>,
+    suggestion: &ReplenishmentSuggestionResponse,
+    decision: &str,
+) -> reqwest::Response {
+    app.post(
+        &format!("/replenishment/suggestions/{}/{decision}", suggestion.id),
+        serde_json::json!({}),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn accepted_purchase_suggestion_counts_as_inbound() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    save_rule(
+        &app,
+        serde_json::json!({
+            "product_id": fixture.product_id,
+            "warehouse_id": fixture.warehouse_id,
+            "reorder_point": 20,
+            "max_quantity": 100,
+        }),
+    )
+    .await;
+
+    // Act
+    let suggestions = run(&app, &fixture).await;
+    let response = review(&app, &suggestions[0], "accept").await;
+    let rerun = run(&app, &fixture).await;
+
+    // Assert
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].kind, ReplenishmentKind::Purchase);
+    assert_eq!(suggestions[0].available, Decimal::from(10));
+    assert_eq!(suggestions[0].quantity, Decimal::from(90));
+
+    assert_eq!(response.status(), 200);
+    let accepted = response
+        .json::<ReplenishmentSuggestionResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(accepted.status, SuggestionStatus::Accepted);
+    assert_eq!(accepted.document_type, Some(DocumentType::PurchaseOrder));
+    assert!(accepted.document_id.is_some());
+
+    assert!(rerun.is_empty());
+}
+
+#[tokio::test]
+async fn fixed_reorder_quantity_is_suggested_in_multiples() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 5).await;
+    save_rule(
+        &app,
+        serde_json::json!({
+            "product_id": fixture.product_id,
+            "warehouse_id": fixture.warehouse_id,
+            "reorder_point": 20,
+            "reorder_quantity": 12,
+        }),
+    )
+    .await;
+
+    // Act
+    let suggestions = run(&app, &fixture).await;
+
+    // Assert
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].quantity, Decimal::from(24));
+}
+
+#[tokio::test]
+async fn rule_quantities_are_converted_from_the_given_unit() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            &format!("/products/{}/uoms", fixture.product_id),
+            serde_json::json!({ "code": "case", "factor": 12 }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    app.receive(&fixture, 5).await;
+
+    // Act
+    let response = app
+        .post(
+            "/replenishment/rules",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "warehouse_id": fixture.warehouse_id,
+                "reorder_point": 2,
+                "reorder_quantity": 1,
+                "uom": "case",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    let suggestions = run(&app, &fixture).await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let rule = response
+        .json::<ReplenishmentRuleResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(rule.reorder_point, Decimal::from(24));
+    assert_eq!(rule.reorder_quantity, Some(Decimal::from(12)));
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].quantity, Decimal::from(24));
+}
+
+#[tokio::test]
+async fn accepted_pick_face_suggestion_moves_stock() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let pick_face = app
+        .post(
+            "/locations",
+            serde_json::json!({ "warehouse_id": fixture.warehouse_id, "code": "P-01" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    save_rule(
+        &app,
+        serde_json::json!({
+            "product_id": fixture.product_id,
+            "warehouse_id": fixture.warehouse_id,
+            "location_id": pick_face.id,
+            "reorder_point": 5,
+            "max_quantity": 30,
+        }),
+    )
+    .await;
+
+    // Act
+    let suggestions = run(&app, &fixture).await;
+    let response = review(&app, &suggestions[0], "accept").await;
+
+    // Assert
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].kind, ReplenishmentKind::PickFace);
+    assert_eq!(suggestions[0].from_location_id, Some(fixture.location_id));
+    assert_eq!(suggestions[0].quantity, Decimal::from(30));
+    assert_eq!(response.status(), 200);
+
+    let levels = app
+        .get(&format!("/stock/levels?location_id={}", pick_face.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(levels.len(), 1);
+    assert_eq!(levels[0].on_hand, Decimal::from(30));
+}
+
+#[tokio::test]
+async fn rerun_supersedes_pending_suggestion() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    save_rule(
+        &app,
+        serde_json::json!({
+            "product_id": fixture.product_id,
+            "warehouse_id": fixture.warehouse_id,
+            "reorder_point": 0,
+            "max_quantity": 50,
+        }),
+    )
+    .await;
+    let first = run(&app, &fixture).await;
+    run(&app, &fixture).await;
+
+    // Act
+    let response = review(&app, &first[0], "reject").await;
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+
+    let pending = app
+        .get(&format!(
+            "/replenishment/suggestions?warehouse_id={}&status=pending",
+            fixture.warehouse_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<ReplenishmentSuggestionResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(pending.len(), 1);
+    assert_ne!(pending[0].id, first[0].id);
+}
@@ -0,0 +1,310 @@
+use crate::helpers::spawn_app;
+use chrono::{Duration, Utc};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::ReservationStatus;
+use warehouse::dto::{AppError, AvailableToPromiseResponse, ReservationResponse};
+
+#[tokio::test]
+async fn reserve_decreases_available_to_promise() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+
+    // Act
+    let response = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 6,
+                "document_type": "sales_order",
+                "document_id": Uuid::new_v4(),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let reservation = response
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(reservation.status, ReservationStatus::Active);
+
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    assert_eq!(atp.on_hand, Decimal::from(10));
+    assert_eq!(atp.reserved, Decimal::from(6));
+    assert_eq!(atp.available, Decimal::from(4));
+}
+
+#[tokio::test]
+async fn reserve_more_than_available_fails() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 5).await;
+
+    let reserve = |quantity: u32| {
+        serde_json::json!({
+            "product_id": fixture.product_id,
+            "location_id": fixture.location_id,
+            "quantity": quantity,
+            "document_type": "sales_order",
+            "document_id": Uuid::new_v4(),
+        })
+    };
+
+    let response = app
+        .post("/reservations", reserve(3))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    // Act
+    let response = app
+        .post("/reservations", reserve(3))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    assert_eq!(
+        response.json::<AppError>().await.unwrap().code,
+        ErrorCode::InsufficientStock
+    );
+}
+
+#[tokio::test]
+async fn released_reservation_frees_stock() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 5).await;
+
+    let reservation = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 5,
+                "document_type": "sales_order",
+                "document_id": Uuid::new_v4(),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = app
+        .post(
+            &format!("/reservations/{}/release", reservation.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.available, Decimal::from(5));
+}
+
+#[tokio::test]
+async fn consume_reservation_issues_stock() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 8).await;
+
+    let reservation = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 5,
+                "document_type": "sales_order",
+                "document_id": Uuid::new_v4(),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = app
+        .post(
+            &format!("/reservations/{}/consume", reservation.id),
+            serde_json::json!({ "quantity": 2 }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.on_hand, Decimal::from(6));
+    assert_eq!(atp.reserved, Decimal::from(3));
+    assert_eq!(atp.available, Decimal::from(3));
+}
+
+#[tokio::test]
+async fn consume_without_quantity_issues_what_is_still_reserved() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 8).await;
+
+    let reservation = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 5,
+                "document_type": "sales_order",
+                "document_id": Uuid::new_v4(),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    let path = format!("/reservations/{}/consume", reservation.id);
+
+    // Act
+    let (partial, rest) = tokio::join!(
+        app.post(&path, serde_json::json!({ "quantity": 2 })),
+        app.post(&path, serde_json::json!({})),
+    );
+
+    // Assert
+    assert_eq!(rest.expect("Failed to execute request.").status(), 201);
+    partial.expect("Failed to execute request.");
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.on_hand, Decimal::from(3));
+    assert_eq!(atp.reserved, Decimal::ZERO);
+}
+
+#[tokio::test]
+async fn overdue_reservations_expire_on_schedule() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 5).await;
+
+    // Act
+    let reservation = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 5,
+                "document_type": "sales_order",
+                "document_id": Uuid::new_v4(),
+                "expires_at": Utc::now() - Duration::minutes(1),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ReservationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    let mut status = reservation.status;
+    for _ in 0..50 {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        status = app
+            .get(&format!("/reservations/{}", reservation.id))
+            .await
+            .expect("Failed to execute request.")
+            .json::<ReservationResponse>()
+            .await
+            .expect("Failed to parse response.")
+            .status;
+        if status == ReservationStatus::Expired {
+            break;
+        }
+    }
+    assert_eq!(status, ReservationStatus::Expired);
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.available, Decimal::from(5));
+}
+
+#[tokio::test]
+async fn reserve_without_token_fails() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/v1/reservations", &app.address))
+        .json(&serde_json::json!({}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 401);
+}
@@ -0,0 +1,410 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{ReturnDisposition, ReturnReason, ReturnStatus};
+use warehouse::dto::{
+    AppError, LocationResponse, ReceiveReturnResponse, ReturnAuthorizationResponse,
+    ReturnSummaryResponse, StockLevelResponse,
+};
+
+/// Issues stock on a new sales order and returns its id.
+async fn ship(app: &TestApp<'_>, fixture: &StockFixture, quantity: u32) -> Uuid {
+    let sales_order_id = Uuid::new_v4();
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "issue",
+                "product_id": fixture.product_id,
+                "from_location_id": fixture.location_id,
+                "quantity": quantity,
+                "document_type": "sales_order",
+                "document_id": sales_order_id,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    sales_order_id
+}
+
+async fn create_quarantine(app: &TestApp<'_>, fixture: &StockFixture) -> Uuid {
+    let response = app
+        .post(
+            "/locations",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "code": "RETURNS",
+                "quarantine": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    response
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id
+}
+
+async fn authorize(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    sales_order_id: Uuid,
+    quantity: u32,
+) -> reqwest::Response {
+    app.post(
+        "/returns",
+        serde_json::json!({
+            "sales_order_id": sales_order_id,
+            "warehouse_id": fixture.warehouse_id,
+            "lines": [{
+                "product_id": fixture.product_id,
+                "quantity": quantity,
+                "reason": "damaged",
+            }],
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+async fn receive(
+    app: &TestApp<'_>,
+    authorization: &ReturnAuthorizationResponse,
+    location_id: Uuid,
+    quantity: u32,
+) -> reqwest::Response {
+    app.post(
+        &format!("/returns/{}/receive", authorization.id),
+        serde_json::json!({
+            "lines": [{
+                "line_id": authorization.lines[0].id,
+                "location_id": location_id,
+                "quantity": quantity,
+            }],
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+async fn level(app: &TestApp<'_>, location_id: Uuid) -> StockLevelResponse {
+    app.get(&format!("/stock/levels?location_id={location_id}"))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.")
+        .remove(0)
+}
+
+#[tokio::test]
+async fn returned_stock_is_quarantined_until_inspected() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    let quarantine_id = create_quarantine(&app, &fixture).await;
+    let authorization = authorize(&app, &fixture, sales_order_id, 3)
+        .await
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = receive(&app, &authorization, quarantine_id, 3).await;
+    let quarantined = level(&app, quarantine_id).await;
+    let inspected = app
+        .post(
+            &format!("/returns/{}/inspect", authorization.id),
+            serde_json::json!({
+                "lines": [
+                    {
+                        "line_id": authorization.lines[0].id,
+                        "quantity": 2,
+                        "disposition": "restock",
+                        "to_location_id": fixture.location_id,
+                    },
+                    {
+                        "line_id": authorization.lines[0].id,
+                        "quantity": 1,
+                        "disposition": "scrap",
+                    },
+                ],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let received = response
+        .json::<ReceiveReturnResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        received.authorization.lines[0].received_quantity,
+        Decimal::from(3)
+    );
+    assert_eq!(received.movements.len(), 1);
+
+    assert_eq!(quarantined.on_hand, Decimal::from(3));
+    assert_eq!(quarantined.blocked, Decimal::from(3));
+    assert_eq!(quarantined.available, Decimal::ZERO);
+
+    assert_eq!(inspected.status(), 200);
+    let inspected = inspected
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(inspected.status, ReturnStatus::Completed);
+    assert_eq!(inspected.inspections.len(), 2);
+    assert_eq!(level(&app, quarantine_id).await.on_hand, Decimal::ZERO);
+    assert_eq!(
+        level(&app, fixture.location_id).await.on_hand,
+        Decimal::from(8)
+    );
+
+    let summary = app
+        .get(&format!(
+            "/returns/summary?product_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<ReturnSummaryResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        summary,
+        vec![
+            ReturnSummaryResponse {
+                product_id: fixture.product_id,
+                reason: ReturnReason::Damaged,
+                disposition: Some(ReturnDisposition::Restock),
+                quantity: Decimal::from(2),
+            },
+            ReturnSummaryResponse {
+                product_id: fixture.product_id,
+                reason: ReturnReason::Damaged,
+                disposition: Some(ReturnDisposition::Scrap),
+                quantity: Decimal::from(1),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn inspection_takes_units_from_the_given_quarantine_location() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    let first_quarantine_id = create_quarantine(&app, &fixture).await;
+    let second_quarantine_id = app
+        .post(
+            "/locations",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "code": "RETURNS-2",
+                "quarantine": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let authorization = authorize(&app, &fixture, sales_order_id, 2)
+        .await
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    for location_id in [first_quarantine_id, second_quarantine_id] {
+        assert_eq!(
+            receive(&app, &authorization, location_id, 1).await.status(),
+            200
+        );
+    }
+
+    // Act
+    let response = app
+        .post(
+            &format!("/returns/{}/inspect", authorization.id),
+            serde_json::json!({
+                "lines": [{
+                    "line_id": authorization.lines[0].id,
+                    "quantity": 1,
+                    "disposition": "scrap",
+                    "from_location_id": first_quarantine_id,
+                }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        app.get(&format!("/stock/levels?location_id={first_quarantine_id}"))
+            .await
+            .expect("Failed to execute request.")
+            .json::<Vec<StockLevelResponse>>()
+            .await
+            .expect("Failed to parse response.")
+            .iter()
+            .map(|level| level.on_hand)
+            .sum::<Decimal>(),
+        Decimal::ZERO
+    );
+    assert_eq!(
+        level(&app, second_quarantine_id).await.on_hand,
+        Decimal::from(1)
+    );
+}
+
+#[tokio::test]
+async fn cannot_authorize_more_than_was_shipped() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    assert_eq!(
+        authorize(&app, &fixture, sales_order_id, 3).await.status(),
+        201
+    );
+
+    // Act
+    let response = authorize(&app, &fixture, sales_order_id, 2).await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn concurrent_authorizations_cannot_exceed_shipped() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+
+    // Act
+    let (first, second) = tokio::join!(
+        authorize(&app, &fixture, sales_order_id, 3),
+        authorize(&app, &fixture, sales_order_id, 3),
+    );
+
+    // Assert
+    let statuses = [first.status(), second.status()];
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn concurrent_receipts_cannot_exceed_authorized() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    let quarantine_id = create_quarantine(&app, &fixture).await;
+    let authorization = authorize(&app, &fixture, sales_order_id, 3)
+        .await
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let (first, second) = tokio::join!(
+        receive(&app, &authorization, quarantine_id, 2),
+        receive(&app, &authorization, quarantine_id, 2),
+    );
+
+    // Assert
+    let statuses = [first.status(), second.status()];
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        1
+    );
+    assert_eq!(level(&app, quarantine_id).await.on_hand, Decimal::from(2));
+}
+
+#[tokio::test]
+async fn returns_must_be_received_into_quarantine() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    let authorization = authorize(&app, &fixture, sales_order_id, 1)
+        .await
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Act
+    let response = receive(&app, &authorization, fixture.location_id, 1).await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        level(&app, fixture.location_id).await.on_hand,
+        Decimal::from(6)
+    );
+}
+
+#[tokio::test]
+async fn close_requires_received_stock_to_be_inspected() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let sales_order_id = ship(&app, &fixture, 4).await;
+    let quarantine_id = create_quarantine(&app, &fixture).await;
+    let authorization = authorize(&app, &fixture, sales_order_id, 2)
+        .await
+        .json::<ReturnAuthorizationResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        receive(&app, &authorization, quarantine_id, 1)
+            .await
+            .status(),
+        200
+    );
+
+    // Act
+    let response = app
+        .post(
+            &format!("/returns/{}/close", authorization.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
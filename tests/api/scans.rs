@@ -0,0 +1,221 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{
+    AppError, Gs1ScanResponse, LotResponse, ProductResponse, StockMovementResponse,
+};
+
+/// EAN-13 of the case, scanned as GTIN-14 with a leading zero.
+const CASE_BARCODE: &str = "4006381333931";
+
+async fn create_case(app: &TestApp<'_>) -> StockFixture {
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            &format!("/products/{}/uoms", fixture.product_id),
+            serde_json::json!({ "code": "case", "factor": 12, "barcode": CASE_BARCODE }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    fixture
+}
+
+/// Like [`create_case`], with a product that is lot and expiry tracked.
+async fn create_tracked_case(app: &TestApp<'_>) -> StockFixture {
+    let mut fixture = app.create_stock_fixture().await;
+    fixture.product_id = app
+        .post(
+            "/products",
+            serde_json::json!({
+                "sku": "YOG-1",
+                "name": "Yoghurt",
+                "lot_tracked": true,
+                "expiry_tracked": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let response = app
+        .post(
+            &format!("/products/{}/uoms", fixture.product_id),
+            serde_json::json!({ "code": "case", "factor": 12, "barcode": CASE_BARCODE }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    fixture
+}
+
+async fn scan(app: &TestApp<'_>, data: &str) -> reqwest::Response {
+    app.post("/scans/gs1", serde_json::json!({ "data": data }))
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn raw_scan_fills_product_lot_expiry_and_quantity() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_case(&app).await;
+
+    // Act
+    let response = scan(&app, "]C10104006381333931172612311042-A\u{1d}3712").await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let scan = response
+        .json::<Gs1ScanResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(scan.elements.len(), 4);
+    assert_eq!(scan.gtin.as_deref(), Some("04006381333931"));
+    assert_eq!(scan.expiry_date, NaiveDate::from_ymd_opt(2026, 12, 31));
+    assert_eq!(scan.lot_number.as_deref(), Some("42-A"));
+    assert_eq!(scan.quantity, Some(Decimal::from(12)));
+    assert_eq!(scan.product_id, Some(fixture.product_id));
+    assert_eq!(scan.uom.as_deref(), Some("case"));
+}
+
+#[tokio::test]
+async fn human_readable_scan_is_parsed() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = scan(&app, "(00)006141410000000012(17)270200(3102)001250").await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let scan = response
+        .json::<Gs1ScanResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(scan.sscc.as_deref(), Some("006141410000000012"));
+    assert_eq!(scan.expiry_date, NaiveDate::from_ymd_opt(2027, 2, 28));
+    assert_eq!(scan.net_weight_kg, Some(Decimal::new(1250, 2)));
+    assert_eq!(scan.product_id, None);
+}
+
+#[tokio::test]
+async fn scan_with_wrong_check_digit_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = scan(&app, "0104006381333932").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn scan_with_non_ascii_characters_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+
+    // Act
+    let response = scan(&app, "111€€").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn receipt_takes_unit_lot_and_expiry_from_scan() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_tracked_case(&app).await;
+
+    // Act
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": 2,
+                "scan": "]C10104006381333931172912311042-A",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let movement = response
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.")
+        .remove(0);
+    assert_eq!(movement.quantity, Decimal::from(24));
+    let lot = app
+        .get(&format!(
+            "/lots/{}",
+            movement.lot_id.expect("Receipt has no lot.")
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<LotResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(lot.lot_number.as_deref(), Some("42-A"));
+    assert_eq!(lot.expiry_date, NaiveDate::from_ymd_opt(2029, 12, 31));
+}
+
+#[tokio::test]
+async fn receipt_with_scan_of_another_product_or_lot_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+    let case = create_tracked_case(&app).await;
+    let other = app.create_stock_fixture().await;
+
+    // Act
+    let mut responses = Vec::new();
+    for (product_id, lot_number) in [(other.product_id, None), (case.product_id, Some("43-B"))] {
+        responses.push(
+            app.post(
+                "/stock/movements",
+                serde_json::json!({
+                    "kind": "receipt",
+                    "product_id": product_id,
+                    "to_location_id": case.location_id,
+                    "quantity": 1,
+                    "lot_number": lot_number,
+                    "scan": "(01)04006381333931(17)291231(10)42-A",
+                }),
+            )
+            .await
+            .expect("Failed to execute request."),
+        );
+    }
+
+    // Assert
+    for response in responses {
+        assert_eq!(response.status(), 400);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use reqwest::Response;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{MovementKind, SerialStatus};
+use warehouse::dto::{AppError, ProductResponse, SerialNumberResponse, StockMovementResponse};
+
+/// Replaces the fixture product with a serial-tracked one.
+async fn create_serial_fixture(app: &TestApp<'_>) -> StockFixture {
+    let mut fixture = app.create_stock_fixture().await;
+    let sku = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+
+    let product = app
+        .post(
+            "/products",
+            serde_json::json!({ "sku": &sku, "name": "Laptop", "serial_tracked": true }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.");
+    fixture.product_id = product.id;
+
+    fixture
+}
+
+async fn post_serials(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    kind: &str,
+    serial_numbers: &[&str],
+) -> Response {
+    let (from, to) = match kind {
+        "receipt" => (None, Some(fixture.location_id)),
+        _ => (Some(fixture.location_id), None),
+    };
+
+    app.post(
+        "/stock/movements",
+        serde_json::json!({
+            "kind": kind,
+            "product_id": fixture.product_id,
+            "from_location_id": from,
+            "to_location_id": to,
+            "quantity": serial_numbers.len().max(1),
+            "serial_numbers": serial_numbers,
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+async fn find_serial(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    serial_number: &str,
+) -> SerialNumberResponse {
+    app.get(&format!(
+        "/products/{}/serial-numbers/{}",
+        fixture.product_id, serial_number
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<SerialNumberResponse>()
+    .await
+    .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn receipt_of_serial_tracked_product_requires_serial_per_unit() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_serial_fixture(&app).await;
+
+    // Act
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": 2,
+                "serial_numbers": ["SN-1"],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response.json::<AppError>().await.unwrap().code,
+        ErrorCode::ValidationFailed
+    );
+}
+
+#[tokio::test]
+async fn serial_is_tracked_through_receipt_and_issue() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_serial_fixture(&app).await;
+    let response = post_serials(&app, &fixture, "receipt", &["SN-1", "SN-2"]).await;
+    assert_eq!(response.status(), 201);
+
+    let received = find_serial(&app, &fixture, "SN-1").await;
+    assert_eq!(received.status, SerialStatus::InStock);
+    assert_eq!(received.location_id, Some(fixture.location_id));
+
+    // Act
+    let response = post_serials(&app, &fixture, "issue", &["SN-1"]).await;
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let issued = find_serial(&app, &fixture, "SN-1").await;
+    assert_eq!(issued.status, SerialStatus::Issued);
+    assert_eq!(
+        find_serial(&app, &fixture, "SN-2").await.status,
+        SerialStatus::InStock
+    );
+
+    let history = app
+        .get(&format!("/serial-numbers/{}/history", issued.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    let kinds: Vec<MovementKind> = history.iter().map(|movement| movement.kind).collect();
+    assert_eq!(kinds, vec![MovementKind::Receipt, MovementKind::Issue]);
+}
+
+#[tokio::test]
+async fn serial_in_stock_cannot_be_received_twice() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_serial_fixture(&app).await;
+    post_serials(&app, &fixture, "receipt", &["SN-1"]).await;
+
+    // Act
+    let response = post_serials(&app, &fixture, "receipt", &["SN-1"]).await;
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    assert_eq!(
+        response.json::<AppError>().await.unwrap().code,
+        ErrorCode::InvalidState
+    );
+}
+
+#[tokio::test]
+async fn issue_requires_serials_in_stock_at_location() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = create_serial_fixture(&app).await;
+    post_serials(&app, &fixture, "receipt", &["SN-1"]).await;
+
+    // Act
+    let unknown = post_serials(&app, &fixture, "issue", &["SN-404"]).await;
+    let missing = post_serials(&app, &fixture, "issue", &[]).await;
+
+    // Assert
+    assert_eq!(unknown.status(), 409);
+    assert_eq!(missing.status(), 400);
+}
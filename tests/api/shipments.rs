@@ -0,0 +1,196 @@
+use crate::helpers::{TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use reqwest::header::CONTENT_TYPE;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::ShipmentStatus;
+use warehouse::dto::{AppError, RateResponse, ShipmentDetailResponse, ShipmentResponse};
+
+/// An open shipment with a 30x20x10 cm parcel per given weight in kg.
+async fn create_shipment(app: &TestApp<'_>, weights: &[u32]) -> Uuid {
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            "/shipments",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "ship_to_name": "Jane Doe",
+                "ship_to_address": "1 Main Street",
+                "ship_to_postal_code": "12345",
+                "ship_to_country": "us",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let shipment = response
+        .json::<ShipmentResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(shipment.ship_to_country, "US");
+
+    for weight in weights {
+        let response = app
+            .post(
+                &format!("/shipments/{}/parcels", shipment.id),
+                serde_json::json!({
+                    "length_cm": 30,
+                    "width_cm": 20,
+                    "height_cm": 10,
+                    "weight_kg": weight,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 201);
+    }
+
+    shipment.id
+}
+
+async fn ship(app: &TestApp<'_>, id: Uuid, body: serde_json::Value) -> reqwest::Response {
+    app.post(&format!("/shipments/{id}/ship"), body)
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn rates_are_ranked_cheapest_first() {
+    // Arrange
+    let app = spawn_app().await;
+    let id = create_shipment(&app, &[2]).await;
+
+    // Act
+    let rates = app
+        .get(&format!("/shipments/{id}/rates"))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<RateResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(
+        rates
+            .iter()
+            .map(|rate| (rate.carrier.as_str(), rate.service.as_str(), rate.amount))
+            .collect::<Vec<_>>(),
+        vec![
+            ("mock", "ground", Decimal::new(700, 2)),
+            ("mock", "express", Decimal::new(1700, 2)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn shipping_with_mock_carrier_prints_labels() {
+    // Arrange
+    let app = spawn_app().await;
+    let id = create_shipment(&app, &[2]).await;
+
+    // Act
+    let response = ship(
+        &app,
+        id,
+        serde_json::json!({ "carrier": "mock", "service": "ground", "label_format": "zpl" }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let shipped = response
+        .json::<ShipmentDetailResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(shipped.shipment.status, ShipmentStatus::Shipped);
+    assert_eq!(shipped.shipment.shipping_cost, Some(Decimal::new(700, 2)));
+    assert_eq!(shipped.shipment.currency.as_deref(), Some("USD"));
+    let parcel = &shipped.parcels[0];
+    assert!(
+        parcel
+            .tracking_number
+            .as_deref()
+            .is_some_and(|tracking_number| tracking_number.starts_with("MOCK"))
+    );
+    assert!(parcel.has_label);
+
+    let response = app
+        .get(&format!("/parcels/{}/label", parcel.id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/zpl");
+
+    let response = app
+        .post(
+            &format!("/shipments/{id}/parcels"),
+            serde_json::json!({
+                "length_cm": 10,
+                "width_cm": 10,
+                "height_cm": 10,
+                "weight_kg": 1,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}
+
+#[tokio::test]
+async fn manual_carrier_takes_a_tracking_number_per_parcel() {
+    // Arrange
+    let app = spawn_app().await;
+    let id = create_shipment(&app, &[1, 3]).await;
+
+    // Act
+    let response = ship(
+        &app,
+        id,
+        serde_json::json!({
+            "carrier": "manual",
+            "service": "freight",
+            "tracking_numbers": ["TRK-1"],
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+
+    let response = ship(
+        &app,
+        id,
+        serde_json::json!({
+            "carrier": "manual",
+            "service": "freight",
+            "tracking_numbers": ["TRK-1", "TRK-2"],
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let shipped = response
+        .json::<ShipmentDetailResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(shipped.shipment.shipping_cost, None);
+    assert_eq!(
+        shipped
+            .parcels
+            .iter()
+            .map(|parcel| (parcel.tracking_number.as_deref(), parcel.has_label))
+            .collect::<Vec<_>>(),
+        vec![(Some("TRK-1"), false), (Some("TRK-2"), false)]
+    );
+
+    let response = app
+        .get(&format!("/parcels/{}/label", shipped.parcels[0].id))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 404);
+}
@@ -0,0 +1,158 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use chrono::{DateTime, SecondsFormat, Utc};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use warehouse::domain::{MovementKind, SnapshotKind};
+use warehouse::dto::{
+    StockMovementResponse, StockPositionResponse, StockSnapshotDetailResponse,
+    StockSnapshotDiffResponse, StockSnapshotResponse,
+};
+
+async fn issue(app: &TestApp<'_>, fixture: &StockFixture, quantity: u32) {
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "issue",
+                "product_id": fixture.product_id,
+                "from_location_id": fixture.location_id,
+                "quantity": quantity,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+}
+
+async fn as_of(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    as_of: DateTime<Utc>,
+) -> Vec<StockPositionResponse> {
+    app.get(&format!(
+        "/stock/as-of?as_of={}&location_id={}",
+        as_of.to_rfc3339_opts(SecondsFormat::Micros, true),
+        fixture.location_id
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<Vec<StockPositionResponse>>()
+    .await
+    .expect("Failed to parse response.")
+}
+
+async fn take_snapshot(app: &TestApp<'_>) -> StockSnapshotResponse {
+    let response = app
+        .post("/stock/snapshots", serde_json::json!({}))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<StockSnapshotResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn stock_as_of_ignores_later_movements() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let before_issue = Utc::now();
+    issue(&app, &fixture, 4).await;
+
+    // Act
+    let then = as_of(&app, &fixture, before_issue).await;
+    let now = as_of(&app, &fixture, Utc::now()).await;
+
+    // Assert
+    assert_eq!(
+        then.iter()
+            .map(|position| (position.product_id, position.quantity))
+            .collect::<Vec<_>>(),
+        vec![(fixture.product_id, Decimal::from(10))]
+    );
+    assert_eq!(now[0].quantity, Decimal::from(6));
+}
+
+#[tokio::test]
+async fn movement_history_lists_movements_in_and_out_of_location() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    issue(&app, &fixture, 4).await;
+
+    // Act
+    let movements = app
+        .get(&format!(
+            "/stock/movements?location_id={}",
+            fixture.location_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(
+        movements
+            .iter()
+            .map(|movement| (movement.kind, movement.quantity))
+            .collect::<Vec<_>>(),
+        vec![
+            (MovementKind::Receipt, Decimal::from(10)),
+            (MovementKind::Issue, Decimal::from(4)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn snapshot_diff_reports_change_per_location() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    let from = take_snapshot(&app).await;
+    issue(&app, &fixture, 3).await;
+    let to = take_snapshot(&app).await;
+
+    // Act
+    let diff = app
+        .get(&format!(
+            "/stock/snapshots/diff?from={}&to={}&product_id={}",
+            from.id, to.id, fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockSnapshotDiffResponse>>()
+        .await
+        .expect("Failed to parse response.");
+
+    // Assert
+    assert_eq!(
+        diff,
+        vec![StockSnapshotDiffResponse {
+            product_id: fixture.product_id,
+            location_id: fixture.location_id,
+            from_quantity: Decimal::from(10),
+            to_quantity: Decimal::from(7),
+            change: Decimal::from(-3),
+        }]
+    );
+
+    let snapshot = app
+        .get(&format!(
+            "/stock/snapshots/{}?product_id={}",
+            to.id, fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<StockSnapshotDetailResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(snapshot.snapshot.kind, SnapshotKind::Manual);
+    assert_eq!(snapshot.lines[0].quantity, Decimal::from(7));
+}
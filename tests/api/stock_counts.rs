@@ -0,0 +1,352 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{CountTaskStatus, ResourceAction, ResourceType};
+use warehouse::dto::{
+    AppError, AvailableToPromiseResponse, CountSessionResponse, CountTaskResponse, LocationResponse,
+};
+
+async fn plan_full_count(app: &TestApp<'_>, fixture: &StockFixture) -> CountSessionResponse {
+    let response = app
+        .post(
+            "/stock-counts",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "scope": "full",
+                "blind": true,
+                "tolerance_percent": 10,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    response
+        .json::<CountSessionResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn record_count(
+    app: &TestApp<'_>,
+    session: &CountSessionResponse,
+    counted_quantity: u32,
+) -> CountTaskResponse {
+    let response = app
+        .post(
+            &format!(
+                "/stock-counts/{}/tasks/{}/count",
+                session.id, session.tasks[0].id
+            ),
+            serde_json::json!({ "counted_quantity": counted_quantity }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<CountTaskResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn on_hand(app: &TestApp<'_>, fixture: &StockFixture) -> Decimal {
+    app.get(&format!(
+        "/stock/available-to-promise/{}",
+        fixture.product_id
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<AvailableToPromiseResponse>()
+    .await
+    .expect("Failed to parse response.")
+    .on_hand
+}
+
+#[tokio::test]
+async fn blind_count_hides_expected_quantity() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+
+    // Act
+    let session = plan_full_count(&app, &fixture).await;
+
+    // Assert
+    assert_eq!(session.tasks.len(), 1);
+    assert_eq!(session.tasks[0].status, CountTaskStatus::Pending);
+    assert_eq!(session.tasks[0].expected_quantity, None);
+}
+
+#[tokio::test]
+async fn count_within_tolerance_is_adjusted_automatically() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let session = plan_full_count(&app, &fixture).await;
+
+    // Act
+    let task = record_count(&app, &session, 95).await;
+
+    // Assert
+    assert_eq!(task.status, CountTaskStatus::Approved);
+    assert_eq!(task.variance, Some(Decimal::from(-5)));
+    assert!(task.movement_id.is_some());
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(95));
+}
+
+#[tokio::test]
+async fn count_leaves_held_stock_out_of_expected_quantity() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 15).await;
+    let response = app
+        .post(
+            "/stock/holds",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 5,
+                "status": "quarantine",
+                "reason": "Suspected contamination",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let session = plan_full_count(&app, &fixture).await;
+
+    // Act
+    let task = record_count(&app, &session, 10).await;
+
+    // Assert
+    assert_eq!(task.status, CountTaskStatus::Approved);
+    assert_eq!(task.variance, Some(Decimal::ZERO));
+    assert_eq!(task.movement_id, None);
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(15));
+}
+
+#[tokio::test]
+async fn count_beyond_tolerance_requires_recount_then_approval() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let session = plan_full_count(&app, &fixture).await;
+
+    // Act
+    let first = record_count(&app, &session, 50).await;
+    let second = record_count(&app, &session, 50).await;
+
+    // Assert
+    assert_eq!(first.status, CountTaskStatus::Recount);
+    assert_eq!(first.variance, None);
+    assert_eq!(second.status, CountTaskStatus::Counted);
+    assert_eq!(second.attempts, 2);
+    assert_eq!(second.variance, Some(Decimal::from(-50)));
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(100));
+}
+
+#[tokio::test]
+async fn counter_cannot_approve_own_count() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let session = plan_full_count(&app, &fixture).await;
+    record_count(&app, &session, 50).await;
+    record_count(&app, &session, 50).await;
+
+    // The first count by the admin, the recount by another user
+    let recounted_fixture = app.create_stock_fixture().await;
+    app.receive(&recounted_fixture, 100).await;
+    let recounted_session = plan_full_count(&app, &recounted_fixture).await;
+    record_count(&app, &recounted_session, 50).await;
+    let recounter = app
+        .create_user(&[(ResourceAction::Update, ResourceType::StockCount)])
+        .await;
+    let response = app
+        .post_as(
+            &recounter,
+            &format!(
+                "/stock-counts/{}/tasks/{}/count",
+                recounted_session.id, recounted_session.tasks[0].id
+            ),
+            serde_json::json!({ "counted_quantity": 50 }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    // Act
+    let mut responses = Vec::new();
+    for session in [&session, &recounted_session] {
+        let response = app
+            .post(
+                &format!(
+                    "/stock-counts/{}/tasks/{}/approve",
+                    session.id, session.tasks[0].id
+                ),
+                serde_json::json!({}),
+            )
+            .await
+            .expect("Failed to execute request.");
+        responses.push(response);
+    }
+
+    // Assert
+    for response in responses {
+        assert_eq!(response.status(), 403);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::PermissionDenied);
+    }
+}
+
+#[tokio::test]
+async fn supervisor_approval_posts_adjustment() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let session = plan_full_count(&app, &fixture).await;
+    record_count(&app, &session, 50).await;
+    record_count(&app, &session, 50).await;
+    let supervisor = app
+        .create_user(&[(ResourceAction::Approve, ResourceType::StockCount)])
+        .await;
+
+    // Act
+    let response = app
+        .post_as(
+            &supervisor,
+            &format!(
+                "/stock-counts/{}/tasks/{}/approve",
+                session.id, session.tasks[0].id
+            ),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let task = response
+        .json::<CountTaskResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(task.status, CountTaskStatus::Approved);
+    assert!(task.movement_id.is_some());
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(50));
+}
+
+#[tokio::test]
+async fn approval_fails_when_stock_moved_since_the_count() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let session = plan_full_count(&app, &fixture).await;
+    record_count(&app, &session, 50).await;
+    record_count(&app, &session, 50).await;
+    app.receive(&fixture, 10).await;
+    let supervisor = app
+        .create_user(&[(ResourceAction::Approve, ResourceType::StockCount)])
+        .await;
+
+    // Act
+    let response = app
+        .post_as(
+            &supervisor,
+            &format!(
+                "/stock-counts/{}/tasks/{}/approve",
+                session.id, session.tasks[0].id
+            ),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(110));
+}
+
+#[tokio::test]
+async fn stock_found_in_empty_bin_is_counted_and_adjusted() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 100).await;
+    let empty_bin = app
+        .post(
+            "/locations",
+            serde_json::json!({ "warehouse_id": fixture.warehouse_id, "code": "B-99" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let session = plan_full_count(&app, &fixture).await;
+    let supervisor = app
+        .create_user(&[(ResourceAction::Approve, ResourceType::StockCount)])
+        .await;
+
+    // Act
+    let response = app
+        .post(
+            &format!("/stock-counts/{}/tasks", session.id),
+            serde_json::json!({ "product_id": fixture.product_id, "location_id": empty_bin }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let task = response
+        .json::<CountTaskResponse>()
+        .await
+        .expect("Failed to parse response.");
+    for _ in 0..2 {
+        let response = app
+            .post(
+                &format!("/stock-counts/{}/tasks/{}/count", session.id, task.id),
+                serde_json::json!({ "counted_quantity": 4 }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 200);
+    }
+    let response = app
+        .post_as(
+            &supervisor,
+            &format!("/stock-counts/{}/tasks/{}/approve", session.id, task.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(task.location_id, empty_bin);
+    assert_eq!(task.status, CountTaskStatus::Pending);
+    assert_eq!(response.status(), 200);
+    let task = response
+        .json::<CountTaskResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(task.expected_quantity, Some(Decimal::ZERO));
+    assert_eq!(task.variance, Some(Decimal::from(4)));
+    assert!(task.movement_id.is_some());
+    assert_eq!(on_hand(&app, &fixture).await, Decimal::from(104));
+}
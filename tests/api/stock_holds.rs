@@ -0,0 +1,261 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{MovementKind, ReservationStatus, StockStatus};
+use warehouse::dto::{
+    AppError, AvailableToPromiseResponse, ProductResponse, ReservationResponse,
+    StockBalanceResponse, StockLevelResponse, StockMovementResponse,
+};
+
+async fn post_hold(
+    app: &TestApp<'_>,
+    path: &str,
+    fixture: &StockFixture,
+    quantity: u32,
+) -> Vec<StockMovementResponse> {
+    let response = app
+        .post(
+            path,
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": quantity,
+                "status": "quarantine",
+                "reason": "Suspected contamination",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn level(app: &TestApp<'_>, fixture: &StockFixture) -> StockLevelResponse {
+    app.get(&format!(
+        "/stock/levels?location_id={}&product_id={}",
+        fixture.location_id, fixture.product_id
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<Vec<StockLevelResponse>>()
+    .await
+    .expect("Failed to parse response.")
+    .remove(0)
+}
+
+#[tokio::test]
+async fn held_stock_is_not_available() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+
+    // Act
+    let movements = post_hold(&app, "/stock/holds", &fixture, 4).await;
+
+    // Assert
+    assert_eq!(movements.len(), 1);
+    assert_eq!(movements[0].kind, MovementKind::StatusChange);
+    assert_eq!(movements[0].status, StockStatus::Available);
+    assert_eq!(movements[0].to_status, Some(StockStatus::Quarantine));
+    assert_eq!(
+        movements[0].reason.as_deref(),
+        Some("Suspected contamination")
+    );
+
+    let level = level(&app, &fixture).await;
+    assert_eq!(level.on_hand, Decimal::from(10));
+    assert_eq!(level.blocked, Decimal::from(4));
+    assert_eq!(level.available, Decimal::from(6));
+
+    let held = app
+        .get(&format!("/stock/holds?product_id={}", fixture.product_id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockBalanceResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(
+        held.iter()
+            .map(|balance| (balance.status, balance.on_hand))
+            .collect::<Vec<_>>(),
+        vec![(StockStatus::Quarantine, Decimal::from(4))]
+    );
+}
+
+#[tokio::test]
+async fn released_stock_is_available_again() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    post_hold(&app, "/stock/holds", &fixture, 4).await;
+
+    // Act
+    let movements = post_hold(&app, "/stock/holds/release", &fixture, 4).await;
+
+    // Assert
+    assert_eq!(movements[0].status, StockStatus::Quarantine);
+    assert_eq!(movements[0].to_status, Some(StockStatus::Available));
+    assert_eq!(level(&app, &fixture).await.available, Decimal::from(10));
+}
+
+#[tokio::test]
+async fn scrapping_held_stock_writes_it_off() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+    post_hold(&app, "/stock/holds", &fixture, 4).await;
+
+    // Act
+    let movements = post_hold(&app, "/stock/holds/scrap", &fixture, 3).await;
+
+    // Assert
+    assert_eq!(movements[0].kind, MovementKind::Adjustment);
+    assert_eq!(movements[0].to_location_id, None);
+
+    let level = level(&app, &fixture).await;
+    assert_eq!(level.on_hand, Decimal::from(7));
+    assert_eq!(level.blocked, Decimal::from(1));
+    assert_eq!(level.available, Decimal::from(6));
+}
+
+#[tokio::test]
+async fn hold_requires_reason() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    app.receive(&fixture, 10).await;
+
+    // Act
+    let response = app
+        .post(
+            "/stock/holds",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": fixture.location_id,
+                "quantity": 4,
+                "status": "on_hold",
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn holding_a_lot_releases_reservations_it_backed() {
+    // Arrange
+    let app = spawn_app().await;
+    let mut fixture = app.create_stock_fixture().await;
+    fixture.product_id = app
+        .post(
+            "/products",
+            serde_json::json!({
+                "sku": uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string(),
+                "name": "Yoghurt",
+                "lot_tracked": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let mut lot_ids = Vec::new();
+    for (lot_number, quantity) in [("LOT-A", 6), ("LOT-B", 4)] {
+        let movement = app
+            .post(
+                "/stock/movements",
+                serde_json::json!({
+                    "kind": "receipt",
+                    "product_id": fixture.product_id,
+                    "to_location_id": fixture.location_id,
+                    "quantity": quantity,
+                    "lot_number": lot_number,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.")
+            .json::<Vec<StockMovementResponse>>()
+            .await
+            .expect("Failed to parse response.")
+            .remove(0);
+        lot_ids.push(movement.lot_id.expect("Receipt has no lot."));
+    }
+    let mut reservations = Vec::new();
+    for quantity in [4, 3] {
+        let reservation = app
+            .post(
+                "/reservations",
+                serde_json::json!({
+                    "product_id": fixture.product_id,
+                    "location_id": fixture.location_id,
+                    "quantity": quantity,
+                    "document_type": "sales_order",
+                    "document_id": Uuid::new_v4(),
+                }),
+            )
+            .await
+            .expect("Failed to execute request.")
+            .json::<ReservationResponse>()
+            .await
+            .expect("Failed to parse response.");
+        reservations.push(reservation);
+    }
+
+    // Act
+    let response = app
+        .post(
+            &format!("/lots/{}/hold", lot_ids[0]),
+            serde_json::json!({ "status": "quarantine", "reason": "Recall" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let mut statuses = Vec::new();
+    for reservation in &reservations {
+        let reservation = app
+            .get(&format!("/reservations/{}", reservation.id))
+            .await
+            .expect("Failed to execute request.")
+            .json::<ReservationResponse>()
+            .await
+            .expect("Failed to parse response.");
+        statuses.push(reservation.status);
+    }
+    // The newest reservation is released, the oldest is still covered by the other lot
+    assert_eq!(
+        statuses,
+        vec![ReservationStatus::Active, ReservationStatus::Released]
+    );
+
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.reserved, Decimal::from(4));
+    assert_eq!(atp.available, Decimal::ZERO);
+}
@@ -0,0 +1,382 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{SerialStatus, TransferOrderStatus};
+use warehouse::dto::{
+    AppError, AvailableToPromiseResponse, InTransitStockResponse, LocationResponse,
+    ProductResponse, ReceiveTransferOrderResponse, SerialNumberResponse,
+    TransferDiscrepancyResponse, TransferOrderResponse, WarehouseResponse,
+};
+
+/// Destination warehouse with a single location.
+async fn create_destination(app: &TestApp<'_>) -> (Uuid, Uuid) {
+    let code = Uuid::new_v4().simple().to_string();
+    let warehouse = app
+        .post(
+            "/warehouses",
+            serde_json::json!({ "code": &code[..16], "name": "Branch warehouse" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<WarehouseResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    let location = app
+        .post(
+            "/locations",
+            serde_json::json!({ "warehouse_id": warehouse.id, "code": "B-01-01" }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    (warehouse.id, location.id)
+}
+
+async fn create_shipped_order(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    destination_id: Uuid,
+    quantity: u32,
+) -> TransferOrderResponse {
+    let order = app
+        .post(
+            "/transfer-orders",
+            serde_json::json!({
+                "source_warehouse_id": fixture.warehouse_id,
+                "destination_warehouse_id": destination_id,
+                "lines": [{
+                    "product_id": fixture.product_id,
+                    "from_location_id": fixture.location_id,
+                    "quantity": quantity,
+                }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<TransferOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+
+    let response = app
+        .post(
+            &format!("/transfer-orders/{}/ship", order.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<TransferOrderResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn receive(
+    app: &TestApp<'_>,
+    order: &TransferOrderResponse,
+    location_id: Uuid,
+    quantity: u32,
+) -> reqwest::Response {
+    app.post(
+        &format!("/transfer-orders/{}/receive", order.id),
+        serde_json::json!({
+            "lines": [{
+                "line_id": order.lines[0].id,
+                "to_location_id": location_id,
+                "quantity": quantity,
+            }],
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+async fn available_to_promise(
+    app: &TestApp<'_>,
+    product_id: Uuid,
+    warehouse_id: Uuid,
+) -> AvailableToPromiseResponse {
+    app.get(&format!(
+        "/stock/available-to-promise/{product_id}?warehouse_id={warehouse_id}"
+    ))
+    .await
+    .expect("Failed to execute request.")
+    .json::<AvailableToPromiseResponse>()
+    .await
+    .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn shipped_stock_is_in_transit_and_not_available() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (destination_id, _) = create_destination(&app).await;
+    app.receive(&fixture, 100).await;
+
+    // Act
+    let order = create_shipped_order(&app, &fixture, destination_id, 40).await;
+
+    // Assert
+    assert_eq!(order.status, TransferOrderStatus::Shipped);
+    assert_eq!(order.lines[0].in_transit, Decimal::from(40));
+
+    let source = available_to_promise(&app, fixture.product_id, fixture.warehouse_id).await;
+    assert_eq!(source.on_hand, Decimal::from(60));
+    let destination = available_to_promise(&app, fixture.product_id, destination_id).await;
+    assert_eq!(destination.in_transit, Decimal::from(40));
+    assert_eq!(destination.available, Decimal::ZERO);
+
+    let in_transit = app
+        .get(&format!(
+            "/transfer-orders/in-transit?product_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<InTransitStockResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(in_transit.len(), 1);
+    assert_eq!(in_transit[0].quantity, Decimal::from(40));
+}
+
+#[tokio::test]
+async fn partial_receipt_leaves_rest_in_transit() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (destination_id, location_id) = create_destination(&app).await;
+    app.receive(&fixture, 100).await;
+    let order = create_shipped_order(&app, &fixture, destination_id, 40).await;
+
+    // Act
+    let response = receive(&app, &order, location_id, 25).await;
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let receipt = response
+        .json::<ReceiveTransferOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(receipt.order.status, TransferOrderStatus::PartiallyReceived);
+    assert_eq!(receipt.order.lines[0].received_quantity, Decimal::from(25));
+    assert_eq!(receipt.order.lines[0].in_transit, Decimal::from(15));
+    assert_eq!(receipt.movements.len(), 1);
+
+    let destination = available_to_promise(&app, fixture.product_id, destination_id).await;
+    assert_eq!(destination.on_hand, Decimal::from(40));
+    assert_eq!(destination.in_transit, Decimal::from(15));
+    assert_eq!(destination.available, Decimal::from(25));
+}
+
+#[tokio::test]
+async fn receiving_more_than_in_transit_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (destination_id, location_id) = create_destination(&app).await;
+    app.receive(&fixture, 100).await;
+    let order = create_shipped_order(&app, &fixture, destination_id, 40).await;
+    assert_eq!(receive(&app, &order, location_id, 30).await.status(), 200);
+
+    // Act
+    let response = receive(&app, &order, location_id, 20).await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn concurrent_receipts_cannot_exceed_in_transit() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (destination_id, location_id) = create_destination(&app).await;
+    app.receive(&fixture, 100).await;
+    let order = create_shipped_order(&app, &fixture, destination_id, 10).await;
+
+    // Act
+    let (first, second) = tokio::join!(
+        receive(&app, &order, location_id, 6),
+        receive(&app, &order, location_id, 6),
+    );
+
+    // Assert
+    let statuses = [first.status(), second.status()];
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        1
+    );
+    let destination = available_to_promise(&app, fixture.product_id, destination_id).await;
+    assert_eq!(destination.in_transit, Decimal::from(4));
+    assert_eq!(destination.available, Decimal::from(6));
+}
+
+#[tokio::test]
+async fn closing_writes_off_missing_quantity_as_discrepancy() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let (destination_id, location_id) = create_destination(&app).await;
+    app.receive(&fixture, 100).await;
+    let order = create_shipped_order(&app, &fixture, destination_id, 40).await;
+    assert_eq!(receive(&app, &order, location_id, 35).await.status(), 200);
+
+    // Act
+    let response = app
+        .post(
+            &format!("/transfer-orders/{}/close", order.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let order = response
+        .json::<TransferOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(order.status, TransferOrderStatus::Closed);
+    assert_eq!(order.lines[0].written_off_quantity, Decimal::from(5));
+    assert_eq!(order.lines[0].in_transit, Decimal::ZERO);
+
+    let destination = available_to_promise(&app, fixture.product_id, destination_id).await;
+    assert_eq!(destination.on_hand, Decimal::from(35));
+    assert_eq!(destination.in_transit, Decimal::ZERO);
+
+    let discrepancies = app
+        .get(&format!(
+            "/transfer-orders/discrepancies?warehouse_id={destination_id}"
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<TransferDiscrepancyResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(discrepancies.len(), 1);
+    assert_eq!(discrepancies[0].transfer_order_id, order.id);
+    assert_eq!(discrepancies[0].missing_quantity, Decimal::from(5));
+}
+
+#[tokio::test]
+async fn closing_writes_off_serials_still_in_transit() {
+    // Arrange
+    let app = spawn_app().await;
+    let mut fixture = app.create_stock_fixture().await;
+    let sku = Uuid::new_v4().simple().to_string();
+    fixture.product_id = app
+        .post(
+            "/products",
+            serde_json::json!({ "sku": sku, "name": "Laptop", "serial_tracked": true }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": 3,
+                "serial_numbers": ["SN-1", "SN-2", "SN-3"],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let (destination_id, location_id) = create_destination(&app).await;
+    let order = app
+        .post(
+            "/transfer-orders",
+            serde_json::json!({
+                "source_warehouse_id": fixture.warehouse_id,
+                "destination_warehouse_id": destination_id,
+                "lines": [{
+                    "product_id": fixture.product_id,
+                    "from_location_id": fixture.location_id,
+                    "quantity": 3,
+                }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<TransferOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    let response = app
+        .post(
+            &format!("/transfer-orders/{}/ship", order.id),
+            serde_json::json!({
+                "lines": [{
+                    "line_id": order.lines[0].id,
+                    "serial_numbers": ["SN-1", "SN-2", "SN-3"],
+                }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    let response = app
+        .post(
+            &format!("/transfer-orders/{}/receive", order.id),
+            serde_json::json!({
+                "lines": [{
+                    "line_id": order.lines[0].id,
+                    "to_location_id": location_id,
+                    "quantity": 1,
+                    "serial_numbers": ["SN-1"],
+                }],
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    // Act
+    let response = app
+        .post(
+            &format!("/transfer-orders/{}/close", order.id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    for (serial_number, status) in [
+        ("SN-1", SerialStatus::InStock),
+        ("SN-2", SerialStatus::WrittenOff),
+        ("SN-3", SerialStatus::WrittenOff),
+    ] {
+        let serial = app
+            .get(&format!(
+                "/products/{}/serial-numbers/{serial_number}",
+                fixture.product_id
+            ))
+            .await
+            .expect("Failed to execute request.")
+            .json::<SerialNumberResponse>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(serial.status, status);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::dto::{AppError, AvailableToPromiseResponse, ProductResponse, ProductUomResponse};
+
+async fn add_uom(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    code: &str,
+    factor: &str,
+    barcode: Option<&str>,
+) -> ProductUomResponse {
+    let response = app
+        .post(
+            &format!("/products/{}/uoms", fixture.product_id),
+            serde_json::json!({ "code": code, "factor": factor, "barcode": barcode }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    response
+        .json::<ProductUomResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn receive_in(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    quantity: &str,
+    uom: &str,
+) -> reqwest::Response {
+    app.post(
+        "/stock/movements",
+        serde_json::json!({
+            "kind": "receipt",
+            "product_id": fixture.product_id,
+            "to_location_id": fixture.location_id,
+            "quantity": quantity,
+            "uom": uom,
+        }),
+    )
+    .await
+    .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn receipt_in_alternate_uom_is_stored_in_base_units() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    add_uom(&app, &fixture, "case", "12", None).await;
+
+    // Act
+    let response = receive_in(&app, &fixture, "2", "case").await;
+
+    // Assert
+    assert_eq!(response.status(), 201);
+    let atp = app
+        .get(&format!(
+            "/stock/available-to-promise/{}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<AvailableToPromiseResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(atp.on_hand, Decimal::from(24));
+}
+
+#[tokio::test]
+async fn unknown_uom_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+
+    // Act
+    let response = receive_in(&app, &fixture, "2", "pallet").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn fractional_base_quantity_is_rejected() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    add_uom(&app, &fixture, "half", "0.5", None).await;
+
+    // Act
+    let response = receive_in(&app, &fixture, "3", "half").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn barcode_resolves_to_product_uom() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let barcode = uuid::fmt::Simple::from_uuid(Uuid::new_v4()).to_string();
+    let uom = add_uom(&app, &fixture, "case", "12", Some(&barcode)).await;
+
+    // Act
+    let response = app
+        .get(&format!("/products/barcodes/{barcode}"))
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let found = response
+        .json::<ProductUomResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(found, uom);
+    assert_eq!(found.product_id, fixture.product_id);
+}
+
+#[tokio::test]
+async fn receipt_overflowing_base_units_fails() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    add_uom(&app, &fixture, "case", "12", None).await;
+
+    // Act
+    let response = receive_in(&app, &fixture, &Decimal::MAX.to_string(), "case").await;
+
+    // Assert
+    assert_eq!(response.status(), 400);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::ValidationFailed);
+}
+
+#[tokio::test]
+async fn receipt_not_fitting_stored_quantities_fails() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    add_uom(&app, &fixture, "case", "12", None).await;
+    let product = app
+        .post(
+            "/products",
+            serde_json::json!({
+                "sku": "FLOUR-1",
+                "name": "Flour",
+                "base_uom": "kg",
+                "fractional_quantities": true,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.");
+    let fractional = StockFixture {
+        warehouse_id: fixture.warehouse_id,
+        location_id: fixture.location_id,
+        product_id: product.id,
+    };
+
+    // Act
+    let responses = vec![
+        // More than 6 decimals once converted
+        receive_in(&app, &fractional, "0.0000001", "kg").await,
+        // 10^12 base units
+        receive_in(&app, &fixture, "1000000000000", "ea").await,
+        receive_in(&app, &fixture, "100000000000", "case").await,
+    ];
+
+    // Assert
+    for response in responses {
+        assert_eq!(response.status(), 400);
+        let error = response
+            .json::<AppError>()
+            .await
+            .expect("Failed to parse response.");
+        assert_eq!(error.code, ErrorCode::ValidationFailed);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use chrono::{SecondsFormat, Utc};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use warehouse::domain::CostingMethod;
+use warehouse::dto::{ProductValuationResponse, StockMovementResponse};
+
+async fn receive_at(app: &TestApp<'_>, fixture: &StockFixture, quantity: u32, unit_cost: &str) {
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "receipt",
+                "product_id": fixture.product_id,
+                "to_location_id": fixture.location_id,
+                "quantity": quantity,
+                "unit_cost": unit_cost,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+}
+
+async fn issue(app: &TestApp<'_>, fixture: &StockFixture, quantity: u32) -> StockMovementResponse {
+    let response = app
+        .post(
+            "/stock/movements",
+            serde_json::json!({
+                "kind": "issue",
+                "product_id": fixture.product_id,
+                "from_location_id": fixture.location_id,
+                "quantity": quantity,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    let mut movements = response
+        .json::<Vec<StockMovementResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(movements.len(), 1);
+    movements.remove(0)
+}
+
+async fn valuation(app: &TestApp<'_>, query: &str) -> Vec<ProductValuationResponse> {
+    let response = app
+        .get(&format!("/valuation?{query}"))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    response
+        .json::<Vec<ProductValuationResponse>>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn fifo_issue_consumes_oldest_receipts_first() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    receive_at(&app, &fixture, 10, "2").await;
+    receive_at(&app, &fixture, 10, "3").await;
+
+    // Act
+    let issued = issue(&app, &fixture, 15).await;
+    let valuations = valuation(&app, &format!("product_id={}", fixture.product_id)).await;
+
+    // Assert
+    assert_eq!(issued.total_cost, Some(Decimal::from(35)));
+    assert_eq!(
+        issued.unit_cost,
+        Some(Decimal::from_str("2.333333").unwrap())
+    );
+
+    assert_eq!(valuations.len(), 1);
+    assert_eq!(valuations[0].costing_method, CostingMethod::Fifo);
+    assert_eq!(valuations[0].quantity, Decimal::from(5));
+    assert_eq!(valuations[0].value, Decimal::from(15));
+    assert_eq!(valuations[0].unit_cost, Some(Decimal::from(3)));
+}
+
+#[tokio::test]
+async fn weighted_average_issue_costs_the_average_on_hand() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            "/valuation/settings",
+            serde_json::json!({ "costing_method": "weighted_average" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    receive_at(&app, &fixture, 10, "2").await;
+    receive_at(&app, &fixture, 10, "3").await;
+
+    // Act
+    let issued = issue(&app, &fixture, 5).await;
+    let valuations = valuation(&app, &format!("product_id={}", fixture.product_id)).await;
+
+    // Assert
+    assert_eq!(issued.total_cost, Some(Decimal::from_str("12.5").unwrap()));
+    assert_eq!(issued.unit_cost, Some(Decimal::from_str("2.5").unwrap()));
+
+    assert_eq!(valuations[0].costing_method, CostingMethod::WeightedAverage);
+    assert_eq!(valuations[0].quantity, Decimal::from(15));
+    assert_eq!(valuations[0].value, Decimal::from_str("37.5").unwrap());
+}
+
+#[tokio::test]
+async fn product_costing_method_overrides_company_setting() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            &format!("/products/{}/costing-method", fixture.product_id),
+            serde_json::json!({ "costing_method": "weighted_average" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    receive_at(&app, &fixture, 10, "2").await;
+    receive_at(&app, &fixture, 10, "4").await;
+
+    // Act
+    let issued = issue(&app, &fixture, 10).await;
+
+    // Assert
+    assert_eq!(issued.total_cost, Some(Decimal::from(30)));
+}
+
+#[tokio::test]
+async fn valuation_as_of_ignores_later_movements() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    receive_at(&app, &fixture, 10, "2").await;
+    let as_of = Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true);
+    receive_at(&app, &fixture, 10, "3").await;
+    issue(&app, &fixture, 5).await;
+
+    // Act
+    let earlier = valuation(&app, &format!("as_of={as_of}")).await;
+    let now = valuation(&app, "").await;
+
+    // Assert
+    assert_eq!(earlier.len(), 1);
+    assert_eq!(earlier[0].quantity, Decimal::from(10));
+    assert_eq!(earlier[0].value, Decimal::from(20));
+
+    assert_eq!(now[0].quantity, Decimal::from(15));
+    assert_eq!(now[0].value, Decimal::from(40));
+}
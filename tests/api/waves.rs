@@ -0,0 +1,413 @@
+use crate::helpers::{StockFixture, TestApp, spawn_app};
+use chrono::{Duration, Utc};
+use pretty_assertions::assert_eq;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use warehouse::contract::error::ErrorCode;
+use warehouse::domain::{PickOrderStatus, PickTaskStatus, WaveStatus};
+use warehouse::dto::{
+    AppError, ConfirmPickResponse, LocationResponse, PickOrderResponse, ProductResponse,
+    StockLevelResponse, StockMovementResponse, WaveResponse,
+};
+
+/// Location at `x` metres from the floor plan origin, holding ten units of the product.
+async fn stocked_location(app: &TestApp<'_>, fixture: &StockFixture, code: &str, x: u32) -> Uuid {
+    let location_id = app
+        .post(
+            "/locations",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "code": code,
+                "position_x": x,
+                "position_y": 0,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<LocationResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+
+    let location = StockFixture {
+        location_id,
+        ..*fixture
+    };
+    app.receive(&location, 10).await;
+
+    location_id
+}
+
+/// Reserves stock for a new sales order and releases the order for picking.
+async fn release_order(
+    app: &TestApp<'_>,
+    fixture: &StockFixture,
+    location_id: Uuid,
+    quantity: u32,
+    release: serde_json::Value,
+) -> PickOrderResponse {
+    let document_id = Uuid::new_v4();
+    let response = app
+        .post(
+            "/reservations",
+            serde_json::json!({
+                "product_id": fixture.product_id,
+                "location_id": location_id,
+                "quantity": quantity,
+                "document_type": "sales_order",
+                "document_id": document_id,
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    let mut body = serde_json::json!({
+        "warehouse_id": fixture.warehouse_id,
+        "document_id": document_id,
+    });
+    body.as_object_mut()
+        .unwrap()
+        .extend(release.as_object().unwrap().clone());
+    let response = app
+        .post("/pick-orders", body)
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<PickOrderResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+async fn create_wave(app: &TestApp<'_>, body: serde_json::Value) -> WaveResponse {
+    let response = app
+        .post("/waves", body)
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    response
+        .json::<WaveResponse>()
+        .await
+        .expect("Failed to parse response.")
+}
+
+#[tokio::test]
+async fn cluster_wave_sequences_tasks_along_pick_path() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let far = stocked_location(&app, &fixture, "P-20", 20).await;
+    let near = stocked_location(&app, &fixture, "P-05", 5).await;
+    let routine = release_order(&app, &fixture, far, 2, serde_json::json!({})).await;
+    let urgent = release_order(
+        &app,
+        &fixture,
+        near,
+        3,
+        serde_json::json!({ "priority": 5 }),
+    )
+    .await;
+
+    // Act
+    let wave = create_wave(
+        &app,
+        serde_json::json!({ "warehouse_id": fixture.warehouse_id, "totes": 2 }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(wave.status, WaveStatus::Open);
+    assert_eq!(
+        wave.orders
+            .iter()
+            .map(|order| (order.id, order.tote, order.status))
+            .collect::<Vec<_>>(),
+        vec![
+            (urgent.id, Some(1), PickOrderStatus::Waved),
+            (routine.id, Some(2), PickOrderStatus::Waved),
+        ]
+    );
+    assert_eq!(
+        wave.tasks
+            .iter()
+            .map(|task| (task.sequence, task.location_id, task.tote, task.quantity))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, near, Some(1), Decimal::from(3)),
+            (2, far, Some(2), Decimal::from(2)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn wave_takes_only_orders_due_before_cutoff() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let location_id = stocked_location(&app, &fixture, "P-01", 1).await;
+    let now = Utc::now();
+    let due = release_order(
+        &app,
+        &fixture,
+        location_id,
+        1,
+        serde_json::json!({ "carrier": "DHL", "cutoff_at": now + Duration::hours(1) }),
+    )
+    .await;
+    let later = release_order(
+        &app,
+        &fixture,
+        location_id,
+        1,
+        serde_json::json!({ "carrier": "DHL", "cutoff_at": now + Duration::hours(8) }),
+    )
+    .await;
+
+    // Act
+    let wave = create_wave(
+        &app,
+        serde_json::json!({
+            "warehouse_id": fixture.warehouse_id,
+            "carrier": "DHL",
+            "cutoff_before": now + Duration::hours(2),
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(
+        wave.orders.iter().map(|order| order.id).collect::<Vec<_>>(),
+        vec![due.id]
+    );
+    assert_eq!(wave.tasks[0].tote, None);
+
+    let later = app
+        .get(&format!("/pick-orders/{}", later.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<PickOrderResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(later.status, PickOrderStatus::Released);
+}
+
+#[tokio::test]
+async fn picking_every_task_completes_wave() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let location_id = stocked_location(&app, &fixture, "P-01", 1).await;
+    let order = release_order(&app, &fixture, location_id, 4, serde_json::json!({})).await;
+    let wave = create_wave(
+        &app,
+        serde_json::json!({ "warehouse_id": fixture.warehouse_id }),
+    )
+    .await;
+
+    // Act
+    let response = app
+        .post(
+            &format!("/pick-tasks/{}/confirm", wave.tasks[0].id),
+            serde_json::json!({}),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 200);
+    let picked = response
+        .json::<ConfirmPickResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(picked.task.status, PickTaskStatus::Picked);
+    assert_eq!(picked.task.picked_quantity, Some(Decimal::from(4)));
+    assert_eq!(picked.movements.len(), 1);
+
+    let wave = app
+        .get(&format!("/waves/{}", wave.id))
+        .await
+        .expect("Failed to execute request.")
+        .json::<WaveResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(wave.status, WaveStatus::Completed);
+    assert_eq!(wave.orders[0].id, order.id);
+    assert_eq!(wave.orders[0].status, PickOrderStatus::Picked);
+
+    let levels = app
+        .get(&format!(
+            "/stock/levels?location_id={location_id}&product_id={}",
+            fixture.product_id
+        ))
+        .await
+        .expect("Failed to execute request.")
+        .json::<Vec<StockLevelResponse>>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(levels[0].on_hand, Decimal::from(6));
+    assert_eq!(levels[0].reserved, Decimal::ZERO);
+}
+
+#[tokio::test]
+async fn pick_takes_lot_from_scan() {
+    // Arrange
+    let app = spawn_app().await;
+    let mut fixture = app.create_stock_fixture().await;
+    fixture.product_id = app
+        .post(
+            "/products",
+            serde_json::json!({ "sku": "YOG-1", "name": "Yoghurt", "lot_tracked": true }),
+        )
+        .await
+        .expect("Failed to execute request.")
+        .json::<ProductResponse>()
+        .await
+        .expect("Failed to parse response.")
+        .id;
+    let mut lot_ids = Vec::new();
+    for lot_number in ["LOT-A", "LOT-B"] {
+        let response = app
+            .post(
+                "/stock/movements",
+                serde_json::json!({
+                    "kind": "receipt",
+                    "product_id": fixture.product_id,
+                    "to_location_id": fixture.location_id,
+                    "quantity": 5,
+                    "lot_number": lot_number,
+                }),
+            )
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 201);
+        let movements = response
+            .json::<Vec<StockMovementResponse>>()
+            .await
+            .expect("Failed to parse response.");
+        lot_ids.push(movements[0].lot_id);
+    }
+    release_order(
+        &app,
+        &fixture,
+        fixture.location_id,
+        2,
+        serde_json::json!({}),
+    )
+    .await;
+    let wave = create_wave(
+        &app,
+        serde_json::json!({ "warehouse_id": fixture.warehouse_id }),
+    )
+    .await;
+
+    // Act
+    let wrong_product = app
+        .post(
+            &format!("/pick-tasks/{}/confirm", wave.tasks[0].id),
+            serde_json::json!({ "scan": "(01)04006381333931(10)LOT-B" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    let response = app
+        .post(
+            &format!("/pick-tasks/{}/confirm", wave.tasks[0].id),
+            serde_json::json!({ "scan": "(10)LOT-B" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(wrong_product.status(), 400);
+    assert_eq!(response.status(), 200);
+    let picked = response
+        .json::<ConfirmPickResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(picked.movements.len(), 1);
+    assert_eq!(picked.movements[0].lot_id, lot_ids[1]);
+    assert_eq!(picked.movements[0].quantity, Decimal::from(2));
+}
+
+#[tokio::test]
+async fn pick_counts_cases_from_scan() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+    let response = app
+        .post(
+            &format!("/products/{}/uoms", fixture.product_id),
+            serde_json::json!({ "code": "case", "factor": 12, "barcode": "4006381333931" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    app.receive(&fixture, 36).await;
+    release_order(
+        &app,
+        &fixture,
+        fixture.location_id,
+        24,
+        serde_json::json!({}),
+    )
+    .await;
+    let wave = create_wave(
+        &app,
+        serde_json::json!({ "warehouse_id": fixture.warehouse_id }),
+    )
+    .await;
+    let path = format!("/pick-tasks/{}/confirm", wave.tasks[0].id);
+
+    // Act
+    let over_pick = app
+        .post(
+            &path,
+            serde_json::json!({ "quantity": 3, "scan": "(01)04006381333931" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+    let response = app
+        .post(
+            &path,
+            serde_json::json!({ "quantity": 2, "scan": "(01)04006381333931" }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(over_pick.status(), 400);
+    assert_eq!(response.status(), 200);
+    let picked = response
+        .json::<ConfirmPickResponse>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(picked.task.status, PickTaskStatus::Picked);
+    assert_eq!(picked.movements[0].quantity, Decimal::from(24));
+}
+
+#[tokio::test]
+async fn release_without_reservations_fails() {
+    // Arrange
+    let app = spawn_app().await;
+    let fixture = app.create_stock_fixture().await;
+
+    // Act
+    let response = app
+        .post(
+            "/pick-orders",
+            serde_json::json!({
+                "warehouse_id": fixture.warehouse_id,
+                "document_id": Uuid::new_v4(),
+            }),
+        )
+        .await
+        .expect("Failed to execute request.");
+
+    // Assert
+    assert_eq!(response.status(), 409);
+    let error = response
+        .json::<AppError>()
+        .await
+        .expect("Failed to parse response.");
+    assert_eq!(error.code, ErrorCode::InvalidState);
+}